@@ -0,0 +1,69 @@
+//! Parsing times for the bundled fixture corpus, and serde_json against simd-json
+//! on large synthetic webhook payloads.
+//!
+//! Run with `cargo bench --features simd-json,test-fixtures`.
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use github_events::builders::PushEventBuilder;
+use github_events::timeline::TimelineEvent;
+use github_events::{fixtures, Commit, Event, EventType, PullRequestRef};
+
+fn push() -> Vec<u8> {
+    let mut push = PushEventBuilder::new().payload();
+    push.commits = vec![Commit::sample(); 500];
+    push.size = Some(500);
+    serde_json::to_vec(&push).unwrap()
+}
+
+fn check_suite() -> Vec<u8> {
+    let mut check_suite = match Event::sample(EventType::CheckSuite).unwrap() {
+        Event::CheckSuiteEvent(e) => *e,
+        _ => unreachable!(),
+    };
+    check_suite.check_suite.pull_requests = vec![PullRequestRef::sample(); 100];
+    serde_json::to_vec(&check_suite).unwrap()
+}
+
+fn bench(c: &mut Criterion, name: &str, event_type: EventType, payload: Vec<u8>) {
+    let mut group = c.benchmark_group(name);
+    group.bench_function("serde_json", |b| {
+        b.iter(|| Event::from_slice(event_type, &payload).unwrap())
+    });
+    group.bench_function("simd-json", |b| {
+        b.iter_batched_ref(
+            || payload.clone(),
+            |payload| Event::from_slice_simd(event_type, payload).unwrap(),
+            BatchSize::SmallInput,
+        )
+    });
+    group.finish();
+}
+
+fn corpus(c: &mut Criterion) {
+    let mut group = c.benchmark_group("webhooks");
+    for fixture in fixtures::webhooks() {
+        group.bench_function(fixture.name, |b| {
+            b.iter(|| Event::from_slice(fixture.event_type, fixture.body).unwrap())
+        });
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group("timeline");
+    for fixture in fixtures::timeline() {
+        group.bench_function(fixture.name, |b| {
+            b.iter(|| serde_json::from_slice::<TimelineEvent>(fixture.body).unwrap())
+        });
+    }
+    let page = fixtures::timeline_page();
+    group.bench_function("page", |b| {
+        b.iter(|| serde_json::from_slice::<Vec<TimelineEvent>>(&page).unwrap())
+    });
+    group.finish();
+}
+
+fn parse(c: &mut Criterion) {
+    bench(c, "push", EventType::Push, push());
+    bench(c, "check_suite", EventType::CheckSuite, check_suite());
+}
+
+criterion_group!(benches, corpus, parse);
+criterion_main!(benches);
@@ -0,0 +1,126 @@
+use core::fmt;
+use core::str::FromStr;
+
+use crate::prelude::*;
+
+/// A dated version of the REST API, e.g. `2022-11-28`, sent in the `X-GitHub-Api-Version`
+/// header to pin the shapes of responses.
+///
+/// ```
+/// use github_events::ApiVersion;
+///
+/// let version: ApiVersion = "2022-11-28".parse().unwrap();
+/// assert_eq!(version, ApiVersion::V2022_11_28);
+/// assert!(version.is_known());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ApiVersion {
+    year: u16,
+    month: u8,
+    day: u8,
+}
+
+impl ApiVersion {
+    /// The request header naming the version a client wants.
+    pub const HEADER: &'static str = "X-GitHub-Api-Version";
+    /// The response header naming the version GitHub answered with.
+    pub const SELECTED_HEADER: &'static str = "X-GitHub-Api-Version-Selected";
+
+    /// The first dated version, and the one GitHub uses for requests that don't name one.
+    pub const V2022_11_28: ApiVersion = ApiVersion {
+        year: 2022,
+        month: 11,
+        day: 28,
+    };
+
+    /// The versions whose payloads this crate's types model, oldest first.
+    pub const KNOWN: &'static [ApiVersion] = &[ApiVersion::V2022_11_28];
+
+    /// The version of `year`-`month`-`day`, or `None` if that isn't a date.
+    pub fn new(year: u16, month: u8, day: u8) -> Option<Self> {
+        if (1..=12).contains(&month) && (1..=31).contains(&day) {
+            Some(ApiVersion { year, month, day })
+        } else {
+            None
+        }
+    }
+
+    /// Whether this crate's types model the payloads of this version.
+    pub fn is_known(self) -> bool {
+        ApiVersion::KNOWN.contains(&self)
+    }
+}
+
+impl Default for ApiVersion {
+    fn default() -> Self {
+        ApiVersion::V2022_11_28
+    }
+}
+
+impl fmt::Display for ApiVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// The error returned when parsing something that isn't a `YYYY-MM-DD` date.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseApiVersionError(pub String);
+
+impl fmt::Display for ParseApiVersionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "`{}` is not a REST API version like `2022-11-28`",
+            self.0
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseApiVersionError {}
+
+impl FromStr for ApiVersion {
+    type Err = ParseApiVersionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseApiVersionError(s.to_owned());
+        let mut parts = s.split('-');
+        let (year, month, day) = match (parts.next(), parts.next(), parts.next(), parts.next()) {
+            (Some(year), Some(month), Some(day), None)
+                if year.len() == 4 && month.len() == 2 && day.len() == 2 =>
+            {
+                (year, month, day)
+            }
+            _ => return Err(err()),
+        };
+        ApiVersion::new(
+            digits(year).ok_or_else(err)?,
+            digits(month).ok_or_else(err)?,
+            digits(day).ok_or_else(err)?,
+        )
+        .ok_or_else(err)
+    }
+}
+
+/// `part` as a number, if it's all digits, without the sign `parse` would accept.
+fn digits<T: FromStr>(part: &str) -> Option<T> {
+    if part.bytes().all(|b| b.is_ascii_digit()) {
+        part.parse().ok()
+    } else {
+        None
+    }
+}
+
+/// The error of parsing a payload of a version this crate doesn't model.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnknownApiVersion(pub ApiVersion);
+
+impl fmt::Display for UnknownApiVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "no payload types for REST API version {}", self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnknownApiVersion {}
@@ -0,0 +1,91 @@
+//! [Audit log](https://docs.github.com/en/organizations/keeping-your-organization-secure/managing-security-settings-for-your-organization/reviewing-the-audit-log-for-your-organization)
+//! types.
+//!
+//! Organizations and enterprises on GitHub Enterprise Cloud keep a log of what their members
+//! did, such as changing a repository's visibility or a member's role, which webhooks don't
+//! report. Each entry's `action` is a category and an operation, e.g. `repo.create` or
+//! `org.update_member`; the fields beyond the common ones depend on it and are kept in
+//! [`other`](struct.AuditLogEntry.html#structfield.other).
+use alloc::collections::BTreeMap;
+#[cfg(feature = "std")]
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::prelude::*;
+
+/// An entry in an organization's or enterprise's audit log.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[non_exhaustive]
+pub struct AuditLogEntry {
+    /// Unique identifier of the entry.
+    #[serde(rename = "_document_id")]
+    pub document_id: Option<String>,
+    /// When the entry was logged, in milliseconds since the epoch.
+    #[serde(rename = "@timestamp")]
+    pub timestamp: Option<i64>,
+    /// What was done, e.g. `repo.create`.
+    pub action: String,
+    /// The login of the user or app that did it.
+    pub actor: Option<String>,
+    pub actor_id: Option<i64>,
+    /// Where the actor was, judging by their IP address.
+    pub actor_location: Option<ActorLocation>,
+    /// When the action was performed, in milliseconds since the epoch.
+    pub created_at: Option<i64>,
+    /// The slug of the enterprise the organization belongs to.
+    pub business: Option<String>,
+    pub business_id: Option<i64>,
+    /// The login of the organization.
+    pub org: Option<String>,
+    pub org_id: Option<i64>,
+    /// The login of the user the action affected.
+    pub user: Option<String>,
+    pub user_id: Option<i64>,
+    /// The full name of the repository the action affected.
+    pub repo: Option<String>,
+    pub repo_id: Option<i64>,
+    /// `create`, `access`, `modify`, `remove`, `restore`, `transfer`, or `authentication`.
+    pub operation_type: Option<String>,
+    /// The fields specific to the action.
+    #[serde(flatten)]
+    pub other: BTreeMap<String, Value>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[non_exhaustive]
+pub struct ActorLocation {
+    /// An ISO 3166-1 alpha-2 code, e.g. `US`.
+    pub country_code: Option<String>,
+    pub country_name: Option<String>,
+    pub region: Option<String>,
+    pub city: Option<String>,
+}
+
+impl AuditLogEntry {
+    /// The part of the action before the `.`, e.g. `repo` for `repo.create`.
+    pub fn category(&self) -> &str {
+        self.action.split('.').next().unwrap_or_default()
+    }
+
+    /// The part of the action after the `.`, e.g. `create` for `repo.create`.
+    pub fn operation(&self) -> &str {
+        self.action
+            .split_once('.')
+            .map_or("", |(_, operation)| operation)
+    }
+
+    /// When the action was performed, or else when the entry was logged.
+    #[cfg(feature = "std")]
+    pub fn time(&self) -> Option<SystemTime> {
+        let millis = self.created_at.or(self.timestamp)?;
+        Some(if millis >= 0 {
+            UNIX_EPOCH + Duration::from_millis(millis as u64)
+        } else {
+            UNIX_EPOCH - Duration::from_millis(millis.unsigned_abs())
+        })
+    }
+}
@@ -0,0 +1,185 @@
+//! [GitHub App authentication](https://developer.github.com/apps/building-github-apps/authenticating-with-github-apps/).
+//!
+//! A GitHub App authenticates as itself with a short-lived JWT signed by its private key,
+//! which is only good for a few endpoints such as creating installation access tokens.
+//! Everything else, including polling events, is done with an installation access token,
+//! which expires after an hour.
+//!
+//! ```no_run
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use github_events::auth::AppAuth;
+//! use github_events::client::Client;
+//! use github_events::InstallationId;
+//!
+//! let app = AppAuth::new(42, &std::fs::read("private-key.pem")?)?;
+//! let client = Client::new().with_auth(app.installation(InstallationId::new(1337)));
+//! let events = client.repo_events("octocat", "Hello-World").into_stream();
+//! # Ok(())
+//! # }
+//! ```
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use jsonwebtoken::{Algorithm, EncodingKey, Header};
+use serde::{Deserialize, Serialize};
+
+use crate::client::backend::{BoxFuture, Method};
+use crate::client::{Authenticator, Client, ClientError};
+use crate::InstallationId;
+
+/// How long app JWTs are valid for. GitHub rejects JWTs that expire more than 10 minutes out.
+const JWT_LIFETIME: Duration = Duration::from_secs(9 * 60);
+/// JWTs are backdated to allow for clock drift between us and GitHub.
+const JWT_BACKDATE: Duration = Duration::from_secs(60);
+/// Cached installation tokens are renewed this long before they expire,
+/// so that requests in flight don't race the expiry.
+const TOKEN_EXPIRY_MARGIN: Duration = Duration::from_secs(5 * 60);
+
+/// Authenticates as a GitHub App using a JWT.
+#[derive(Clone)]
+pub struct AppAuth {
+    app_id: u64,
+    key: Arc<EncodingKey>,
+}
+
+impl std::fmt::Debug for AppAuth {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("AppAuth")
+            .field("app_id", &self.app_id)
+            .finish()
+    }
+}
+
+#[derive(Serialize)]
+struct Claims {
+    iat: u64,
+    exp: u64,
+    iss: String,
+}
+
+impl AppAuth {
+    /// `private_key` is the PEM encoded RSA private key generated in the app's settings.
+    pub fn new(app_id: u64, private_key: &[u8]) -> Result<Self, jsonwebtoken::errors::Error> {
+        Ok(AppAuth {
+            app_id,
+            key: Arc::new(EncodingKey::from_rsa_pem(private_key)?),
+        })
+    }
+
+    pub fn app_id(&self) -> u64 {
+        self.app_id
+    }
+
+    /// A freshly signed JWT identifying the app.
+    pub fn jwt(&self) -> Result<String, jsonwebtoken::errors::Error> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        let claims = Claims {
+            iat: (now - JWT_BACKDATE).as_secs(),
+            exp: (now + JWT_LIFETIME).as_secs(),
+            iss: self.app_id.to_string(),
+        };
+        jsonwebtoken::encode(&Header::new(Algorithm::RS256), &claims, &self.key)
+    }
+
+    /// Authenticate as the installation `installation_id` of this app.
+    pub fn installation(&self, installation_id: InstallationId) -> InstallationAuth {
+        InstallationAuth {
+            app: self.clone(),
+            installation_id,
+            token: Arc::new(Mutex::new(None)),
+        }
+    }
+}
+
+impl Authenticator for AppAuth {
+    fn authorization<'a>(&'a self, _: &'a Client) -> BoxFuture<'a, Result<String, ClientError>> {
+        let jwt = self
+            .jwt()
+            .map(|jwt| format!("Bearer {}", jwt))
+            .map_err(|e| ClientError::Auth(e.into()));
+        Box::pin(futures::future::ready(jwt))
+    }
+}
+
+/// An [installation access token](https://developer.github.com/v3/apps/#create-a-new-installation-token).
+#[derive(Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct InstallationToken {
+    pub token: String,
+    pub expires_at: SystemTime,
+}
+
+impl std::fmt::Debug for InstallationToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("InstallationToken")
+            .field("expires_at", &self.expires_at)
+            .finish()
+    }
+}
+
+#[derive(Deserialize)]
+struct AccessTokenResponse {
+    token: String,
+    expires_at: String,
+}
+
+/// Authenticates as an installation of a GitHub App,
+/// creating installation access tokens as needed and caching them until they expire.
+///
+/// Clones share the cached token.
+#[derive(Debug, Clone)]
+pub struct InstallationAuth {
+    app: AppAuth,
+    installation_id: InstallationId,
+    token: Arc<Mutex<Option<InstallationToken>>>,
+}
+
+impl InstallationAuth {
+    pub fn installation_id(&self) -> InstallationId {
+        self.installation_id
+    }
+
+    /// A valid installation access token, created with `client` if the cached one is about to expire.
+    pub async fn token(&self, client: &Client) -> Result<InstallationToken, ClientError> {
+        if let Some(token) = self.token.lock().unwrap().clone() {
+            if token.expires_at > SystemTime::now() + TOKEN_EXPIRY_MARGIN {
+                return Ok(token);
+            }
+        }
+
+        let app_client = client.authenticated_as(self.app.clone());
+        let url = app_client.url(&format!(
+            "/app/installations/{}/access_tokens",
+            self.installation_id
+        ));
+        let res = app_client
+            .send(|| app_client.request(Method::Post, &url))
+            .await?;
+        if !res.is_success() {
+            return Err(ClientError::Status(res.status));
+        }
+        let created: AccessTokenResponse = serde_json::from_slice(&res.body)?;
+        let expires_at = chrono::DateTime::parse_from_rfc3339(&created.expires_at)
+            .map_err(|e| ClientError::Auth(e.into()))?;
+        let token = InstallationToken {
+            token: created.token,
+            expires_at: UNIX_EPOCH + Duration::from_secs(expires_at.timestamp().max(0) as u64),
+        };
+        *self.token.lock().unwrap() = Some(token.clone());
+        Ok(token)
+    }
+}
+
+impl Authenticator for InstallationAuth {
+    fn authorization<'a>(
+        &'a self,
+        client: &'a Client,
+    ) -> BoxFuture<'a, Result<String, ClientError>> {
+        Box::pin(async move {
+            let token = self.token(client).await?;
+            Ok(format!("token {}", token.token))
+        })
+    }
+}
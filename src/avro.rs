@@ -0,0 +1,245 @@
+//! [Apache Avro](https://avro.apache.org/docs/1.11.1/specification/) schemas for webhook payloads,
+//! to keep Kafka producers and consumers in sync through a schema registry.
+//! Enabled by the `avro` feature.
+//!
+//! The schemas are derived from the payloads' JSON Schemas (see the `schemars` feature), so they
+//! follow the types exactly:
+//!
+//! - Fields GitHub may leave out or send as `null` are unions with `null`, defaulting to `null`.
+//! - Timestamps that are strings in some payloads and numbers in others are `["string", "long"]`.
+//! - Untyped JSON, such as a push's `head_commit`, is a `string` holding the JSON, as in the other
+//!   formats that aren't self-describing.
+//! - JSON keys that aren't valid Avro names have their other characters replaced by `_`, and are
+//!   numbered if that gives two fields of a record the same name, e.g. the `+1` and `-1` of
+//!   reactions become `_1` and `_1_2`.
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde_json::{json, Map, Value};
+
+use crate::{Event, EventType};
+
+/// The namespace of every named type in the schemas.
+pub const NAMESPACE: &str = "com.github";
+
+/// The schema of `event_type`'s payload, a record named after the payload type, e.g. `PushEvent`.
+///
+/// `None` if the event's family is turned off by a cargo feature.
+pub fn schema(event_type: EventType) -> Option<Value> {
+    let root = schemars::schema_for!(Event);
+    let root = root.as_value();
+    let variant = event_type.variant_name();
+    let payload = root["oneOf"]
+        .as_array()?
+        .iter()
+        .find_map(|tagged| tagged["properties"].get(variant))?;
+
+    let mut converter = Converter {
+        defs: root["$defs"].as_object()?,
+        names: BTreeMap::new(),
+        taken: BTreeSet::new(),
+    };
+    let mut schema = converter.convert(payload, variant);
+    schema["namespace"] = NAMESPACE.into();
+    Some(schema)
+}
+
+/// Converts JSON Schemas to Avro, defining each named type the first time it's used.
+struct Converter<'a> {
+    defs: &'a Map<String, Value>,
+    /// The Avro names of the `$defs` converted so far.
+    names: BTreeMap<&'a str, String>,
+    taken: BTreeSet<String>,
+}
+
+impl<'a> Converter<'a> {
+    /// `hint` names the type if it's a record or an enum not in `$defs`.
+    fn convert(&mut self, schema: &'a Value, hint: &str) -> Value {
+        if let Some(reference) = schema["$ref"].as_str() {
+            let def = reference.trim_start_matches("#/$defs/");
+            if let Some(name) = self.names.get(def) {
+                return name.as_str().into();
+            }
+            return match self.defs.get_key_value(def) {
+                Some((def, schema)) => self.convert(schema, def),
+                None => "string".into(),
+            };
+        }
+
+        let branches = schema["anyOf"]
+            .as_array()
+            .or_else(|| schema["oneOf"].as_array());
+        if let Some(branches) = branches {
+            let symbols: Option<Vec<&str>> = branches.iter().map(|b| b["const"].as_str()).collect();
+            return match symbols {
+                Some(symbols) => self.enumeration(hint, symbols),
+                None => union(branches.iter().map(|b| self.convert(b, hint)).collect()),
+            };
+        }
+
+        match &schema["type"] {
+            Value::Array(types) => union(
+                types
+                    .iter()
+                    .map(|ty| self.convert_type(schema, ty.as_str().unwrap_or_default(), hint))
+                    .collect(),
+            ),
+            Value::String(ty) => self.convert_type(schema, ty, hint),
+            // Untyped JSON.
+            _ => "string".into(),
+        }
+    }
+
+    fn convert_type(&mut self, schema: &'a Value, ty: &str, hint: &str) -> Value {
+        match ty {
+            "null" => "null".into(),
+            "boolean" => "boolean".into(),
+            "integer" => match schema["format"].as_str() {
+                Some("int8" | "int16" | "int32" | "uint8" | "uint16") => "int".into(),
+                _ => "long".into(),
+            },
+            "number" => "double".into(),
+            "string" => match schema["enum"].as_array() {
+                Some(symbols) => {
+                    let symbols = symbols.iter().filter_map(Value::as_str).collect();
+                    self.enumeration(hint, symbols)
+                }
+                None => "string".into(),
+            },
+            "array" => json!({
+                "type": "array",
+                "items": self.convert(&schema["items"], hint),
+            }),
+            "object" => match schema["properties"].as_object() {
+                Some(properties) => self.record(schema, properties, hint),
+                None if schema["additionalProperties"].is_object() => json!({
+                    "type": "map",
+                    "values": self.convert(&schema["additionalProperties"], hint),
+                }),
+                None => "string".into(),
+            },
+            _ => "string".into(),
+        }
+    }
+
+    fn record(
+        &mut self,
+        schema: &'a Value,
+        properties: &'a Map<String, Value>,
+        hint: &str,
+    ) -> Value {
+        let name = self.name(hint);
+        let required = schema["required"].as_array();
+        let mut fields = Vec::new();
+        let mut field_names = BTreeSet::new();
+        for (key, property) in properties {
+            let field_hint = format!("{}{}", name, camel_case(key));
+            let base = avro_name(key);
+            let mut field_name = base.clone();
+            let mut n = 1;
+            while !field_names.insert(field_name.clone()) {
+                n += 1;
+                field_name = format!("{}_{}", base, n);
+            }
+            let mut field = json!({
+                "name": field_name,
+                "type": self.convert(property, &field_hint),
+            });
+            let is_required = required.is_some_and(|r| r.iter().any(|r| r == key.as_str()));
+            if !is_required || is_nullable(&field["type"]) {
+                field["type"] = union(vec!["null".into(), field["type"].take()]);
+                field["default"] = Value::Null;
+            }
+            if let Some(doc) = property["description"].as_str() {
+                field["doc"] = doc.into();
+            }
+            fields.push(field);
+        }
+
+        let mut record = json!({ "type": "record", "name": name, "fields": fields });
+        if let Some(doc) = schema["description"].as_str() {
+            record["doc"] = doc.into();
+        }
+        record
+    }
+
+    fn enumeration(&mut self, hint: &str, symbols: Vec<&str>) -> Value {
+        if !symbols.iter().all(|s| avro_name(s) == *s) {
+            return "string".into();
+        }
+        json!({ "type": "enum", "name": self.name(hint), "symbols": symbols })
+    }
+
+    /// Claim a unique name for a named type, recording it if `hint` is one of the `$defs`.
+    fn name(&mut self, hint: &str) -> String {
+        let base = avro_name(hint);
+        let mut name = base.clone();
+        let mut n = 1;
+        while self.taken.contains(&name) {
+            n += 1;
+            name = format!("{}{}", base, n);
+        }
+        self.taken.insert(name.clone());
+        if let Some((def, _)) = self.defs.get_key_value(hint) {
+            self.names.insert(def, name.clone());
+        }
+        name
+    }
+}
+
+/// A union of `branches`, flattened and deduplicated, with `null` first so that it can default
+/// to `null`. A single branch is returned as is.
+fn union(branches: Vec<Value>) -> Value {
+    let mut flat = Vec::new();
+    for branch in branches {
+        match branch {
+            Value::Array(nested) => flat.extend(nested),
+            branch => flat.push(branch),
+        }
+    }
+    let mut union: Vec<Value> = Vec::new();
+    for branch in flat {
+        if !union.contains(&branch) {
+            union.push(branch);
+        }
+    }
+    if let Some(null) = union.iter().position(|b| b == "null") {
+        let null = union.remove(null);
+        union.insert(0, null);
+    }
+    if union.len() == 1 {
+        union.remove(0)
+    } else {
+        Value::Array(union)
+    }
+}
+
+fn is_nullable(schema: &Value) -> bool {
+    schema
+        .as_array()
+        .is_some_and(|union| union.contains(&"null".into()))
+}
+
+/// `name` as a valid Avro name: letters, digits, and `_`, not starting with a digit.
+fn avro_name(name: &str) -> String {
+    let mut avro: String = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    if !avro.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_') {
+        avro.insert(0, '_');
+    }
+    avro
+}
+
+fn camel_case(key: &str) -> String {
+    key.split('_')
+        .flat_map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase())
+                .into_iter()
+                .chain(chars)
+        })
+        .collect()
+}
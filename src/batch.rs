@@ -0,0 +1,150 @@
+//! Grouping events into batches, so that a burst of events makes one database write or chat
+//! message instead of one each. Enabled by the `batch` feature.
+//!
+//! A [`Batcher`] groups the events of a stream by repository and type, and yields each group
+//! once a window has passed since its first event.
+//!
+//! ```
+//! use std::time::Duration;
+//!
+//! use futures::executor::block_on;
+//! use futures::future;
+//! use futures::stream::{self, StreamExt};
+//! use github_events::batch::Batcher;
+//! use github_events::{Event, EventType};
+//!
+//! let push = Event::sample(EventType::Push).unwrap();
+//! let watch = Event::sample(EventType::Watch).unwrap();
+//! let events = stream::iter([push.clone(), watch.clone(), push.clone()]);
+//!
+//! // Wait with e.g. `|duration| Box::pin(tokio::time::sleep(duration))`.
+//! let batcher = Batcher::new(Duration::from_secs(10), |_| Box::pin(future::ready(())));
+//! let batches: Vec<_> = block_on(batcher.batch(events).collect());
+//! assert_eq!(batches, [vec![push.clone(), push], vec![watch]]);
+//! ```
+use std::fmt;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use futures::future::BoxFuture;
+use futures::stream::{BoxStream, Stream, StreamExt};
+
+pub use crate::wait::SleepFn;
+use crate::wait::{self, Window};
+use crate::{Event, EventType};
+
+/// Groups events by repository and type into batches of those that arrive within a window of
+/// the first.
+#[derive(Clone)]
+pub struct Batcher {
+    window: Duration,
+    max_size: usize,
+    sleep: Arc<SleepFn>,
+}
+
+/// A batch whose window hasn't closed yet.
+struct Open {
+    key: (Option<String>, EventType),
+    events: Vec<Event>,
+    started: Instant,
+}
+
+/// The batches of a [`Batcher`] whose windows haven't closed yet, ordered by when each started.
+struct Batches {
+    window: Duration,
+    max_size: usize,
+    open: Vec<Open>,
+}
+
+impl Window for Batches {
+    type Item = Event;
+    type Output = Vec<Event>;
+
+    fn push(&mut self, event: Event) -> Option<Vec<Event>> {
+        let key = (
+            event.repository().map(|r| r.full_name.to_lowercase()),
+            event.event_type(),
+        );
+        let at = match self.open.iter().position(|batch| batch.key == key) {
+            Some(at) => {
+                self.open[at].events.push(event);
+                at
+            }
+            None => {
+                self.open.push(Open {
+                    key,
+                    events: vec![event],
+                    started: Instant::now(),
+                });
+                self.open.len() - 1
+            }
+        };
+        if self.open[at].events.len() >= self.max_size {
+            return Some(self.open.remove(at).events);
+        }
+        None
+    }
+
+    fn due(&self) -> Option<Instant> {
+        self.open.first().map(|oldest| oldest.started + self.window)
+    }
+
+    fn release(&mut self) -> Option<Vec<Event>> {
+        if self.open.is_empty() {
+            return None;
+        }
+        Some(self.open.remove(0).events)
+    }
+}
+
+impl Batcher {
+    /// Batch the events that arrive within `window` of the first of their batch, waiting with
+    /// `sleep`.
+    pub fn new(
+        window: Duration,
+        sleep: impl Fn(Duration) -> BoxFuture<'static, ()> + Send + Sync + 'static,
+    ) -> Self {
+        Batcher {
+            window,
+            max_size: usize::MAX,
+            sleep: Arc::new(sleep),
+        }
+    }
+
+    /// Yield a batch as soon as it has `max_size` events, without waiting for its window to
+    /// close.
+    pub fn with_max_size(mut self, max_size: usize) -> Self {
+        self.max_size = max_size.max(1);
+        self
+    }
+
+    /// How long a batch stays open after its first event.
+    pub fn window(&self) -> Duration {
+        self.window
+    }
+
+    /// Batch `events`. Events outside a repository are batched by type alone.
+    ///
+    /// Batches are yielded as their windows close, and keep their events in the order they
+    /// came. When `events` ends, the batches still open are yielded right away, oldest first.
+    pub fn batch(
+        &self,
+        events: impl Stream<Item = Event> + Send + 'static,
+    ) -> BoxStream<'static, Vec<Event>> {
+        let batches = Batches {
+            window: self.window,
+            max_size: self.max_size,
+            open: Vec::new(),
+        };
+        wait::windowed(events.boxed(), batches, self.sleep.clone())
+    }
+}
+
+impl fmt::Debug for Batcher {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Batcher")
+            .field("window", &self.window)
+            .field("max_size", &self.max_size)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,353 @@
+//! A webhook receiver for developing against this crate, built with the `dev-server` feature.
+//!
+//! It verifies each delivery's signature, prints the event as this crate parsed it, and
+//! optionally forwards the delivery to a local target. Fixtures can be replayed through the same
+//! path instead of listening.
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::process;
+use std::str::FromStr;
+use std::{env, fmt::Write as _};
+
+use futures::executor::block_on;
+use github_events::client::backend::{HttpBackend, Method, Request, Ureq};
+use github_events::{fixtures, hooks, signature, Event, EventType};
+use serde_json::Value;
+
+const USAGE: &str = "\
+usage: github-events-dev-server [options]
+
+options:
+    --listen <addr>     Receive deliveries on <addr> (default: 127.0.0.1:3000).
+    --secret <secret>   Reject deliveries not signed with <secret>, and sign replays with it
+                        (default: $GITHUB_WEBHOOK_SECRET).
+    --forward <url>     Pass deliveries on to <url>, with their original headers.
+    --replay <name>     Handle the fixture <name>, e.g. `issues.opened`, or every fixture with
+                        `all`, instead of listening.
+    --no-color          Print without colors, as when $NO_COLOR is set.";
+
+/// Bodies larger than GitHub sends are refused.
+const MAX_BODY: usize = 25 * 1024 * 1024;
+
+struct Options {
+    listen: String,
+    secret: Option<String>,
+    forward: Option<String>,
+    replay: Option<String>,
+    color: bool,
+}
+
+/// A request received, or a fixture to replay.
+struct Delivery {
+    headers: Vec<(String, String)>,
+    body: Vec<u8>,
+}
+
+impl Delivery {
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+fn main() {
+    let options = match parse_args(env::args().skip(1)) {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, USAGE);
+            process::exit(2);
+        }
+    };
+    let result = match &options.replay {
+        Some(name) => replay(&options, name),
+        None => listen(&options),
+    };
+    if let Err(e) = result {
+        eprintln!("error: {}", e);
+        process::exit(1);
+    }
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let mut options = Options {
+        listen: "127.0.0.1:3000".to_owned(),
+        secret: env::var("GITHUB_WEBHOOK_SECRET").ok(),
+        forward: None,
+        replay: None,
+        color: env::var_os("NO_COLOR").is_none(),
+    };
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or_else(|| format!("{} needs a value", arg));
+        match arg.as_str() {
+            "--listen" => options.listen = value()?,
+            "--secret" => options.secret = Some(value()?),
+            "--forward" => options.forward = Some(value()?),
+            "--replay" => options.replay = Some(value()?),
+            "--no-color" => options.color = false,
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                process::exit(0);
+            }
+            _ => return Err(format!("unknown argument `{}`", arg)),
+        }
+    }
+    Ok(options)
+}
+
+fn listen(options: &Options) -> Result<(), String> {
+    let listener = TcpListener::bind(&options.listen).map_err(|e| e.to_string())?;
+    println!("listening on http://{}", options.listen);
+    if options.secret.is_none() {
+        println!("no secret given; signatures won't be checked");
+    }
+    for stream in listener.incoming() {
+        let mut stream = match stream {
+            Ok(stream) => stream,
+            Err(e) => {
+                eprintln!("error: {}", e);
+                continue;
+            }
+        };
+        let (status, reason) = match read_request(&mut stream) {
+            Ok(delivery) => handle(options, &delivery),
+            Err(e) => {
+                eprintln!("error: {}", e);
+                (400, "Bad Request")
+            }
+        };
+        let _ = write!(
+            stream,
+            "HTTP/1.1 {} {}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+            status, reason
+        );
+    }
+    Ok(())
+}
+
+fn replay(options: &Options, name: &str) -> Result<(), String> {
+    let chosen: Vec<_> = fixtures::webhooks()
+        .iter()
+        .filter(|fixture| name == "all" || fixture.name == name)
+        .collect();
+    if chosen.is_empty() {
+        let names: Vec<_> = fixtures::webhooks().iter().map(|f| f.name).collect();
+        return Err(format!(
+            "no fixture `{}`; there are {}",
+            name,
+            names.join(", ")
+        ));
+    }
+    for (i, fixture) in chosen.into_iter().enumerate() {
+        let mut headers = vec![
+            ("Content-Type".to_owned(), "application/json".to_owned()),
+            (
+                "X-GitHub-Event".to_owned(),
+                fixture.event_type.as_str().to_owned(),
+            ),
+            (
+                "X-GitHub-Delivery".to_owned(),
+                format!("fixture-{}-{}", fixture.name, i),
+            ),
+        ];
+        if let Some(secret) = &options.secret {
+            headers.push((
+                signature::HEADER.to_owned(),
+                signature::sign(secret, fixture.body),
+            ));
+        }
+        handle(
+            options,
+            &Delivery {
+                headers,
+                body: fixture.body.to_vec(),
+            },
+        );
+    }
+    Ok(())
+}
+
+/// Print and forward `delivery`, returning the status to answer it with.
+fn handle(options: &Options, delivery: &Delivery) -> (u16, &'static str) {
+    let paint = Paint(options.color);
+    let event_name = delivery.header("X-GitHub-Event").unwrap_or_default();
+    let guid = delivery
+        .header("X-GitHub-Delivery")
+        .unwrap_or("no delivery GUID");
+    println!(
+        "\n{} {}",
+        paint.bold(&format!("▶ {}", event_name)),
+        paint.dim(guid)
+    );
+
+    let body = match hooks::decompress(delivery.header("Content-Encoding"), &delivery.body) {
+        Ok(body) => body,
+        Err(e) => {
+            println!("{}", paint.red(&format!("✗ doesn't decompress: {}", e)));
+            return (400, "Bad Request");
+        }
+    };
+
+    if let Some(secret) = &options.secret {
+        match delivery.header(signature::HEADER) {
+            Some(sig) if signature::verify(secret, &body, sig) => {
+                println!("{}", paint.green("✓ signature verified"))
+            }
+            Some(_) => {
+                println!("{}", paint.red("✗ signature doesn't match the secret"));
+                return (401, "Unauthorized");
+            }
+            None => {
+                println!("{}", paint.red("✗ unsigned"));
+                return (401, "Unauthorized");
+            }
+        }
+    }
+
+    let status = match EventType::from_str(event_name) {
+        Ok(event_type) => {
+            match Event::from_body(event_type, delivery.header("Content-Type"), &body) {
+                Ok(event) => {
+                    println!("{}", paint.bold(&event.summary()));
+                    let payload = match serde_json::to_value(&event) {
+                        Ok(Value::Object(tagged)) => tagged.into_iter().next().map(|(_, p)| p),
+                        _ => None,
+                    }
+                    .unwrap_or(Value::Null);
+                    let mut out = String::new();
+                    paint.json(&mut out, &payload, 0);
+                    println!("{}", out);
+                    (204, "No Content")
+                }
+                Err(e) => {
+                    println!("{}", paint.red(&format!("✗ doesn't parse: {}", e)));
+                    (400, "Bad Request")
+                }
+            }
+        }
+        Err(_) => {
+            println!("{}", paint.red("✗ unknown or missing X-GitHub-Event"));
+            (400, "Bad Request")
+        }
+    };
+
+    if let Some(url) = &options.forward {
+        let mut request = Request::new(Method::Post, url.as_str()).body(body.into_owned());
+        for (name, value) in &delivery.headers {
+            if name.eq_ignore_ascii_case("content-type")
+                || name.to_ascii_lowercase().starts_with("x-")
+            {
+                request = request.header(name.as_str(), value.as_str());
+            }
+        }
+        match block_on(Ureq::default().send(request)) {
+            Ok(response) => {
+                let line = format!("→ {} answered {}", url, response.status);
+                if response.is_success() {
+                    println!("{}", paint.green(&line));
+                } else {
+                    println!("{}", paint.red(&line));
+                }
+            }
+            Err(e) => println!("{}", paint.red(&format!("→ {} failed: {}", url, e))),
+        }
+    }
+    status
+}
+
+/// Reads an HTTP/1.1 request, which must have a `Content-Length`.
+fn read_request(stream: &mut TcpStream) -> Result<Delivery, String> {
+    let mut reader = BufReader::new(stream);
+    let mut line = String::new();
+    reader.read_line(&mut line).map_err(|e| e.to_string())?;
+    if !line.starts_with("POST ") {
+        return Err(format!("expected a POST, got `{}`", line.trim_end()));
+    }
+    let mut headers = Vec::new();
+    loop {
+        line.clear();
+        reader.read_line(&mut line).map_err(|e| e.to_string())?;
+        let header = line.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            headers.push((name.trim().to_owned(), value.trim().to_owned()));
+        }
+    }
+    let delivery = Delivery {
+        headers,
+        body: Vec::new(),
+    };
+    let length: usize = delivery
+        .header("Content-Length")
+        .and_then(|length| length.parse().ok())
+        .filter(|&length| length <= MAX_BODY)
+        .ok_or("missing or too large Content-Length")?;
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body).map_err(|e| e.to_string())?;
+    Ok(Delivery { body, ..delivery })
+}
+
+/// ANSI colors, if enabled.
+#[derive(Clone, Copy)]
+struct Paint(bool);
+
+impl Paint {
+    fn paint(self, code: &str, text: &str) -> String {
+        if self.0 {
+            format!("\x1b[{}m{}\x1b[0m", code, text)
+        } else {
+            text.to_owned()
+        }
+    }
+
+    fn bold(self, text: &str) -> String {
+        self.paint("1", text)
+    }
+
+    fn dim(self, text: &str) -> String {
+        self.paint("2", text)
+    }
+
+    fn red(self, text: &str) -> String {
+        self.paint("31", text)
+    }
+
+    fn green(self, text: &str) -> String {
+        self.paint("32", text)
+    }
+
+    /// Pretty-prints `value` with keys, strings, numbers, booleans, and nulls each colored
+    /// differently.
+    fn json(self, out: &mut String, value: &Value, indent: usize) {
+        let pad = "  ".repeat(indent + 1);
+        match value {
+            Value::Object(map) if !map.is_empty() => {
+                out.push_str("{\n");
+                for (i, (key, value)) in map.iter().enumerate() {
+                    let key = Value::String(key.clone()).to_string();
+                    let _ = write!(out, "{}{}: ", pad, self.paint("34", &key));
+                    self.json(out, value, indent + 1);
+                    out.push_str(if i + 1 < map.len() { ",\n" } else { "\n" });
+                }
+                let _ = write!(out, "{}}}", "  ".repeat(indent));
+            }
+            Value::Array(items) if !items.is_empty() => {
+                out.push_str("[\n");
+                for (i, item) in items.iter().enumerate() {
+                    out.push_str(&pad);
+                    self.json(out, item, indent + 1);
+                    out.push_str(if i + 1 < items.len() { ",\n" } else { "\n" });
+                }
+                let _ = write!(out, "{}]", "  ".repeat(indent));
+            }
+            Value::String(_) => out.push_str(&self.green(&value.to_string())),
+            Value::Number(_) => out.push_str(&self.paint("36", &value.to_string())),
+            Value::Bool(_) => out.push_str(&self.paint("33", &value.to_string())),
+            Value::Null => out.push_str(&self.dim("null")),
+            _ => out.push_str(&value.to_string()),
+        }
+    }
+}
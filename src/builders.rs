@@ -0,0 +1,422 @@
+//! Builders for event payloads, for unit testing code that handles events.
+//!
+//! Every builder starts from the [`sample`](../enum.Event.html#method.sample) of its event, in
+//! `octocat/Hello-World` and sent by `octocat`, so a test only has to set the fields it looks at.
+//!
+//! ```
+//! use github_events::builders::PushEventBuilder;
+//! use github_events::Event;
+//!
+//! let event = PushEventBuilder::new()
+//!     .branch("release")
+//!     .commit("6dcb09b5b57875f334f61aebed695e2e4193db5e", "Bump version")
+//!     .build();
+//! match event {
+//!     Event::PushEvent(push) => assert_eq!(push.ref_field, "refs/heads/release"),
+//!     _ => unreachable!(),
+//! }
+//! ```
+use serde_json::Value;
+
+use crate::prelude::*;
+use crate::sample::{self, parse};
+#[cfg(feature = "issues")]
+use crate::{actions, IssueCommentEvent, IssueEvent, Label, PullRequestEvent};
+use crate::{Commit, Event, EventType, GitRef, PushEvent, Repository, Sender, Sha, User};
+
+const NULL_SHA: &str = "0000000000000000000000000000000000000000";
+
+/// A user as it appears in payloads, e.g. as the author of an issue.
+pub fn user(login: &str) -> User {
+    parse(sample::user(login, 1))
+}
+
+/// The `sender` of an event.
+pub fn sender(login: &str) -> Sender {
+    parse(sample::user(login, 1))
+}
+
+/// A public repository with a `master` default branch.
+pub fn repository(owner: &str, name: &str) -> Repository {
+    parse(sample::repository_named(owner, 1, name, 1))
+}
+
+/// `s` as a [`Sha`](../struct.Sha.html). Payloads with anything else don't parse, so a typo in a
+/// test is better caught right away.
+fn sha(s: &str) -> Sha {
+    s.parse().unwrap()
+}
+
+/// The webhook body of the sample event of type `event_type`, as the payload type `T`.
+fn sample_payload<T: serde::de::DeserializeOwned>(event_type: EventType) -> T {
+    parse(sample::payload(event_type))
+}
+
+/// Setters shared by every builder.
+macro_rules! common_setters {
+    () => {
+        /// The repository the event happened in.
+        pub fn repository(mut self, owner: &str, name: &str) -> Self {
+            self.0.repository = repository(owner, name);
+            self.relink();
+            self
+        }
+
+        /// The user who triggered the event.
+        pub fn sender(mut self, login: &str) -> Self {
+            self.0.sender = sender(login);
+            self
+        }
+    };
+}
+
+/// Builds a [`PushEvent`](../struct.PushEvent.html).
+///
+/// Defaults to pushing no commits to `master`.
+#[derive(Debug, Clone)]
+pub struct PushEventBuilder(PushEvent);
+
+impl PushEventBuilder {
+    pub fn new() -> Self {
+        let mut push: PushEvent = sample_payload(EventType::Push);
+        push.ref_field = GitRef::branch("master");
+        push.before = sha(NULL_SHA);
+        push.after = sha(NULL_SHA);
+        push.head = None;
+        push.size = None;
+        push.commits = Vec::new();
+        push.head_commit = Value::Null;
+        let mut builder = PushEventBuilder(push);
+        builder.relink();
+        builder
+    }
+
+    common_setters!();
+
+    /// Push to a branch, e.g. `main`.
+    pub fn branch(mut self, branch: &str) -> Self {
+        self.0.ref_field = GitRef::branch(branch);
+        self
+    }
+
+    /// Push a tag, e.g. `v1.0.0`.
+    pub fn tag(mut self, tag: &str) -> Self {
+        self.0.ref_field = GitRef::tag(tag);
+        self
+    }
+
+    /// The full ref pushed, e.g. `refs/heads/main`.
+    pub fn git_ref(mut self, git_ref: &str) -> Self {
+        self.0.ref_field = git_ref.into();
+        self
+    }
+
+    /// The SHA the ref pointed to before the push.
+    ///
+    /// Panics if `before` isn't a SHA.
+    pub fn before(mut self, before: &str) -> Self {
+        self.0.before = sha(before);
+        self.relink();
+        self
+    }
+
+    /// Append a commit, which becomes the new head of the ref.
+    ///
+    /// Panics if `commit_sha` isn't a SHA.
+    pub fn commit(mut self, commit_sha: &str, message: &str) -> Self {
+        let commit_sha = sha(commit_sha);
+        let mut commit = Commit::sample();
+        commit.sha = commit_sha.clone();
+        commit.message = message.to_owned();
+        self.0.commits.push(commit);
+        self.0.after = commit_sha.clone();
+        self.0.head = Some(commit_sha);
+        self.0.size = Some(self.0.commits.len() as isize);
+        self.relink();
+        self
+    }
+
+    fn relink(&mut self) {
+        let push = &mut self.0;
+        for commit in &mut push.commits {
+            commit.url = format!("{}/commits/{}", push.repository.url, commit.sha);
+        }
+        push.compare = format!(
+            "{}/compare/{}...{}",
+            push.repository.html_url,
+            abbreviate(&push.before),
+            abbreviate(&push.after)
+        );
+    }
+
+    /// Whether the ref was created by the push.
+    pub fn created(mut self, created: bool) -> Self {
+        self.0.created = created;
+        self
+    }
+
+    /// Whether the ref was deleted by the push.
+    pub fn deleted(mut self, deleted: bool) -> Self {
+        self.0.deleted = deleted;
+        self
+    }
+
+    /// Whether the push was a force push.
+    pub fn forced(mut self, forced: bool) -> Self {
+        self.0.forced = forced;
+        self
+    }
+
+    pub fn payload(self) -> PushEvent {
+        self.0
+    }
+
+    pub fn build(self) -> Event {
+        Event::PushEvent(Box::new(self.0))
+    }
+}
+
+/// The first 12 digits of `sha`, as in compare urls.
+fn abbreviate(sha: &str) -> &str {
+    sha.get(..12).unwrap_or(sha)
+}
+
+impl Default for PushEventBuilder {
+    fn default() -> Self {
+        PushEventBuilder::new()
+    }
+}
+
+/// Builds a [`PullRequestEvent`](../struct.PullRequestEvent.html).
+///
+/// Defaults to an open pull request #1 from `octocat`, merging `patch-1` into `master`.
+#[cfg(feature = "issues")]
+#[derive(Debug, Clone)]
+pub struct PullRequestEventBuilder(PullRequestEvent);
+
+#[cfg(feature = "issues")]
+impl PullRequestEventBuilder {
+    /// A pull request event with the given `action`, e.g. `opened`.
+    pub fn new(action: &str) -> Self {
+        let mut event: PullRequestEvent = sample_payload(EventType::PullRequest);
+        event.action = action.to_owned();
+        let sha = event.pull_request.head.sha.clone();
+        PullRequestEventBuilder(event)
+            .head("patch-1", &sha)
+            .base("master")
+            .number(1)
+    }
+
+    common_setters!();
+
+    pub fn number(mut self, number: i64) -> Self {
+        self.0.number = number;
+        self.0.pull_request.number = number;
+        self.relink();
+        self
+    }
+
+    fn relink(&mut self) {
+        let (repository, pull_request) = (&self.0.repository, &mut self.0.pull_request);
+        let html_url = format!("{}/pull/{}", repository.html_url, pull_request.number);
+        pull_request.url = format!("{}/pulls/{}", repository.url, pull_request.number);
+        pull_request.diff_url = format!("{}.diff", html_url);
+        pull_request.patch_url = format!("{}.patch", html_url);
+        pull_request.html_url = html_url;
+        pull_request.head.repo = repository.clone();
+        pull_request.base.label =
+            format!("{}:{}", repository.owner.login, pull_request.base.ref_field);
+    }
+
+    pub fn title(mut self, title: &str) -> Self {
+        self.0.pull_request.title = title.to_owned();
+        self
+    }
+
+    pub fn body(mut self, body: &str) -> Self {
+        self.0.pull_request.body = Some(body.to_owned());
+        self
+    }
+
+    /// The user who opened the pull request.
+    pub fn author(mut self, login: &str) -> Self {
+        self.0.pull_request.user = user(login);
+        self
+    }
+
+    /// The branch being merged, and its head commit.
+    ///
+    /// Panics if `head_sha` isn't a SHA.
+    pub fn head(mut self, branch: &str, head_sha: &str) -> Self {
+        let head = &mut self.0.pull_request.head;
+        head.label = format!("{}:{}", head.user.login, branch);
+        head.ref_field = branch.to_owned();
+        head.sha = sha(head_sha);
+        self
+    }
+
+    /// The branch being merged into.
+    pub fn base(mut self, branch: &str) -> Self {
+        self.0.pull_request.base.ref_field = branch.to_owned();
+        self.relink();
+        self
+    }
+
+    /// Close the pull request, merging it if `merged`.
+    pub fn closed(mut self, merged: bool) -> Self {
+        let pull_request = &mut self.0.pull_request;
+        pull_request.state = "closed".to_owned();
+        pull_request.merged = merged;
+        self
+    }
+
+    /// Make the pull request a draft, or not.
+    pub fn draft(mut self, draft: bool) -> Self {
+        self.0.pull_request.draft = draft;
+        self
+    }
+
+    pub fn payload(self) -> PullRequestEvent {
+        self.0
+    }
+
+    pub fn build(self) -> Event {
+        Event::PullRequestEvent(Box::new(self.0))
+    }
+}
+
+/// Builds an [`IssueEvent`](../struct.IssueEvent.html).
+///
+/// Defaults to an open issue #1 from `octocat`.
+#[cfg(feature = "issues")]
+#[derive(Debug, Clone)]
+pub struct IssueEventBuilder(IssueEvent);
+
+#[cfg(feature = "issues")]
+impl IssueEventBuilder {
+    /// An issues event with the given `action`, e.g. `opened`.
+    pub fn new(action: &str) -> Self {
+        let mut event: IssueEvent = sample_payload(EventType::Issues);
+        event.action = action.to_owned();
+        event.issue.labels = Vec::new();
+        IssueEventBuilder(event).number(1)
+    }
+
+    common_setters!();
+
+    pub fn number(mut self, number: i64) -> Self {
+        self.0.issue.number = number;
+        self.relink();
+        self
+    }
+
+    fn relink(&mut self) {
+        link_issue(&mut self.0.issue, &self.0.repository);
+        for label in &mut self.0.issue.labels {
+            label.url = format!("{}/labels/{}", self.0.repository.url, label.name);
+        }
+    }
+
+    pub fn title(mut self, title: &str) -> Self {
+        self.0.issue.title = title.to_owned();
+        self
+    }
+
+    pub fn body(mut self, body: &str) -> Self {
+        self.0.issue.body = Some(body.to_owned());
+        self
+    }
+
+    /// The user who opened the issue.
+    pub fn author(mut self, login: &str) -> Self {
+        self.0.issue.user = user(login);
+        self
+    }
+
+    /// Add a label to the issue.
+    pub fn label(mut self, name: &str) -> Self {
+        let mut label = Label::sample();
+        label.name = name.to_owned();
+        label.url = format!("{}/labels/{}", self.0.repository.url, name);
+        label.color = "ededed".to_owned();
+        label.default = false;
+        self.0.issue.labels.push(label);
+        self
+    }
+
+    pub fn closed(mut self) -> Self {
+        self.0.issue.state = "closed".to_owned();
+        self
+    }
+
+    pub fn payload(self) -> IssueEvent {
+        self.0
+    }
+
+    pub fn build(self) -> Event {
+        Event::IssueEvent(Box::new(self.0))
+    }
+}
+
+/// Builds an [`IssueCommentEvent`](../struct.IssueCommentEvent.html).
+///
+/// Defaults to a comment from `octocat` on issue #1.
+#[cfg(feature = "issues")]
+#[derive(Debug, Clone)]
+pub struct IssueCommentEventBuilder(IssueCommentEvent);
+
+#[cfg(feature = "issues")]
+impl IssueCommentEventBuilder {
+    pub fn new(action: actions::CrEdDel) -> Self {
+        let mut event: IssueCommentEvent = sample_payload(EventType::IssueComment);
+        event.action = action;
+        event.issue.labels = Vec::new();
+        IssueCommentEventBuilder(event).number(1)
+    }
+
+    common_setters!();
+
+    /// The number of the issue commented on.
+    pub fn number(mut self, number: i64) -> Self {
+        self.0.issue.number = number;
+        self.relink();
+        self
+    }
+
+    fn relink(&mut self) {
+        link_issue(&mut self.0.issue, &self.0.repository);
+        let comment = &mut self.0.comment;
+        comment.url = format!("{}/comments/{}", self.0.issue.url, comment.id);
+        comment.html_url = format!("{}#issuecomment-{}", self.0.issue.html_url, comment.id);
+    }
+
+    /// The text of the comment.
+    pub fn body(mut self, body: &str) -> Self {
+        self.0.comment.body = body.to_owned();
+        self
+    }
+
+    /// The user who wrote the comment.
+    pub fn author(mut self, login: &str) -> Self {
+        self.0.comment.user = user(login);
+        self
+    }
+
+    pub fn payload(self) -> IssueCommentEvent {
+        self.0
+    }
+
+    pub fn build(self) -> Event {
+        Event::IssueCommentEvent(Box::new(self.0))
+    }
+}
+
+#[cfg(feature = "issues")]
+fn link_issue(issue: &mut crate::Issue, repository: &Repository) {
+    issue.repository_url = repository.url.to_string();
+    issue.url = format!("{}/issues/{}", repository.url, issue.number);
+    issue.html_url = format!("{}/issues/{}", repository.html_url, issue.number);
+    issue.comments_url = format!("{}/comments", issue.url);
+    issue.events_url = format!("{}/events", issue.url);
+    issue.labels_url = format!("{}/labels{{/name}}", issue.url);
+}
@@ -0,0 +1,196 @@
+//! What chat notifications show of an event, whatever the chat.
+use serde_json::Value;
+
+use crate::{summary, Event, EventType};
+
+/// How much of a body notifications quote.
+const MAX_BODY: usize = 500;
+/// How many of a push's commits notifications list.
+const MAX_COMMITS: usize = 5;
+
+const GREEN: u32 = 0x28a745;
+const RED: u32 = 0xd73a49;
+const PURPLE: u32 = 0x6f42c1;
+const BLUE: u32 = 0x0366d6;
+const YELLOW: u32 = 0xdbab09;
+const GREY: u32 = 0x6a737d;
+
+pub(crate) struct Card {
+    /// The event's [summary](../struct.Event.html#method.summary).
+    pub title: String,
+    /// The pull request, issue, comment, commit, or other page the event is about.
+    pub url: Option<String>,
+    pub author: Option<Author>,
+    /// Green for openings and successes, red for closings and failures, purple for merges,
+    /// yellow for pending checks, blue for pushes, and grey for everything else.
+    pub color: u32,
+    /// The start of the issue's, pull request's, comment's, or release's body.
+    pub body: Option<String>,
+    /// The first commits of a push.
+    pub commits: Vec<CardCommit>,
+    /// How many commits of a push aren't in `commits`.
+    pub more_commits: usize,
+    /// The repository's full name and url.
+    pub repository: Option<(String, String)>,
+}
+
+pub(crate) struct Author {
+    pub login: String,
+    pub url: String,
+    pub avatar_url: String,
+}
+
+pub(crate) struct CardCommit {
+    /// Abbreviated to 7 characters.
+    pub sha: String,
+    pub url: String,
+    /// The first line of the message.
+    pub message: String,
+}
+
+impl Card {
+    pub fn new(event: &Event) -> Self {
+        let p = match ::serde_json::to_value(event) {
+            Ok(Value::Object(tagged)) => tagged.into_iter().next().map(|(_, payload)| payload),
+            _ => None,
+        }
+        .unwrap_or(Value::Null);
+        let event_type = event.event_type();
+
+        let repository = text(&p["repository"]["full_name"])
+            .zip(text(&p["repository"]["html_url"]))
+            .map(|(name, url)| (name.to_owned(), url.to_owned()));
+        let author = text(&p["sender"]["login"]).map(|login| Author {
+            login: login.to_owned(),
+            url: text(&p["sender"]["html_url"])
+                .map(String::from)
+                .unwrap_or_else(|| format!("https://github.com/{}", login)),
+            // `slim` leaves avatar urls out, but GitHub serves them by login too.
+            avatar_url: text(&p["sender"]["avatar_url"])
+                .map(String::from)
+                .unwrap_or_else(|| format!("https://github.com/{}.png", login)),
+        });
+
+        let mut commits = Vec::new();
+        let mut more_commits = 0;
+        if event_type == EventType::Push {
+            let all = p["commits"]
+                .as_array()
+                .map(Vec::as_slice)
+                .unwrap_or_default();
+            more_commits = all.len().saturating_sub(MAX_COMMITS);
+            for commit in all.iter().take(MAX_COMMITS) {
+                let sha = text(&commit["sha"])
+                    .or_else(|| text(&commit["id"]))
+                    .unwrap_or_default();
+                commits.push(CardCommit {
+                    sha: sha.chars().take(7).collect(),
+                    url: match &repository {
+                        Some((_, url)) => format!("{}/commit/{}", url, sha),
+                        None => String::new(),
+                    },
+                    message: text(&commit["message"])
+                        .and_then(|m| m.lines().next())
+                        .unwrap_or_default()
+                        .to_owned(),
+                });
+            }
+        }
+
+        Card {
+            title: summary::summarize(event_type, &p),
+            url: url(event_type, &p).map(String::from),
+            author,
+            color: color(event_type, &p),
+            body: body(&p).map(truncate),
+            commits,
+            more_commits,
+            repository,
+        }
+    }
+}
+
+fn text(value: &Value) -> Option<&str> {
+    value.as_str().filter(|s| !s.is_empty())
+}
+
+fn url(event_type: EventType, p: &Value) -> Option<&str> {
+    let candidates: &[&Value] = match event_type {
+        EventType::Push => &[&p["compare"], &p["head_commit"]["url"]],
+        EventType::Status => &[&p["commit"]["html_url"], &p["target_url"]],
+        EventType::Fork => &[&p["forkee"]["html_url"]],
+        EventType::DeploymentStatus => &[&p["deployment_status"]["target_url"]],
+        EventType::RepositoryVulnerabilityAlert => &[&p["alert"]["external_reference"]],
+        EventType::SecurityAdvisory => &[&p["security_advisory"]["references"][0]["url"]],
+        _ => &[
+            &p["comment"]["html_url"],
+            &p["review"]["html_url"],
+            &p["pull_request"]["html_url"],
+            &p["issue"]["html_url"],
+            &p["release"]["html_url"],
+            &p["check_run"]["html_url"],
+            &p["milestone"]["html_url"],
+            &p["project"]["html_url"],
+            &p["team"]["html_url"],
+        ],
+    };
+    candidates
+        .iter()
+        .find_map(|url| text(url))
+        .or_else(|| text(&p["repository"]["html_url"]))
+}
+
+fn color(event_type: EventType, p: &Value) -> u32 {
+    if p["pull_request"]["merged"] == true && p["action"] == "closed" {
+        return PURPLE;
+    }
+    if matches!(event_type, EventType::Push | EventType::Create) {
+        return BLUE;
+    }
+    let outcome = [
+        &p["review"]["state"],
+        &p["check_run"]["conclusion"],
+        &p["check_suite"]["conclusion"],
+        &p["check_run"]["status"],
+        &p["check_suite"]["status"],
+        &p["deployment_status"]["state"],
+        &p["build"]["status"],
+        &p["state"],
+        &p["action"],
+    ]
+    .iter()
+    .find_map(|value| text(value));
+    match outcome.unwrap_or_default() {
+        "opened" | "reopened" | "created" | "published" | "approved" | "success" | "built"
+        | "added" | "member_added" | "resolved" => GREEN,
+        "closed" | "deleted" | "failure" | "error" | "errored" | "changes_requested"
+        | "removed" | "member_removed" | "cancelled" | "timed_out" | "action_required"
+        | "blocked" => RED,
+        "pending" | "queued" | "in_progress" | "requested" => YELLOW,
+        _ => GREY,
+    }
+}
+
+/// The body worth quoting from an event that introduces it.
+fn body(p: &Value) -> Option<&str> {
+    let action = p["action"].as_str().unwrap_or_default();
+    let body = match action {
+        "opened" => &p["pull_request"]["body"],
+        "created" => &p["comment"]["body"],
+        "submitted" => &p["review"]["body"],
+        "published" => &p["release"]["body"],
+        _ => return None,
+    };
+    let body = match (action, body) {
+        ("opened", Value::Null) => &p["issue"]["body"],
+        (_, body) => body,
+    };
+    text(body).map(str::trim).filter(|b| !b.is_empty())
+}
+
+fn truncate(body: &str) -> String {
+    match body.char_indices().nth(MAX_BODY) {
+        Some((end, _)) => format!("{}…", body[..end].trim_end()),
+        None => body.to_owned(),
+    }
+}
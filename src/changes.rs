@@ -0,0 +1,88 @@
+//! Undoing edits: the state of an edited object before the edit, from the `changes` of its event.
+use crate::prelude::*;
+use crate::{
+    Comment, CommentChanges, Issue, IssueChanges, Label, LabelChanges, PullRequest,
+    PullRequestChanges,
+};
+#[cfg(feature = "issues")]
+use crate::{Milestone, MilestoneChanges};
+
+impl Issue {
+    /// The issue as it was before the edit described by `changes`.
+    pub fn apply_changes_backwards(&self, changes: &IssueChanges) -> Issue {
+        let mut issue = self.clone();
+        if let Some(title) = &changes.title {
+            issue.title = title.from.clone();
+        }
+        if let Some(body) = &changes.body {
+            issue.body = body.from.clone();
+        }
+        issue
+    }
+}
+
+impl PullRequest {
+    /// The pull request as it was before the edit described by `changes`.
+    pub fn apply_changes_backwards(&self, changes: &PullRequestChanges) -> PullRequest {
+        let mut pull_request = self.clone();
+        if let Some(title) = &changes.title {
+            pull_request.title = title.from.clone();
+        }
+        if let Some(body) = &changes.body {
+            pull_request.body = body.from.clone();
+        }
+        if let Some(base) = &changes.base {
+            pull_request.base.ref_field = base.ref_field.from.clone();
+            pull_request.base.sha = base.sha.from.clone();
+            pull_request.base.label = format!(
+                "{}:{}",
+                pull_request.base.user.login, pull_request.base.ref_field
+            );
+        }
+        pull_request
+    }
+}
+
+impl Comment {
+    /// The comment as it was before the edit described by `changes`.
+    pub fn apply_changes_backwards(&self, changes: &CommentChanges) -> Comment {
+        let mut comment = self.clone();
+        if let Some(body) = &changes.body {
+            comment.body = body.from.clone();
+        }
+        comment
+    }
+}
+
+impl Label {
+    /// The label as it was before the edit described by `changes`.
+    pub fn apply_changes_backwards(&self, changes: &LabelChanges) -> Label {
+        let mut label = self.clone();
+        if let Some(name) = &changes.name {
+            label.name = name.from.clone();
+        }
+        if let Some(color) = &changes.color {
+            label.color = color.from.clone();
+        }
+        label
+    }
+}
+
+#[cfg(feature = "issues")]
+impl Milestone {
+    /// The milestone as it was before the edit described by `changes`. A description or due
+    /// date that was previously unset is restored as empty.
+    pub fn apply_changes_backwards(&self, changes: &MilestoneChanges) -> Milestone {
+        let mut milestone = self.clone();
+        if let Some(title) = &changes.title {
+            milestone.title = title.from.clone();
+        }
+        if let Some(description) = &changes.description {
+            milestone.description = description.from.clone().unwrap_or_default();
+        }
+        if let Some(due_on) = &changes.due_on {
+            milestone.due_on = due_on.from.clone().unwrap_or_default();
+        }
+        milestone
+    }
+}
@@ -0,0 +1,664 @@
+//! Polling client for the [Events API](https://developer.github.com/v3/activity/events/).
+//!
+//! The Events API is optimized for polling. Every response carries an `ETag`; sending it back in
+//! `If-None-Match` yields a `304 Not Modified` that does not count against the rate limit when
+//! nothing changed. The `X-Poll-Interval` header specifies how often (in seconds) you are allowed
+//! to poll, and may increase during times of high server load.
+//!
+//! Requests pin the REST API [version](../struct.ApiVersion.html) whose shapes the payload types
+//! model, 2022-11-28 unless [`with_api_version`](struct.Client.html#method.with_api_version) says
+//! otherwise, and responses GitHub answered in a version the types don't model are refused with
+//! [`ClientError::UnsupportedApiVersion`](enum.ClientError.html#variant.UnsupportedApiVersion).
+//!
+//! Requests are subject to GitHub's [rate limits](https://developer.github.com/v3/#rate-limiting).
+//! The client keeps track of the remaining quota and, by default, sleeps until the limit resets
+//! instead of hammering the API. Server errors are retried with jittered exponential backoff.
+//!
+//! The client runs on any [`HttpBackend`](backend/trait.HttpBackend.html);
+//! enable the `reqwest` (async) or `ureq` (blocking) feature for a built-in one.
+use std::collections::{HashSet, VecDeque};
+use std::error::Error;
+use std::fmt;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use futures::stream::{self, Stream, StreamExt};
+
+use self::backend::{BackendError, BoxFuture, HttpBackend, Method, Request, Response};
+use crate::filter::EventFilter;
+use crate::timeline::TimelineEvent;
+use crate::wait;
+use crate::{ApiVersion, UnknownApiVersion};
+
+mod audit_log;
+pub mod backend;
+mod dispatch;
+mod hooks;
+mod merge;
+mod notifications;
+mod reconcile;
+
+pub use self::audit_log::*;
+pub use self::hooks::*;
+pub use self::notifications::*;
+pub use self::reconcile::*;
+
+const API_URL: &str = "https://api.github.com";
+/// Used until the first response tells us the real interval.
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(60);
+/// The Events API never exposes more than the 300 most recent events of an endpoint,
+/// so remembering a few more ids than that is enough to never emit an event twice.
+const SEEN_CAPACITY: usize = 1000;
+/// How often a request failing with a server error, or rejected by a rate limit, is retried.
+const MAX_RETRIES: u32 = 5;
+const BASE_BACKOFF: Duration = Duration::from_secs(1);
+const MAX_BACKOFF: Duration = Duration::from_secs(60);
+/// GitHub asks clients to wait at least a minute after hitting a secondary rate limit
+/// that didn't specify `Retry-After`.
+const SECONDARY_RATE_LIMIT_WAIT: Duration = Duration::from_secs(60);
+
+/// An Events API client.
+///
+/// Cloning is cheap; clones share the underlying backend and rate limit.
+#[derive(Debug, Clone)]
+pub struct Client {
+    http: Arc<dyn HttpBackend>,
+    base_url: String,
+    user_agent: String,
+    api_version: ApiVersion,
+    auth: Option<Arc<dyn Authenticator>>,
+    wait_on_rate_limit: bool,
+    rate_limit: Arc<Mutex<Option<RateLimit>>>,
+}
+
+#[cfg(any(feature = "reqwest", feature = "ureq"))]
+impl Default for Client {
+    fn default() -> Self {
+        Client::new()
+    }
+}
+
+impl Client {
+    /// A client using the `reqwest` backend if that feature is enabled, or else the `ureq` backend.
+    #[cfg(any(feature = "reqwest", feature = "ureq"))]
+    pub fn new() -> Self {
+        #[cfg(feature = "reqwest")]
+        let backend = backend::Reqwest::default();
+        #[cfg(not(feature = "reqwest"))]
+        let backend = backend::Ureq::default();
+        Client::with_backend(backend)
+    }
+
+    pub fn with_backend(backend: impl HttpBackend + 'static) -> Self {
+        Client {
+            http: Arc::new(backend),
+            base_url: API_URL.to_owned(),
+            user_agent: concat!("github-events/", env!("CARGO_PKG_VERSION")).to_owned(),
+            api_version: ApiVersion::default(),
+            auth: None,
+            wait_on_rate_limit: true,
+            rate_limit: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Authenticate requests with a personal access or installation token.
+    /// Unauthenticated clients are limited to 60 requests per hour.
+    pub fn with_token(self, token: impl Into<String>) -> Self {
+        self.with_auth(Token(token.into()))
+    }
+
+    /// Authenticate requests with dynamically produced credentials,
+    /// e.g. the GitHub App authentication in the `auth` module.
+    pub fn with_auth(mut self, auth: impl Authenticator + 'static) -> Self {
+        self.auth = Some(Arc::new(auth));
+        self
+    }
+
+    /// Use a different API root, e.g. `https://github.example.com/api/v3` for GitHub Enterprise.
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_owned();
+        self
+    }
+
+    /// GitHub requires every request to carry a `User-Agent`, preferably your app's name.
+    pub fn with_user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = user_agent.into();
+        self
+    }
+
+    /// Pin requests to a REST API version other than 2022-11-28.
+    pub fn with_api_version(mut self, api_version: ApiVersion) -> Self {
+        self.api_version = api_version;
+        self
+    }
+
+    /// The REST API version requests are pinned to.
+    pub fn api_version(&self) -> ApiVersion {
+        self.api_version
+    }
+
+    /// Whether to sleep until the rate limit resets (the default),
+    /// or to fail with [`ClientError::RateLimited`] instead.
+    pub fn wait_on_rate_limit(mut self, wait: bool) -> Self {
+        self.wait_on_rate_limit = wait;
+        self
+    }
+
+    /// The rate limit reported by the most recent response, if any.
+    pub fn rate_limit(&self) -> Option<RateLimit> {
+        *self.rate_limit.lock().unwrap()
+    }
+
+    /// Poll [public events](https://developer.github.com/v3/activity/events/#list-public-events).
+    pub fn events(&self) -> Poller {
+        self.poller("/events")
+    }
+
+    /// Poll [repository events](https://developer.github.com/v3/activity/events/#list-repository-events).
+    pub fn repo_events(&self, owner: &str, repo: &str) -> Poller {
+        self.poller(&format!("/repos/{}/{}/events", owner, repo))
+    }
+
+    /// Poll [public organization events](https://developer.github.com/v3/activity/events/#list-public-events-for-an-organization).
+    pub fn org_events(&self, org: &str) -> Poller {
+        self.poller(&format!("/orgs/{}/events", org))
+    }
+
+    /// Poll [events performed by a user](https://developer.github.com/v3/activity/events/#list-events-performed-by-a-user).
+    /// Private events are only included when authenticated as that user.
+    pub fn user_events(&self, user: &str) -> Poller {
+        self.poller(&format!("/users/{}/events", user))
+    }
+
+    /// Poll [public events performed by a user](https://developer.github.com/v3/activity/events/#list-public-events-performed-by-a-user).
+    pub fn user_public_events(&self, user: &str) -> Poller {
+        self.poller(&format!("/users/{}/events/public", user))
+    }
+
+    /// Poll [events that a user has received](https://developer.github.com/v3/activity/events/#list-events-that-a-user-has-received),
+    /// i.e. activity on repositories and users they watch.
+    /// Private events are only included when authenticated as that user.
+    pub fn received_events(&self, user: &str) -> Poller {
+        self.poller(&format!("/users/{}/received_events", user))
+    }
+
+    /// Poll [public events that a user has received](https://developer.github.com/v3/activity/events/#list-public-events-that-a-user-has-received).
+    pub fn received_public_events(&self, user: &str) -> Poller {
+        self.poller(&format!("/users/{}/received_events/public", user))
+    }
+
+    fn poller(&self, path: &str) -> Poller {
+        Poller {
+            client: self.clone(),
+            url: self.url(path),
+            per_page: None,
+            backfill: 0,
+            etag: None,
+            interval: DEFAULT_POLL_INTERVAL,
+            next_poll: None,
+        }
+    }
+
+    /// A copy of this client using different credentials and keeping track of its own rate limit.
+    #[cfg(feature = "auth")]
+    pub(crate) fn authenticated_as(&self, auth: impl Authenticator + 'static) -> Client {
+        Client {
+            auth: Some(Arc::new(auth)),
+            rate_limit: Arc::new(Mutex::new(None)),
+            ..self.clone()
+        }
+    }
+
+    pub(crate) fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base_url, path)
+    }
+
+    fn get(&self, url: &str) -> Request {
+        self.request(Method::Get, url)
+    }
+
+    pub(crate) fn request(&self, method: Method, url: &str) -> Request {
+        Request::new(method, url)
+            .header("User-Agent", self.user_agent.as_str())
+            .header("Accept", "application/vnd.github.v3+json")
+            .header(ApiVersion::HEADER, self.api_version.to_string())
+    }
+
+    /// Send the request built by `request`, respecting rate limits and retrying server errors.
+    pub(crate) async fn send(
+        &self,
+        request: impl Fn() -> Request,
+    ) -> Result<Response, ClientError> {
+        let mut retries = 0;
+        let mut rate_limited = 0;
+        loop {
+            let exhausted = self
+                .rate_limit()
+                .filter(|limit| limit.remaining == 0 && limit.reset_at > SystemTime::now());
+            if let Some(limit) = exhausted {
+                self.wait_until(limit.reset_at).await?;
+            }
+
+            // Asked for on every attempt, since a token may have expired while waiting.
+            let request = match &self.auth {
+                Some(auth) => request().header("Authorization", auth.authorization(self).await?),
+                None => request(),
+            };
+            #[cfg(feature = "tracing")]
+            let span = crate::tracing::request_span(&request);
+            #[cfg(feature = "metrics")]
+            let start = Instant::now();
+            let res = self.http.send(request);
+            #[cfg(feature = "tracing")]
+            let res = ::tracing::Instrument::instrument(res, span.clone());
+            let res = res.await;
+            #[cfg(feature = "metrics")]
+            crate::metrics::requested(res.as_ref().ok().map(|res| res.status), start);
+            #[cfg(feature = "tracing")]
+            match &res {
+                Ok(res) => {
+                    span.record("status", res.status);
+                }
+                Err(e) => ::tracing::warn!(parent: &span, error = %e, "request failed"),
+            }
+            let res = res.map_err(ClientError::Http)?;
+            if let Some(limit) = RateLimit::from_headers(&res) {
+                *self.rate_limit.lock().unwrap() = Some(limit);
+            }
+            if res.status >= 500 && retries < MAX_RETRIES {
+                self.http.sleep(backoff(retries)).await;
+                retries += 1;
+                continue;
+            }
+            match rate_limited_until(&res) {
+                Some(reset_at) if rate_limited < MAX_RETRIES => {
+                    // A reset that has already passed, e.g. by a skewed clock, still gets a pause.
+                    let retry_at = SystemTime::now() + backoff(rate_limited);
+                    self.wait_until(reset_at.max(retry_at)).await?;
+                    rate_limited += 1;
+                }
+                Some(reset_at) => return Err(ClientError::RateLimited { reset_at }),
+                None => {
+                    check_api_version(&res)?;
+                    return Ok(res);
+                }
+            }
+        }
+    }
+
+    async fn wait_until(&self, reset_at: SystemTime) -> Result<(), ClientError> {
+        if !self.wait_on_rate_limit {
+            return Err(ClientError::RateLimited { reset_at });
+        }
+        if let Ok(wait) = reset_at.duration_since(SystemTime::now()) {
+            self.http.sleep(wait).await;
+        }
+        Ok(())
+    }
+}
+
+/// Produces the `Authorization` header of a [`Client`]'s requests.
+pub trait Authenticator: fmt::Debug + Send + Sync {
+    fn authorization<'a>(
+        &'a self,
+        client: &'a Client,
+    ) -> BoxFuture<'a, Result<String, ClientError>>;
+}
+
+/// A personal access or OAuth token.
+struct Token(String);
+
+impl fmt::Debug for Token {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Token")
+    }
+}
+
+impl Authenticator for Token {
+    fn authorization<'a>(&'a self, _: &'a Client) -> BoxFuture<'a, Result<String, ClientError>> {
+        Box::pin(futures::future::ready(Ok(format!("token {}", self.0))))
+    }
+}
+
+/// The [rate limit](https://developer.github.com/v3/#rate-limiting) status of the client.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct RateLimit {
+    /// The maximum number of requests you're permitted to make per hour.
+    pub limit: u64,
+    /// The number of requests remaining in the current rate limit window.
+    pub remaining: u64,
+    /// The time at which the current rate limit window resets.
+    pub reset_at: SystemTime,
+}
+
+impl RateLimit {
+    fn from_headers(res: &Response) -> Option<Self> {
+        let number = |name| res.header(name).and_then(|v| v.parse::<u64>().ok());
+        Some(RateLimit {
+            limit: number("x-ratelimit-limit")?,
+            remaining: number("x-ratelimit-remaining")?,
+            reset_at: UNIX_EPOCH + Duration::from_secs(number("x-ratelimit-reset")?),
+        })
+    }
+}
+
+/// If `res` was rejected by the primary or a secondary rate limit, when to try again.
+fn rate_limited_until(res: &Response) -> Option<SystemTime> {
+    if res.status != 403 && res.status != 429 {
+        return None;
+    }
+    if let Some(secs) = res.header("retry-after").and_then(|v| v.parse().ok()) {
+        return Some(SystemTime::now() + Duration::from_secs(secs));
+    }
+    match RateLimit::from_headers(res) {
+        Some(limit) if limit.remaining == 0 => Some(limit.reset_at),
+        _ if res.status == 429 => Some(SystemTime::now() + SECONDARY_RATE_LIMIT_WAIT),
+        // A plain permission error.
+        _ => None,
+    }
+}
+
+fn backoff(retries: u32) -> Duration {
+    wait::backoff(BASE_BACKOFF, MAX_BACKOFF, retries)
+}
+
+/// Repeatedly polls a single Events API endpoint.
+#[derive(Debug, Clone)]
+pub struct Poller {
+    client: Client,
+    url: String,
+    per_page: Option<u8>,
+    backfill: usize,
+    etag: Option<String>,
+    interval: Duration,
+    next_poll: Option<Instant>,
+}
+
+impl Poller {
+    /// Request up to `per_page` events per poll instead of GitHub's default of 30.
+    /// GitHub caps this at 100.
+    pub fn per_page(mut self, per_page: u8) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Make the first poll walk back through up to `pages` pages of history
+    /// before switching to live polling.
+    ///
+    /// The Events API exposes at most 300 events per endpoint,
+    /// i.e. 10 pages at the default `per_page` of 30, or 3 pages at 100.
+    pub fn backfill(mut self, pages: usize) -> Self {
+        self.backfill = pages;
+        self
+    }
+
+    /// The interval GitHub most recently asked us to respect between polls.
+    pub fn poll_interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Fetch the latest page of events, newest first.
+    ///
+    /// Waits out the remainder of the current poll interval before sending the request.
+    /// Returns an empty `Vec` if nothing changed since the previous poll.
+    /// If a [`backfill`](#method.backfill) was requested, the first poll also
+    /// follows `Link: <…>; rel="next"` headers to fetch older pages.
+    pub async fn poll(&mut self) -> Result<Vec<TimelineEvent>, ClientError> {
+        if let Some(next_poll) = self.next_poll {
+            let now = Instant::now();
+            if next_poll > now {
+                self.client.http.sleep(next_poll - now).await;
+            }
+        }
+
+        let res = self
+            .client
+            .send(|| {
+                let req = match self.per_page {
+                    Some(per_page) => self
+                        .client
+                        .get(&format!("{}?per_page={}", self.url, per_page)),
+                    None => self.client.get(&self.url),
+                };
+                match &self.etag {
+                    Some(etag) => req.header("If-None-Match", etag.as_str()),
+                    None => req,
+                }
+            })
+            .await?;
+        self.update_interval(&res);
+        if res.status == 304 {
+            return Ok(Vec::new());
+        }
+        self.etag = res.header("etag").map(str::to_owned);
+        let (mut events, mut next) = read_page(res)?;
+
+        let backfill = std::mem::replace(&mut self.backfill, 0);
+        for _ in 1..backfill {
+            let url = match next.take() {
+                Some(url) => url,
+                None => break,
+            };
+            let (older, older_next) = read_page(self.client.send(|| self.client.get(&url)).await?)?;
+            events.extend(older);
+            next = older_next;
+        }
+        Ok(events)
+    }
+
+    /// Turn this poller into an endless stream of events.
+    pub fn into_stream(self) -> EventStream {
+        EventStream::new(self)
+    }
+
+    fn update_interval(&mut self, res: &Response) {
+        if let Some(secs) = res.header("x-poll-interval").and_then(|s| s.parse().ok()) {
+            self.interval = Duration::from_secs(secs);
+        }
+        self.next_poll = Some(Instant::now() + self.interval);
+    }
+}
+
+/// An endless stream of events produced by repeatedly polling a single endpoint.
+///
+/// Each event is yielded once, even when it appears in several consecutive polls.
+/// Events within a poll are yielded oldest first.
+pub struct EventStream {
+    inner: Pin<Box<dyn Stream<Item = Result<TimelineEvent, ClientError>> + Send>>,
+}
+
+struct StreamState {
+    poller: Poller,
+    seen: Seen,
+    pending: VecDeque<TimelineEvent>,
+}
+
+impl EventStream {
+    pub fn new(poller: Poller) -> Self {
+        let state = StreamState {
+            poller,
+            seen: Seen::default(),
+            pending: VecDeque::new(),
+        };
+        let inner = stream::unfold(state, |mut state| async move {
+            loop {
+                if let Some(event) = state.pending.pop_front() {
+                    return Some((Ok(event), state));
+                }
+                match state.poller.poll().await {
+                    // Pages are ordered newest first.
+                    Ok(events) => {
+                        for event in events.into_iter().rev() {
+                            if state.seen.insert(&event.id) {
+                                #[cfg(feature = "metrics")]
+                                crate::metrics::polled(&event);
+                                state.pending.push_back(event);
+                            }
+                        }
+                    }
+                    Err(e) => return Some((Err(e), state)),
+                }
+            }
+        });
+        EventStream {
+            inner: Box::pin(inner),
+        }
+    }
+}
+
+impl EventStream {
+    /// Only the events matching `filter`. Errors are still yielded.
+    pub fn filtered(self, filter: EventFilter) -> EventStream {
+        let inner = self.inner.filter(move |event| {
+            futures::future::ready(match event {
+                Ok(event) => filter.matches_timeline(event),
+                Err(_) => true,
+            })
+        });
+        EventStream {
+            inner: Box::pin(inner),
+        }
+    }
+}
+
+impl Stream for EventStream {
+    type Item = Result<TimelineEvent, ClientError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        self.inner.as_mut().poll_next(cx)
+    }
+}
+
+impl fmt::Debug for EventStream {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("EventStream").finish()
+    }
+}
+
+/// A bounded set of recently seen event ids.
+#[derive(Debug, Default)]
+struct Seen {
+    ids: HashSet<String>,
+    order: VecDeque<String>,
+}
+
+impl Seen {
+    fn contains(&self, id: &str) -> bool {
+        self.ids.contains(id)
+    }
+
+    /// Returns `true` if `id` had not been seen before.
+    fn insert(&mut self, id: &str) -> bool {
+        if self.ids.contains(id) {
+            return false;
+        }
+        if self.order.len() == SEEN_CAPACITY {
+            if let Some(oldest) = self.order.pop_front() {
+                self.ids.remove(&oldest);
+            }
+        }
+        self.ids.insert(id.to_owned());
+        self.order.push_back(id.to_owned());
+        true
+    }
+}
+
+/// Refuse a response in a REST API version the payload types don't model, as its bodies might
+/// parse into the wrong fields.
+fn check_api_version(res: &Response) -> Result<(), ClientError> {
+    match res
+        .header(ApiVersion::SELECTED_HEADER)
+        .map(str::parse::<ApiVersion>)
+    {
+        Some(Ok(version)) if !version.is_known() => {
+            Err(ClientError::UnsupportedApiVersion(version))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Parse a page of events and the url of the next (older) page.
+fn read_page(res: Response) -> Result<(Vec<TimelineEvent>, Option<String>), ClientError> {
+    if !res.is_success() {
+        return Err(ClientError::Status(res.status));
+    }
+    let next = res.header("link").and_then(|links| link_rel(links, "next"));
+    Ok((serde_json::from_slice(&res.body)?, next))
+}
+
+/// Find the target of the link with relation type `rel` in an
+/// [RFC 5988](https://tools.ietf.org/html/rfc5988) `Link` header, e.g.
+/// `<https://api.github.com/events?page=2>; rel="next", <https://api.github.com/events?page=10>; rel="last"`.
+fn link_rel(links: &str, rel: &str) -> Option<String> {
+    links.split(',').find_map(|link| {
+        let mut parts = link.split(';');
+        let target = parts.next()?.trim();
+        let target = target.strip_prefix('<')?.strip_suffix('>')?;
+        let matches = parts.any(|param| match param.trim().strip_prefix("rel=") {
+            Some(rels) => rels.trim_matches('"').split_whitespace().any(|r| r == rel),
+            None => false,
+        });
+        if matches {
+            Some(target.to_owned())
+        } else {
+            None
+        }
+    })
+}
+
+#[derive(Debug)]
+pub enum ClientError {
+    /// The request could not be sent or the response could not be read.
+    Http(BackendError),
+    /// GitHub responded with an unexpected status code.
+    Status(u16),
+    /// A rate limit was hit and the client was configured not to
+    /// [wait](struct.Client.html#method.wait_on_rate_limit) for it to reset,
+    /// or was still hit after waiting several times.
+    RateLimited { reset_at: SystemTime },
+    /// Credentials for the request could not be produced.
+    Auth(BackendError),
+    /// The response body was not valid.
+    Json(serde_json::Error),
+    /// GitHub answered in a REST API version the payload types don't model.
+    UnsupportedApiVersion(ApiVersion),
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ClientError::Http(e) => write!(f, "http error: {}", e),
+            ClientError::Status(status) => write!(f, "unexpected status code: {}", status),
+            ClientError::RateLimited { reset_at } => match reset_at.duration_since(UNIX_EPOCH) {
+                Ok(reset) => write!(f, "rate limited until {}", reset.as_secs()),
+                Err(_) => write!(f, "rate limited"),
+            },
+            ClientError::Auth(e) => write!(f, "authentication failed: {}", e),
+            ClientError::Json(e) => write!(f, "invalid response: {}", e),
+            ClientError::UnsupportedApiVersion(version) => {
+                write!(f, "invalid response: {}", UnknownApiVersion(*version))
+            }
+        }
+    }
+}
+
+impl Error for ClientError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ClientError::Http(e) | ClientError::Auth(e) => Some(&**e),
+            ClientError::Status(_)
+            | ClientError::RateLimited { .. }
+            | ClientError::UnsupportedApiVersion(_) => None,
+            ClientError::Json(e) => Some(e),
+        }
+    }
+}
+
+impl From<serde_json::Error> for ClientError {
+    fn from(e: serde_json::Error) -> Self {
+        ClientError::Json(e)
+    }
+}
@@ -0,0 +1,258 @@
+//! Audit log API calls.
+use std::collections::VecDeque;
+use std::fmt;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+
+use futures::stream::{self, Stream};
+
+use super::{link_rel, Client, ClientError, Seen, DEFAULT_POLL_INTERVAL};
+use crate::audit_log::AuditLogEntry;
+
+/// How many pages a stream walks back through to catch up after a busy interval.
+const MAX_CATCH_UP_PAGES: usize = 10;
+
+/// Which entries of an audit log to list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Include {
+    /// Actions taken on github.com, which GitHub lists by default.
+    Web,
+    /// Git events, such as clones and pushes.
+    Git,
+    All,
+}
+
+impl Include {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Include::Web => "web",
+            Include::Git => "git",
+            Include::All => "all",
+        }
+    }
+}
+
+impl Client {
+    /// Poll an [organization's audit log](https://docs.github.com/en/rest/orgs/orgs#get-the-audit-log-for-an-organization).
+    /// Requires GitHub Enterprise Cloud, and a token of an owner with the `read:audit_log` scope.
+    pub fn org_audit_log(&self, org: &str) -> AuditLogPoller {
+        self.audit_log_poller(&format!("/orgs/{}/audit-log", org))
+    }
+
+    /// Poll an [enterprise's audit log](https://docs.github.com/en/enterprise-cloud@latest/rest/enterprise-admin/audit-log#get-the-audit-log-for-an-enterprise).
+    pub fn enterprise_audit_log(&self, enterprise: &str) -> AuditLogPoller {
+        self.audit_log_poller(&format!("/enterprises/{}/audit-log", enterprise))
+    }
+
+    fn audit_log_poller(&self, path: &str) -> AuditLogPoller {
+        AuditLogPoller {
+            client: self.clone(),
+            url: self.url(path),
+            phrase: None,
+            include: None,
+            backfill: 0,
+            interval: DEFAULT_POLL_INTERVAL,
+            next_poll: None,
+        }
+    }
+}
+
+/// Repeatedly polls an audit log.
+#[derive(Debug, Clone)]
+pub struct AuditLogPoller {
+    client: Client,
+    url: String,
+    phrase: Option<String>,
+    include: Option<Include>,
+    backfill: usize,
+    interval: Duration,
+    next_poll: Option<Instant>,
+}
+
+impl AuditLogPoller {
+    /// Only list entries matching a [search phrase](https://docs.github.com/en/organizations/keeping-your-organization-secure/managing-security-settings-for-your-organization/reviewing-the-audit-log-for-your-organization#searching-the-audit-log),
+    /// e.g. `action:repo.create actor:octocat`.
+    pub fn phrase(mut self, phrase: impl Into<String>) -> Self {
+        self.phrase = Some(phrase.into());
+        self
+    }
+
+    pub fn include(mut self, include: Include) -> Self {
+        self.include = Some(include);
+        self
+    }
+
+    /// Make the first poll walk back through up to `pages` pages of 100 entries
+    /// before switching to live polling.
+    pub fn backfill(mut self, pages: usize) -> Self {
+        self.backfill = pages;
+        self
+    }
+
+    /// Poll every `interval` instead of every minute. The audit log has no poll interval of
+    /// its own, but polls count against the rate limit.
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Fetch the latest page of entries, newest first, and on the first poll the
+    /// [`backfill`](#method.backfill) pages before it.
+    ///
+    /// Waits out the remainder of the current interval before sending the request.
+    pub async fn poll(&mut self) -> Result<Vec<AuditLogEntry>, ClientError> {
+        let (mut entries, mut next) = self.poll_page().await?;
+        let backfill = std::mem::replace(&mut self.backfill, 0);
+        for _ in 1..backfill {
+            let url = match next.take() {
+                Some(url) => url,
+                None => break,
+            };
+            let (older, older_next) = self.page(&url).await?;
+            entries.extend(older);
+            next = older_next;
+        }
+        Ok(entries)
+    }
+
+    /// Turn this poller into an endless stream of entries.
+    pub fn into_stream(self) -> AuditLogStream {
+        AuditLogStream::new(self)
+    }
+
+    /// Wait for the interval, then fetch the latest page and the url of the one before it.
+    async fn poll_page(&mut self) -> Result<(Vec<AuditLogEntry>, Option<String>), ClientError> {
+        if let Some(next_poll) = self.next_poll {
+            let now = Instant::now();
+            if next_poll > now {
+                self.client.http.sleep(next_poll - now).await;
+            }
+        }
+        let mut url = format!("{}?per_page=100", self.url);
+        if let Some(phrase) = &self.phrase {
+            url.push_str("&phrase=");
+            url.push_str(&query_encode(phrase));
+        }
+        if let Some(include) = self.include {
+            url.push_str("&include=");
+            url.push_str(include.as_str());
+        }
+        let page = self.page(&url).await;
+        self.next_poll = Some(Instant::now() + self.interval);
+        page
+    }
+
+    async fn page(&self, url: &str) -> Result<(Vec<AuditLogEntry>, Option<String>), ClientError> {
+        let res = self.client.send(|| self.client.get(url)).await?;
+        if !res.is_success() {
+            return Err(ClientError::Status(res.status));
+        }
+        let next = res.header("link").and_then(|links| link_rel(links, "next"));
+        Ok((serde_json::from_slice(&res.body)?, next))
+    }
+}
+
+/// Percent-encode everything but unreserved characters.
+fn query_encode(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                encoded.push(char::from(byte))
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+/// An endless stream of audit log entries, oldest first, produced by repeatedly polling.
+///
+/// Each entry is yielded once. When more than a page of entries was logged between polls,
+/// up to ten pages are fetched to catch up.
+pub struct AuditLogStream {
+    inner: Pin<Box<dyn Stream<Item = Result<AuditLogEntry, ClientError>> + Send>>,
+}
+
+struct StreamState {
+    poller: AuditLogPoller,
+    seen: Seen,
+    pending: VecDeque<AuditLogEntry>,
+    first: bool,
+}
+
+impl AuditLogStream {
+    pub fn new(poller: AuditLogPoller) -> Self {
+        let state = StreamState {
+            poller,
+            seen: Seen::default(),
+            pending: VecDeque::new(),
+            first: true,
+        };
+        let inner = stream::unfold(state, |mut state| async move {
+            loop {
+                if let Some(entry) = state.pending.pop_front() {
+                    return Some((Ok(entry), state));
+                }
+                let first = std::mem::replace(&mut state.first, false);
+                let result = if first {
+                    state.poller.poll().await
+                } else {
+                    catch_up(&mut state).await
+                };
+                match result {
+                    // Pages are ordered newest first.
+                    Ok(entries) => {
+                        for entry in entries.into_iter().rev() {
+                            let new = match &entry.document_id {
+                                Some(id) => state.seen.insert(id),
+                                None => true,
+                            };
+                            if new {
+                                state.pending.push_back(entry);
+                            }
+                        }
+                    }
+                    Err(e) => return Some((Err(e), state)),
+                }
+            }
+        });
+        AuditLogStream {
+            inner: Box::pin(inner),
+        }
+    }
+}
+
+/// The latest page, and the pages before it until one with an entry already seen.
+async fn catch_up(state: &mut StreamState) -> Result<Vec<AuditLogEntry>, ClientError> {
+    let (mut entries, mut next) = state.poller.poll_page().await?;
+    for _ in 1..MAX_CATCH_UP_PAGES {
+        let caught_up = entries.iter().any(|entry| match &entry.document_id {
+            Some(id) => state.seen.contains(id),
+            None => false,
+        });
+        let url = match next.take() {
+            Some(url) if !caught_up => url,
+            _ => break,
+        };
+        let (older, older_next) = state.poller.page(&url).await?;
+        entries.extend(older);
+        next = older_next;
+    }
+    Ok(entries)
+}
+
+impl Stream for AuditLogStream {
+    type Item = Result<AuditLogEntry, ClientError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        self.inner.as_mut().poll_next(cx)
+    }
+}
+
+impl fmt::Debug for AuditLogStream {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AuditLogStream").finish()
+    }
+}
@@ -0,0 +1,221 @@
+//! HTTP backends the [`Client`](../struct.Client.html) can run on.
+//!
+//! The `reqwest` feature provides an async backend that requires a tokio runtime.
+//! The `ureq` feature provides a blocking backend, for tools that don't want an async runtime:
+//! drive the client with `futures::executor::block_on`, or turn an
+//! [`EventStream`](../struct.EventStream.html) into an iterator with
+//! `futures::executor::block_on_stream`.
+use std::error::Error;
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::time::Duration;
+
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// Any failure to send a request or read its response.
+pub type BackendError = Box<dyn Error + Send + Sync>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Method {
+    Get,
+    Post,
+    Put,
+    Patch,
+    Delete,
+}
+
+impl Method {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Method::Get => "GET",
+            Method::Post => "POST",
+            Method::Put => "PUT",
+            Method::Patch => "PATCH",
+            Method::Delete => "DELETE",
+        }
+    }
+}
+
+impl fmt::Display for Method {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Request {
+    pub method: Method,
+    /// The absolute url, including the query string.
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Option<Vec<u8>>,
+}
+
+impl Request {
+    pub fn new(method: Method, url: impl Into<String>) -> Self {
+        Request {
+            method,
+            url: url.into(),
+            headers: Vec::new(),
+            body: None,
+        }
+    }
+
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    pub fn body(mut self, body: Vec<u8>) -> Self {
+        self.body = Some(body);
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl Response {
+    /// The value of the first header called `name`, compared case-insensitively.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+}
+
+/// Sends requests and waits on behalf of a [`Client`](../struct.Client.html).
+pub trait HttpBackend: fmt::Debug + Send + Sync {
+    /// Send `request` and read the whole response, whatever its status code.
+    fn send(&self, request: Request) -> BoxFuture<'_, Result<Response, BackendError>>;
+
+    /// Wait for `duration`. Used for poll intervals, rate limit resets, and retry backoff.
+    fn sleep(&self, duration: Duration) -> BoxFuture<'_, ()>;
+}
+
+/// An async backend built on [reqwest](https://docs.rs/reqwest). Must be used within a tokio runtime.
+#[cfg(feature = "reqwest")]
+#[derive(Debug, Clone, Default)]
+pub struct Reqwest {
+    client: reqwest::Client,
+}
+
+#[cfg(feature = "reqwest")]
+impl Reqwest {
+    pub fn new(client: reqwest::Client) -> Self {
+        Reqwest { client }
+    }
+}
+
+#[cfg(feature = "reqwest")]
+impl HttpBackend for Reqwest {
+    fn send(&self, request: Request) -> BoxFuture<'_, Result<Response, BackendError>> {
+        Box::pin(async move {
+            let method = reqwest::Method::from_bytes(request.method.as_str().as_bytes())?;
+            let mut req = self.client.request(method, &request.url);
+            for (name, value) in &request.headers {
+                req = req.header(name.as_str(), value.as_str());
+            }
+            if let Some(body) = request.body {
+                req = req.body(body);
+            }
+            let res = req.send().await?;
+            let status = res.status().as_u16();
+            let headers = res
+                .headers()
+                .iter()
+                .filter_map(|(name, value)| {
+                    Some((name.to_string(), value.to_str().ok()?.to_owned()))
+                })
+                .collect();
+            let body = res.bytes().await?.to_vec();
+            Ok(Response {
+                status,
+                headers,
+                body,
+            })
+        })
+    }
+
+    fn sleep(&self, duration: Duration) -> BoxFuture<'_, ()> {
+        Box::pin(tokio::time::sleep(duration))
+    }
+}
+
+/// A blocking backend built on [ureq](https://docs.rs/ureq).
+///
+/// The futures returned by this backend do all their work before they are first polled,
+/// blocking the calling thread, so they can be driven by any executor.
+#[cfg(feature = "ureq")]
+#[derive(Debug, Clone)]
+pub struct Ureq {
+    agent: ureq::Agent,
+}
+
+#[cfg(feature = "ureq")]
+impl Default for Ureq {
+    fn default() -> Self {
+        Ureq::new(ureq::Agent::new())
+    }
+}
+
+#[cfg(feature = "ureq")]
+impl Ureq {
+    pub fn new(agent: ureq::Agent) -> Self {
+        Ureq { agent }
+    }
+
+    fn send_blocking(&self, request: Request) -> Result<Response, BackendError> {
+        let mut req = self.agent.request(request.method.as_str(), &request.url);
+        for (name, value) in &request.headers {
+            req = req.set(name, value);
+        }
+        let result = match request.body {
+            Some(body) => req.send_bytes(&body),
+            None => req.call(),
+        };
+        let res = match result {
+            Ok(res) | Err(ureq::Error::Status(_, res)) => res,
+            Err(e) => return Err(e.into()),
+        };
+        let status = res.status();
+        let headers = res
+            .headers_names()
+            .into_iter()
+            .filter_map(|name| {
+                let value = res.header(&name)?.to_owned();
+                Some((name, value))
+            })
+            .collect();
+        let mut body = Vec::new();
+        std::io::Read::read_to_end(&mut res.into_reader(), &mut body)?;
+        Ok(Response {
+            status,
+            headers,
+            body,
+        })
+    }
+}
+
+#[cfg(feature = "ureq")]
+impl HttpBackend for Ureq {
+    fn send(&self, request: Request) -> BoxFuture<'_, Result<Response, BackendError>> {
+        Box::pin(futures::future::ready(self.send_blocking(request)))
+    }
+
+    fn sleep(&self, duration: Duration) -> BoxFuture<'_, ()> {
+        std::thread::sleep(duration);
+        Box::pin(futures::future::ready(()))
+    }
+}
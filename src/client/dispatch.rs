@@ -0,0 +1,35 @@
+//! Repository dispatch API calls.
+use serde::Serialize;
+
+use super::backend::Method;
+use super::{Client, ClientError};
+
+#[derive(Serialize)]
+struct Dispatch<'a, P> {
+    event_type: &'a str,
+    client_payload: P,
+}
+
+impl Client {
+    /// [Create a repository dispatch event](https://docs.github.com/en/rest/repos/repos#create-a-repository-dispatch-event),
+    /// which is delivered to the repository's webhooks as a
+    /// [`RepositoryDispatchEvent`](../enum.Event.html#variant.RepositoryDispatchEvent)
+    /// with `event_type` as its `action`.
+    ///
+    /// `client_payload` must serialize to a JSON object with at most 10 top-level properties.
+    pub async fn send_repository_dispatch(
+        &self,
+        owner: &str,
+        repo: &str,
+        event_type: &str,
+        client_payload: impl Serialize,
+    ) -> Result<(), ClientError> {
+        let url = self.url(&format!("/repos/{}/{}/dispatches", owner, repo));
+        let body = Dispatch {
+            event_type,
+            client_payload,
+        };
+        self.send_body(Method::Post, &url, &body).await?;
+        Ok(())
+    }
+}
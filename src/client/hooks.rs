@@ -0,0 +1,226 @@
+//! Webhook API calls.
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use super::backend::{Method, Response};
+use super::{link_rel, Client, ClientError};
+use crate::deliveries::{HookDelivery, HookDeliveryDetail};
+use crate::hooks::{EditHook, Hook, NewHook};
+use crate::HookId;
+
+/// Identifies a webhook.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HookRef<'a> {
+    /// A repository webhook.
+    Repo {
+        owner: &'a str,
+        repo: &'a str,
+        id: HookId,
+    },
+    /// An organization webhook.
+    Org { org: &'a str, id: HookId },
+    /// The webhook of the GitHub App the client is [authenticated](../../auth/struct.AppAuth.html) as.
+    /// Only its deliveries can be managed through this client.
+    App,
+}
+
+impl<'a> HookRef<'a> {
+    fn path(&self) -> String {
+        match self {
+            HookRef::Repo { owner, repo, id } => format!("/repos/{}/{}/hooks/{}", owner, repo, id),
+            HookRef::Org { org, id } => format!("/orgs/{}/hooks/{}", org, id),
+            HookRef::App => "/app/hook".to_owned(),
+        }
+    }
+}
+
+/// One page of a webhook's deliveries, most recent first.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct DeliveryPage {
+    pub deliveries: Vec<HookDelivery>,
+    /// The url of the next (older) page, if any.
+    pub next: Option<String>,
+}
+
+/// Sent in place of a [`NewHook`](../hooks/struct.NewHook.html), since the name of every
+/// webhook has to be `web`.
+#[derive(Serialize)]
+struct CreateHook<'a> {
+    name: &'static str,
+    #[serde(flatten)]
+    hook: &'a NewHook,
+}
+
+impl Client {
+    /// [List](https://docs.github.com/en/rest/webhooks/repos#list-repository-webhooks)
+    /// a repository's webhooks.
+    pub async fn repo_hooks(&self, owner: &str, repo: &str) -> Result<Vec<Hook>, ClientError> {
+        let url = self.url(&format!("/repos/{}/{}/hooks?per_page=100", owner, repo));
+        self.get_all(url).await
+    }
+
+    /// [List](https://docs.github.com/en/rest/orgs/webhooks#list-organization-webhooks)
+    /// an organization's webhooks.
+    pub async fn org_hooks(&self, org: &str) -> Result<Vec<Hook>, ClientError> {
+        let url = self.url(&format!("/orgs/{}/hooks?per_page=100", org));
+        self.get_all(url).await
+    }
+
+    /// [Create](https://docs.github.com/en/rest/webhooks/repos#create-a-repository-webhook)
+    /// a repository webhook. GitHub sends it a [`PingEvent`](../enum.Event.html#variant.PingEvent) once created.
+    pub async fn create_repo_hook(
+        &self,
+        owner: &str,
+        repo: &str,
+        hook: &NewHook,
+    ) -> Result<Hook, ClientError> {
+        let url = self.url(&format!("/repos/{}/{}/hooks", owner, repo));
+        let body = CreateHook { name: "web", hook };
+        self.send_json(Method::Post, &url, &body).await
+    }
+
+    /// [Create](https://docs.github.com/en/rest/orgs/webhooks#create-an-organization-webhook)
+    /// an organization webhook. GitHub sends it a [`PingEvent`](../enum.Event.html#variant.PingEvent) once created.
+    pub async fn create_org_hook(&self, org: &str, hook: &NewHook) -> Result<Hook, ClientError> {
+        let url = self.url(&format!("/orgs/{}/hooks", org));
+        let body = CreateHook { name: "web", hook };
+        self.send_json(Method::Post, &url, &body).await
+    }
+
+    /// [Get](https://docs.github.com/en/rest/webhooks/repos#get-a-repository-webhook) a webhook.
+    pub async fn hook(&self, hook: HookRef<'_>) -> Result<Hook, ClientError> {
+        let url = self.url(&hook.path());
+        self.get_json(&url).await
+    }
+
+    /// [Update](https://docs.github.com/en/rest/webhooks/repos#update-a-repository-webhook) a webhook.
+    pub async fn update_hook(
+        &self,
+        hook: HookRef<'_>,
+        edit: &EditHook,
+    ) -> Result<Hook, ClientError> {
+        let url = self.url(&hook.path());
+        self.send_json(Method::Patch, &url, edit).await
+    }
+
+    /// [Ping](https://docs.github.com/en/rest/webhooks/repos#ping-a-repository-webhook) a webhook,
+    /// triggering a [`PingEvent`](../enum.Event.html#variant.PingEvent) to be sent to it.
+    pub async fn ping_hook(&self, hook: HookRef<'_>) -> Result<(), ClientError> {
+        let url = self.url(&format!("{}/pings", hook.path()));
+        let res = self.send(|| self.request(Method::Post, &url)).await?;
+        if !res.is_success() {
+            return Err(ClientError::Status(res.status));
+        }
+        Ok(())
+    }
+
+    /// List the most recent [deliveries](https://docs.github.com/en/rest/webhooks/repo-deliveries#list-deliveries-for-a-repository-webhook)
+    /// of a webhook.
+    pub async fn hook_deliveries(&self, hook: HookRef<'_>) -> Result<DeliveryPage, ClientError> {
+        let url = self.url(&format!("{}/deliveries?per_page=100", hook.path()));
+        self.delivery_page(&url).await
+    }
+
+    /// The page of deliveries following `page`, if any.
+    pub async fn next_hook_deliveries(
+        &self,
+        page: &DeliveryPage,
+    ) -> Result<Option<DeliveryPage>, ClientError> {
+        match &page.next {
+            Some(url) => self.delivery_page(url).await.map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// [Get a delivery](https://docs.github.com/en/rest/webhooks/repo-deliveries#get-a-delivery-for-a-repository-webhook),
+    /// including the payload that was sent.
+    pub async fn hook_delivery(
+        &self,
+        hook: HookRef<'_>,
+        delivery_id: i64,
+    ) -> Result<HookDeliveryDetail, ClientError> {
+        let url = self.url(&format!("{}/deliveries/{}", hook.path(), delivery_id));
+        self.get_json(&url).await
+    }
+
+    /// [Redeliver](https://docs.github.com/en/rest/webhooks/repo-deliveries#redeliver-a-delivery-for-a-repository-webhook)
+    /// a delivery. GitHub sends it again asynchronously, with a new delivery id but the same `guid`.
+    pub async fn redeliver(&self, hook: HookRef<'_>, delivery_id: i64) -> Result<(), ClientError> {
+        let url = self.url(&format!(
+            "{}/deliveries/{}/attempts",
+            hook.path(),
+            delivery_id
+        ));
+        let res = self.send(|| self.request(Method::Post, &url)).await?;
+        if !res.is_success() {
+            return Err(ClientError::Status(res.status));
+        }
+        Ok(())
+    }
+
+    async fn delivery_page(&self, url: &str) -> Result<DeliveryPage, ClientError> {
+        let res = self.send(|| self.get(url)).await?;
+        if !res.is_success() {
+            return Err(ClientError::Status(res.status));
+        }
+        Ok(DeliveryPage {
+            deliveries: serde_json::from_slice(&res.body)?,
+            next: res.header("link").and_then(|links| link_rel(links, "next")),
+        })
+    }
+
+    pub(crate) async fn get_json<T: DeserializeOwned>(&self, url: &str) -> Result<T, ClientError> {
+        let res = self.send(|| self.get(url)).await?;
+        if !res.is_success() {
+            return Err(ClientError::Status(res.status));
+        }
+        Ok(serde_json::from_slice(&res.body)?)
+    }
+
+    /// Get every page of a list, following `next` links from `url`.
+    async fn get_all<T: DeserializeOwned>(&self, url: String) -> Result<Vec<T>, ClientError> {
+        let mut items = Vec::new();
+        let mut next = Some(url);
+        while let Some(url) = next {
+            let res = self.send(|| self.get(&url)).await?;
+            if !res.is_success() {
+                return Err(ClientError::Status(res.status));
+            }
+            items.extend(serde_json::from_slice::<Vec<T>>(&res.body)?);
+            next = res.header("link").and_then(|links| link_rel(links, "next"));
+        }
+        Ok(items)
+    }
+
+    async fn send_json<B: Serialize, T: DeserializeOwned>(
+        &self,
+        method: Method,
+        url: &str,
+        body: &B,
+    ) -> Result<T, ClientError> {
+        let res = self.send_body(method, url, body).await?;
+        Ok(serde_json::from_slice(&res.body)?)
+    }
+
+    /// Send `body` as JSON, failing unless the response is successful.
+    pub(crate) async fn send_body<B: Serialize>(
+        &self,
+        method: Method,
+        url: &str,
+        body: &B,
+    ) -> Result<Response, ClientError> {
+        let body = serde_json::to_vec(body)?;
+        let res = self
+            .send(|| {
+                self.request(method, url)
+                    .header("Content-Type", "application/json")
+                    .body(body.clone())
+            })
+            .await?;
+        if !res.is_success() {
+            return Err(ClientError::Status(res.status));
+        }
+        Ok(res)
+    }
+}
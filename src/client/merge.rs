@@ -0,0 +1,78 @@
+//! Merging the streams of several endpoints into one timeline.
+use std::time::{Duration, Instant};
+
+use futures::future::{self, Either};
+use futures::stream::{self, Stream, StreamExt};
+
+use super::{Client, ClientError, EventStream};
+use crate::timeline::TimelineEvent;
+
+/// How many events a merged stream holds back at most, waiting for earlier ones.
+const MERGE_CAPACITY: usize = 1000;
+
+struct Held {
+    event: TimelineEvent,
+    arrived: Instant,
+}
+
+impl Client {
+    /// Merge `streams`, such as those of several repositories or organizations, into one stream
+    /// ordered by `created_at`. Sleeps on this client's backend.
+    ///
+    /// Endpoints are polled independently, so an event can arrive after later ones from another
+    /// stream. Each event is held back for up to `window` after it arrives, and yielded after
+    /// any earlier event that arrives in the meantime; one arriving even later is yielded as it
+    /// comes, out of order. At most 1000 events are held back, beyond which the earliest are
+    /// yielded early. Errors are yielded as they come.
+    pub fn merge<S>(&self, streams: impl IntoIterator<Item = S>, window: Duration) -> EventStream
+    where
+        S: Stream<Item = Result<TimelineEvent, ClientError>> + Send + Unpin + 'static,
+    {
+        let state = (
+            self.http.clone(),
+            stream::select_all(streams),
+            Vec::<Held>::new(),
+            false,
+        );
+        let inner = stream::unfold(state, move |mut state| async move {
+            let (http, inputs, held, done) = &mut state;
+            loop {
+                let next = match held.iter().map(|h| h.arrived).min() {
+                    Some(arrived) => {
+                        let now = Instant::now();
+                        if *done || held.len() > MERGE_CAPACITY || now >= arrived + window {
+                            // `held` is ordered by `created_at`.
+                            let event = held.remove(0).event;
+                            return Some((Ok(event), state));
+                        }
+                        match future::select(inputs.next(), http.sleep(arrived + window - now))
+                            .await
+                        {
+                            Either::Left((next, _)) => next,
+                            Either::Right(_) => continue,
+                        }
+                    }
+                    None if *done => return None,
+                    None => inputs.next().await,
+                };
+                match next {
+                    Some(Ok(event)) => {
+                        let at = held.partition_point(|h| h.event.created_at <= event.created_at);
+                        held.insert(
+                            at,
+                            Held {
+                                event,
+                                arrived: Instant::now(),
+                            },
+                        );
+                    }
+                    Some(Err(e)) => return Some((Err(e), state)),
+                    None => *done = true,
+                }
+            }
+        });
+        EventStream {
+            inner: Box::pin(inner),
+        }
+    }
+}
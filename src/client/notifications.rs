@@ -0,0 +1,145 @@
+//! Notifications API calls.
+use std::time::{Duration, Instant};
+
+use super::backend::{Method, Response};
+use super::{link_rel, Client, ClientError, DEFAULT_POLL_INTERVAL};
+use crate::notifications::Thread;
+
+impl Client {
+    /// Poll the authenticated user's [notifications](https://docs.github.com/en/rest/activity/notifications#list-notifications-for-the-authenticated-user).
+    pub fn notifications(&self) -> NotificationPoller {
+        self.notification_poller("/notifications")
+    }
+
+    /// Poll the authenticated user's [notifications in a repository](https://docs.github.com/en/rest/activity/notifications#list-repository-notifications-for-the-authenticated-user).
+    pub fn repo_notifications(&self, owner: &str, repo: &str) -> NotificationPoller {
+        self.notification_poller(&format!("/repos/{}/{}/notifications", owner, repo))
+    }
+
+    fn notification_poller(&self, path: &str) -> NotificationPoller {
+        NotificationPoller {
+            client: self.clone(),
+            url: self.url(path),
+            all: false,
+            participating: false,
+            last_modified: None,
+            interval: DEFAULT_POLL_INTERVAL,
+            next_poll: None,
+        }
+    }
+
+    /// [Mark a thread as read](https://docs.github.com/en/rest/activity/notifications#mark-a-thread-as-read).
+    pub async fn mark_thread_read(&self, thread_id: &str) -> Result<(), ClientError> {
+        let url = self.url(&format!("/notifications/threads/{}", thread_id));
+        let res = self.send(|| self.request(Method::Patch, &url)).await?;
+        if !res.is_success() {
+            return Err(ClientError::Status(res.status));
+        }
+        Ok(())
+    }
+}
+
+/// Repeatedly polls a user's notifications.
+///
+/// Each poll sends the `Last-Modified` time of the previous one in `If-Modified-Since`, so that
+/// it returns only the threads updated since, and nothing, without counting against the rate
+/// limit, if none were.
+#[derive(Debug, Clone)]
+pub struct NotificationPoller {
+    client: Client,
+    url: String,
+    all: bool,
+    participating: bool,
+    last_modified: Option<String>,
+    interval: Duration,
+    next_poll: Option<Instant>,
+}
+
+impl NotificationPoller {
+    /// Also list threads already marked as read.
+    pub fn all(mut self, all: bool) -> Self {
+        self.all = all;
+        self
+    }
+
+    /// Only list threads the user is participating in or was mentioned in.
+    pub fn participating(mut self, participating: bool) -> Self {
+        self.participating = participating;
+        self
+    }
+
+    /// Start from the `Last-Modified` time of an earlier poll, such as one saved with
+    /// [`last_modified`](#method.last_modified) before a restart.
+    pub fn with_last_modified(mut self, last_modified: impl Into<String>) -> Self {
+        self.last_modified = Some(last_modified.into());
+        self
+    }
+
+    /// The `Last-Modified` header of the latest poll that returned threads.
+    pub fn last_modified(&self) -> Option<&str> {
+        self.last_modified.as_deref()
+    }
+
+    /// The interval GitHub most recently asked us to respect between polls.
+    pub fn poll_interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Fetch the threads updated since the previous poll, most recently updated first, or
+    /// every thread on the first poll.
+    ///
+    /// Waits out the remainder of the current poll interval before sending the request.
+    pub async fn poll(&mut self) -> Result<Vec<Thread>, ClientError> {
+        if let Some(next_poll) = self.next_poll {
+            let now = Instant::now();
+            if next_poll > now {
+                self.client.http.sleep(next_poll - now).await;
+            }
+        }
+
+        let url = format!(
+            "{}?all={}&participating={}&per_page=50",
+            self.url, self.all, self.participating
+        );
+        let res = self
+            .client
+            .send(|| match &self.last_modified {
+                Some(since) => self
+                    .client
+                    .get(&url)
+                    .header("If-Modified-Since", since.as_str()),
+                None => self.client.get(&url),
+            })
+            .await?;
+        self.update_interval(&res);
+        if res.status == 304 {
+            return Ok(Vec::new());
+        }
+        if !res.is_success() {
+            return Err(ClientError::Status(res.status));
+        }
+        let last_modified = res.header("last-modified").map(str::to_owned);
+        let mut threads: Vec<Thread> = serde_json::from_slice(&res.body)?;
+        let mut next = res.header("link").and_then(|links| link_rel(links, "next"));
+        while let Some(url) = next {
+            let res = self.client.send(|| self.client.get(&url)).await?;
+            if !res.is_success() {
+                return Err(ClientError::Status(res.status));
+            }
+            threads.extend(serde_json::from_slice::<Vec<Thread>>(&res.body)?);
+            next = res.header("link").and_then(|links| link_rel(links, "next"));
+        }
+        // Only once every page was read, so that a failure is retried from the same point.
+        if last_modified.is_some() {
+            self.last_modified = last_modified;
+        }
+        Ok(threads)
+    }
+
+    fn update_interval(&mut self, res: &Response) {
+        if let Some(secs) = res.header("x-poll-interval").and_then(|s| s.parse().ok()) {
+            self.interval = Duration::from_secs(secs);
+        }
+        self.next_poll = Some(Instant::now() + self.interval);
+    }
+}
@@ -0,0 +1,151 @@
+//! Recovering events missed while a webhook receiver was down.
+use std::collections::HashSet;
+
+use super::{Client, ClientError, HookRef, Poller};
+use crate::deliveries::HookDeliveryDetail;
+use crate::timeline::TimelineEvent;
+use crate::{timestamp, Event};
+
+/// The last event a receiver is known to have processed.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Checkpoint {
+    /// The `X-GitHub-Delivery` header of the last processed delivery.
+    Guid(String),
+    /// A timestamp in the format GitHub uses, e.g. `2019-06-03T00:57:16Z`, or with an offset.
+    /// Everything delivered or created after it, to the second, is missed.
+    Since(String),
+}
+
+/// An event a receiver missed.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Missed {
+    /// A webhook delivery, including its original payload.
+    Delivery(Box<HookDeliveryDetail>),
+    /// An event from the Events API that happened before the hook's oldest logged delivery.
+    Timeline(Box<TimelineEvent>),
+}
+
+impl Missed {
+    /// When the event was delivered or created.
+    pub fn timestamp(&self) -> &str {
+        match self {
+            Missed::Delivery(detail) => &detail.delivery.delivered_at,
+            Missed::Timeline(event) => &event.created_at,
+        }
+    }
+
+    /// The webhook event, if this is a delivery.
+    pub fn event(&self) -> Option<Result<Event, ::serde_json::Error>> {
+        match self {
+            Missed::Delivery(detail) => Some(detail.parse_event()),
+            Missed::Timeline(_) => None,
+        }
+    }
+}
+
+/// The events missed since a [`Checkpoint`](enum.Checkpoint.html), oldest first.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct Reconciliation {
+    pub missed: Vec<Missed>,
+    /// Whether the checkpoint was found in the hook's delivery log.
+    /// GitHub only keeps a few days of deliveries, so if it wasn't, events may have been lost
+    /// beyond what the Events API fallback was able to recover.
+    pub complete: bool,
+}
+
+/// Finds the events a webhook receiver missed during downtime.
+///
+/// The hook's delivery log is searched back to the checkpoint. Every delivery after it is fetched
+/// with its payload; redeliveries of the same event are only returned once.
+/// If the log doesn't reach back that far, an optional Events API [`Poller`](struct.Poller.html)
+/// fills in the gap before the oldest logged delivery. That takes a [`Since`] checkpoint: the
+/// time of a [`Guid`] that's no longer in the log isn't known, so neither is which events came
+/// after it.
+///
+/// [`Since`]: enum.Checkpoint.html#variant.Since
+/// [`Guid`]: enum.Checkpoint.html#variant.Guid
+#[derive(Debug, Clone)]
+pub struct Reconciler<'a> {
+    client: &'a Client,
+    hook: HookRef<'a>,
+    fallback: Option<Poller>,
+}
+
+impl<'a> Reconciler<'a> {
+    pub fn new(client: &'a Client, hook: HookRef<'a>) -> Self {
+        Reconciler {
+            client,
+            hook,
+            fallback: None,
+        }
+    }
+
+    /// Recover events older than the delivery log from `poller`,
+    /// e.g. [`Client::repo_events`](struct.Client.html#method.repo_events) with a `backfill`.
+    pub fn fallback(mut self, poller: Poller) -> Self {
+        self.fallback = Some(poller);
+        self
+    }
+
+    pub async fn missed_since(
+        &self,
+        checkpoint: &Checkpoint,
+    ) -> Result<Reconciliation, ClientError> {
+        let since = match checkpoint {
+            Checkpoint::Since(since) => timestamp::to_unix(since),
+            Checkpoint::Guid(_) => None,
+        };
+        let mut deliveries = Vec::new();
+        let mut complete = false;
+        let mut page = Some(self.client.hook_deliveries(self.hook).await?);
+        'pages: while let Some(current) = page {
+            for delivery in &current.deliveries {
+                let reached = match checkpoint {
+                    Checkpoint::Guid(guid) => &delivery.guid == guid,
+                    Checkpoint::Since(_) => {
+                        let delivered_at = timestamp::to_unix(&delivery.delivered_at);
+                        matches!((delivered_at, since), (Some(at), Some(since)) if at <= since)
+                    }
+                };
+                if reached {
+                    complete = true;
+                    break 'pages;
+                }
+                deliveries.push(delivery.clone());
+            }
+            page = self.client.next_hook_deliveries(&current).await?;
+        }
+
+        let mut missed = Vec::new();
+        if let (false, Some(since), Some(poller)) = (complete, since, &self.fallback) {
+            let until = deliveries
+                .last()
+                .and_then(|d| timestamp::to_unix(&d.delivered_at));
+            let mut events = poller.clone().poll().await?;
+            // Events whose time can't be read are kept, to be safe.
+            events.retain(|event| match timestamp::to_unix(&event.created_at) {
+                Some(created_at) => {
+                    created_at > since && until.is_none_or(|until| created_at < until)
+                }
+                None => true,
+            });
+            missed.extend(
+                events
+                    .into_iter()
+                    .rev()
+                    .map(|event| Missed::Timeline(Box::new(event))),
+            );
+        }
+        // Keep the first attempt of each event, so redeliveries don't reorder it.
+        let mut guids = HashSet::new();
+        for delivery in deliveries.iter().rev() {
+            if !guids.insert(&delivery.guid) {
+                continue;
+            }
+            let detail = self.client.hook_delivery(self.hook, delivery.id).await?;
+            missed.push(Missed::Delivery(Box::new(detail)));
+        }
+        Ok(Reconciliation { missed, complete })
+    }
+}
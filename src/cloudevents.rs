@@ -0,0 +1,91 @@
+//! Events in the [CloudEvents 1.0](https://github.com/cloudevents/spec/blob/v1.0.2/cloudevents/formats/json-format.md)
+//! JSON format, for Knative, Argo Events, and other systems built on CloudEvents.
+//! Enabled by the `cloudevents` feature.
+use serde::de::Error as _;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{Event, EventType};
+
+const SPEC_VERSION: &str = "1.0";
+const TYPE_PREFIX: &str = "com.github.";
+
+/// A webhook event wrapped in a CloudEvent.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct CloudEvent {
+    /// Always `1.0`.
+    pub specversion: String,
+    /// The delivery's GUID, from the `X-GitHub-Delivery` header.
+    pub id: String,
+    /// The `html_url` of the event's repository, or else of its organization.
+    pub source: String,
+    /// `com.github.<event>.<action>`, e.g. `com.github.issues.opened`,
+    /// or `com.github.<event>` for events without actions.
+    #[serde(rename = "type")]
+    pub type_field: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub datacontenttype: Option<String>,
+    /// The webhook payload.
+    pub data: Value,
+}
+
+impl CloudEvent {
+    /// Wrap `event`, which was delivered with the GUID `delivery`.
+    pub fn wrap(event: &Event, delivery: impl Into<String>) -> Result<Self, ::serde_json::Error> {
+        let data = match ::serde_json::to_value(event)? {
+            Value::Object(tagged) => tagged.into_iter().next().map(|(_, data)| data),
+            _ => None,
+        }
+        .unwrap_or(Value::Null);
+
+        let event_type = event.event_type();
+        let type_field = match data.get("action").and_then(Value::as_str) {
+            Some(action) => format!("{}{}.{}", TYPE_PREFIX, event_type, action),
+            None => format!("{}{}", TYPE_PREFIX, event_type),
+        };
+        let source = data["repository"]["html_url"]
+            .as_str()
+            .map(String::from)
+            .or_else(|| {
+                data["organization"]["login"]
+                    .as_str()
+                    .map(|login| format!("https://github.com/{}", login))
+            })
+            .unwrap_or_else(|| "https://github.com".to_owned());
+
+        Ok(CloudEvent {
+            specversion: SPEC_VERSION.to_owned(),
+            id: delivery.into(),
+            source,
+            type_field,
+            datacontenttype: Some("application/json".to_owned()),
+            data,
+        })
+    }
+
+    /// The type of the wrapped event, read from [`type_field`](#structfield.type_field).
+    ///
+    /// `None` if the CloudEvent didn't come from GitHub, or the event is unknown.
+    pub fn event_type(&self) -> Option<EventType> {
+        let name = self.type_field.strip_prefix(TYPE_PREFIX)?;
+        name.split('.').next()?.parse().ok()
+    }
+
+    /// Parse the wrapped event.
+    pub fn into_event(self) -> Result<Event, ::serde_json::Error> {
+        if self.specversion != SPEC_VERSION {
+            return Err(::serde_json::Error::custom(format_args!(
+                "unsupported CloudEvents version {}",
+                self.specversion
+            )));
+        }
+        let event_type = self.event_type().ok_or_else(|| {
+            ::serde_json::Error::custom(format_args!(
+                "`{}` isn't a GitHub event type",
+                self.type_field
+            ))
+        })?;
+        Event::from_value(event_type, self.data)
+    }
+}
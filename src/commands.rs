@@ -0,0 +1,213 @@
+//! Slash commands in comments, such as `/deploy staging` or `@mybot retry "flaky test"`, for
+//! ChatOps-style bots.
+//!
+//! A [`CommandParser`](struct.CommandParser.html) finds commands in the bodies of issue
+//! comments, pull request review comments, and reviews. Each command is on a line of its own,
+//! starting with `/` or, if the parser knows the bot's login, with a mention of the bot.
+//! Lines in code blocks and quotes are skipped, so that quoting someone's command doesn't run it
+//! again.
+//!
+//! Arguments are separated by whitespace and quoted like in a shell: `"…"` and `'…'` group
+//! words, and outside single quotes `\` escapes the next character.
+use crate::actions::CrEdDel;
+use crate::prelude::*;
+use crate::Event;
+
+/// A command found in a comment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Command {
+    /// The command's name, as written, without the `/`.
+    pub name: String,
+    pub args: Vec<String>,
+    pub source: Source,
+}
+
+/// Where a command was written.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Source {
+    pub kind: SourceKind,
+    /// The number of the issue or pull request.
+    pub number: i64,
+    /// The id of the comment or review.
+    pub id: i64,
+    /// The login of the comment's or review's author.
+    pub author: String,
+    /// A link to the comment or review.
+    pub html_url: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SourceKind {
+    /// A comment on an issue, or in a pull request's conversation.
+    IssueComment,
+    /// A comment on a line of a pull request's diff.
+    PullRequestReviewComment,
+    /// The body of a pull request review.
+    PullRequestReview,
+}
+
+/// Finds commands in comments.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CommandParser {
+    bot: Option<String>,
+}
+
+impl CommandParser {
+    /// A parser for commands starting with `/`.
+    pub fn new() -> Self {
+        CommandParser::default()
+    }
+
+    /// Also accept commands addressed to `login`, as in `@mybot deploy` or `@mybot /deploy`, and
+    /// ignore comments written by it. The `[bot]` suffix of an app's login may be left off.
+    pub fn with_bot(mut self, login: impl Into<String>) -> Self {
+        self.bot = Some(login.into());
+        self
+    }
+
+    /// The commands in `event`: a newly created issue comment or review comment, or a newly
+    /// submitted review. Edits, deletions, and other events have none.
+    pub fn commands(&self, event: &Event) -> Vec<Command> {
+        let (body, source) = match event {
+            Event::IssueCommentEvent(e) if e.action == CrEdDel::Created => (
+                e.comment.body.as_str(),
+                Source {
+                    kind: SourceKind::IssueComment,
+                    number: e.issue.number,
+                    id: e.comment.id,
+                    author: e.comment.user.login.to_string(),
+                    html_url: e.comment.html_url.clone(),
+                },
+            ),
+            Event::PullRequestReviewCommentEvent(e) if e.action == "created" => (
+                e.comment.body.as_str(),
+                Source {
+                    kind: SourceKind::PullRequestReviewComment,
+                    number: e.pull_request.number,
+                    id: e.comment.id,
+                    author: e.comment.user.login.to_string(),
+                    html_url: e.comment.html_url.clone(),
+                },
+            ),
+            Event::PullRequestReviewEvent(e) if e.action == "submitted" => (
+                e.review.body.as_str().unwrap_or_default(),
+                Source {
+                    kind: SourceKind::PullRequestReview,
+                    number: e.pull_request.number,
+                    id: e.review.id,
+                    author: e.review.user.login.to_string(),
+                    html_url: e.review.html_url.clone(),
+                },
+            ),
+            _ => return Vec::new(),
+        };
+        if self.is_bot(&source.author) {
+            return Vec::new();
+        }
+        self.parse(body)
+            .into_iter()
+            .map(|(name, args)| Command {
+                name,
+                args,
+                source: source.clone(),
+            })
+            .collect()
+    }
+
+    /// The names and arguments of the commands in a comment's `body`, in order.
+    pub fn parse(&self, body: &str) -> Vec<(String, Vec<String>)> {
+        let mut commands = Vec::new();
+        let mut fence: Option<&str> = None;
+        for line in body.lines() {
+            let trimmed = line.trim();
+            if let Some(marker) = fence {
+                if trimmed.starts_with(marker) {
+                    fence = None;
+                }
+                continue;
+            }
+            if let Some(marker) = ["```", "~~~"].iter().find(|m| trimmed.starts_with(**m)) {
+                fence = Some(marker);
+                continue;
+            }
+            if line.starts_with("    ") || line.starts_with('\t') || trimmed.starts_with('>') {
+                continue;
+            }
+            if let Some(command) = self.parse_line(trimmed) {
+                commands.push(command);
+            }
+        }
+        commands
+    }
+
+    fn parse_line(&self, line: &str) -> Option<(String, Vec<String>)> {
+        let rest = match line.strip_prefix('@') {
+            Some(mention) => {
+                let end = mention.find(char::is_whitespace).unwrap_or(mention.len());
+                if !self.is_bot(&mention[..end]) {
+                    return None;
+                }
+                let rest = mention[end..].trim_start();
+                rest.strip_prefix('/').unwrap_or(rest)
+            }
+            None => line.strip_prefix('/')?,
+        };
+        let end = rest
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '-' || c == '_'))
+            .unwrap_or(rest.len());
+        let (name, args) = rest.split_at(end);
+        if name.is_empty() || !(args.is_empty() || args.starts_with(char::is_whitespace)) {
+            return None;
+        }
+        Some((name.to_owned(), split_args(args)))
+    }
+
+    /// Whether `login` is the bot's, with or without `[bot]`.
+    fn is_bot(&self, login: &str) -> bool {
+        let bot = match &self.bot {
+            Some(bot) => bot.strip_suffix("[bot]").unwrap_or(bot),
+            None => return false,
+        };
+        let login = login.strip_suffix("[bot]").unwrap_or(login);
+        login.eq_ignore_ascii_case(bot)
+    }
+}
+
+/// The arguments in `s`, unquoted. An unclosed quote runs to the end of the line.
+fn split_args(s: &str) -> Vec<String> {
+    let mut args = Vec::new();
+    let mut arg = String::new();
+    let mut in_arg = false;
+    let mut quote = None;
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (None, '"') | (None, '\'') => {
+                quote = Some(c);
+                in_arg = true;
+            }
+            (Some('\''), c) => arg.push(c),
+            (_, '\\') => {
+                arg.extend(chars.next());
+                in_arg = true;
+            }
+            (None, c) if c.is_whitespace() => {
+                if in_arg {
+                    args.push(core::mem::take(&mut arg));
+                    in_arg = false;
+                }
+            }
+            (_, c) => {
+                arg.push(c);
+                in_arg = true;
+            }
+        }
+    }
+    if in_arg {
+        args.push(arg);
+    }
+    args
+}
@@ -0,0 +1,202 @@
+//! Checking this crate's types against GitHub's published webhook examples,
+//! enabled by the `conformance` feature.
+//!
+//! The examples are the `index.json` of the
+//! [`@octokit/webhooks-examples`](https://github.com/octokit/webhooks/tree/main/payload-examples)
+//! package, e.g. from `https://unpkg.com/@octokit/webhooks-examples/api.github.com/index.json`.
+//! They aren't bundled, since they change with every GitHub release:
+//!
+//! ```no_run
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let index = std::fs::read("index.json")?;
+//! let report = github_events::conformance::check(&github_events::conformance::parse_index(&index)?);
+//! println!("{}", report);
+//! # Ok(())
+//! # }
+//! ```
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::{Event, EventType};
+
+/// An example payload of one event.
+#[derive(Debug, Clone)]
+pub struct Example {
+    /// The webhook name of the event, e.g. `pull_request`.
+    pub event: String,
+    pub body: Value,
+}
+
+impl Example {
+    fn name(&self, index: usize) -> String {
+        match self.body.get("action").and_then(Value::as_str) {
+            Some(action) => format!("{}.{} #{}", self.event, action, index),
+            None => format!("{} #{}", self.event, index),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct IndexEntry {
+    name: String,
+    #[serde(default)]
+    examples: Vec<Value>,
+}
+
+/// Read the examples out of an `@octokit/webhooks-examples` index.
+pub fn parse_index(index: &[u8]) -> Result<Vec<Example>, ::serde_json::Error> {
+    let entries: Vec<IndexEntry> = ::serde_json::from_slice(index)?;
+    Ok(entries
+        .into_iter()
+        .flat_map(|entry| {
+            let event = entry.name;
+            entry.examples.into_iter().map(move |body| Example {
+                event: event.clone(),
+                body,
+            })
+        })
+        .collect())
+}
+
+/// How well the types fit a set of examples.
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct Report {
+    /// The number of examples that parsed.
+    pub accepted: usize,
+    /// Examples that failed to parse, with the error.
+    pub rejected: Vec<(String, String)>,
+    /// Events the crate has no type for, with how many examples of them were skipped.
+    pub unsupported: BTreeMap<String, usize>,
+    /// Examples that still parsed with one of their objects replaced by a number,
+    /// which means the field is typed too loosely to catch malformed payloads.
+    pub lenient: Vec<(String, String)>,
+    /// The fields of each event that parsing drops, as paths like `issue.reactions.url`.
+    pub unmodeled: BTreeMap<EventType, BTreeSet<String>>,
+}
+
+impl Report {
+    /// Whether every supported example parsed and no malformed one did.
+    pub fn is_conformant(&self) -> bool {
+        self.rejected.is_empty() && self.lenient.is_empty()
+    }
+}
+
+/// Parse every example, and every example with each of its top-level objects replaced
+/// by a number.
+pub fn check(examples: &[Example]) -> Report {
+    let mut report = Report::default();
+    for (index, example) in examples.iter().enumerate() {
+        let event_type: EventType = match example.event.parse() {
+            Ok(event_type) => event_type,
+            Err(_) => {
+                *report.unsupported.entry(example.event.clone()).or_insert(0) += 1;
+                continue;
+            }
+        };
+        let name = example.name(index);
+        let parsed = match Event::from_value(event_type, example.body.clone()) {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                report.rejected.push((name, e.to_string()));
+                continue;
+            }
+        };
+        report.accepted += 1;
+
+        let reserialized = ::serde_json::to_value(&parsed).expect("events serialize");
+        let reserialized = reserialized
+            .as_object()
+            .and_then(|tagged| tagged.values().next())
+            .unwrap_or(&Value::Null);
+        let mut unmodeled = BTreeSet::new();
+        paths(&example.body, "", &mut unmodeled);
+        let mut modeled = BTreeSet::new();
+        paths(reserialized, "", &mut modeled);
+        report
+            .unmodeled
+            .entry(event_type)
+            .or_default()
+            .extend(unmodeled.difference(&modeled).cloned());
+
+        if let Value::Object(fields) = &example.body {
+            for (key, value) in fields {
+                if !value.is_object() || !reserialized[key].is_object() {
+                    continue;
+                }
+                let mut malformed = example.body.clone();
+                malformed[key] = Value::from(42);
+                if Event::from_value(event_type, malformed).is_ok() {
+                    report.lenient.push((name.clone(), key.clone()));
+                }
+            }
+        }
+    }
+    report
+}
+
+/// Every path to a field in `value`, with array elements written as `[]`.
+fn paths(value: &Value, prefix: &str, out: &mut BTreeSet<String>) {
+    match value {
+        Value::Object(fields) => {
+            for (key, value) in fields {
+                let path = if prefix.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", prefix, key)
+                };
+                paths(value, &path, out);
+                out.insert(path);
+            }
+        }
+        Value::Array(elements) => {
+            let path = format!("{}[]", prefix);
+            for element in elements {
+                paths(element, &path, out);
+            }
+        }
+        _ => {}
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "accepted {} of {} supported examples",
+            self.accepted,
+            self.accepted + self.rejected.len()
+        )?;
+        if !self.rejected.is_empty() {
+            writeln!(f, "\nrejected:")?;
+            for (name, error) in &self.rejected {
+                writeln!(f, "  {}: {}", name, error)?;
+            }
+        }
+        if !self.lenient.is_empty() {
+            writeln!(f, "\naccepted with a field replaced by a number:")?;
+            for (name, field) in &self.lenient {
+                writeln!(f, "  {}: {}", name, field)?;
+            }
+        }
+        if !self.unsupported.is_empty() {
+            writeln!(f, "\nunsupported events:")?;
+            for (event, count) in &self.unsupported {
+                writeln!(f, "  {} ({} examples)", event, count)?;
+            }
+        }
+        let unmodeled: usize = self.unmodeled.values().map(BTreeSet::len).sum();
+        if unmodeled > 0 {
+            writeln!(f, "\n{} unmodeled fields:", unmodeled)?;
+            for (event_type, fields) in &self.unmodeled {
+                for field in fields {
+                    writeln!(f, "  {}: {}", event_type, field)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,226 @@
+//! Clients authenticated as the GitHub App installation a delivery was made for, handed to
+//! handlers with the delivery. Enabled by the `context` feature.
+//!
+//! An app acts on an account's repositories with an access token of the installation on that
+//! account, which expires after an hour. A [`ContextProvider`] supplies the [`Context`] of each
+//! delivery, and [`with_context`] turns a handler taking the delivery and its context into a
+//! [`Handler`](../router/trait.Handler.html), so that handlers don't keep tokens themselves.
+//! [`AppContexts`] keeps one context per installation, whose token is created the first time a
+//! handler makes a request with it and renewed when it's about to expire.
+//!
+//! ```
+//! use std::sync::Arc;
+//!
+//! use github_events::auth::AppAuth;
+//! use github_events::client::Client;
+//! use github_events::context::{with_context, AppContexts, Context};
+//! use github_events::router::{EventRouter, HandlerError, WebhookDelivery};
+//! use github_events::EventType;
+//!
+//! async fn on_push(delivery: Arc<WebhookDelivery>, ctx: Context) -> Result<(), HandlerError> {
+//!     let token = ctx.token().await?;
+//!     println!("{} as {:?}", delivery.event.summary(), ctx.installation_id());
+//!     Ok(())
+//! }
+//!
+//! fn router(app: AppAuth, client: Client) -> EventRouter {
+//!     let contexts = Arc::new(AppContexts::new(app, client));
+//!     EventRouter::new().on(EventType::Push, with_context(contexts, on_push))
+//! }
+//! ```
+use std::collections::HashMap;
+use std::fmt;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+
+use futures::future::{self, BoxFuture};
+
+use crate::auth::{AppAuth, InstallationAuth, InstallationToken};
+use crate::client::{Client, ClientError};
+use crate::router::{Handler, HandlerError, WebhookDelivery};
+use crate::InstallationId;
+
+/// What a handler acts on a delivery with: a client authenticated as the installation the
+/// delivery was made for.
+///
+/// Cloning is cheap; clones share the installation's cached token.
+#[derive(Debug, Clone)]
+pub struct Context {
+    client: Client,
+    installation: Option<InstallationAuth>,
+}
+
+impl Context {
+    /// A context outside any installation, making requests with `client` as it is.
+    pub fn new(client: Client) -> Self {
+        Context {
+            client,
+            installation: None,
+        }
+    }
+
+    /// A context authenticated as `installation`, making requests with `client`'s backend and
+    /// settings, and keeping track of the installation's own rate limit.
+    pub fn installation(client: &Client, installation: InstallationAuth) -> Self {
+        Context {
+            client: client.authenticated_as(installation.clone()),
+            installation: Some(installation),
+        }
+    }
+
+    pub fn installation_id(&self) -> Option<InstallationId> {
+        self.installation
+            .as_ref()
+            .map(InstallationAuth::installation_id)
+    }
+
+    pub fn client(&self) -> &Client {
+        &self.client
+    }
+
+    /// A valid access token of the installation, e.g. to clone its repositories over git, or
+    /// `None` outside an installation.
+    pub async fn token(&self) -> Result<Option<InstallationToken>, ClientError> {
+        match &self.installation {
+            Some(installation) => Ok(Some(installation.token(&self.client).await?)),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Supplies the [`Context`] of each delivery a handler made with [`with_context`] handles.
+pub trait ContextProvider: Send + Sync {
+    fn context<'a>(
+        &'a self,
+        delivery: &'a WebhookDelivery,
+    ) -> BoxFuture<'a, Result<Context, HandlerError>>;
+}
+
+/// The error of an [`AppContexts`] asked for the context of a delivery that wasn't made to an
+/// installation, e.g. one to a repository's own webhook.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NotInstalled {
+    pub delivery_id: String,
+}
+
+impl fmt::Display for NotInstalled {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "delivery {} wasn't made to a GitHub App installation",
+            self.delivery_id
+        )
+    }
+}
+
+impl std::error::Error for NotInstalled {}
+
+/// The contexts of a GitHub App's installations, one for each, whose tokens are shared by all
+/// their deliveries.
+///
+/// Deliveries that weren't made to an installation fail with [`NotInstalled`]; handlers of
+/// events about the app itself, such as `github_app_authorization`, can act as the app with
+/// [`app`](#method.app) instead.
+pub struct AppContexts {
+    app: AppAuth,
+    client: Client,
+    installations: Mutex<HashMap<InstallationId, Context>>,
+}
+
+impl AppContexts {
+    /// Contexts of the installations of `app`, making requests with `client`'s backend and
+    /// settings.
+    pub fn new(app: AppAuth, client: Client) -> Self {
+        AppContexts {
+            app,
+            client,
+            installations: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// The context of the installation `installation_id`, made the first time it's asked for.
+    pub fn installation(&self, installation_id: InstallationId) -> Context {
+        let mut installations = self.installations.lock().unwrap();
+        installations
+            .entry(installation_id)
+            .or_insert_with(|| {
+                let installation = self.app.installation(installation_id);
+                Context::installation(&self.client, installation)
+            })
+            .clone()
+    }
+
+    /// A context authenticated as the app itself, which can only use the few endpoints about
+    /// the app and its installations.
+    pub fn app(&self) -> Context {
+        Context::new(self.client.authenticated_as(self.app.clone()))
+    }
+}
+
+impl ContextProvider for AppContexts {
+    fn context<'a>(
+        &'a self,
+        delivery: &'a WebhookDelivery,
+    ) -> BoxFuture<'a, Result<Context, HandlerError>> {
+        let context = match delivery.event.installation_id() {
+            Some(installation_id) => Ok(self.installation(installation_id)),
+            None => Err(NotInstalled {
+                delivery_id: delivery.id.clone(),
+            }
+            .into()),
+        };
+        Box::pin(future::ready(context))
+    }
+}
+
+impl fmt::Debug for AppContexts {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AppContexts")
+            .field("app", &self.app)
+            .field("installations", &self.installations.lock().unwrap().len())
+            .finish_non_exhaustive()
+    }
+}
+
+/// A handler taking each delivery with its [`Context`], from [`with_context`].
+pub struct WithContext<F> {
+    provider: Arc<dyn ContextProvider>,
+    handler: Arc<F>,
+}
+
+/// Handle deliveries with `handler`, an async function or closure taking an
+/// `Arc<WebhookDelivery>` and the [`Context`] `provider` supplies for it. If the provider fails,
+/// so does the handler.
+pub fn with_context<F, Fut>(provider: Arc<dyn ContextProvider>, handler: F) -> WithContext<F>
+where
+    F: Fn(Arc<WebhookDelivery>, Context) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<(), HandlerError>> + Send + 'static,
+{
+    WithContext {
+        provider,
+        handler: Arc::new(handler),
+    }
+}
+
+impl<F, Fut> Handler for WithContext<F>
+where
+    F: Fn(Arc<WebhookDelivery>, Context) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<(), HandlerError>> + Send + 'static,
+{
+    fn handle(
+        &self,
+        delivery: Arc<WebhookDelivery>,
+    ) -> BoxFuture<'static, Result<(), HandlerError>> {
+        let (provider, handler) = (self.provider.clone(), self.handler.clone());
+        Box::pin(async move {
+            let context = provider.context(&delivery).await?;
+            handler(delivery, context).await
+        })
+    }
+}
+
+impl<F> fmt::Debug for WithContext<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("WithContext").finish_non_exhaustive()
+    }
+}
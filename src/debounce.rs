@@ -0,0 +1,177 @@
+//! Collapsing bursts of events about the same thing into the latest of them, so that a bot acts
+//! once on the state they leave rather than on each. Enabled by the `debounce` feature.
+//!
+//! A [`Debouncer`] holds back the events its rules match until none has come about the same
+//! thing for the rule's quiet period, then yields only the last: one of several `synchronize`
+//! pushes to a pull request, or the final state of a label added and removed over and over.
+//! What an event is about is its repository, type, and subject, such as the pull request, the
+//! issue, or the branch pushed to. Actions aren't told apart, so a rule for pull requests
+//! collapses an `opened` followed by a `synchronize`; match actions with
+//! [`with_filter`](struct.Debouncer.html#method.with_filter) to keep them.
+//!
+//! ```
+//! use std::time::Duration;
+//!
+//! use futures::executor::block_on;
+//! use futures::future;
+//! use futures::stream::{self, StreamExt};
+//! use github_events::debounce::Debouncer;
+//! use github_events::{Event, EventType};
+//!
+//! let push = Event::sample(EventType::Push).unwrap();
+//! let watch = Event::sample(EventType::Watch).unwrap();
+//! let events = stream::iter([push.clone(), watch.clone(), push.clone()]);
+//!
+//! let debouncer = Debouncer::new(|_| Box::pin(future::ready(())))
+//!     .with(EventType::Push, Duration::from_secs(30));
+//! let events: Vec<_> = block_on(debouncer.debounce(events).collect());
+//! assert_eq!(events, [watch, push]);
+//! ```
+use std::fmt;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use futures::future::BoxFuture;
+use futures::stream::{BoxStream, Stream, StreamExt};
+
+use crate::filter::EventFilter;
+use crate::identity::subject_of;
+pub use crate::wait::SleepFn;
+use crate::wait::{self, Window};
+use crate::{Event, EventType};
+
+#[derive(Clone)]
+enum Rule {
+    Event(EventType),
+    Filter(EventFilter),
+}
+
+impl Rule {
+    fn matches(&self, event: &Event) -> bool {
+        match self {
+            Rule::Event(event_type) => event.event_type() == *event_type,
+            Rule::Filter(filter) => filter.matches(event),
+        }
+    }
+}
+
+/// Collapses the events its rules match into the last of each burst about the same thing.
+/// Events no rule matches pass straight through.
+#[derive(Clone)]
+pub struct Debouncer {
+    rules: Vec<(Rule, Duration)>,
+    sleep: Arc<SleepFn>,
+}
+
+/// The latest event about a subject, waiting for its quiet period to pass.
+struct Held {
+    key: (Option<String>, EventType, String),
+    event: Event,
+    until: Instant,
+}
+
+/// The events a [`Debouncer`] holds back.
+struct Holding {
+    debouncer: Debouncer,
+    held: Vec<Held>,
+}
+
+impl Holding {
+    fn soonest(&self) -> Option<usize> {
+        (0..self.held.len()).min_by_key(|&i| self.held[i].until)
+    }
+}
+
+impl Window for Holding {
+    type Item = Event;
+    type Output = Event;
+
+    fn push(&mut self, event: Event) -> Option<Event> {
+        let quiet = match self.debouncer.quiet_period(&event) {
+            Some(quiet) => quiet,
+            None => return Some(event),
+        };
+        let key = (
+            event.repository().map(|r| r.full_name.to_lowercase()),
+            event.event_type(),
+            subject_of(&event),
+        );
+        let until = Instant::now() + quiet;
+        match self.held.iter_mut().find(|h| h.key == key) {
+            Some(h) => {
+                h.event = event;
+                h.until = until;
+            }
+            None => self.held.push(Held { key, event, until }),
+        }
+        None
+    }
+
+    fn due(&self) -> Option<Instant> {
+        self.soonest().map(|i| self.held[i].until)
+    }
+
+    fn release(&mut self) -> Option<Event> {
+        self.soonest().map(|i| self.held.remove(i).event)
+    }
+}
+
+impl Debouncer {
+    /// Debounce nothing yet, waiting with `sleep`.
+    pub fn new(sleep: impl Fn(Duration) -> BoxFuture<'static, ()> + Send + Sync + 'static) -> Self {
+        Debouncer {
+            rules: Vec::new(),
+            sleep: Arc::new(sleep),
+        }
+    }
+
+    /// Collapse events of type `event_type` that come less than `quiet` apart.
+    pub fn with(self, event_type: EventType, quiet: Duration) -> Self {
+        self.rule(Rule::Event(event_type), quiet)
+    }
+
+    /// Collapse the events `filter` matches that come less than `quiet` apart.
+    pub fn with_filter(self, filter: EventFilter, quiet: Duration) -> Self {
+        self.rule(Rule::Filter(filter), quiet)
+    }
+
+    /// Rules are tried in the order they were added; the first that matches an event decides
+    /// its quiet period.
+    fn rule(mut self, rule: Rule, quiet: Duration) -> Self {
+        self.rules.push((rule, quiet));
+        self
+    }
+
+    /// The quiet period of `event`, or `None` if no rule matches it.
+    pub fn quiet_period(&self, event: &Event) -> Option<Duration> {
+        self.rules
+            .iter()
+            .find(|(rule, _)| rule.matches(event))
+            .map(|&(_, quiet)| quiet)
+    }
+
+    /// Debounce `events`.
+    ///
+    /// An event held back is yielded once its quiet period has passed without another about the
+    /// same thing, which takes its place and starts the period over. Events passing through can
+    /// overtake those held back. When `events` ends, the events still held back are yielded
+    /// right away, those due soonest first.
+    pub fn debounce(
+        &self,
+        events: impl Stream<Item = Event> + Send + 'static,
+    ) -> BoxStream<'static, Event> {
+        let holding = Holding {
+            debouncer: self.clone(),
+            held: Vec::new(),
+        };
+        wait::windowed(events.boxed(), holding, self.sleep.clone())
+    }
+}
+
+impl fmt::Debug for Debouncer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Debouncer")
+            .field("rules", &self.rules.len())
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,77 @@
+//! `Debug` output that leaves out webhook secrets and email addresses, so that logging a payload
+//! doesn't leak them. With the `debug-full` feature the types derive `Debug` instead.
+#[cfg(not(feature = "debug-full"))]
+use core::fmt;
+
+#[cfg(not(feature = "debug-full"))]
+use crate::prelude::*;
+
+/// Implement `Debug` for a struct, showing the fields marked `(redacted)` only as `<redacted>`.
+/// The struct should derive `Debug` under the `debug-full` feature instead.
+macro_rules! debug_redacting {
+    ($ty:ident { $($field:ident $(($redacted:ident))?),* $(,)? }) => {
+        #[cfg(not(feature = "debug-full"))]
+        impl ::core::fmt::Debug for $ty {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                f.debug_struct(stringify!($ty))
+                    $(.field(stringify!($field), &debug_redacting!(@field self.$field $(, $redacted)?)))*
+                    .finish()
+            }
+        }
+    };
+    (@field $value:expr) => {
+        &$value
+    };
+    (@field $value:expr, redacted) => {
+        $crate::debug::Redacted(&$value)
+    };
+}
+
+/// A sensitive value in `Debug` output.
+#[cfg(not(feature = "debug-full"))]
+pub(crate) struct Redacted<'a, T: ?Sized>(pub(crate) &'a T);
+
+#[cfg(not(feature = "debug-full"))]
+impl fmt::Debug for Redacted<'_, String> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("<redacted>")
+    }
+}
+
+#[cfg(not(feature = "debug-full"))]
+impl<T> fmt::Debug for Redacted<'_, Option<T>> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.0 {
+            Some(_) => f
+                .debug_tuple("Some")
+                .field(&format_args!("<redacted>"))
+                .finish(),
+            None => f.write_str("None"),
+        }
+    }
+}
+
+/// Untyped JSON, such as a push's `head_commit`, with the `email` fields redacted.
+#[cfg(not(feature = "debug-full"))]
+impl fmt::Debug for Redacted<'_, ::serde_json::Value> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fn redact(value: &mut ::serde_json::Value) {
+            match value {
+                ::serde_json::Value::Object(fields) => {
+                    for (key, value) in fields.iter_mut() {
+                        if key == "email" && value.is_string() {
+                            *value = "<redacted>".into();
+                        } else {
+                            redact(value);
+                        }
+                    }
+                }
+                ::serde_json::Value::Array(elements) => elements.iter_mut().for_each(redact),
+                _ => {}
+            }
+        }
+        let mut value = self.0.clone();
+        redact(&mut value);
+        fmt::Debug::fmt(&value, f)
+    }
+}
@@ -0,0 +1,342 @@
+//! Recognizing deliveries that were already handled. Enabled by the `dedup` feature.
+//!
+//! GitHub redelivers a webhook when the receiver doesn't answer in time, and anyone can
+//! redeliver one by hand, so the same event can arrive several times. Each delivery's GUID, from
+//! the `X-GitHub-Delivery` header, stays the same across redeliveries; a
+//! [`DeliveryGuard`](struct.DeliveryGuard.html) remembers the GUIDs it has seen for a while and
+//! reports any that come again, with when they were first seen.
+//!
+//! A replayed request passes signature verification just like a redelivery, so the guard also
+//! keeps an attacker who captured a delivery from having it handled twice, as long as the
+//! replay comes within the window.
+//!
+//! GUIDs are kept in a [`DeliveryStore`](trait.DeliveryStore.html): a
+//! [`MemoryStore`](struct.MemoryStore.html) for a single process, or with the `redis` feature a
+//! [`RedisStore`](struct.RedisStore.html) shared by every replica of a receiver.
+use std::collections::{HashMap, VecDeque};
+use std::error::Error;
+use std::fmt;
+use std::sync::Mutex;
+#[cfg(feature = "redis")]
+use std::time::UNIX_EPOCH;
+use std::time::{Duration, SystemTime};
+
+use futures::future::BoxFuture;
+
+/// How long a [`DeliveryGuard`](struct.DeliveryGuard.html) remembers deliveries unless
+/// [`with_window`](struct.DeliveryGuard.html#method.with_window) is called: three days, as
+/// long as GitHub keeps deliveries around to be redelivered.
+pub const DEFAULT_WINDOW: Duration = Duration::from_secs(3 * 24 * 60 * 60);
+
+/// A failure to reach a [`DeliveryStore`](trait.DeliveryStore.html).
+#[derive(Debug)]
+pub enum GuardError {
+    Store(Box<dyn Error + Send + Sync>),
+}
+
+impl fmt::Display for GuardError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GuardError::Store(e) => write!(f, "delivery store error: {}", e),
+        }
+    }
+}
+
+impl Error for GuardError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            GuardError::Store(e) => Some(&**e),
+        }
+    }
+}
+
+#[cfg(feature = "redis")]
+impl From<redis::RedisError> for GuardError {
+    fn from(e: redis::RedisError) -> Self {
+        GuardError::Store(Box::new(e))
+    }
+}
+
+/// Whether a delivery should be handled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    /// Not seen within the window; handle it.
+    New { received_at: SystemTime },
+    /// Already seen within the window; skip it.
+    Duplicate {
+        /// When the delivery was first received.
+        first_seen: SystemTime,
+        received_at: SystemTime,
+    },
+}
+
+impl Verdict {
+    pub fn is_new(&self) -> bool {
+        matches!(self, Verdict::New { .. })
+    }
+
+    pub fn received_at(&self) -> SystemTime {
+        match *self {
+            Verdict::New { received_at } | Verdict::Duplicate { received_at, .. } => received_at,
+        }
+    }
+
+    /// How long after its first arrival a duplicate came again; zero for new deliveries.
+    ///
+    /// Redeliveries after a timeout come within seconds or minutes; much later ones were made
+    /// by hand, or are replays.
+    pub fn delay(&self) -> Duration {
+        match *self {
+            Verdict::New { .. } => Duration::ZERO,
+            Verdict::Duplicate {
+                first_seen,
+                received_at,
+            } => received_at
+                .duration_since(first_seen)
+                .unwrap_or(Duration::ZERO),
+        }
+    }
+}
+
+/// Where a [`DeliveryGuard`](struct.DeliveryGuard.html) remembers deliveries.
+pub trait DeliveryStore: fmt::Debug + Send + Sync {
+    /// Remember that `delivery_id` was received at `received_at`, forgetting it `ttl` later.
+    /// If it's already remembered, keeps the earlier record and returns when it was received.
+    ///
+    /// Must be atomic, so that concurrent deliveries of the same GUID aren't both new.
+    fn insert<'a>(
+        &'a self,
+        delivery_id: &'a str,
+        received_at: SystemTime,
+        ttl: Duration,
+    ) -> BoxFuture<'a, Result<Option<SystemTime>, GuardError>>;
+
+    /// When `delivery_id` was first received, if it's still remembered.
+    fn first_seen<'a>(
+        &'a self,
+        delivery_id: &'a str,
+    ) -> BoxFuture<'a, Result<Option<SystemTime>, GuardError>>;
+}
+
+/// Rejects deliveries whose GUID was seen within a window.
+#[derive(Debug)]
+pub struct DeliveryGuard<S> {
+    store: S,
+    window: Duration,
+}
+
+impl<S: DeliveryStore> DeliveryGuard<S> {
+    /// Remember deliveries for [`DEFAULT_WINDOW`](constant.DEFAULT_WINDOW.html).
+    pub fn new(store: S) -> Self {
+        DeliveryGuard {
+            store,
+            window: DEFAULT_WINDOW,
+        }
+    }
+
+    pub fn with_window(mut self, window: Duration) -> Self {
+        self.window = window;
+        self
+    }
+
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    /// Record the delivery `delivery_id`, received now.
+    pub async fn check(&self, delivery_id: &str) -> Result<Verdict, GuardError> {
+        self.check_at(delivery_id, SystemTime::now()).await
+    }
+
+    /// Record the delivery `delivery_id`, received at `received_at`.
+    pub async fn check_at(
+        &self,
+        delivery_id: &str,
+        received_at: SystemTime,
+    ) -> Result<Verdict, GuardError> {
+        let verdict = match self
+            .store
+            .insert(delivery_id, received_at, self.window)
+            .await?
+        {
+            Some(first_seen) => Verdict::Duplicate {
+                first_seen,
+                received_at,
+            },
+            None => Verdict::New { received_at },
+        };
+        Ok(verdict)
+    }
+
+    /// When `delivery_id` was first received, if it was within the window.
+    pub async fn first_seen(&self, delivery_id: &str) -> Result<Option<SystemTime>, GuardError> {
+        self.store.first_seen(delivery_id).await
+    }
+}
+
+/// Keeps deliveries in memory, forgetting the earliest received once there are more than its
+/// capacity.
+#[derive(Debug)]
+pub struct MemoryStore {
+    capacity: usize,
+    entries: Mutex<Entries>,
+}
+
+#[derive(Debug, Default)]
+struct Entries {
+    /// When each delivery was received and will be forgotten.
+    seen: HashMap<String, (SystemTime, SystemTime)>,
+    /// The deliveries in `seen`, the earliest received first.
+    order: VecDeque<String>,
+}
+
+impl MemoryStore {
+    pub fn new(capacity: usize) -> Self {
+        MemoryStore {
+            capacity,
+            entries: Mutex::default(),
+        }
+    }
+
+    /// How many deliveries are remembered.
+    pub fn len(&self) -> usize {
+        self.entries.lock().unwrap().seen.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Entries {
+    fn forget_expired(&mut self, now: SystemTime) {
+        while let Some(id) = self.order.front() {
+            match self.seen.get(id) {
+                Some(&(_, expires_at)) if expires_at > now => break,
+                _ => {
+                    self.seen.remove(id);
+                    self.order.pop_front();
+                }
+            }
+        }
+    }
+}
+
+impl DeliveryStore for MemoryStore {
+    fn insert<'a>(
+        &'a self,
+        delivery_id: &'a str,
+        received_at: SystemTime,
+        ttl: Duration,
+    ) -> BoxFuture<'a, Result<Option<SystemTime>, GuardError>> {
+        let mut entries = self.entries.lock().unwrap();
+        entries.forget_expired(received_at);
+        let first_seen = match entries.seen.get(delivery_id) {
+            Some(&(first_seen, expires_at)) if expires_at > received_at => Some(first_seen),
+            expired => {
+                if expired.is_some() {
+                    // Windows of different lengths can leave it behind later ones.
+                    entries.order.retain(|id| id != delivery_id);
+                }
+                entries
+                    .seen
+                    .insert(delivery_id.to_owned(), (received_at, received_at + ttl));
+                entries.order.push_back(delivery_id.to_owned());
+                while entries.order.len() > self.capacity {
+                    if let Some(id) = entries.order.pop_front() {
+                        entries.seen.remove(&id);
+                    }
+                }
+                None
+            }
+        };
+        Box::pin(futures::future::ready(Ok(first_seen)))
+    }
+
+    fn first_seen<'a>(
+        &'a self,
+        delivery_id: &'a str,
+    ) -> BoxFuture<'a, Result<Option<SystemTime>, GuardError>> {
+        let now = SystemTime::now();
+        let first_seen = match self.entries.lock().unwrap().seen.get(delivery_id) {
+            Some(&(first_seen, expires_at)) if expires_at > now => Some(first_seen),
+            _ => None,
+        };
+        Box::pin(futures::future::ready(Ok(first_seen)))
+    }
+}
+
+/// Keeps deliveries in [Redis](https://redis.io) 7 or later, through
+/// [redis-rs](https://docs.rs/redis), as keys that expire with the window.
+#[cfg(feature = "redis")]
+#[derive(Clone)]
+pub struct RedisStore {
+    connection: redis::aio::MultiplexedConnection,
+    prefix: String,
+}
+
+#[cfg(feature = "redis")]
+impl RedisStore {
+    /// Keys are the GUIDs prefixed with `github-delivery:`.
+    pub fn new(connection: redis::aio::MultiplexedConnection) -> Self {
+        RedisStore {
+            connection,
+            prefix: "github-delivery:".to_owned(),
+        }
+    }
+
+    pub fn with_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+}
+
+#[cfg(feature = "redis")]
+impl fmt::Debug for RedisStore {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RedisStore")
+            .field("prefix", &self.prefix)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Times are kept as milliseconds since the epoch.
+#[cfg(feature = "redis")]
+impl DeliveryStore for RedisStore {
+    fn insert<'a>(
+        &'a self,
+        delivery_id: &'a str,
+        received_at: SystemTime,
+        ttl: Duration,
+    ) -> BoxFuture<'a, Result<Option<SystemTime>, GuardError>> {
+        Box::pin(async move {
+            let millis = received_at
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |since| since.as_millis() as u64);
+            // `GET` returns the earlier value, and `NX` keeps it.
+            let earlier: Option<u64> = redis::cmd("SET")
+                .arg(format!("{}{}", self.prefix, delivery_id))
+                .arg(millis)
+                .arg("NX")
+                .arg("GET")
+                .arg("PX")
+                .arg(ttl.as_millis().max(1) as u64)
+                .query_async(&mut self.connection.clone())
+                .await?;
+            Ok(earlier.map(|millis| UNIX_EPOCH + Duration::from_millis(millis)))
+        })
+    }
+
+    fn first_seen<'a>(
+        &'a self,
+        delivery_id: &'a str,
+    ) -> BoxFuture<'a, Result<Option<SystemTime>, GuardError>> {
+        Box::pin(async move {
+            let millis: Option<u64> = redis::cmd("GET")
+                .arg(format!("{}{}", self.prefix, delivery_id))
+                .query_async(&mut self.connection.clone())
+                .await?;
+            Ok(millis.map(|millis| UNIX_EPOCH + Duration::from_millis(millis)))
+        })
+    }
+}
@@ -0,0 +1,172 @@
+//! [Webhook deliveries](https://docs.github.com/en/rest/webhooks/repo-deliveries) types.
+//!
+//! GitHub keeps a log of every delivery attempt made to a webhook over the past few days,
+//! including the full request it sent. Together with redelivery this makes it possible to
+//! recover events that were missed while a receiver was down.
+use alloc::collections::BTreeMap;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::prelude::*;
+use crate::{Event, EventType, InstallationId, RepositoryId};
+
+/// A summary of a single delivery attempt, as returned when listing deliveries.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[non_exhaustive]
+pub struct HookDelivery {
+    /// Unique identifier of the delivery attempt.
+    pub id: i64,
+    /// Unique identifier of the event, shared by all attempts to deliver it.
+    /// Sent in the `X-GitHub-Delivery` header.
+    pub guid: String,
+    /// Time when the delivery was delivered.
+    pub delivered_at: String,
+    /// Whether the delivery is a redelivery.
+    pub redelivery: bool,
+    /// Time spent delivering, in seconds.
+    pub duration: f64,
+    /// Description of the delivery's status, e.g. `OK` or `Invalid HTTP Response: 500`.
+    pub status: String,
+    /// Status code received when the delivery was made.
+    pub status_code: u16,
+    /// The event that triggered the delivery, e.g. `issues`.
+    pub event: String,
+    /// The type of activity for the event that triggered the delivery.
+    pub action: Option<String>,
+    /// The id of the GitHub App installation associated with this event.
+    pub installation_id: Option<InstallationId>,
+    /// The id of the repository associated with this event.
+    pub repository_id: Option<RepositoryId>,
+}
+
+impl HookDelivery {
+    /// Whether the receiver acknowledged the delivery with a `2xx` status code.
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status_code)
+    }
+}
+
+/// A single delivery attempt, including the request GitHub sent and the response it received.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[non_exhaustive]
+pub struct HookDeliveryDetail {
+    /// The summary's fields sit beside the others in JSON; formats that can't flatten
+    /// nest them instead.
+    pub delivery: HookDelivery,
+    /// The URL target of the delivery.
+    pub url: Option<String>,
+    pub request: DeliveryRequest,
+    pub response: DeliveryResponse,
+}
+
+macro_rules! details {
+    ($($(#[$attr:meta])* $borrowed:ident, $owned:ident;)*) => {$(
+        #[derive(Serialize)]
+        #[serde(rename = "HookDeliveryDetail")]
+        struct $borrowed<'a> {
+            $(#[$attr])*
+            delivery: &'a HookDelivery,
+            url: &'a Option<String>,
+            request: &'a DeliveryRequest,
+            response: &'a DeliveryResponse,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename = "HookDeliveryDetail")]
+        struct $owned {
+            $(#[$attr])*
+            delivery: HookDelivery,
+            url: Option<String>,
+            request: DeliveryRequest,
+            response: DeliveryResponse,
+        }
+
+        impl<'a> From<&'a HookDeliveryDetail> for $borrowed<'a> {
+            fn from(detail: &'a HookDeliveryDetail) -> Self {
+                $borrowed {
+                    delivery: &detail.delivery,
+                    url: &detail.url,
+                    request: &detail.request,
+                    response: &detail.response,
+                }
+            }
+        }
+
+        impl From<$owned> for HookDeliveryDetail {
+            fn from(detail: $owned) -> Self {
+                HookDeliveryDetail {
+                    delivery: detail.delivery,
+                    url: detail.url,
+                    request: detail.request,
+                    response: detail.response,
+                }
+            }
+        }
+    )*};
+}
+
+details! {
+    #[serde(flatten)]
+    FlatRef, Flat;
+    NestedRef, Nested;
+}
+
+impl Serialize for HookDeliveryDetail {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            FlatRef::from(self).serialize(serializer)
+        } else {
+            NestedRef::from(self).serialize(serializer)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for HookDeliveryDetail {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            Flat::deserialize(deserializer).map(HookDeliveryDetail::from)
+        } else {
+            Nested::deserialize(deserializer).map(HookDeliveryDetail::from)
+        }
+    }
+}
+
+impl HookDeliveryDetail {
+    /// Parse the delivered payload into an [`Event`](../enum.Event.html).
+    pub fn parse_event(&self) -> Result<Event, ::serde_json::Error> {
+        let event_type = self
+            .delivery
+            .event
+            .parse::<EventType>()
+            .map_err(<::serde_json::Error as serde::de::Error>::custom)?;
+        let payload = self
+            .request
+            .payload
+            .clone()
+            .unwrap_or(::serde_json::Value::Null);
+        Event::from_value(event_type, payload)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[non_exhaustive]
+pub struct DeliveryRequest {
+    /// The request headers sent with the webhook delivery.
+    pub headers: Option<BTreeMap<String, String>>,
+    /// The webhook payload.
+    #[serde(default, with = "crate::untyped::OptionJson")]
+    pub payload: Option<::serde_json::Value>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[non_exhaustive]
+pub struct DeliveryResponse {
+    /// The response headers received when the delivery was made.
+    pub headers: Option<BTreeMap<String, String>>,
+    /// The response body received when the delivery was made.
+    pub payload: Option<String>,
+}
@@ -0,0 +1,75 @@
+//! Discord messages for events, as [embeds](https://discord.com/developers/docs/resources/message#embed-object),
+//! for posting to a Discord webhook. Enabled by the `discord` feature.
+use serde_json::{json, Map, Value};
+
+use crate::card::Card;
+use crate::Event;
+
+/// Discord rejects embeds with longer titles or descriptions.
+const MAX_TITLE: usize = 256;
+const MAX_DESCRIPTION: usize = 4096;
+
+/// A webhook message with the [`embed`](fn.embed.html) of `event`.
+pub fn message(event: &Event) -> Value {
+    json!({ "embeds": [embed(event)] })
+}
+
+/// An embed describing `event`: its summary linked to the pull request, issue, commit, or other
+/// page it's about, any body or commits it introduces, and its sender and repository, colored by
+/// its action.
+pub fn embed(event: &Event) -> Value {
+    let card = Card::new(event);
+    let mut embed = Map::new();
+    embed.insert("title".into(), truncate(&card.title, MAX_TITLE).into());
+    if let Some(url) = card.url {
+        embed.insert("url".into(), url.into());
+    }
+
+    let mut description = Vec::new();
+    if let Some(body) = &card.body {
+        description.push(
+            body.lines()
+                .map(|line| format!("> {}", line))
+                .collect::<Vec<_>>()
+                .join("\n"),
+        );
+    }
+    for commit in &card.commits {
+        description.push(format!(
+            "[`{}`]({}) {}",
+            commit.sha, commit.url, commit.message
+        ));
+    }
+    if card.more_commits > 0 {
+        description.push(format!("…and {} more", card.more_commits));
+    }
+    if !description.is_empty() {
+        embed.insert(
+            "description".into(),
+            truncate(&description.join("\n"), MAX_DESCRIPTION).into(),
+        );
+    }
+
+    embed.insert("color".into(), card.color.into());
+    if let Some(author) = card.author {
+        embed.insert(
+            "author".into(),
+            json!({
+                "name": author.login,
+                "url": author.url,
+                "icon_url": author.avatar_url,
+            }),
+        );
+    }
+    if let Some((name, _)) = card.repository {
+        embed.insert("footer".into(), json!({ "text": name }));
+    }
+    Value::Object(embed)
+}
+
+fn truncate(text: &str, max: usize) -> String {
+    match text.char_indices().nth(max - 1) {
+        Some((end, _)) if text[end..].chars().nth(1).is_some() => format!("{}…", &text[..end]),
+        _ => text.to_owned(),
+    }
+}
@@ -0,0 +1,98 @@
+//! Events wrapped with metadata of your own, for passing payloads between your services.
+//!
+//! A service that receives webhooks often re-delivers them internally with something added, such
+//! as the tenant they belong to or where to route them. An [`Envelope`] carries that beside the
+//! event, as JSON with the metadata's fields next to the event's:
+//!
+//! ```
+//! use github_events::envelope::Envelope;
+//! use github_events::{Event, EventType};
+//!
+//! #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+//! struct Routing {
+//!     tenant: String,
+//! }
+//!
+//! let envelope = Envelope::new(
+//!     Event::sample(EventType::Ping).unwrap(),
+//!     Routing { tenant: "acme".to_owned() },
+//! );
+//! let json = serde_json::to_value(&envelope).unwrap();
+//! assert_eq!((&json["event_type"], &json["tenant"]), (&"ping".into(), &"acme".into()));
+//! assert_eq!(serde_json::from_value::<Envelope<Routing>>(json).unwrap(), envelope);
+//! ```
+use serde::de::{DeserializeOwned, Error};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+
+use crate::{Event, EventType};
+
+/// An event and the metadata `M` it was sent with.
+///
+/// The metadata's fields can't be named `event_type` or `payload`, which hold the event.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Envelope<M = ()> {
+    pub event: Event,
+    pub metadata: M,
+}
+
+impl<M> Envelope<M> {
+    pub fn new(event: Event, metadata: M) -> Self {
+        Envelope { event, metadata }
+    }
+
+    /// The same event, with its metadata mapped by `f`.
+    pub fn map<N>(self, f: impl FnOnce(M) -> N) -> Envelope<N> {
+        Envelope {
+            event: self.event,
+            metadata: f(self.metadata),
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename = "Envelope")]
+struct EnvelopeRef<'a, M> {
+    event_type: EventType,
+    payload: Value,
+    #[serde(flatten)]
+    metadata: &'a M,
+}
+
+// The payload is buffered as a `Value`, since flattening the metadata buffers the whole
+// envelope anyway, and the payload can't be parsed before its type is known.
+#[derive(Deserialize)]
+#[serde(rename = "Envelope")]
+struct Owned<M> {
+    event_type: EventType,
+    payload: Value,
+    #[serde(flatten)]
+    metadata: M,
+}
+
+impl<M: Serialize> Serialize for Envelope<M> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let payload = match ::serde_json::to_value(&self.event) {
+            Ok(Value::Object(tagged)) => tagged.into_iter().next().map(|(_, payload)| payload),
+            Ok(_) => None,
+            Err(e) => return Err(serde::ser::Error::custom(e)),
+        }
+        .unwrap_or(Value::Null);
+        EnvelopeRef {
+            event_type: self.event.event_type(),
+            payload,
+            metadata: &self.metadata,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, M: DeserializeOwned> Deserialize<'de> for Envelope<M> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let owned = Owned::<M>::deserialize(deserializer)?;
+        Ok(Envelope {
+            event: Event::from_value(owned.event_type, owned.payload).map_err(D::Error::custom)?,
+            metadata: owned.metadata,
+        })
+    }
+}
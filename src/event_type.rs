@@ -0,0 +1,225 @@
+//! Names of webhook events, as sent in the `X-GitHub-Event` header.
+use core::fmt;
+use core::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+/// The kind of a webhook [`Event`](enum.Event.html), as named by the `X-GitHub-Event` header
+/// and in a webhook's list of subscribed events.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum EventType {
+    CheckRun,
+    CheckSuite,
+    CommitComment,
+    Create,
+    Delete,
+    Deployment,
+    DeploymentStatus,
+    Fork,
+    GithubAppAuthorization,
+    Gollum,
+    Installation,
+    InstallationRepositories,
+    IssueComment,
+    Issues,
+    Label,
+    Member,
+    Membership,
+    Milestone,
+    Organization,
+    OrgBlock,
+    PageBuild,
+    Ping,
+    ProjectCard,
+    ProjectColumn,
+    Project,
+    Public,
+    PullRequest,
+    PullRequestReview,
+    PullRequestReviewComment,
+    Push,
+    Release,
+    Repository,
+    RepositoryDispatch,
+    RepositoryImport,
+    RepositoryVulnerabilityAlert,
+    SecurityAdvisory,
+    Status,
+    Team,
+    TeamAdd,
+    Watch,
+}
+
+impl EventType {
+    /// Every event type, in the order of the [`Event`](enum.Event.html) variants.
+    pub const ALL: &'static [EventType] = &[
+        EventType::CheckRun,
+        EventType::CheckSuite,
+        EventType::CommitComment,
+        EventType::Create,
+        EventType::Delete,
+        EventType::Deployment,
+        EventType::DeploymentStatus,
+        EventType::Fork,
+        EventType::GithubAppAuthorization,
+        EventType::Gollum,
+        EventType::Installation,
+        EventType::InstallationRepositories,
+        EventType::IssueComment,
+        EventType::Issues,
+        EventType::Label,
+        EventType::Member,
+        EventType::Membership,
+        EventType::Milestone,
+        EventType::Organization,
+        EventType::OrgBlock,
+        EventType::PageBuild,
+        EventType::Ping,
+        EventType::ProjectCard,
+        EventType::ProjectColumn,
+        EventType::Project,
+        EventType::Public,
+        EventType::PullRequest,
+        EventType::PullRequestReview,
+        EventType::PullRequestReviewComment,
+        EventType::Push,
+        EventType::Release,
+        EventType::Repository,
+        EventType::RepositoryDispatch,
+        EventType::RepositoryImport,
+        EventType::RepositoryVulnerabilityAlert,
+        EventType::SecurityAdvisory,
+        EventType::Status,
+        EventType::Team,
+        EventType::TeamAdd,
+        EventType::Watch,
+    ];
+
+    /// The webhook name of the event, e.g. `pull_request`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            EventType::CheckRun => "check_run",
+            EventType::CheckSuite => "check_suite",
+            EventType::CommitComment => "commit_comment",
+            EventType::Create => "create",
+            EventType::Delete => "delete",
+            EventType::Deployment => "deployment",
+            EventType::DeploymentStatus => "deployment_status",
+            EventType::Fork => "fork",
+            EventType::GithubAppAuthorization => "github_app_authorization",
+            EventType::Gollum => "gollum",
+            EventType::Installation => "installation",
+            EventType::InstallationRepositories => "installation_repositories",
+            EventType::IssueComment => "issue_comment",
+            EventType::Issues => "issues",
+            EventType::Label => "label",
+            EventType::Member => "member",
+            EventType::Membership => "membership",
+            EventType::Milestone => "milestone",
+            EventType::Organization => "organization",
+            EventType::OrgBlock => "org_block",
+            EventType::PageBuild => "page_build",
+            EventType::Ping => "ping",
+            EventType::ProjectCard => "project_card",
+            EventType::ProjectColumn => "project_column",
+            EventType::Project => "project",
+            EventType::Public => "public",
+            EventType::PullRequest => "pull_request",
+            EventType::PullRequestReview => "pull_request_review",
+            EventType::PullRequestReviewComment => "pull_request_review_comment",
+            EventType::Push => "push",
+            EventType::Release => "release",
+            EventType::Repository => "repository",
+            EventType::RepositoryDispatch => "repository_dispatch",
+            EventType::RepositoryImport => "repository_import",
+            EventType::RepositoryVulnerabilityAlert => "repository_vulnerability_alert",
+            EventType::SecurityAdvisory => "security_advisory",
+            EventType::Status => "status",
+            EventType::Team => "team",
+            EventType::TeamAdd => "team_add",
+            EventType::Watch => "watch",
+        }
+    }
+
+    /// The name of the corresponding [`Event`](enum.Event.html) variant.
+    pub(crate) fn variant_name(self) -> &'static str {
+        match self {
+            EventType::CheckRun => "CheckRunEvent",
+            EventType::CheckSuite => "CheckSuiteEvent",
+            EventType::CommitComment => "CommitCommentEvent",
+            EventType::Create => "CreateEvent",
+            EventType::Delete => "DeleteEvent",
+            EventType::Deployment => "DeploymentEvent",
+            EventType::DeploymentStatus => "DeploymentStatusEvent",
+            EventType::Fork => "ForkEvent",
+            EventType::GithubAppAuthorization => "GitHubAppAuthorizationEvent",
+            EventType::Gollum => "GollumEvent",
+            EventType::Installation => "InstallationEvent",
+            EventType::InstallationRepositories => "InstallationRepositoriesEvent",
+            EventType::IssueComment => "IssueCommentEvent",
+            EventType::Issues => "IssueEvent",
+            EventType::Label => "LabelEvent",
+            EventType::Member => "MemberEvent",
+            EventType::Membership => "MembershipEvent",
+            EventType::Milestone => "MilestoneEvent",
+            EventType::Organization => "OrganizationEvent",
+            EventType::OrgBlock => "OrgBlockEvent",
+            EventType::PageBuild => "PageBuildEvent",
+            EventType::Ping => "PingEvent",
+            EventType::ProjectCard => "ProjectCardEvent",
+            EventType::ProjectColumn => "ProjectColumnEvent",
+            EventType::Project => "ProjectEvent",
+            EventType::Public => "PublicEvent",
+            EventType::PullRequest => "PullRequestEvent",
+            EventType::PullRequestReview => "PullRequestReviewEvent",
+            EventType::PullRequestReviewComment => "PullRequestReviewCommentEvent",
+            EventType::Push => "PushEvent",
+            EventType::Release => "ReleaseEvent",
+            EventType::Repository => "RepositoryEvent",
+            EventType::RepositoryDispatch => "RepositoryDispatchEvent",
+            EventType::RepositoryImport => "RepositoryImportEvent",
+            EventType::RepositoryVulnerabilityAlert => "RepositoryVulnerabilityAlertEvent",
+            EventType::SecurityAdvisory => "SecurityAdvisoryEvent",
+            EventType::Status => "StatusEvent",
+            EventType::Team => "TeamEvent",
+            EventType::TeamAdd => "TeamAddEvent",
+            EventType::Watch => "WatchEvent",
+        }
+    }
+}
+
+impl fmt::Display for EventType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The error returned when parsing an unknown event name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownEventType(pub String);
+
+impl fmt::Display for UnknownEventType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unknown event type `{}`", self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnknownEventType {}
+
+impl FromStr for EventType {
+    type Err = UnknownEventType;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        EventType::ALL
+            .iter()
+            .find(|event_type| event_type.as_str() == s)
+            .cloned()
+            .ok_or_else(|| UnknownEventType(s.to_owned()))
+    }
+}
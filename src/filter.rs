@@ -0,0 +1,336 @@
+//! Deciding which events a handler is interested in.
+//!
+//! An [`EventFilter`](enum.EventFilter.html) is a small expression over an event's repository,
+//! organization, type, action, sender, branch, and labels. Filters are built from the variants and
+//! combined with `&`, `|`, and `!`, or parsed from a string such as
+//! `repo:rust-lang/* type:pull_request,issues action:opened -sender:dependabot[bot]`:
+//! whitespace-separated terms that must all hold, each a key and comma-separated values of which
+//! any must match, negated by a leading `-`. Values with spaces can be quoted,
+//! e.g. `label:"good first issue"`.
+//!
+//! The same filter applies to webhook [`Event`](../enum.Event.html)s,
+//! [`LazyEvent`](../lazy/struct.LazyEvent.html)s, and
+//! [`TimelineEvent`](../timeline/struct.TimelineEvent.html)s from the Events API.
+use core::fmt;
+use core::ops;
+use core::str::FromStr;
+
+use serde_json::Value;
+
+#[cfg(feature = "std")]
+use crate::lazy::LazyEvent;
+use crate::prelude::*;
+use crate::timeline::TimelineEvent;
+use crate::{Event, EventType};
+
+/// A predicate on events.
+///
+/// Repository names, logins, and labels are compared case-insensitively, as GitHub does.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum EventFilter {
+    /// Matches every event.
+    #[default]
+    All,
+    /// The repository's full name matches a glob, e.g. `rust-lang/*`.
+    /// `*` matches anything but `/`, and `**` anything at all.
+    Repository(String),
+    /// The event's organization, or for repositories outside one, the repository's owner.
+    Org(String),
+    EventType(EventType),
+    Action(String),
+    /// The login of the user who triggered the event.
+    Sender(String),
+    /// The event was triggered by the bot of an app, given as its slug or its bot's login.
+    /// `-app:mybot` keeps an app from reacting to its own comments, pushes, and check runs.
+    App(String),
+    /// The branch or tag matches a glob, e.g. `release/*`: the `ref` of pushes, creations, and
+    /// deletions, the base branch of pull requests, and the head branch of checks.
+    /// Globs match both the full `refs/heads/…` name and the branch alone.
+    Ref(String),
+    /// The label that was added or removed, or one of the issue's or pull request's labels.
+    Label(String),
+    And(Vec<EventFilter>),
+    Or(Vec<EventFilter>),
+    Not(Box<EventFilter>),
+}
+
+impl EventFilter {
+    /// Matches events of any of `event_types`.
+    pub fn event_types(event_types: impl IntoIterator<Item = EventType>) -> Self {
+        EventFilter::Or(
+            event_types
+                .into_iter()
+                .map(EventFilter::EventType)
+                .collect(),
+        )
+    }
+
+    /// Matches events with any of `actions`.
+    pub fn actions<S: Into<String>>(actions: impl IntoIterator<Item = S>) -> Self {
+        EventFilter::Or(
+            actions
+                .into_iter()
+                .map(|action| EventFilter::Action(action.into()))
+                .collect(),
+        )
+    }
+
+    /// Matches events matching both `self` and `other`.
+    pub fn and(self, other: EventFilter) -> Self {
+        match self {
+            EventFilter::All => other,
+            EventFilter::And(mut filters) => {
+                filters.push(other);
+                EventFilter::And(filters)
+            }
+            filter => EventFilter::And(vec![filter, other]),
+        }
+    }
+
+    /// Matches events matching either `self` or `other`.
+    pub fn or(self, other: EventFilter) -> Self {
+        match self {
+            EventFilter::Or(mut filters) => {
+                filters.push(other);
+                EventFilter::Or(filters)
+            }
+            filter => EventFilter::Or(vec![filter, other]),
+        }
+    }
+
+    pub fn matches(&self, event: &Event) -> bool {
+        let payload = match ::serde_json::to_value(event) {
+            Ok(Value::Object(tagged)) => tagged.into_iter().next().map(|(_, payload)| payload),
+            _ => None,
+        }
+        .unwrap_or(Value::Null);
+        self.test(&Facts::webhook(event.event_type(), &payload))
+    }
+
+    #[cfg(feature = "std")]
+    pub fn matches_lazy(&self, event: &LazyEvent) -> bool {
+        let payload = ::serde_json::from_str(event.payload().get()).unwrap_or(Value::Null);
+        self.test(&Facts::webhook(event.event_type(), &payload))
+    }
+
+    pub fn matches_timeline(&self, event: &TimelineEvent) -> bool {
+        let event_type = event.event_type();
+        let event = ::serde_json::to_value(event).unwrap_or(Value::Null);
+        let repository = event["repo"]["name"].as_str();
+        self.test(&Facts {
+            event_type,
+            repository,
+            org: event["org"]["login"]
+                .as_str()
+                .or_else(|| repository.and_then(|r| r.split('/').next())),
+            sender: event["actor"]["login"].as_str(),
+            sender_type: None,
+            payload: &event["payload"],
+        })
+    }
+
+    fn test(&self, facts: &Facts) -> bool {
+        let payload = facts.payload;
+        match self {
+            EventFilter::All => true,
+            EventFilter::Repository(glob) => facts
+                .repository
+                .is_some_and(|name| glob_matches(&glob.to_lowercase(), &name.to_lowercase())),
+            EventFilter::Org(login) => facts.org.is_some_and(|o| o.eq_ignore_ascii_case(login)),
+            EventFilter::EventType(event_type) => facts.event_type == Some(*event_type),
+            EventFilter::Action(action) => payload["action"].as_str() == Some(action.as_str()),
+            EventFilter::Sender(login) => {
+                facts.sender.is_some_and(|s| s.eq_ignore_ascii_case(login))
+            }
+            EventFilter::App(app) => facts
+                .sender
+                .is_some_and(|s| crate::is_app_bot(s, facts.sender_type.unwrap_or(""), app)),
+            EventFilter::Ref(glob) => {
+                let git_ref = payload["ref"]
+                    .as_str()
+                    .or_else(|| payload["pull_request"]["base"]["ref"].as_str())
+                    .or_else(|| payload["check_suite"]["head_branch"].as_str())
+                    .or_else(|| payload["check_run"]["check_suite"]["head_branch"].as_str());
+                git_ref.is_some_and(|git_ref| {
+                    glob_matches(glob, git_ref)
+                        || glob_matches(glob, crate::git_ref::short_name(git_ref))
+                })
+            }
+            EventFilter::Label(label) => {
+                let is = |l: &Value| {
+                    l["name"]
+                        .as_str()
+                        .is_some_and(|n| n.eq_ignore_ascii_case(label))
+                };
+                is(&payload["label"])
+                    || ["issue", "pull_request"].iter().any(|item| {
+                        payload[item]["labels"]
+                            .as_array()
+                            .is_some_and(|labels| labels.iter().any(is))
+                    })
+            }
+            EventFilter::And(filters) => filters.iter().all(|f| f.test(facts)),
+            EventFilter::Or(filters) => filters.iter().any(|f| f.test(facts)),
+            EventFilter::Not(filter) => !filter.test(facts),
+        }
+    }
+}
+
+/// What filters look at, wherever the event came from.
+struct Facts<'a> {
+    /// `None` for legacy timeline events, which no webhook event corresponds to.
+    event_type: Option<EventType>,
+    repository: Option<&'a str>,
+    org: Option<&'a str>,
+    sender: Option<&'a str>,
+    /// `User`, `Bot`, or `Organization`.
+    sender_type: Option<&'a str>,
+    payload: &'a Value,
+}
+
+impl<'a> Facts<'a> {
+    fn webhook(event_type: EventType, payload: &'a Value) -> Self {
+        Facts {
+            event_type: Some(event_type),
+            repository: payload["repository"]["full_name"].as_str(),
+            org: payload["organization"]["login"]
+                .as_str()
+                .or_else(|| payload["repository"]["owner"]["login"].as_str()),
+            sender: payload["sender"]["login"].as_str(),
+            sender_type: payload["sender"]["type"].as_str(),
+            payload,
+        }
+    }
+}
+
+impl ops::BitAnd for EventFilter {
+    type Output = EventFilter;
+
+    fn bitand(self, other: EventFilter) -> EventFilter {
+        self.and(other)
+    }
+}
+
+impl ops::BitOr for EventFilter {
+    type Output = EventFilter;
+
+    fn bitor(self, other: EventFilter) -> EventFilter {
+        self.or(other)
+    }
+}
+
+impl ops::Not for EventFilter {
+    type Output = EventFilter;
+
+    fn not(self) -> EventFilter {
+        match self {
+            EventFilter::Not(filter) => *filter,
+            filter => EventFilter::Not(Box::new(filter)),
+        }
+    }
+}
+
+/// The error returned when parsing an invalid filter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseFilterError(pub String);
+
+impl fmt::Display for ParseFilterError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid filter: {}", self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseFilterError {}
+
+impl FromStr for EventFilter {
+    type Err = ParseFilterError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut filter = EventFilter::All;
+        for term in terms(s)? {
+            let (negated, term) = match term.strip_prefix('-') {
+                Some(term) => (true, term),
+                None => (false, term.as_str()),
+            };
+            let (key, values) = term
+                .split_once(':')
+                .ok_or_else(|| ParseFilterError(format!("`{}` is not `key:value`", term)))?;
+            let mut alternatives = Vec::new();
+            for value in values.split(',') {
+                let value = value.to_owned();
+                alternatives.push(match key {
+                    "repo" => EventFilter::Repository(value),
+                    "org" => EventFilter::Org(value),
+                    "type" => EventFilter::EventType(
+                        value
+                            .parse()
+                            .map_err(|e| ParseFilterError(format!("{}", e)))?,
+                    ),
+                    "action" => EventFilter::Action(value),
+                    "sender" => EventFilter::Sender(value),
+                    "app" => EventFilter::App(value),
+                    "ref" => EventFilter::Ref(value),
+                    "label" => EventFilter::Label(value),
+                    _ => return Err(ParseFilterError(format!("unknown key `{}`", key))),
+                });
+            }
+            let term = if alternatives.len() == 1 {
+                alternatives.remove(0)
+            } else {
+                EventFilter::Or(alternatives)
+            };
+            filter = filter.and(if negated { !term } else { term });
+        }
+        Ok(filter)
+    }
+}
+
+/// The whitespace-separated terms of `s`, with double quotes removed.
+fn terms(s: &str) -> Result<Vec<String>, ParseFilterError> {
+    let mut terms = Vec::new();
+    let mut term = String::new();
+    let mut in_quotes = false;
+    for c in s.chars() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            c if c.is_whitespace() && !in_quotes => {
+                if !term.is_empty() {
+                    terms.push(core::mem::take(&mut term));
+                }
+            }
+            c => term.push(c),
+        }
+    }
+    if in_quotes {
+        return Err(ParseFilterError(format!("unclosed quote in `{}`", s)));
+    }
+    if !term.is_empty() {
+        terms.push(term);
+    }
+    Ok(terms)
+}
+
+/// Whether `text` matches `glob`, in which `*` matches anything but `/`, and `**` anything at all.
+fn glob_matches(glob: &str, text: &str) -> bool {
+    match glob.find('*') {
+        None => glob == text,
+        Some(star) => {
+            let (prefix, rest) = (&glob[..star], &glob[star..]);
+            let text = match text.strip_prefix(prefix) {
+                Some(text) => text,
+                None => return false,
+            };
+            let (any, pattern) = match rest.strip_prefix("**") {
+                Some(pattern) => (true, pattern),
+                None => (false, &rest[1..]),
+            };
+            // Try every split of `text` between the star and the rest of the pattern.
+            text.char_indices()
+                .map(|(i, _)| i)
+                .chain(Some(text.len()))
+                .take_while(|&i| any || !text[..i].contains('/'))
+                .any(|i| glob_matches(pattern, &text[i..]))
+        }
+    }
+}
@@ -0,0 +1,82 @@
+//! A corpus of real payloads for benchmarking and testing, enabled by the `test-fixtures` feature.
+//!
+//! The payloads are embedded in the binary, so this is meant for benches and tests
+//! rather than production builds. See `fixtures/README.md` for where they came from.
+use crate::EventType;
+
+/// A bundled payload.
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub struct Fixture {
+    /// The file name without its extension, e.g. `issues.opened`.
+    pub name: &'static str,
+    pub event_type: EventType,
+    pub body: &'static [u8],
+}
+
+macro_rules! fixtures {
+    ($dir:literal: $($name:literal => $event_type:ident,)*) => {
+        &[$(Fixture {
+            name: $name,
+            event_type: EventType::$event_type,
+            body: include_bytes!(concat!("../fixtures/", $dir, "/", $name, ".json")),
+        },)*]
+    };
+}
+
+static WEBHOOKS: &[Fixture] = fixtures! { "webhooks":
+    "commit_comment.created" => CommitComment,
+    "installation.created" => Installation,
+    "installation.deleted" => Installation,
+    "installation.new_permissions_accepted" => Installation,
+    "installation_repositories.removed" => InstallationRepositories,
+    "issue_comment.created" => IssueComment,
+    "issue_comment.deleted" => IssueComment,
+    "issue_comment.edited" => IssueComment,
+    "issues.labeled" => Issues,
+    "issues.opened" => Issues,
+    "ping" => Ping,
+    "pull_request.closed" => PullRequest,
+    "pull_request.opened" => PullRequest,
+    "pull_request.synchronize" => PullRequest,
+    "push" => Push,
+    "repository.deleted" => Repository,
+};
+
+static TIMELINE: &[Fixture] = fixtures! { "timeline":
+    "commit_comment" => CommitComment,
+    "create" => Create,
+    "delete" => Delete,
+    "fork" => Fork,
+    "gollum" => Gollum,
+    "issues" => Issues,
+    "member" => Member,
+    "push" => Push,
+    "push.20_commits" => Push,
+    "release" => Release,
+    "watch" => Watch,
+};
+
+/// Webhook request bodies, to be parsed with [`Event::from_slice`](../enum.Event.html#method.from_slice).
+pub fn webhooks() -> &'static [Fixture] {
+    WEBHOOKS
+}
+
+/// Single events from the Events API, to be parsed as a
+/// [`TimelineEvent`](../timeline/struct.TimelineEvent.html).
+pub fn timeline() -> &'static [Fixture] {
+    TIMELINE
+}
+
+/// Every timeline fixture as one Events API page.
+pub fn timeline_page() -> Vec<u8> {
+    let mut page = b"[".to_vec();
+    for (i, fixture) in TIMELINE.iter().enumerate() {
+        if i > 0 {
+            page.push(b',');
+        }
+        page.extend_from_slice(fixture.body);
+    }
+    page.push(b']');
+    page
+}
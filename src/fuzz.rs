@@ -0,0 +1,57 @@
+//! Generators for the fields `Arbitrary` can't derive, enabled by the `fuzz` feature.
+//!
+//! The untyped `serde_json::Value` fields are filled with small JSON documents without floats,
+//! so that every generated event survives a round trip through JSON unchanged.
+use arbitrary::{Result, Unstructured};
+use serde_json::{Map, Value};
+
+/// How deeply arrays and objects are nested.
+const MAX_DEPTH: usize = 2;
+/// The most elements in an array or object.
+const MAX_LEN: usize = 4;
+
+pub(crate) fn value(u: &mut Unstructured) -> Result<Value> {
+    value_at(u, MAX_DEPTH)
+}
+
+pub(crate) fn values(u: &mut Unstructured) -> Result<Vec<Value>> {
+    let len = u.int_in_range(0..=MAX_LEN)?;
+    (0..len).map(|_| value(u)).collect()
+}
+
+/// `Some(null)` is parsed back as `None`, so it is never generated.
+#[cfg(any(feature = "issues", feature = "orgs", feature = "projects"))]
+pub(crate) fn option_value(u: &mut Unstructured) -> Result<Option<Value>> {
+    if !u.arbitrary::<bool>()? {
+        return Ok(None);
+    }
+    Ok(Some(match value(u)? {
+        Value::Null => Value::Bool(false),
+        value => value,
+    }))
+}
+
+fn value_at(u: &mut Unstructured, depth: usize) -> Result<Value> {
+    let kinds = if depth == 0 { 4 } else { 6 };
+    Ok(match u.choose_index(kinds)? {
+        0 => Value::Null,
+        1 => Value::Bool(u.arbitrary()?),
+        2 => Value::from(u.arbitrary::<i64>()?),
+        3 => Value::String(u.arbitrary()?),
+        4 => {
+            let len = u.int_in_range(0..=MAX_LEN)?;
+            Value::Array(
+                (0..len)
+                    .map(|_| value_at(u, depth - 1))
+                    .collect::<Result<_>>()?,
+            )
+        }
+        _ => {
+            let mut object = Map::new();
+            for _ in 0..u.int_in_range(0..=MAX_LEN)? {
+                object.insert(u.arbitrary()?, value_at(u, depth - 1)?);
+            }
+            Value::Object(object)
+        }
+    })
+}
@@ -0,0 +1,123 @@
+//! Reading the hourly dumps of the public timeline published by
+//! [GH Archive](https://www.gharchive.org).
+//!
+//! Each dump is a gzipped file of JSON lines, one [`TimelineEvent`](../timeline/struct.TimelineEvent.html)
+//! per line, named by the hour it covers, e.g. `2015-01-01-15.json.gz`. Dumps are read as they
+//! are decompressed, so an hour of the timeline never has to fit in memory. Only dumps from 2015
+//! on are in the Events API's format; earlier ones used the retired timeline API's, and every
+//! line of them fails to parse.
+//!
+//! ```no_run
+//! use github_events::gharchive;
+//!
+//! # fn main() -> std::io::Result<()> {
+//! for event in gharchive::fetch(&gharchive::hour_url(2015, 1, 1, 15))? {
+//!     match event {
+//!         Ok(event) => println!("{} {}", event.type_name(), event.repo.name),
+//!         Err(e) => eprintln!("skipped: {}", e),
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::Path;
+
+use flate2::read::MultiGzDecoder;
+
+use crate::timeline::TimelineEvent;
+
+/// Where GH Archive serves its dumps.
+pub const BASE_URL: &str = "https://data.gharchive.org";
+
+/// The url of the dump of an hour, in UTC, from 0 to 23.
+pub fn hour_url(year: u32, month: u32, day: u32, hour: u32) -> String {
+    format!(
+        "{}/{:04}-{:02}-{:02}-{}.json.gz",
+        BASE_URL, year, month, day, hour
+    )
+}
+
+/// Read a dump from a local file.
+pub fn open(path: impl AsRef<Path>) -> io::Result<Archive<File>> {
+    File::open(path).map(Archive::new)
+}
+
+/// Download a dump, reading it as it arrives.
+pub fn fetch(url: &str) -> io::Result<Archive<Box<dyn Read + Send + Sync>>> {
+    let res = ureq::get(url).call().map_err(io::Error::other)?;
+    Ok(Archive::new(res.into_reader()))
+}
+
+/// [`fetch`](fn.fetch.html) `source` if it's an `http` or `https` url, or else
+/// [`open`](fn.open.html) it as a path.
+pub fn open_or_fetch(source: &str) -> io::Result<Archive<Box<dyn Read + Send + Sync>>> {
+    if source.starts_with("https://") || source.starts_with("http://") {
+        fetch(source)
+    } else {
+        let file = File::open(source)?;
+        Ok(Archive::new(Box::new(file)))
+    }
+}
+
+/// The events of a dump, in the order GH Archive recorded them.
+///
+/// A line that isn't a `TimelineEvent` is yielded as an error, naming its line number, and
+/// reading goes on past it. An error reading or decompressing the dump is yielded once and ends
+/// the iteration, as nothing after it can be trusted.
+#[derive(Debug)]
+pub struct Archive<R> {
+    lines: io::Lines<BufReader<MultiGzDecoder<R>>>,
+    line: u64,
+    done: bool,
+}
+
+impl<R: Read> Archive<R> {
+    /// Read a gzipped dump from `reader`.
+    pub fn new(reader: R) -> Self {
+        Archive {
+            lines: BufReader::new(MultiGzDecoder::new(reader)).lines(),
+            line: 0,
+            done: false,
+        }
+    }
+
+    /// The number of lines read so far.
+    pub fn lines_read(&self) -> u64 {
+        self.line
+    }
+}
+
+impl<R: Read> Iterator for Archive<R> {
+    type Item = io::Result<TimelineEvent>;
+
+    fn next(&mut self) -> Option<io::Result<TimelineEvent>> {
+        if self.done {
+            return None;
+        }
+        loop {
+            let line = match self.lines.next() {
+                Some(Ok(line)) => line,
+                Some(Err(e)) => {
+                    self.done = true;
+                    return Some(Err(e));
+                }
+                None => {
+                    self.done = true;
+                    return None;
+                }
+            };
+            self.line += 1;
+            if line.trim().is_empty() {
+                continue;
+            }
+            return Some(::serde_json::from_str(&line).map_err(|e| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("line {}: {}", self.line, e),
+                )
+            }));
+        }
+    }
+}
@@ -0,0 +1,145 @@
+//! Which events and fields GitHub Enterprise Server doesn't send, by version, enabled by the
+//! `ghes` feature.
+//!
+//! GHES releases lag github.com, so their payloads can lack what dotcom's have. The payload
+//! types already accept what the versions below send, by taking the names GHES uses as aliases
+//! and leaving out fields that are missing as `None` or empty; this module is for receivers that
+//! want to know ahead of time what an instance will never send them, e.g. to warn that a filter
+//! can't match.
+//!
+//! GHES sends its version in the `X-GitHub-Enterprise-Version` header of every delivery:
+//!
+//! ```
+//! use github_events::ghes::{self, Version};
+//! use github_events::EventType;
+//!
+//! let version: Version = "3.10.4".parse().unwrap();
+//! assert_eq!(version, Version::V3_10);
+//! assert!(ghes::sends(version, EventType::Push));
+//! assert!(!ghes::sends(version, EventType::RepositoryImport));
+//! ```
+use core::fmt;
+use core::str::FromStr;
+
+use crate::prelude::*;
+use crate::EventType;
+
+/// A supported GHES feature release.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum Version {
+    V3_8,
+    V3_9,
+    V3_10,
+    V3_11,
+    V3_12,
+    V3_13,
+}
+
+impl Version {
+    /// Every version, oldest first.
+    pub const ALL: &'static [Version] = &[
+        Version::V3_8,
+        Version::V3_9,
+        Version::V3_10,
+        Version::V3_11,
+        Version::V3_12,
+        Version::V3_13,
+    ];
+
+    /// The minor version, e.g. `10` for 3.10.
+    pub fn minor(self) -> u8 {
+        match self {
+            Version::V3_8 => 8,
+            Version::V3_9 => 9,
+            Version::V3_10 => 10,
+            Version::V3_11 => 11,
+            Version::V3_12 => 12,
+            Version::V3_13 => 13,
+        }
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "3.{}", self.minor())
+    }
+}
+
+/// The error returned when parsing a version that isn't one of [`Version::ALL`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownVersion(pub String);
+
+impl fmt::Display for UnknownVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unknown GitHub Enterprise Server version `{}`", self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnknownVersion {}
+
+impl FromStr for Version {
+    type Err = UnknownVersion;
+
+    /// Parse a feature release like `3.10`, or a patch release like `3.10.4` as its feature
+    /// release.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.trim().split('.');
+        let minor = match (parts.next(), parts.next()) {
+            (Some("3"), Some(minor)) => minor.parse().ok(),
+            _ => None,
+        };
+        Version::ALL
+            .iter()
+            .find(|version| Some(version.minor()) == minor)
+            .copied()
+            .ok_or_else(|| UnknownVersion(s.to_owned()))
+    }
+}
+
+/// Something dotcom sends that some GHES versions don't.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Gap {
+    pub event: EventType,
+    /// The path of the missing field in the event's payload, e.g. `pull_request.auto_merge`,
+    /// or `None` if the whole event is never sent.
+    pub field: Option<&'static str>,
+    /// The first version that sends it, or `None` if none of them do.
+    pub since: Option<Version>,
+    pub reason: &'static str,
+}
+
+impl Gap {
+    /// Whether GHES `version` lacks this.
+    pub fn applies_to(&self, version: Version) -> bool {
+        self.since.is_none_or(|since| version < since)
+    }
+}
+
+/// What GHES lacks, across every supported version.
+pub const GAPS: &[Gap] = &[Gap {
+    event: EventType::RepositoryImport,
+    field: None,
+    since: None,
+    reason: "GitHub Importer is only on github.com",
+}];
+
+/// What GHES `version` lacks.
+pub fn gaps(version: Version) -> impl Iterator<Item = &'static Gap> {
+    GAPS.iter().filter(move |gap| gap.applies_to(version))
+}
+
+/// Whether GHES `version` ever sends events of type `event_type`.
+pub fn sends(version: Version, event_type: EventType) -> bool {
+    !gaps(version).any(|gap| gap.event == event_type && gap.field.is_none())
+}
+
+/// The paths of the fields of `event_type`'s payload that GHES `version` doesn't send.
+pub fn missing_fields(version: Version, event_type: EventType) -> Vec<&'static str> {
+    gaps(version)
+        .filter(|gap| gap.event == event_type)
+        .filter_map(|gap| gap.field)
+        .collect()
+}
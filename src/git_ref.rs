@@ -0,0 +1,118 @@
+use core::fmt;
+use core::ops::Deref;
+
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+const BRANCH_PREFIX: &str = "refs/heads/";
+const TAG_PREFIX: &str = "refs/tags/";
+
+/// A full git ref, such as `refs/heads/main` or `refs/tags/v1.0.0`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(transparent)]
+pub struct GitRef(String);
+
+impl GitRef {
+    pub fn new(git_ref: impl Into<String>) -> Self {
+        GitRef(git_ref.into())
+    }
+
+    /// The ref of the branch `name`, e.g. `refs/heads/main` for `main`.
+    pub fn branch(name: &str) -> Self {
+        GitRef(format!("{}{}", BRANCH_PREFIX, name))
+    }
+
+    /// The ref of the tag `name`, e.g. `refs/tags/v1.0.0` for `v1.0.0`.
+    pub fn tag(name: &str) -> Self {
+        GitRef(format!("{}{}", TAG_PREFIX, name))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn is_branch(&self) -> bool {
+        self.branch_name().is_some()
+    }
+
+    pub fn is_tag(&self) -> bool {
+        self.tag_name().is_some()
+    }
+
+    /// The name of the branch, e.g. `main` for `refs/heads/main`.
+    pub fn branch_name(&self) -> Option<&str> {
+        self.0.strip_prefix(BRANCH_PREFIX)
+    }
+
+    /// The name of the tag, e.g. `v1.0.0` for `refs/tags/v1.0.0`.
+    pub fn tag_name(&self) -> Option<&str> {
+        self.0.strip_prefix(TAG_PREFIX)
+    }
+
+    /// The name of the branch or tag, or else the whole ref.
+    pub fn short_name(&self) -> &str {
+        short_name(&self.0)
+    }
+}
+
+/// The name of the branch or tag `git_ref` refers to, or else `git_ref` itself.
+pub(crate) fn short_name(git_ref: &str) -> &str {
+    git_ref
+        .strip_prefix(BRANCH_PREFIX)
+        .or_else(|| git_ref.strip_prefix(TAG_PREFIX))
+        .unwrap_or(git_ref)
+}
+
+impl Deref for GitRef {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for GitRef {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<String> for GitRef {
+    fn from(git_ref: String) -> Self {
+        GitRef(git_ref)
+    }
+}
+
+impl From<&str> for GitRef {
+    fn from(git_ref: &str) -> Self {
+        GitRef(git_ref.to_owned())
+    }
+}
+
+impl From<GitRef> for String {
+    fn from(git_ref: GitRef) -> Self {
+        git_ref.0
+    }
+}
+
+impl PartialEq<str> for GitRef {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl<'a> PartialEq<&'a str> for GitRef {
+    fn eq(&self, other: &&'a str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl fmt::Display for GitRef {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
@@ -0,0 +1,280 @@
+//! [Webhook](https://docs.github.com/en/rest/webhooks/repos) management types.
+use alloc::borrow::Cow;
+
+use serde::ser::{SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+use crate::{EventType, HookId};
+
+/// A repository or organization webhook.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct Hook {
+    pub id: HookId,
+    /// Always `web`.
+    pub name: String,
+    /// Whether notifications are sent when the webhook is triggered.
+    pub active: bool,
+    /// The events the hook is triggered for, or `*` for all of them.
+    ///
+    /// Kept as strings, since GitHub adds events faster than [`EventType`](../enum.EventType.html)
+    /// can keep up with; see [`subscribes_to`](#method.subscribes_to).
+    pub events: Vec<String>,
+    pub config: HookConfig,
+    pub created_at: String,
+    pub updated_at: String,
+    /// `None` for the webhook of a GitHub App.
+    pub url: Option<String>,
+    pub ping_url: Option<String>,
+    pub deliveries_url: Option<String>,
+}
+
+impl Hook {
+    /// Whether the hook is triggered by `event_type`.
+    pub fn subscribes_to(&self, event_type: EventType) -> bool {
+        self.events
+            .iter()
+            .any(|e| e == "*" || e == event_type.as_str())
+    }
+}
+
+/// How a webhook delivers its payloads. Unset fields are left unchanged when updating a hook.
+#[derive(Clone, PartialEq, Eq, Hash, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "debug-full", derive(Debug))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct HookConfig {
+    /// The URL to which the payloads will be delivered.
+    pub url: Option<String>,
+    pub content_type: Option<ContentType>,
+    /// The key used to sign payloads in the `X-Hub-Signature-256` header.
+    /// GitHub never returns it, only `********` if one is set.
+    pub secret: Option<String>,
+    /// `"0"` to verify the SSL certificate of `url` when delivering payloads, `"1"` not to.
+    pub insecure_ssl: Option<String>,
+}
+
+debug_redacting!(HookConfig { url, content_type, secret(redacted), insecure_ssl });
+
+impl Serialize for HookConfig {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // Unset fields are left out, so that updating a hook leaves them unchanged, but only
+        // where the format is self-describing enough to tell which fields are missing.
+        let skip_unset = serializer.is_human_readable();
+        let mut state = serializer.serialize_struct("HookConfig", 4)?;
+        macro_rules! field {
+            ($field:ident) => {
+                if skip_unset && self.$field.is_none() {
+                    state.skip_field(stringify!($field))?;
+                } else {
+                    state.serialize_field(stringify!($field), &self.$field)?;
+                }
+            };
+        }
+        field!(url);
+        field!(content_type);
+        field!(secret);
+        field!(insecure_ssl);
+        state.end()
+    }
+}
+
+impl HookConfig {
+    /// Deliver JSON payloads to `url`.
+    pub fn new(url: impl Into<String>) -> Self {
+        HookConfig {
+            url: Some(url.into()),
+            content_type: Some(ContentType::Json),
+            secret: None,
+            insecure_ssl: None,
+        }
+    }
+}
+
+/// The media type used to serialize payloads.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum ContentType {
+    /// `application/json`
+    Json,
+    /// `application/x-www-form-urlencoded`, with the JSON payload in a `payload` parameter.
+    Form,
+}
+
+impl ContentType {
+    /// The content type named by a delivery's `Content-Type` header, e.g.
+    /// `application/json; charset=utf-8`, or `None` for other media types.
+    pub fn from_header(value: &str) -> Option<ContentType> {
+        let media_type = value.split(';').next().unwrap_or_default().trim();
+        if media_type.eq_ignore_ascii_case("application/json") {
+            Some(ContentType::Json)
+        } else if media_type.eq_ignore_ascii_case("application/x-www-form-urlencoded") {
+            Some(ContentType::Form)
+        } else {
+            None
+        }
+    }
+
+    /// The JSON payload in the `body` of a delivery of this content type. Signatures are of the
+    /// whole `body`, so verify them before taking the payload out.
+    pub fn payload(self, body: &[u8]) -> Result<Cow<'_, [u8]>, ::serde_json::Error> {
+        match self {
+            ContentType::Json => Ok(Cow::Borrowed(body)),
+            ContentType::Form => body
+                .split(|&b| b == b'&')
+                .find_map(|param| param.strip_prefix(b"payload="))
+                .ok_or_else(|| serde::de::Error::custom("no `payload` parameter in the form"))
+                .and_then(form_decode)
+                .map(Cow::Owned),
+        }
+    }
+}
+
+/// GitHub doesn't deliver payloads over 25 MB, so a body that decompresses to more is refused
+/// rather than inflated into memory.
+#[cfg(feature = "compression")]
+const MAX_PAYLOAD: u64 = 25 * 1024 * 1024;
+
+/// The body of a delivery as GitHub sent it, undoing the gzip or deflate `Content-Encoding`
+/// that some proxies and load balancers apply on the way.
+///
+/// GitHub signs the body it sent, so verify the signature of the decompressed body, and parse
+/// that too.
+#[cfg(feature = "compression")]
+pub fn decompress<'a>(
+    content_encoding: Option<&str>,
+    body: &'a [u8],
+) -> std::io::Result<Cow<'a, [u8]>> {
+    use flate2::read::{DeflateDecoder, MultiGzDecoder, ZlibDecoder};
+
+    let mut body = Cow::Borrowed(body);
+    // Codings are listed in the order they were applied.
+    for coding in content_encoding.unwrap_or_default().rsplit(',') {
+        let coding = coding.trim();
+        let decoded =
+            if coding.eq_ignore_ascii_case("gzip") || coding.eq_ignore_ascii_case("x-gzip") {
+                inflate(MultiGzDecoder::new(&body[..]))?
+            } else if coding.eq_ignore_ascii_case("deflate") {
+                // HTTP's deflate is zlib-wrapped, but some servers send it raw.
+                match inflate(ZlibDecoder::new(&body[..])) {
+                    Ok(decoded) => decoded,
+                    Err(_) => inflate(DeflateDecoder::new(&body[..]))?,
+                }
+            } else if coding.is_empty() || coding.eq_ignore_ascii_case("identity") {
+                continue;
+            } else {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    format!("unsupported Content-Encoding `{}`", coding),
+                ));
+            };
+        body = Cow::Owned(decoded);
+    }
+    Ok(body)
+}
+
+#[cfg(feature = "compression")]
+fn inflate(decoder: impl std::io::Read) -> std::io::Result<Vec<u8>> {
+    use std::io::Read;
+
+    let mut decoded = Vec::new();
+    decoder.take(MAX_PAYLOAD + 1).read_to_end(&mut decoded)?;
+    if decoded.len() as u64 > MAX_PAYLOAD {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            "decompressed body is over 25 MB",
+        ));
+    }
+    Ok(decoded)
+}
+
+/// Undo the percent-encoding of a form value, in which `+` stands for a space.
+fn form_decode(value: &[u8]) -> Result<Vec<u8>, ::serde_json::Error> {
+    let hex = |b: u8| (b as char).to_digit(16);
+    let mut decoded = Vec::with_capacity(value.len());
+    let mut bytes = value.iter();
+    while let Some(&b) = bytes.next() {
+        match b {
+            b'+' => decoded.push(b' '),
+            b'%' => match (
+                bytes.next().and_then(|&b| hex(b)),
+                bytes.next().and_then(|&b| hex(b)),
+            ) {
+                (Some(high), Some(low)) => decoded.push((high << 4 | low) as u8),
+                _ => {
+                    return Err(serde::de::Error::custom(
+                        "invalid percent-encoding in the form",
+                    ))
+                }
+            },
+            b => decoded.push(b),
+        }
+    }
+    Ok(decoded)
+}
+
+/// The parameters for [creating a webhook](https://docs.github.com/en/rest/webhooks/repos#create-a-repository-webhook).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+#[non_exhaustive]
+pub struct NewHook {
+    pub config: HookConfig,
+    /// The events the hook is triggered for. GitHub defaults to [`Push`](../enum.EventType.html#variant.Push) when empty.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub events: Vec<EventType>,
+    pub active: bool,
+}
+
+impl NewHook {
+    /// An active hook delivering `push` events to `config`.
+    pub fn new(config: HookConfig) -> Self {
+        NewHook {
+            config,
+            events: Vec::new(),
+            active: true,
+        }
+    }
+}
+
+/// The parameters for [updating a webhook](https://docs.github.com/en/rest/webhooks/repos#update-a-repository-webhook).
+/// Unset fields are left unchanged.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[non_exhaustive]
+pub struct EditHook {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub config: Option<HookConfig>,
+    /// Replaces the events the hook is triggered for.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub events: Option<Vec<EventType>>,
+    /// Events to add to the hook's events. Only supported by repository hooks.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub add_events: Vec<EventType>,
+    /// Events to remove from the hook's events. Only supported by repository hooks.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub remove_events: Vec<EventType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+}
+
+impl EditHook {
+    /// An edit leaving everything unchanged, to set the fields to change on.
+    // `Default` is left to the `test-defaults` feature, like the payloads'.
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        EditHook {
+            config: None,
+            events: None,
+            add_events: Vec::new(),
+            remove_events: Vec::new(),
+            active: None,
+        }
+    }
+}
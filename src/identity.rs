@@ -0,0 +1,114 @@
+use serde_json::Value;
+
+use crate::prelude::*;
+use crate::{Event, EventType};
+
+/// A stable key for an event, for deduplicating events and indexing them in maps and sets.
+///
+/// Unlike comparing whole events, the key stays the same when a payload is redelivered with
+/// fields that change between deliveries, such as counts and the sender's avatar.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum EventIdentity {
+    /// The delivery's GUID, from the `X-GitHub-Delivery` header.
+    Delivery(String),
+    /// What the payload says happened.
+    Payload {
+        event_type: EventType,
+        action: Option<String>,
+        /// What the event is about, e.g. `issue:1` for the issue of an `issues` event, or
+        /// `octocat/Hello-World:refs/heads/master@6dcb09b` for a push.
+        subject: String,
+        /// When it happened, as the payload says; see [`Event::occurred_at`](enum.Event.html#method.occurred_at).
+        /// For comments, when they were last edited, so that each edit is told apart.
+        at: Option<String>,
+    },
+}
+
+/// The objects whose ids identify what an event is about, most specific first:
+/// a comment's event is about the comment rather than its issue.
+const SUBJECTS: &[&str] = &[
+    "comment",
+    "review",
+    "check_run",
+    "check_suite",
+    "deployment_status",
+    "deployment",
+    "pull_request",
+    "issue",
+    "label",
+    "milestone",
+    "release",
+    "project_card",
+    "project_column",
+    "project",
+    "team",
+    "member",
+    "blocked_user",
+    "forkee",
+    "alert",
+    "build",
+    "hook",
+    "installation",
+    "repository",
+    "organization",
+    "sender",
+];
+
+impl EventIdentity {
+    pub(crate) fn of(event: &Event) -> Self {
+        let event_type = event.event_type();
+        let payload = payload(event);
+        EventIdentity::Payload {
+            event_type,
+            action: payload["action"].as_str().map(String::from),
+            subject: subject(event_type, &payload),
+            at: event.changed_at().map(String::from),
+        }
+    }
+}
+
+/// What `event` is about, whatever state it's left it in: the branch of a push rather than the
+/// commit it pushed, or the issue rather than the label added to it.
+#[cfg(feature = "debounce")]
+pub(crate) fn subject_of(event: &Event) -> String {
+    let event_type = event.event_type();
+    let subject = subject(event_type, &payload(event));
+    match event_type {
+        EventType::Push | EventType::Create | EventType::Delete => match subject.rsplit_once('@') {
+            Some((branch, _)) => branch.to_owned(),
+            None => subject,
+        },
+        _ => subject,
+    }
+}
+
+fn payload(event: &Event) -> Value {
+    match ::serde_json::to_value(event) {
+        Ok(Value::Object(tagged)) => tagged.into_iter().next().map(|(_, payload)| payload),
+        _ => None,
+    }
+    .unwrap_or(Value::Null)
+}
+
+fn subject(event_type: EventType, p: &Value) -> String {
+    let text = |value: &Value| match value {
+        Value::String(s) => s.clone(),
+        Value::Null => String::new(),
+        value => value.to_string(),
+    };
+    match event_type {
+        EventType::Push | EventType::Create | EventType::Delete => format!(
+            "{}:{}@{}",
+            text(&p["repository"]["full_name"]),
+            text(&p["ref"]),
+            text(&p["after"])
+        ),
+        EventType::Status => format!("status:{}", text(&p["id"])),
+        EventType::SecurityAdvisory => text(&p["security_advisory"]["ghsa_id"]),
+        _ => SUBJECTS
+            .iter()
+            .find(|&&key| !p[key]["id"].is_null())
+            .map(|&key| format!("{}:{}", key, text(&p[key]["id"])))
+            .unwrap_or_default(),
+    }
+}
@@ -0,0 +1,62 @@
+//! Typed ids, so that an issue's id can't be passed where a comment's or a repository's is
+//! expected. GitHub's ids are non-negative and have outgrown 32 bits, so each wraps a `u64`,
+//! and is (de)serialized as the plain number.
+use core::fmt;
+
+use serde::{Deserialize, Serialize};
+
+macro_rules! ids {
+    ($($(#[$doc:meta])* $name:ident;)*) => {$(
+        $(#[$doc])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+        #[cfg_attr(feature = "test-defaults", derive(Default))]
+        #[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+        #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+        #[serde(transparent)]
+        pub struct $name(u64);
+
+        impl $name {
+            pub const fn new(id: u64) -> Self {
+                $name(id)
+            }
+
+            pub const fn get(self) -> u64 {
+                self.0
+            }
+        }
+
+        impl From<u64> for $name {
+            fn from(id: u64) -> Self {
+                $name(id)
+            }
+        }
+
+        impl From<$name> for u64 {
+            fn from(id: $name) -> Self {
+                id.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                fmt::Display::fmt(&self.0, f)
+            }
+        }
+    )*};
+}
+
+ids! {
+    /// The id of a repository, wherever a payload refers to one.
+    RepositoryId;
+    /// The id of a user's or organization's account, as a payload's users and owners have it.
+    UserId;
+    /// The id of an issue, which isn't its number. Pull requests have ids of their own.
+    IssueId;
+    /// The id of an installation of a GitHub App.
+    InstallationId;
+    /// The id of a check run.
+    CheckRunId;
+    /// The id of a repository's, organization's, or app's webhook, as sent in the
+    /// `X-GitHub-Hook-ID` header.
+    HookId;
+}
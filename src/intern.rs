@@ -0,0 +1,162 @@
+//! Interning of strings that repeat across events, enabled by the `intern` feature.
+//!
+//! Every copy of the same string parsed anywhere in the process shares one `Arc<str>`,
+//! which cuts memory dramatically when buffering many events from the same repositories.
+use std::borrow::Borrow;
+use std::collections::HashSet;
+use std::fmt;
+use std::ops::Deref;
+use std::sync::{Arc, Mutex, OnceLock};
+
+/// A shared, immutable string, deduplicated when deserialized.
+#[derive(Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Interned(Arc<str>);
+
+/// The interner is pruned of strings no event refers to any more once it grows past this,
+/// or twice its size after the last pruning.
+const MIN_PRUNE_LEN: usize = 4096;
+
+struct Interner {
+    strings: HashSet<Arc<str>>,
+    prune_at: usize,
+}
+
+impl Interned {
+    /// The shared copy of `s`.
+    pub fn new(s: &str) -> Self {
+        static INTERNER: OnceLock<Mutex<Interner>> = OnceLock::new();
+        let mut interner = INTERNER
+            .get_or_init(|| {
+                Mutex::new(Interner {
+                    strings: HashSet::new(),
+                    prune_at: MIN_PRUNE_LEN,
+                })
+            })
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        if let Some(shared) = interner.strings.get(s) {
+            return Interned(shared.clone());
+        }
+        if interner.strings.len() >= interner.prune_at {
+            interner
+                .strings
+                .retain(|shared| Arc::strong_count(shared) > 1);
+            interner.prune_at = (interner.strings.len() * 2).max(MIN_PRUNE_LEN);
+        }
+        let shared: Arc<str> = Arc::from(s);
+        interner.strings.insert(shared.clone());
+        Interned(shared)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn into_arc(self) -> Arc<str> {
+        self.0
+    }
+}
+
+impl Deref for Interned {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for Interned {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Borrow<str> for Interned {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<&str> for Interned {
+    fn from(s: &str) -> Self {
+        Interned::new(s)
+    }
+}
+
+impl From<String> for Interned {
+    fn from(s: String) -> Self {
+        Interned::new(&s)
+    }
+}
+
+impl PartialEq<str> for Interned {
+    fn eq(&self, other: &str) -> bool {
+        &*self.0 == other
+    }
+}
+
+impl<'a> PartialEq<&'a str> for Interned {
+    fn eq(&self, other: &&'a str) -> bool {
+        &*self.0 == *other
+    }
+}
+
+impl fmt::Debug for Interned {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&*self.0, f)
+    }
+}
+
+impl fmt::Display for Interned {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&*self.0, f)
+    }
+}
+
+#[cfg(feature = "fuzz")]
+impl<'a> arbitrary::Arbitrary<'a> for Interned {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Interned::new(u.arbitrary()?))
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for Interned {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        String::schema_name()
+    }
+
+    fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        String::json_schema(generator)
+    }
+}
+
+impl serde::Serialize for Interned {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Interned {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct InternedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for InternedVisitor {
+            type Value = Interned;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a string")
+            }
+
+            fn visit_str<E>(self, s: &str) -> Result<Interned, E> {
+                Ok(Interned::new(s))
+            }
+        }
+
+        deserializer.deserialize_str(InternedVisitor)
+    }
+}
@@ -0,0 +1,79 @@
+//! Conversions to and from [octocrab](https://docs.rs/octocrab)'s models, enabled by the
+//! `octocrab-interop` feature, so a payload's repository, issue, etc. can be passed on to
+//! octocrab's API calls.
+//!
+//! octocrab's models can only be built by deserializing them, so the conversions go through
+//! JSON and fail where the two disagree, e.g. on fields left out by the `slim` feature.
+//! Teams and hooks have no conversions, as octocrab requires fields they don't have, and the
+//! templates in urls such as `following_url` come back percent-encoded.
+use std::convert::TryFrom;
+
+use octocrab::models;
+use serde::{de::DeserializeOwned, Serialize};
+
+#[cfg(feature = "issues")]
+use crate::Milestone;
+use crate::{
+    Comment, Installation, Issue, Label, Organization, Owner, PullRequest, Release, Repository,
+    User,
+};
+
+fn convert<T: Serialize, U: DeserializeOwned>(value: &T) -> Result<U, ::serde_json::Error> {
+    ::serde_json::from_value(::serde_json::to_value(value)?)
+}
+
+macro_rules! to_octocrab {
+    ($($(#[$attr:meta])* $ours:ty => $theirs:ty,)*) => {$(
+        $(#[$attr])*
+        impl TryFrom<$ours> for $theirs {
+            type Error = ::serde_json::Error;
+
+            fn try_from(value: $ours) -> Result<Self, Self::Error> {
+                convert(&value)
+            }
+        }
+    )*};
+}
+
+macro_rules! from_octocrab {
+    ($($(#[$attr:meta])* $theirs:ty => $ours:ty,)*) => {$(
+        $(#[$attr])*
+        impl TryFrom<$theirs> for $ours {
+            type Error = ::serde_json::Error;
+
+            fn try_from(value: $theirs) -> Result<Self, Self::Error> {
+                convert(&value)
+            }
+        }
+    )*};
+}
+
+to_octocrab! {
+    Repository => models::Repository,
+    User => models::Author,
+    Owner => models::Author,
+    Organization => models::orgs::Organization,
+    Installation => models::Installation,
+    Issue => models::issues::Issue,
+    Comment => models::issues::Comment,
+    Label => models::Label,
+    #[cfg(feature = "issues")]
+    Milestone => models::Milestone,
+    PullRequest => models::pulls::PullRequest,
+    Release => models::repos::Release,
+}
+
+// octocrab's repositories lack the `forks` count, and its pull requests the
+// `html` link, so those only convert one way.
+from_octocrab! {
+    models::Author => User,
+    models::Author => Owner,
+    models::orgs::Organization => Organization,
+    models::Installation => Installation,
+    models::issues::Issue => Issue,
+    models::issues::Comment => Comment,
+    models::Label => Label,
+    #[cfg(feature = "issues")]
+    models::Milestone => Milestone,
+    models::repos::Release => Release,
+}
@@ -0,0 +1,325 @@
+//! An append-only log of events in [JSON Lines](https://jsonlines.org), for archiving deliveries
+//! and replaying them when debugging.
+//!
+//! Each line is an object like
+//! `{"received_at":"2019-05-15T15:20:33Z","delivery_id":"…","source":"webhook","event_type":"push","payload":{…}}`,
+//! with the event's [`Source`](../provenance/enum.Source.html), the `hook_id` that delivered it if
+//! known, and the payload as GitHub delivered it, so the log can be read with `jq` as well as replayed
+//! into typed [`Event`](../enum.Event.html)s. The log is a directory of segments named
+//! `events-<date>-<n>.jsonl`, which sort in the order they were written.
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use serde_json::value::RawValue;
+use serde_json::Value;
+
+use crate::provenance::{Provenance, Source};
+use crate::timestamp;
+use crate::{Event, EventIdentity, EventType, HookId};
+
+const PREFIX: &str = "events-";
+const EXTENSION: &str = ".jsonl";
+
+/// An event as it was delivered.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct Entry {
+    /// Logged to the second.
+    pub received_at: SystemTime,
+    /// The delivery's GUID, from the `X-GitHub-Delivery` header.
+    pub delivery_id: String,
+    /// `Webhook` for entries logged before sources were.
+    pub source: Source,
+    pub hook_id: Option<HookId>,
+    pub event: Event,
+}
+
+impl Entry {
+    /// `event`, delivered to a webhook now.
+    pub fn new(delivery_id: impl Into<String>, event: Event) -> Self {
+        Entry::from_provenance(Provenance::webhook(delivery_id), event)
+    }
+
+    pub fn from_provenance(provenance: Provenance, event: Event) -> Self {
+        Entry {
+            received_at: provenance.received_at,
+            delivery_id: provenance.delivery_id,
+            source: provenance.source,
+            hook_id: provenance.hook_id,
+            event,
+        }
+    }
+
+    pub fn provenance(&self) -> Provenance {
+        let provenance = Provenance::new(self.source, self.delivery_id.clone())
+            .with_received_at(self.received_at);
+        match self.hook_id {
+            Some(hook_id) => provenance.with_hook_id(hook_id),
+            None => provenance,
+        }
+    }
+
+    /// The entry's delivery GUID, which identifies it across redeliveries.
+    pub fn identity(&self) -> EventIdentity {
+        EventIdentity::Delivery(self.delivery_id.clone())
+    }
+}
+
+#[derive(Serialize)]
+struct LineRef<'a> {
+    received_at: String,
+    delivery_id: &'a str,
+    source: Source,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hook_id: Option<HookId>,
+    event_type: EventType,
+    payload: Value,
+}
+
+#[derive(Deserialize)]
+struct Line {
+    received_at: String,
+    delivery_id: String,
+    #[serde(default)]
+    source: Source,
+    #[serde(default)]
+    hook_id: Option<HookId>,
+    event_type: EventType,
+    payload: Box<RawValue>,
+}
+
+/// The writing end of a log. Only one should be open on a directory at a time.
+#[derive(Debug)]
+pub struct JsonlEventLog {
+    dir: PathBuf,
+    max_size: Option<u64>,
+    daily: bool,
+    segment: Option<Segment>,
+}
+
+/// The segment being appended to.
+#[derive(Debug)]
+struct Segment {
+    file: File,
+    date: String,
+    number: u32,
+    size: u64,
+}
+
+impl JsonlEventLog {
+    /// The log in `dir`, which is created if it doesn't exist. Appends continue the last segment.
+    pub fn open(dir: impl Into<PathBuf>) -> io::Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        Ok(JsonlEventLog {
+            dir,
+            max_size: None,
+            daily: false,
+            segment: None,
+        })
+    }
+
+    /// Start a new segment once the current one reaches `bytes`.
+    pub fn rotate_at_size(mut self, bytes: u64) -> Self {
+        self.max_size = Some(bytes);
+        self
+    }
+
+    /// Start a new segment on each UTC day that events are received.
+    pub fn rotate_daily(mut self) -> Self {
+        self.daily = true;
+        self
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Append `entry`, syncing it to disk before returning.
+    pub fn append(&mut self, entry: &Entry) -> io::Result<()> {
+        let payload = match ::serde_json::to_value(&entry.event)? {
+            Value::Object(tagged) => tagged.into_iter().next().map(|(_, payload)| payload),
+            _ => None,
+        }
+        .unwrap_or(Value::Null);
+        let mut line = ::serde_json::to_vec(&LineRef {
+            received_at: timestamp::from_unix(unix(entry.received_at)),
+            delivery_id: &entry.delivery_id,
+            source: entry.source,
+            hook_id: entry.hook_id,
+            event_type: entry.event.event_type(),
+            payload,
+        })?;
+        line.push(b'\n');
+
+        let segment = self.segment(entry.received_at, line.len() as u64)?;
+        segment.file.write_all(&line)?;
+        segment.file.sync_data()?;
+        segment.size += line.len() as u64;
+        Ok(())
+    }
+
+    /// The segment to append `len` bytes received at `received_at` to.
+    fn segment(&mut self, received_at: SystemTime, len: u64) -> io::Result<&mut Segment> {
+        let today = timestamp::from_unix(unix(received_at))[..10].to_owned();
+        if self.segment.is_none() {
+            self.segment = self.last_segment()?;
+        }
+        let rotate = match &self.segment {
+            None => true,
+            Some(segment) => {
+                (self.daily && segment.date != today)
+                    || self
+                        .max_size
+                        .is_some_and(|max| segment.size > 0 && segment.size + len > max)
+            }
+        };
+        if rotate {
+            let (date, number) = match &self.segment {
+                Some(segment) if !self.daily || segment.date == today => {
+                    (segment.date.clone(), segment.number + 1)
+                }
+                _ => (today, 1),
+            };
+            let file = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(self.dir.join(segment_name(&date, number)))?;
+            let size = file.metadata()?.len();
+            self.segment = Some(Segment {
+                file,
+                date,
+                number,
+                size,
+            });
+        }
+        Ok(self.segment.as_mut().expect("opened above"))
+    }
+
+    fn last_segment(&self) -> io::Result<Option<Segment>> {
+        let last = match segments(&self.dir)?.pop() {
+            Some(last) => last,
+            None => return Ok(None),
+        };
+        let name = last
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or_default();
+        let (date, number) = match parse_segment_name(name) {
+            Some(parsed) => parsed,
+            None => return Ok(None),
+        };
+        let file = OpenOptions::new().append(true).open(&last)?;
+        let size = file.metadata()?.len();
+        Ok(Some(Segment {
+            file,
+            date: date.to_owned(),
+            number,
+            size,
+        }))
+    }
+
+    /// Read every entry of the log in `dir`, oldest first.
+    ///
+    /// Lines that can't be read, such as a line cut short by a crash or an event whose family is
+    /// turned off by a cargo feature, are yielded as errors, and the replay goes on past them.
+    pub fn replay(dir: impl AsRef<Path>) -> io::Result<Replay> {
+        Ok(Replay {
+            segments: segments(dir.as_ref())?.into_iter(),
+            lines: None,
+        })
+    }
+}
+
+/// The entries of a log, from [`JsonlEventLog::replay`](struct.JsonlEventLog.html#method.replay).
+#[derive(Debug)]
+pub struct Replay {
+    segments: std::vec::IntoIter<PathBuf>,
+    lines: Option<io::Lines<BufReader<File>>>,
+}
+
+impl Iterator for Replay {
+    type Item = io::Result<Entry>;
+
+    fn next(&mut self) -> Option<io::Result<Entry>> {
+        loop {
+            if let Some(lines) = &mut self.lines {
+                match lines.next() {
+                    Some(Ok(line)) if line.trim().is_empty() => continue,
+                    Some(Ok(line)) => return Some(parse_line(&line)),
+                    Some(Err(e)) => return Some(Err(e)),
+                    None => self.lines = None,
+                }
+            }
+            let segment = self.segments.next()?;
+            match File::open(segment) {
+                Ok(file) => self.lines = Some(BufReader::new(file).lines()),
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+fn parse_line(line: &str) -> io::Result<Entry> {
+    let line: Line = ::serde_json::from_str(line)?;
+    let secs = timestamp::to_unix(&line.received_at).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("invalid received_at `{}`", line.received_at),
+        )
+    })?;
+    let received_at = if secs >= 0 {
+        UNIX_EPOCH + Duration::from_secs(secs as u64)
+    } else {
+        UNIX_EPOCH - Duration::from_secs(secs.unsigned_abs())
+    };
+    Ok(Entry {
+        received_at,
+        delivery_id: line.delivery_id,
+        source: line.source,
+        hook_id: line.hook_id,
+        event: Event::from_slice(line.event_type, line.payload.get().as_bytes())?,
+    })
+}
+
+/// The segments in `dir`, in the order they were written.
+fn segments(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut segments = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let name = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or_default();
+        if parse_segment_name(name).is_some() {
+            segments.push(path);
+        }
+    }
+    segments.sort();
+    Ok(segments)
+}
+
+fn segment_name(date: &str, number: u32) -> String {
+    format!("{}{}-{:06}{}", PREFIX, date, number, EXTENSION)
+}
+
+/// The date and number of a segment named like `events-2019-05-15-000001.jsonl`.
+fn parse_segment_name(name: &str) -> Option<(&str, u32)> {
+    let stem = name.strip_prefix(PREFIX)?.strip_suffix(EXTENSION)?;
+    let (date, number) = stem.split_at(stem.len().checked_sub(7)?);
+    let number = number.strip_prefix('-')?;
+    if date.len() != 10 || number.len() != 6 || !number.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    Some((date, number.parse().ok()?))
+}
+
+fn unix(time: SystemTime) -> i64 {
+    match time.duration_since(UNIX_EPOCH) {
+        Ok(since) => since.as_secs() as i64,
+        Err(e) => -(e.duration().as_secs() as i64),
+    }
+}
@@ -0,0 +1,129 @@
+//! Events whose heavyweight sub-objects are only parsed when they are used.
+//!
+//! Most payloads embed the full `repository`, `organization`, `sender`, and `installation`
+//! objects, which are usually the bulk of the parsing work. A [`LazyEvent`](struct.LazyEvent.html)
+//! only validates them up front and parses each one the first time it is accessed,
+//! so a router that only looks at the `action` doesn't pay for them at all.
+use std::fmt;
+use std::sync::OnceLock;
+
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::value::RawValue;
+
+use crate::{Event, EventType, InstallationRef, Organization, Repository, Sender};
+
+/// A JSON value that is parsed into a `T` on first access.
+#[derive(Clone)]
+pub struct Lazy<T> {
+    raw: Box<RawValue>,
+    parsed: OnceLock<T>,
+}
+
+impl<T: DeserializeOwned> Lazy<T> {
+    fn new(raw: Box<RawValue>) -> Self {
+        Lazy {
+            raw,
+            parsed: OnceLock::new(),
+        }
+    }
+
+    /// The value, parsed the first time this is called.
+    pub fn get(&self) -> Result<&T, ::serde_json::Error> {
+        if let Some(parsed) = self.parsed.get() {
+            return Ok(parsed);
+        }
+        let parsed = ::serde_json::from_str(self.raw.get())?;
+        Ok(self.parsed.get_or_init(|| parsed))
+    }
+
+    /// The unparsed JSON, e.g. for pulling out a single field with a smaller type than `T`.
+    pub fn raw(&self) -> &RawValue {
+        &self.raw
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Lazy<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.parsed.get() {
+            Some(parsed) => parsed.fmt(f),
+            None => self.raw.fmt(f),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct Fields {
+    action: Option<String>,
+    repository: Option<Box<RawValue>>,
+    organization: Option<Box<RawValue>>,
+    sender: Option<Box<RawValue>>,
+    installation: Option<Box<RawValue>>,
+}
+
+/// A webhook event with only its routing keys parsed.
+#[derive(Debug, Clone)]
+pub struct LazyEvent {
+    event_type: EventType,
+    payload: Box<RawValue>,
+    action: Option<String>,
+    repository: Option<Lazy<Repository>>,
+    organization: Option<Lazy<Organization>>,
+    sender: Option<Lazy<Sender>>,
+    installation: Option<Lazy<InstallationRef>>,
+}
+
+impl LazyEvent {
+    /// Validate the body of a webhook request, given the event's type from the `X-GitHub-Event` header,
+    /// parsing only its `action`.
+    pub fn from_slice(event_type: EventType, payload: &[u8]) -> Result<Self, ::serde_json::Error> {
+        let payload: Box<RawValue> = ::serde_json::from_slice(payload)?;
+        let fields: Fields = ::serde_json::from_str(payload.get())?;
+        Ok(LazyEvent {
+            event_type,
+            payload,
+            action: fields.action,
+            repository: fields.repository.map(Lazy::new),
+            organization: fields.organization.map(Lazy::new),
+            sender: fields.sender.map(Lazy::new),
+            installation: fields.installation.map(Lazy::new),
+        })
+    }
+
+    pub fn event_type(&self) -> EventType {
+        self.event_type
+    }
+
+    /// The action that was performed, for events that have one.
+    pub fn action(&self) -> Option<&str> {
+        self.action.as_deref()
+    }
+
+    pub fn repository(&self) -> Option<&Lazy<Repository>> {
+        self.repository.as_ref()
+    }
+
+    pub fn organization(&self) -> Option<&Lazy<Organization>> {
+        self.organization.as_ref()
+    }
+
+    pub fn sender(&self) -> Option<&Lazy<Sender>> {
+        self.sender.as_ref()
+    }
+
+    /// The installation the event was delivered to. Even the events about installations, which
+    /// carry the whole installation, can be read as a reference to it.
+    pub fn installation(&self) -> Option<&Lazy<InstallationRef>> {
+        self.installation.as_ref()
+    }
+
+    /// The whole payload, unparsed.
+    pub fn payload(&self) -> &RawValue {
+        &self.payload
+    }
+
+    /// Parse the whole payload.
+    pub fn parse(&self) -> Result<Event, ::serde_json::Error> {
+        Event::from_slice(self.event_type, self.payload.get().as_bytes())
+    }
+}
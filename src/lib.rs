@@ -1,1610 +1,3751 @@
-/// Feed Event API types and docs taken from [github docs](https://developer.github.com/v3/activity/events/types).
-///
-/// Utilized [json_typegen](http://vestera.as/json_typegen/) in creation.
+#![cfg_attr(not(feature = "std"), no_std)]
+//! Feed Event API types and docs taken from [github docs](https://developer.github.com/v3/activity/events/types).
+//!
+//! Utilized [json_typegen](http://vestera.as/json_typegen/) in creation.
+extern crate alloc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+pub mod actions;
+mod api_version;
+pub mod audit_log;
+#[cfg(feature = "auth")]
+pub mod auth;
+#[cfg(feature = "avro")]
+pub mod avro;
+#[cfg(feature = "batch")]
+pub mod batch;
+pub mod builders;
+#[cfg(any(feature = "slack", feature = "discord"))]
+mod card;
+mod changes;
+#[cfg(feature = "client")]
+pub mod client;
+#[cfg(feature = "cloudevents")]
+pub mod cloudevents;
+#[cfg(feature = "issues")]
+pub mod commands;
+#[cfg(feature = "conformance")]
+pub mod conformance;
+#[cfg(feature = "context")]
+pub mod context;
+#[cfg(feature = "debounce")]
+pub mod debounce;
 #[macro_use]
-extern crate serde_derive;
-extern crate serde_json;
-
-mod actions;
+mod debug;
+#[cfg(feature = "dedup")]
+pub mod dedup;
+pub mod deliveries;
+#[cfg(feature = "discord")]
+pub mod discord;
+pub mod envelope;
+mod event_type;
+pub mod filter;
+#[cfg(feature = "test-fixtures")]
+pub mod fixtures;
+#[cfg(feature = "fuzz")]
+mod fuzz;
+#[cfg(feature = "gharchive")]
+pub mod gharchive;
+#[cfg(feature = "ghes")]
+pub mod ghes;
+mod git_ref;
+pub mod hooks;
+mod identity;
+mod ids;
+#[cfg(feature = "intern")]
+pub mod intern;
+#[cfg(feature = "octocrab-interop")]
+mod interop;
+#[cfg(feature = "std")]
+pub mod jsonl;
+#[cfg(feature = "std")]
+pub mod lazy;
+#[cfg(feature = "metrics")]
+pub mod metrics;
+pub mod node_id;
+pub mod normalized;
+pub mod notifications;
+mod partial;
+pub mod peek;
+mod prelude;
+#[cfg(feature = "std")]
+pub mod provenance;
+pub mod redact;
+pub mod references;
 mod repository;
-use repository::*;
-
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-enum Event {
-    /// Triggered when a check run is `created`, `rerequested`, `completed`, or has a
-    /// `requested_action`. The checks permission allows you to use the checks API. If you plan to
-    /// create or modify check runs, your GitHub App will need to have the `checks:write` permission.
-    /// If you only plan to consume check runs, your GitHub App only needs the `checks:read`
-    /// permission.
-    ///
-    /// GitHub Apps with the `checks:write` permission will receive the `rerequested` action without
-    /// subscribing to the check_run webhook event. The `rerequested` action occurs when someone
-    /// requests to re-run your app's check from the pull request UI. See "About status checks" for
-    /// more details about the GitHub UI. When you receive a `rerequested` action, you'll need to
-    /// create a new check run. Only the GitHub App that someone requests to re-run the check will
-    /// receive the `rerequested` payload. Similarly,
-    /// only the GitHub App someone requests to perform
-    /// an action specified by the app will receive the `requested_action` payload.
-    ///
-    /// GitHub Apps that have the `checks:read` permission and subscribe to the `check_run` webhook
-    /// event receive the `created` and `completed` action payloads for all check runs in the app's
-    /// repository. Repositories and organizations that subscribe to the `check_run` webhook event
-    /// only receive `created` and `completed` event actions.
-    CheckRunEvent {
-        /// The action performed.
-        /// Can be `Created,` `Rerequested,` `Completed,` or `RequestedAction`.
-        action: actions::Check,
-        /// The [`check_run`](https://developer.github.com/v3/checks/runs/).
-        check_run: CheckRun,
-        ///
-        repository: Repository,
-        organization: Organization,
-        sender: Sender,
-        installation: Installation,
-    },
-
-    /// Triggered when a check suite is `completed`, `requested`, or `rerequested`. The checks permission
-    /// allows you to use the Checks API. If you plan to create or modify check runs, your GitHub
-    /// App will need to have the checks:write permission. If you only plan to consume check runs,
-    /// your GitHub App only needs the checks:read permission.
-    ///
-    /// GitHub Apps with the checks:write permission will receive the requested and `rerequested`
-    /// action payloads without subscribing to the `check_suite` webhook event.
-    /// The `requested` action
-    /// triggers when new code is pushed to the app's repository. A `rerequested` action occurs when
-    /// someone requests to re-run the entire check suite from the pull request UI. See "[About
-    /// status checks](https://help.github.com/articles/about-status-checks#checks)" for more
-    /// details about the GitHub UI. When you receive the `requested` or
-    /// `rerequested` action events, you'll need to
-    /// [create a new check run](https://developer.github.com/v3/checks/runs/#create-a-check-run).
-    /// Only the GitHub App that
-    /// is being asked to run a check will receive the `requested` and `rerequested` payloads.
-    ///
-    /// GitHub Apps that have the `checks:read` permission and
-    /// subscribe to the `check_suite` webhook
-    /// event receive the completed action payload for all check suites in the app's repository.
-    /// Repositories and organizations that subscribe to the `check_suite`
-    /// webhook event only receive
-    /// the `completed` event action.
-    CheckSuiteEvent {
-        /// The action performed.
-        /// Can be `Created,` `Rerequested,` `Completed,` or `RequestedAction.`
-        action: actions::Check,
-        /// The [check_suite](https://developer.github.com/v3/checks/suites/).
-        check_suite: CheckSuite,
-    },
-
-    /// Triggered when a
-    /// [commit comment](https://developer.github.com/v3/repos/comments/#list-commit-comments-for-a-repository) is created.
-    CommitCommentEvent {
-        action: actions::Created,
-        /// The [comment](https://developer.github.com/v3/repos/comments/#list-commit-comments-for-a-repository) itself.
-        // FIXME
-        comment: Comment,
-        repository: Repository,
-        sender: Sender,
-    },
-
-    /// Represents a created repository, branch, or tag.
-    /// Note: webhooks will not receive this event for created repositories.
-    /// Additionally, webhooks will not receive this event for tags
-    /// if more than three tags are pushed at once.
-    CreateEvent {
-        /// The git ref (or `null` if only a repository was created).
-        #[serde(rename = "ref")]
-        ref_field: String,
-        /// The object that was created. Can be one of "repository", "branch", or "tag"
-        ref_type: String,
-        /// The name of the repository's default branch (usually `master`).
-        master_branch: String,
-        /// The repository's current description.
-        description: ::serde_json::Value,
-        pusher_type: String,
-        repository: Repository,
-        sender: Sender,
-    },
-
-    /// Represents a [deleted branch or tag](https://developer.github.com/v3/git/refs/#delete-a-reference).
-    /// Note: webhooks will not receive this event for tags
-    /// if more than three tags are deleted at once.
-    DeleteEvent {
-        /// The full git ref.
-        #[serde(rename = "ref")]
-        ref_field: String,
-        /// The object that was deleted. Can be "branch" or "tag".
-        ref_type: String,
-        pusher_type: String,
-    },
-
-    /// Represents a [deployment](https://developer.github.com/v3/repos/deployments/#list-deployments).
-    ///
-    /// Events of this type are not visible in timelines. These events are only used to trigger hooks.
-    DeploymentEvent {
-        /// The [deployment](https://developer.github.com/v3/repos/deployments/#list-deployments).
-        deployment: Deployment,
-        /// The [repository](https://developer.github.com/v3/repos/) for this deployment.
-        repository: Repository,
-        sender: Sender,
-    },
-
-    /// Represents a [deployment status](https://developer.github.com/v3/repos/deployments/#list-deployment-statuses).
+#[cfg(feature = "router")]
+pub mod router;
+mod sample;
+mod sha;
+#[cfg(feature = "signature")]
+pub mod signature;
+#[cfg(feature = "sink")]
+pub mod sink;
+#[cfg(feature = "slack")]
+pub mod slack;
+#[cfg(feature = "std")]
+pub mod stale;
+#[cfg(feature = "store")]
+pub mod store;
+mod summary;
+mod tagged;
+pub mod timeline;
+mod timestamp;
+#[cfg(feature = "tracing")]
+pub mod tracing;
+mod untyped;
+#[cfg(any(
+    feature = "batch",
+    feature = "client",
+    feature = "debounce",
+    feature = "router"
+))]
+mod wait;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+pub use api_version::*;
+pub use event_type::*;
+pub use git_ref::*;
+pub use identity::*;
+pub use ids::*;
+pub use partial::*;
+pub use repository::*;
+/// The `serde_json` types in the signatures of this crate, so that using them doesn't take a
+/// `serde_json` dependency of the same version.
+pub use serde_json::value::RawValue;
+pub use serde_json::Value;
+pub use sha::*;
+
+/// A string that is often repeated across events, such as a login, repository name, or API url.
+/// An [`Interned`](intern/struct.Interned.html) string with the `intern` feature.
+#[cfg(not(feature = "intern"))]
+pub type IStr = String;
+
+/// A string that is often repeated across events, such as a login, repository name, or API url.
+#[cfg(feature = "intern")]
+pub type IStr = intern::Interned;
+
+/// A webhook event.
+///
+/// Payloads are boxed, so an `Event` is only two words and cheap to move
+/// no matter how large the payload is.
+///
+/// Most events belong to a family that can be turned off with a cargo feature
+/// (`checks`, `deployments`, `issues`, `orgs`, `projects`, `security`; all on by default).
+/// Parsing an event whose family is off fails with an unknown variant error.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum Event {
+    #[cfg(feature = "checks")]
+    CheckRunEvent(Box<CheckRunEvent>),
+    #[cfg(feature = "checks")]
+    CheckSuiteEvent(Box<CheckSuiteEvent>),
+    CommitCommentEvent(Box<CommitCommentEvent>),
+    CreateEvent(Box<CreateEvent>),
+    DeleteEvent(Box<DeleteEvent>),
+    #[cfg(feature = "deployments")]
+    DeploymentEvent(Box<DeploymentEvent>),
+    #[cfg(feature = "deployments")]
+    DeploymentStatusEvent(Box<DeploymentStatusEvent>),
+    ForkEvent(Box<ForkEvent>),
+    GitHubAppAuthorizationEvent(Box<GitHubAppAuthorizationEvent>),
+    GollumEvent(Box<GollumEvent>),
+    InstallationEvent(Box<InstallationEvent>),
+    InstallationRepositoriesEvent(Box<InstallationRepositoriesEvent>),
+    #[cfg(feature = "issues")]
+    IssueCommentEvent(Box<IssueCommentEvent>),
+    #[cfg(feature = "issues")]
+    IssueEvent(Box<IssueEvent>),
+    #[cfg(feature = "issues")]
+    LabelEvent(Box<LabelEvent>),
+    MemberEvent(Box<MemberEvent>),
+    #[cfg(feature = "orgs")]
+    MembershipEvent(Box<MembershipEvent>),
+    #[cfg(feature = "issues")]
+    MilestoneEvent(Box<MilestoneEvent>),
+    #[cfg(feature = "orgs")]
+    OrganizationEvent(Box<OrganizationEvent>),
+    #[cfg(feature = "orgs")]
+    OrgBlockEvent(Box<OrgBlockEvent>),
+    PageBuildEvent(Box<PageBuildEvent>),
+    PingEvent(Box<PingEvent>),
+    #[cfg(feature = "projects")]
+    ProjectCardEvent(Box<ProjectCardEvent>),
+    #[cfg(feature = "projects")]
+    ProjectColumnEvent(Box<ProjectColumnEvent>),
+    #[cfg(feature = "projects")]
+    ProjectEvent(Box<ProjectEvent>),
+    PublicEvent(Box<PublicEvent>),
+    #[cfg(feature = "issues")]
+    PullRequestEvent(Box<PullRequestEvent>),
+    #[cfg(feature = "issues")]
+    PullRequestReviewEvent(Box<PullRequestReviewEvent>),
+    #[cfg(feature = "issues")]
+    PullRequestReviewCommentEvent(Box<PullRequestReviewCommentEvent>),
+    PushEvent(Box<PushEvent>),
+    ReleaseEvent(Box<ReleaseEvent>),
+    RepositoryEvent(Box<RepositoryEvent>),
+    RepositoryDispatchEvent(Box<RepositoryDispatchEvent>),
+    RepositoryImportEvent(Box<RepositoryImportEvent>),
+    #[cfg(feature = "security")]
+    RepositoryVulnerabilityAlertEvent(Box<RepositoryVulnerabilityAlertEvent>),
+    #[cfg(feature = "security")]
+    SecurityAdvisoryEvent(Box<SecurityAdvisoryEvent>),
+    StatusEvent(Box<StatusEvent>),
+    #[cfg(feature = "orgs")]
+    TeamEvent(Box<TeamEvent>),
+    #[cfg(feature = "orgs")]
+    TeamAddEvent(Box<TeamAddEvent>),
+    WatchEvent(Box<WatchEvent>),
+}
+
+/// Parse the `body` of a webhook request, given its `X-GitHub-Event` header `event`, e.g. `push`.
+///
+/// The same as [`Event::from_slice`](enum.Event.html#method.from_slice), for callers that have the
+/// header and body as text, as in JavaScript runtimes; see the `wasm` module.
+pub fn parse_webhook(event: &str, body: &str) -> Result<Event, ::serde_json::Error> {
+    let event_type = event
+        .parse::<EventType>()
+        .map_err(serde::de::Error::custom)?;
+    Event::from_slice(event_type, body.as_bytes())
+}
+
+impl Event {
+    /// Parse a webhook payload, given the event's type from the `X-GitHub-Event` header.
+    pub fn from_value(
+        event_type: EventType,
+        payload: ::serde_json::Value,
+    ) -> Result<Event, ::serde_json::Error> {
+        #[cfg(feature = "metrics")]
+        let action = payload["action"].as_str().map(String::from);
+        let event = serde::Deserialize::deserialize(tagged::Tagged {
+            variant: event_type.variant_name(),
+            payload,
+        });
+        #[cfg(feature = "metrics")]
+        crate::metrics::parsed(event_type, action.as_deref(), event.is_ok());
+        #[cfg(feature = "tracing")]
+        if let Err(e) = &event {
+            crate::tracing::parse_failed(event_type, e);
+        }
+        event
+    }
+
+    /// Parse the body of a webhook request, given the event's type from the `X-GitHub-Event` header.
+    pub fn from_slice(event_type: EventType, payload: &[u8]) -> Result<Event, ::serde_json::Error> {
+        let mut de = ::serde_json::Deserializer::from_slice(payload);
+        let event = serde::Deserialize::deserialize(tagged::Tagged {
+            variant: event_type.variant_name(),
+            payload: &mut de,
+        })
+        .and_then(|event| de.end().map(|()| event));
+        #[cfg(feature = "metrics")]
+        crate::metrics::parsed(event_type, peek::action(payload), event.is_ok());
+        #[cfg(feature = "tracing")]
+        if let Err(e) = &event {
+            crate::tracing::parse_failed(event_type, e);
+        }
+        event
+    }
+
+    /// Parse the body of a webhook request, given its `X-GitHub-Event` and `Content-Type` headers.
     ///
-    /// Events of this type are not visible in timelines.
-    /// These events are only used to trigger hooks.
-    DeploymentStatusEvent {
-        /// The [deployment status](https://developer.github.com/v3/repos/deployments/#list-deployment-statuses).
-        deployment_status: DeploymentStatus,
-        /// The [deployment](https://developer.github.com/v3/repos/deployments/#list-deployments)
-        /// that this status is associated with.
-        deployment: Deployment,
-        /// The [repository](https://developer.github.com/v3/repos/) for this deployment.
-        repository: Repository,
-        sender: Sender,
-    },
-
-    /// Triggered when a user [forks a
-    /// repository](https://developer.github.com/v3/repos/forks/#create-a-fork).
-    ForkEvent {
-        /// The created [repository](https://developer.github.com/v3/repos/).
-        forkee: Forkee,
-        repository: Repository,
-        sender: Sender,
-    },
-
-    /// Triggered when someone revokes their authorization of a GitHub App. A GitHub App receives
-    /// this webhook by default and cannot unsubscribe from this event.
-    /// This event is not available in the [Events API](https://developer.github.com/v3/activity/events/).
+    /// Hooks can be configured to deliver either JSON, which is what
+    /// [`from_slice`](#method.from_slice) takes, or a form with the JSON in its `payload`
+    /// parameter; bodies without a content type this crate knows are taken to be JSON.
+    pub fn from_body(
+        event_type: EventType,
+        content_type: Option<&str>,
+        body: &[u8],
+    ) -> Result<Event, ::serde_json::Error> {
+        let content_type = content_type
+            .and_then(hooks::ContentType::from_header)
+            .unwrap_or(hooks::ContentType::Json);
+        Event::from_slice(event_type, &content_type.payload(body)?)
+    }
+
+    /// Like [`from_slice`](#method.from_slice), for a payload fetched from the REST API at
+    /// `version`, e.g. through the deliveries or Events API.
     ///
-    /// Anyone can revoke their authorization of a GitHub App from their
-    /// [GitHub account settings page](https://github.com/settings/apps/authorizations).
-    /// Revoking the authorization of a GitHub App does not uninstall the GitHub App.
-    /// You should program your GitHub App so that when it receives this webhook,
-    /// it stops calling the API on behalf of the person who revoked the token.
-    /// If your GitHub App continues to use a revoked access token,
-    /// it will receive the `401 Bad Credentials` error.
-    /// For details about user-to-server requests, which require GitHub App authorization,
-    /// see ["Identifying and authorizing users for GitHub Apps.](https://developer.github.com/apps/building-github-apps/identifying-and-authorizing-users-for-github-apps/)"
-    GitHubAppAuthorizationEvent {
-        action: actions::Revoked,
-        sender: Sender,
-    },
-
-    /// Triggered when a Wiki page is created or updated.
-    GollumEvent {
-        pages: Vec<Page>,
-        repository: Repository,
-        sender: Sender,
-    },
-
-    /// Triggered when a GitHub App has been installed or uninstalled.
-    InstallationEvent {
-        /// The action that was performed. Can be either `Created` or `Deleted`.
-        action: actions::CreatedDeleted,
-        /// The installation itself.
-        installation: Installation,
-        repositories: Vec<PartialRepository>,
-        sender: Sender,
-    },
-
-    InstallationRepositoriesEvent {
-        /// The action that was performed. Can be either `Added` or `Removed`.
-        action: actions::AddedRemoved,
-        /// The installation itself.
-        installation: Installation,
-        /// The choice of repositories the installation is on. Can be either "selected" or "all".
-        repository_selection: String,
-        /// An array of repository objects, which were added to the installation.
-        repositories_added: Vec<PartialRepository>,
-        /// An array of repository objects, which were removed from the installation.
-        repositories_removed: Vec<RepositoriesRemoved>,
-        sender: Sender,
-    },
-
-    IssueCommentEvent {
-        /// The action that was performed on the comment.
-        /// Can be one of `Created`, `Edited`, or `Deleted`.
-        action: actions::CrEdDel,
-        /// The changes to the comment if the action was "edited".
-        /// `changes[body][from]: String` The changes to the comment if the action was "edited".
-        // FIXME it's unclear what the structure of changes is.
-        changes: Option<::serde_json::Value>,
-        /// The [issue](https://developer.github.com/v3/issues/) the comment belongs to.
-        issue: Issue,
-        /// The [comment](https://developer.github.com/v3/issues/comments/) itself.
-        comment: Comment,
-        repository: Repository,
-        sender: Sender,
-    },
-
-    IssueEvent(IssueEvent),
-
-    LabelEvent {
-        /// The action that was performed on the comment.
-        /// Can be one of `Created`, `Edited`, or `Deleted`.
-        action: actions::CrEdDel,
-        /// The label that was added.
-        label: Label,
-        /// The changes to the label if the action was "edited".
-        /// `changes[name][from]: String` The previous version of the name if the action was "edited".
-        /// `changes[color][from]: String` The previous version of the color if the action was "edited".
-        changes: Option<serde_json::Value>,
-        repository: Repository,
-        sender: Sender,
-    },
-
-    /// Triggered when a user accepts an invitation or is removed as a collaborator to a repository,
-    /// or has their permissions changed.
-    MemberEvent {
-        /// The action that was performed. Can be one of `added`, `deleted`, or `edited`.
-        action: String,
-        /// The user that was added.
-        member: Member,
-        /// The changes to the collaborator permissions if the action was `edited`.
-        changes: MemberEventChanges,
-        repository: Repository,
-        sender: Sender,
-    },
-
-    /// Triggered when a user is added or removed from a team.
+    /// Every [known](struct.ApiVersion.html#associatedconstant.KNOWN) version shares the
+    /// payload types; payloads of a version this crate doesn't know fail to parse rather than
+    /// be read with the shapes of another.
+    pub fn from_slice_versioned(
+        event_type: EventType,
+        payload: &[u8],
+        version: ApiVersion,
+    ) -> Result<Event, ::serde_json::Error> {
+        if !version.is_known() {
+            return Err(serde::de::Error::custom(UnknownApiVersion(version)));
+        }
+        Event::from_slice(event_type, payload)
+    }
+
+    /// Like [`from_slice`](#method.from_slice), but parsed with [simd-json](https://docs.rs/simd-json),
+    /// which is considerably faster on large payloads such as pushes and check suites.
+    /// `payload` is used as scratch space and is left in an unspecified state.
+    #[cfg(feature = "simd-json")]
+    pub fn from_slice_simd(
+        event_type: EventType,
+        payload: &mut [u8],
+    ) -> Result<Event, simd_json::Error> {
+        #[cfg(feature = "metrics")]
+        let action = peek::action(payload).map(String::from);
+        let event = simd_json::Deserializer::from_slice(payload).and_then(|mut de| {
+            serde::Deserialize::deserialize(tagged::Tagged {
+                variant: event_type.variant_name(),
+                payload: &mut de,
+            })
+        });
+        #[cfg(feature = "metrics")]
+        crate::metrics::parsed(event_type, action.as_deref(), event.is_ok());
+        #[cfg(feature = "tracing")]
+        if let Err(e) = &event {
+            crate::tracing::parse_failed(event_type, e);
+        }
+        event
+    }
+
+    pub fn event_type(&self) -> EventType {
+        match self {
+            #[cfg(feature = "checks")]
+            Event::CheckRunEvent(_) => EventType::CheckRun,
+            #[cfg(feature = "checks")]
+            Event::CheckSuiteEvent(_) => EventType::CheckSuite,
+            Event::CommitCommentEvent(_) => EventType::CommitComment,
+            Event::CreateEvent(_) => EventType::Create,
+            Event::DeleteEvent(_) => EventType::Delete,
+            #[cfg(feature = "deployments")]
+            Event::DeploymentEvent(_) => EventType::Deployment,
+            #[cfg(feature = "deployments")]
+            Event::DeploymentStatusEvent(_) => EventType::DeploymentStatus,
+            Event::ForkEvent(_) => EventType::Fork,
+            Event::GitHubAppAuthorizationEvent(_) => EventType::GithubAppAuthorization,
+            Event::GollumEvent(_) => EventType::Gollum,
+            Event::InstallationEvent(_) => EventType::Installation,
+            Event::InstallationRepositoriesEvent(_) => EventType::InstallationRepositories,
+            #[cfg(feature = "issues")]
+            Event::IssueCommentEvent(_) => EventType::IssueComment,
+            #[cfg(feature = "issues")]
+            Event::IssueEvent(_) => EventType::Issues,
+            #[cfg(feature = "issues")]
+            Event::LabelEvent(_) => EventType::Label,
+            Event::MemberEvent(_) => EventType::Member,
+            #[cfg(feature = "orgs")]
+            Event::MembershipEvent(_) => EventType::Membership,
+            #[cfg(feature = "issues")]
+            Event::MilestoneEvent(_) => EventType::Milestone,
+            #[cfg(feature = "orgs")]
+            Event::OrganizationEvent(_) => EventType::Organization,
+            #[cfg(feature = "orgs")]
+            Event::OrgBlockEvent(_) => EventType::OrgBlock,
+            Event::PageBuildEvent(_) => EventType::PageBuild,
+            Event::PingEvent(_) => EventType::Ping,
+            #[cfg(feature = "projects")]
+            Event::ProjectCardEvent(_) => EventType::ProjectCard,
+            #[cfg(feature = "projects")]
+            Event::ProjectColumnEvent(_) => EventType::ProjectColumn,
+            #[cfg(feature = "projects")]
+            Event::ProjectEvent(_) => EventType::Project,
+            Event::PublicEvent(_) => EventType::Public,
+            #[cfg(feature = "issues")]
+            Event::PullRequestEvent(_) => EventType::PullRequest,
+            #[cfg(feature = "issues")]
+            Event::PullRequestReviewEvent(_) => EventType::PullRequestReview,
+            #[cfg(feature = "issues")]
+            Event::PullRequestReviewCommentEvent(_) => EventType::PullRequestReviewComment,
+            Event::PushEvent(_) => EventType::Push,
+            Event::ReleaseEvent(_) => EventType::Release,
+            Event::RepositoryEvent(_) => EventType::Repository,
+            Event::RepositoryDispatchEvent(_) => EventType::RepositoryDispatch,
+            Event::RepositoryImportEvent(_) => EventType::RepositoryImport,
+            #[cfg(feature = "security")]
+            Event::RepositoryVulnerabilityAlertEvent(_) => EventType::RepositoryVulnerabilityAlert,
+            #[cfg(feature = "security")]
+            Event::SecurityAdvisoryEvent(_) => EventType::SecurityAdvisory,
+            Event::StatusEvent(_) => EventType::Status,
+            #[cfg(feature = "orgs")]
+            Event::TeamEvent(_) => EventType::Team,
+            #[cfg(feature = "orgs")]
+            Event::TeamAddEvent(_) => EventType::TeamAdd,
+            Event::WatchEvent(_) => EventType::Watch,
+        }
+    }
+
+    /// The repository the event happened in. Events about installations, organizations, and
+    /// security advisories have none, and neither do the payload types of check suites and
+    /// deletions, which leave it out, or of team events, whose repository is abridged.
+    pub fn repository(&self) -> Option<&Repository> {
+        match self {
+            #[cfg(feature = "checks")]
+            Event::CheckRunEvent(e) => Some(&e.repository),
+            #[cfg(feature = "checks")]
+            Event::CheckSuiteEvent(_) => None,
+            Event::CommitCommentEvent(e) => Some(&e.repository),
+            Event::CreateEvent(e) => Some(&e.repository),
+            Event::DeleteEvent(_) => None,
+            #[cfg(feature = "deployments")]
+            Event::DeploymentEvent(e) => Some(&e.repository),
+            #[cfg(feature = "deployments")]
+            Event::DeploymentStatusEvent(e) => Some(&e.repository),
+            Event::ForkEvent(e) => Some(&e.repository),
+            Event::GitHubAppAuthorizationEvent(_) => None,
+            Event::GollumEvent(e) => Some(&e.repository),
+            Event::InstallationEvent(_) => None,
+            Event::InstallationRepositoriesEvent(_) => None,
+            #[cfg(feature = "issues")]
+            Event::IssueCommentEvent(e) => Some(&e.repository),
+            #[cfg(feature = "issues")]
+            Event::IssueEvent(e) => Some(&e.repository),
+            #[cfg(feature = "issues")]
+            Event::LabelEvent(e) => Some(&e.repository),
+            Event::MemberEvent(e) => Some(&e.repository),
+            #[cfg(feature = "orgs")]
+            Event::MembershipEvent(_) => None,
+            #[cfg(feature = "issues")]
+            Event::MilestoneEvent(e) => Some(&e.repository),
+            #[cfg(feature = "orgs")]
+            Event::OrganizationEvent(_) => None,
+            #[cfg(feature = "orgs")]
+            Event::OrgBlockEvent(_) => None,
+            Event::PageBuildEvent(e) => Some(&e.repository),
+            Event::PingEvent(e) => e.repository.as_ref(),
+            #[cfg(feature = "projects")]
+            Event::ProjectCardEvent(e) => Some(&e.repository),
+            #[cfg(feature = "projects")]
+            Event::ProjectColumnEvent(e) => Some(&e.repository),
+            #[cfg(feature = "projects")]
+            Event::ProjectEvent(e) => Some(&e.repository),
+            Event::PublicEvent(e) => Some(&e.repository),
+            #[cfg(feature = "issues")]
+            Event::PullRequestEvent(e) => Some(&e.repository),
+            #[cfg(feature = "issues")]
+            Event::PullRequestReviewEvent(e) => Some(&e.repository),
+            #[cfg(feature = "issues")]
+            Event::PullRequestReviewCommentEvent(e) => Some(&e.repository),
+            Event::PushEvent(e) => Some(&e.repository),
+            Event::ReleaseEvent(e) => Some(&e.repository),
+            Event::RepositoryEvent(e) => Some(&e.repository),
+            Event::RepositoryDispatchEvent(e) => Some(&e.repository),
+            Event::RepositoryImportEvent(e) => Some(&e.repository),
+            #[cfg(feature = "security")]
+            Event::RepositoryVulnerabilityAlertEvent(_) => None,
+            #[cfg(feature = "security")]
+            Event::SecurityAdvisoryEvent(_) => None,
+            Event::StatusEvent(e) => Some(&e.repository),
+            #[cfg(feature = "orgs")]
+            Event::TeamEvent(_) => None,
+            #[cfg(feature = "orgs")]
+            Event::TeamAddEvent(e) => Some(&e.repository),
+            Event::WatchEvent(e) => Some(&e.repository),
+        }
+    }
+
+    /// The GitHub App installation the event was delivered for, if it was delivered to an app.
+    pub fn installation_id(&self) -> Option<InstallationId> {
+        match self {
+            #[cfg(feature = "checks")]
+            Event::CheckRunEvent(e) => Some(e.installation.id),
+            #[cfg(feature = "checks")]
+            Event::CheckSuiteEvent(e) => e.installation.as_ref().map(|i| i.id),
+            Event::CommitCommentEvent(e) => e.installation.as_ref().map(|i| i.id),
+            Event::CreateEvent(e) => e.installation.as_ref().map(|i| i.id),
+            Event::DeleteEvent(e) => e.installation.as_ref().map(|i| i.id),
+            #[cfg(feature = "deployments")]
+            Event::DeploymentEvent(e) => e.installation.as_ref().map(|i| i.id),
+            #[cfg(feature = "deployments")]
+            Event::DeploymentStatusEvent(e) => e.installation.as_ref().map(|i| i.id),
+            Event::ForkEvent(e) => e.installation.as_ref().map(|i| i.id),
+            Event::GitHubAppAuthorizationEvent(e) => e.installation.as_ref().map(|i| i.id),
+            Event::GollumEvent(e) => e.installation.as_ref().map(|i| i.id),
+            Event::InstallationEvent(e) => Some(e.installation.id),
+            Event::InstallationRepositoriesEvent(e) => Some(e.installation.id),
+            #[cfg(feature = "issues")]
+            Event::IssueCommentEvent(e) => e.installation.as_ref().map(|i| i.id),
+            #[cfg(feature = "issues")]
+            Event::IssueEvent(e) => e.installation.as_ref().map(|i| i.id),
+            #[cfg(feature = "issues")]
+            Event::LabelEvent(e) => e.installation.as_ref().map(|i| i.id),
+            Event::MemberEvent(e) => e.installation.as_ref().map(|i| i.id),
+            #[cfg(feature = "orgs")]
+            Event::MembershipEvent(e) => e.installation.as_ref().map(|i| i.id),
+            #[cfg(feature = "issues")]
+            Event::MilestoneEvent(e) => e.installation.as_ref().map(|i| i.id),
+            #[cfg(feature = "orgs")]
+            Event::OrganizationEvent(e) => e.installation.as_ref().map(|i| i.id),
+            #[cfg(feature = "orgs")]
+            Event::OrgBlockEvent(e) => e.installation.as_ref().map(|i| i.id),
+            Event::PageBuildEvent(e) => e.installation.as_ref().map(|i| i.id),
+            Event::PingEvent(e) => e.installation.as_ref().map(|i| i.id),
+            #[cfg(feature = "projects")]
+            Event::ProjectCardEvent(e) => e.installation.as_ref().map(|i| i.id),
+            #[cfg(feature = "projects")]
+            Event::ProjectColumnEvent(e) => e.installation.as_ref().map(|i| i.id),
+            #[cfg(feature = "projects")]
+            Event::ProjectEvent(e) => e.installation.as_ref().map(|i| i.id),
+            Event::PublicEvent(e) => e.installation.as_ref().map(|i| i.id),
+            #[cfg(feature = "issues")]
+            Event::PullRequestEvent(e) => e.installation.as_ref().map(|i| i.id),
+            #[cfg(feature = "issues")]
+            Event::PullRequestReviewEvent(e) => e.installation.as_ref().map(|i| i.id),
+            #[cfg(feature = "issues")]
+            Event::PullRequestReviewCommentEvent(e) => e.installation.as_ref().map(|i| i.id),
+            Event::PushEvent(e) => e.installation.as_ref().map(|i| i.id),
+            Event::ReleaseEvent(e) => e.installation.as_ref().map(|i| i.id),
+            Event::RepositoryEvent(e) => e.installation.as_ref().map(|i| i.id),
+            Event::RepositoryDispatchEvent(e) => e.installation.as_ref().map(|i| i.id),
+            Event::RepositoryImportEvent(e) => e.installation.as_ref().map(|i| i.id),
+            #[cfg(feature = "security")]
+            Event::RepositoryVulnerabilityAlertEvent(e) => e.installation.as_ref().map(|i| i.id),
+            #[cfg(feature = "security")]
+            Event::SecurityAdvisoryEvent(e) => e.installation.as_ref().map(|i| i.id),
+            Event::StatusEvent(e) => e.installation.as_ref().map(|i| i.id),
+            #[cfg(feature = "orgs")]
+            Event::TeamEvent(e) => e.installation.as_ref().map(|i| i.id),
+            #[cfg(feature = "orgs")]
+            Event::TeamAddEvent(e) => e.installation.as_ref().map(|i| i.id),
+            Event::WatchEvent(e) => e.installation.as_ref().map(|i| i.id),
+        }
+    }
+
+    /// The organization that owns the event's repository, or that the event happened in.
+    pub fn organization(&self) -> Option<&Organization> {
+        match self {
+            #[cfg(feature = "checks")]
+            Event::CheckRunEvent(e) => e.organization.as_ref(),
+            #[cfg(feature = "checks")]
+            Event::CheckSuiteEvent(e) => e.organization.as_ref(),
+            Event::CommitCommentEvent(e) => e.organization.as_ref(),
+            Event::CreateEvent(e) => e.organization.as_ref(),
+            Event::DeleteEvent(e) => e.organization.as_ref(),
+            #[cfg(feature = "deployments")]
+            Event::DeploymentEvent(e) => e.organization.as_ref(),
+            #[cfg(feature = "deployments")]
+            Event::DeploymentStatusEvent(e) => e.organization.as_ref(),
+            Event::ForkEvent(e) => e.organization.as_ref(),
+            Event::GitHubAppAuthorizationEvent(e) => e.organization.as_ref(),
+            Event::GollumEvent(e) => e.organization.as_ref(),
+            Event::InstallationEvent(e) => e.organization.as_ref(),
+            Event::InstallationRepositoriesEvent(e) => e.organization.as_ref(),
+            #[cfg(feature = "issues")]
+            Event::IssueCommentEvent(e) => e.organization.as_ref(),
+            #[cfg(feature = "issues")]
+            Event::IssueEvent(e) => e.organization.as_ref(),
+            #[cfg(feature = "issues")]
+            Event::LabelEvent(e) => e.organization.as_ref(),
+            Event::MemberEvent(e) => e.organization.as_ref(),
+            #[cfg(feature = "orgs")]
+            Event::MembershipEvent(e) => e.organization.as_ref(),
+            #[cfg(feature = "issues")]
+            Event::MilestoneEvent(e) => e.organization.as_ref(),
+            #[cfg(feature = "orgs")]
+            Event::OrganizationEvent(e) => e.organization.as_ref(),
+            #[cfg(feature = "orgs")]
+            Event::OrgBlockEvent(e) => e.organization.as_ref(),
+            Event::PageBuildEvent(e) => e.organization.as_ref(),
+            Event::PingEvent(e) => e.organization.as_ref(),
+            #[cfg(feature = "projects")]
+            Event::ProjectCardEvent(e) => e.organization.as_ref(),
+            #[cfg(feature = "projects")]
+            Event::ProjectColumnEvent(e) => e.organization.as_ref(),
+            #[cfg(feature = "projects")]
+            Event::ProjectEvent(e) => e.organization.as_ref(),
+            Event::PublicEvent(e) => e.organization.as_ref(),
+            #[cfg(feature = "issues")]
+            Event::PullRequestEvent(e) => e.organization.as_ref(),
+            #[cfg(feature = "issues")]
+            Event::PullRequestReviewEvent(e) => e.organization.as_ref(),
+            #[cfg(feature = "issues")]
+            Event::PullRequestReviewCommentEvent(e) => e.organization.as_ref(),
+            Event::PushEvent(e) => e.organization.as_ref(),
+            Event::ReleaseEvent(e) => e.organization.as_ref(),
+            Event::RepositoryEvent(e) => e.organization.as_ref(),
+            Event::RepositoryDispatchEvent(e) => e.organization.as_ref(),
+            Event::RepositoryImportEvent(e) => e.organization.as_ref(),
+            #[cfg(feature = "security")]
+            Event::RepositoryVulnerabilityAlertEvent(e) => e.organization.as_ref(),
+            #[cfg(feature = "security")]
+            Event::SecurityAdvisoryEvent(e) => e.organization.as_ref(),
+            Event::StatusEvent(e) => e.organization.as_ref(),
+            #[cfg(feature = "orgs")]
+            Event::TeamEvent(e) => e.organization.as_ref(),
+            #[cfg(feature = "orgs")]
+            Event::TeamAddEvent(e) => e.organization.as_ref(),
+            Event::WatchEvent(e) => e.organization.as_ref(),
+        }
+    }
+
+    /// The user that triggered the event. Check suites, deletions, and security alerts have none.
+    pub fn sender(&self) -> Option<&Sender> {
+        match self {
+            #[cfg(feature = "checks")]
+            Event::CheckRunEvent(e) => Some(&e.sender),
+            #[cfg(feature = "checks")]
+            Event::CheckSuiteEvent(_) => None,
+            Event::CommitCommentEvent(e) => Some(&e.sender),
+            Event::CreateEvent(e) => Some(&e.sender),
+            Event::DeleteEvent(_) => None,
+            #[cfg(feature = "deployments")]
+            Event::DeploymentEvent(e) => Some(&e.sender),
+            #[cfg(feature = "deployments")]
+            Event::DeploymentStatusEvent(e) => Some(&e.sender),
+            Event::ForkEvent(e) => Some(&e.sender),
+            Event::GitHubAppAuthorizationEvent(e) => Some(&e.sender),
+            Event::GollumEvent(e) => Some(&e.sender),
+            Event::InstallationEvent(e) => Some(&e.sender),
+            Event::InstallationRepositoriesEvent(e) => Some(&e.sender),
+            #[cfg(feature = "issues")]
+            Event::IssueCommentEvent(e) => Some(&e.sender),
+            #[cfg(feature = "issues")]
+            Event::IssueEvent(e) => Some(&e.sender),
+            #[cfg(feature = "issues")]
+            Event::LabelEvent(e) => Some(&e.sender),
+            Event::MemberEvent(e) => Some(&e.sender),
+            #[cfg(feature = "orgs")]
+            Event::MembershipEvent(e) => Some(&e.sender),
+            #[cfg(feature = "issues")]
+            Event::MilestoneEvent(e) => Some(&e.sender),
+            #[cfg(feature = "orgs")]
+            Event::OrganizationEvent(e) => Some(&e.sender),
+            #[cfg(feature = "orgs")]
+            Event::OrgBlockEvent(e) => Some(&e.sender),
+            Event::PageBuildEvent(e) => Some(&e.sender),
+            Event::PingEvent(e) => e.sender.as_ref(),
+            #[cfg(feature = "projects")]
+            Event::ProjectCardEvent(e) => Some(&e.sender),
+            #[cfg(feature = "projects")]
+            Event::ProjectColumnEvent(e) => Some(&e.sender),
+            #[cfg(feature = "projects")]
+            Event::ProjectEvent(e) => Some(&e.sender),
+            Event::PublicEvent(e) => Some(&e.sender),
+            #[cfg(feature = "issues")]
+            Event::PullRequestEvent(e) => Some(&e.sender),
+            #[cfg(feature = "issues")]
+            Event::PullRequestReviewEvent(e) => Some(&e.sender),
+            #[cfg(feature = "issues")]
+            Event::PullRequestReviewCommentEvent(e) => Some(&e.sender),
+            Event::PushEvent(e) => Some(&e.sender),
+            Event::ReleaseEvent(e) => Some(&e.sender),
+            Event::RepositoryEvent(e) => Some(&e.sender),
+            Event::RepositoryDispatchEvent(e) => Some(&e.sender),
+            Event::RepositoryImportEvent(e) => Some(&e.sender),
+            #[cfg(feature = "security")]
+            Event::RepositoryVulnerabilityAlertEvent(_) => None,
+            #[cfg(feature = "security")]
+            Event::SecurityAdvisoryEvent(_) => None,
+            Event::StatusEvent(e) => Some(&e.sender),
+            #[cfg(feature = "orgs")]
+            Event::TeamEvent(e) => Some(&e.sender),
+            #[cfg(feature = "orgs")]
+            Event::TeamAddEvent(e) => Some(&e.sender),
+            Event::WatchEvent(e) => Some(&e.sender),
+        }
+    }
+
+    /// The page on github.com that best shows what happened: the pull request, comment, review,
+    /// release, or check run, the comparison of a push, or else the repository.
+    /// `None` for events without any, such as deletions and organization events.
+    pub fn html_url(&self) -> Option<&str> {
+        fn repository(r: &Repository) -> Option<&str> {
+            link(&r.html_url)
+        }
+        match self {
+            #[cfg(feature = "checks")]
+            Event::CheckRunEvent(e) => {
+                link(&e.check_run.html_url).or_else(|| repository(&e.repository))
+            }
+            #[cfg(feature = "checks")]
+            Event::CheckSuiteEvent(_) => None,
+            Event::CommitCommentEvent(e) => {
+                link(&e.comment.html_url).or_else(|| repository(&e.repository))
+            }
+            Event::CreateEvent(e) => repository(&e.repository),
+            Event::DeleteEvent(_) => None,
+            #[cfg(feature = "deployments")]
+            Event::DeploymentEvent(e) => repository(&e.repository),
+            #[cfg(feature = "deployments")]
+            Event::DeploymentStatusEvent(e) => {
+                link(&e.deployment_status.target_url).or_else(|| repository(&e.repository))
+            }
+            Event::ForkEvent(e) => link(&e.forkee.html_url).or_else(|| repository(&e.repository)),
+            Event::GitHubAppAuthorizationEvent(_) => None,
+            Event::GollumEvent(e) => e
+                .pages
+                .first()
+                .and_then(|page| link(&page.html_url))
+                .or_else(|| repository(&e.repository)),
+            Event::InstallationEvent(e) => link(&e.installation.html_url),
+            Event::InstallationRepositoriesEvent(e) => link(&e.installation.html_url),
+            #[cfg(feature = "issues")]
+            Event::IssueCommentEvent(e) => link(&e.comment.html_url)
+                .or_else(|| link(&e.issue.html_url))
+                .or_else(|| repository(&e.repository)),
+            #[cfg(feature = "issues")]
+            Event::IssueEvent(e) => link(&e.issue.html_url).or_else(|| repository(&e.repository)),
+            #[cfg(feature = "issues")]
+            Event::LabelEvent(e) => repository(&e.repository),
+            Event::MemberEvent(e) => repository(&e.repository),
+            #[cfg(feature = "orgs")]
+            Event::MembershipEvent(_) => None,
+            #[cfg(feature = "issues")]
+            Event::MilestoneEvent(e) => {
+                link(&e.milestone.html_url).or_else(|| repository(&e.repository))
+            }
+            #[cfg(feature = "orgs")]
+            Event::OrganizationEvent(_) => None,
+            #[cfg(feature = "orgs")]
+            Event::OrgBlockEvent(e) => link(&e.blocked_user.html_url),
+            Event::PageBuildEvent(e) => repository(&e.repository),
+            Event::PingEvent(e) => e.repository.as_ref().and_then(repository),
+            #[cfg(feature = "projects")]
+            Event::ProjectCardEvent(e) => repository(&e.repository),
+            #[cfg(feature = "projects")]
+            Event::ProjectColumnEvent(e) => repository(&e.repository),
+            #[cfg(feature = "projects")]
+            Event::ProjectEvent(e) => {
+                link(&e.project.html_url).or_else(|| repository(&e.repository))
+            }
+            Event::PublicEvent(e) => repository(&e.repository),
+            #[cfg(feature = "issues")]
+            Event::PullRequestEvent(e) => {
+                link(&e.pull_request.html_url).or_else(|| repository(&e.repository))
+            }
+            #[cfg(feature = "issues")]
+            Event::PullRequestReviewEvent(e) => link(&e.review.html_url)
+                .or_else(|| link(&e.pull_request.html_url))
+                .or_else(|| repository(&e.repository)),
+            #[cfg(feature = "issues")]
+            Event::PullRequestReviewCommentEvent(e) => link(&e.comment.html_url)
+                .or_else(|| link(&e.pull_request.html_url))
+                .or_else(|| repository(&e.repository)),
+            Event::PushEvent(e) => link(&e.compare).or_else(|| repository(&e.repository)),
+            Event::ReleaseEvent(e) => {
+                link(&e.release.html_url).or_else(|| repository(&e.repository))
+            }
+            Event::RepositoryEvent(e) => repository(&e.repository),
+            Event::RepositoryDispatchEvent(e) => repository(&e.repository),
+            Event::RepositoryImportEvent(e) => repository(&e.repository),
+            #[cfg(feature = "security")]
+            Event::RepositoryVulnerabilityAlertEvent(e) => link(&e.alert.external_reference),
+            #[cfg(feature = "security")]
+            Event::SecurityAdvisoryEvent(e) => e
+                .security_advisory
+                .references
+                .first()
+                .and_then(|reference| link(&reference.url)),
+            Event::StatusEvent(e) => e
+                .target_url
+                .as_deref()
+                .and_then(link)
+                .or_else(|| repository(&e.repository)),
+            #[cfg(feature = "orgs")]
+            Event::TeamEvent(e) => link(&e.repository.html_url),
+            #[cfg(feature = "orgs")]
+            Event::TeamAddEvent(e) => repository(&e.repository),
+            Event::WatchEvent(e) => repository(&e.repository),
+        }
+    }
+
+    /// When what the event describes happened, as far as its payload says: when a check run
+    /// started, a comment or status was created, a pull request was last changed, a review was
+    /// submitted, or the head commit of a push was made. For ordering events of different types.
     ///
-    /// Events of this type are not visible in timelines.
-    /// These events are only used to trigger hooks.
-    MembershipEvent {
-        /// The action that was performed. Can be "added" or "removed".
-        action: String,
-        /// The scope of the membership. Currently, can only be "team".
-        scope: String,
-        /// The [user](https://developer.github.com/v3/users/) that was added or removed.
-        member: Member,
-        sender: Sender,
-        /// The [team](https://developer.github.com/v3/teams/) for the membership.
-        team: Team,
-        organization: Organization,
-    },
-
-    /// Triggered when a milestone is created, closed, opened, edited, or deleted.
+    /// `None` for events without a timestamp, such as branch creations and deletions.
+    #[cfg(feature = "chrono")]
+    pub fn occurred_at(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        let occurred_at = chrono::DateTime::parse_from_rfc3339(self.timestamp()?).ok()?;
+        Some(occurred_at.with_timezone(&chrono::Utc))
+    }
+
+    /// The timestamp [`occurred_at`](#method.occurred_at) is read from, as in the payload.
+    fn timestamp(&self) -> Option<&str> {
+        match self {
+            #[cfg(feature = "checks")]
+            Event::CheckRunEvent(e) => timestamp::given(&e.check_run.started_at),
+            #[cfg(feature = "checks")]
+            Event::CheckSuiteEvent(e) => Some(&e.check_suite.updated_at),
+            Event::CommitCommentEvent(e) => Some(&e.comment.created_at),
+            #[cfg(feature = "deployments")]
+            Event::DeploymentEvent(e) => Some(&e.deployment.updated_at),
+            #[cfg(feature = "deployments")]
+            Event::DeploymentStatusEvent(e) => Some(&e.deployment_status.updated_at),
+            Event::ForkEvent(e) => Some(&e.forkee.created_at),
+            #[cfg(feature = "issues")]
+            Event::IssueCommentEvent(e) => Some(&e.comment.created_at),
+            #[cfg(feature = "issues")]
+            Event::IssueEvent(e) => Some(&e.issue.updated_at),
+            #[cfg(feature = "issues")]
+            Event::MilestoneEvent(e) => Some(&e.milestone.updated_at),
+            Event::PageBuildEvent(e) => Some(&e.build.updated_at),
+            #[cfg(feature = "projects")]
+            Event::ProjectCardEvent(e) => Some(&e.project_card.updated_at),
+            #[cfg(feature = "projects")]
+            Event::ProjectColumnEvent(e) => Some(&e.project_column.updated_at),
+            #[cfg(feature = "projects")]
+            Event::ProjectEvent(e) => Some(&e.project.updated_at),
+            #[cfg(feature = "issues")]
+            Event::PullRequestEvent(e) => Some(&e.pull_request.updated_at),
+            #[cfg(feature = "issues")]
+            Event::PullRequestReviewEvent(e) => Some(&e.review.submitted_at),
+            #[cfg(feature = "issues")]
+            Event::PullRequestReviewCommentEvent(e) => Some(&e.comment.created_at),
+            Event::PushEvent(e) => e.head_commit["timestamp"].as_str(),
+            Event::ReleaseEvent(e) => timestamp::given(&e.release.published_at)
+                .or_else(|| timestamp::given(&e.release.created_at)),
+            #[cfg(feature = "security")]
+            Event::SecurityAdvisoryEvent(e) => Some(&e.security_advisory.updated_at),
+            Event::StatusEvent(e) => Some(&e.created_at),
+            _ => None,
+        }
+    }
+
+    /// When what the event is about was last changed, which unlike
+    /// [`timestamp`](#method.timestamp) tells edits of the same comment apart.
+    fn changed_at(&self) -> Option<&str> {
+        match self {
+            Event::CommitCommentEvent(e) => Some(&e.comment.updated_at),
+            #[cfg(feature = "issues")]
+            Event::IssueCommentEvent(e) => Some(&e.comment.updated_at),
+            #[cfg(feature = "issues")]
+            Event::PullRequestReviewCommentEvent(e) => Some(&e.comment.updated_at),
+            _ => self.timestamp(),
+        }
+    }
+
+    /// A key identifying the event by what its payload says happened, the same for every
+    /// delivery of it. Events read back with their delivery, like a
+    /// [`jsonl::Entry`](jsonl/struct.Entry.html), are better identified by its GUID.
+    pub fn identity(&self) -> EventIdentity {
+        EventIdentity::of(self)
+    }
+
+    /// Whether the event was triggered by the bot of the app `app`, given as its slug
+    /// (`mybot`) or its bot's login (`mybot[bot]`), such as a comment the app itself wrote.
+    /// Apps reacting to their own events can loop forever, so they usually ignore these.
+    pub fn is_from_app(&self, app: &str) -> bool {
+        self.sender()
+            .is_some_and(|sender| is_app_bot(&sender.login, &sender.type_field, app))
+    }
+}
+
+/// Triggered when a check run is `created`, `rerequested`, `completed`, or has a
+/// `requested_action`. The checks permission allows you to use the checks API. If you plan to
+/// create or modify check runs, your GitHub App will need to have the `checks:write` permission.
+/// If you only plan to consume check runs, your GitHub App only needs the `checks:read`
+/// permission.
+///
+/// GitHub Apps with the `checks:write` permission will receive the `rerequested` action without
+/// subscribing to the check_run webhook event. The `rerequested` action occurs when someone
+/// requests to re-run your app's check from the pull request UI. See "About status checks" for
+/// more details about the GitHub UI. When you receive a `rerequested` action, you'll need to
+/// create a new check run. Only the GitHub App that someone requests to re-run the check will
+/// receive the `rerequested` payload. Similarly,
+/// only the GitHub App someone requests to perform
+/// an action specified by the app will receive the `requested_action` payload.
+///
+/// GitHub Apps that have the `checks:read` permission and subscribe to the `check_run` webhook
+/// event receive the `created` and `completed` action payloads for all check runs in the app's
+/// repository. Repositories and organizations that subscribe to the `check_run` webhook event
+/// only receive `created` and `completed` event actions.
+#[cfg(feature = "checks")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct CheckRunEvent {
+    /// The action performed.
+    /// Can be `Created,` `Rerequested,` `Completed,` or `RequestedAction`.
+    pub action: actions::Check,
+    /// The [`check_run`](https://developer.github.com/v3/checks/runs/).
+    pub check_run: CheckRun,
+    /// The action the user requested if the action was `requested_action`.
+    #[serde(default)]
+    pub requested_action: Option<RequestedAction>,
+    pub repository: Repository,
+    #[serde(default)]
+    pub organization: Option<Organization>,
+    pub sender: Sender,
+    pub installation: Installation,
+}
+
+#[cfg(feature = "checks")]
+impl CheckRunEvent {
+    /// The numbers of the pull requests the check run is for.
+    pub fn pull_request_numbers(&self) -> Vec<i64> {
+        pull_request_numbers(&self.check_run.pull_requests)
+    }
+
+    /// Whether someone asked for the check to be run again; the app should create a new check run.
+    pub fn is_rerun(&self) -> bool {
+        self.action == actions::Check::Rerequested
+    }
+
+    /// The `identifier` of the button the user pressed if the action was `requested_action`.
+    pub fn requested_action_identifier(&self) -> Option<&str> {
+        self.requested_action
+            .as_ref()
+            .map(|action| action.identifier.as_str())
+    }
+}
+
+/// A button an app added to a check run, which a user pressed.
+#[cfg(feature = "checks")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct RequestedAction {
+    /// The `identifier` the app gave the action when it created the check run.
+    pub identifier: String,
+}
+
+/// The `number`s of the pull requests in a check run's or suite's `pull_requests`.
+#[cfg(feature = "checks")]
+fn pull_request_numbers(pull_requests: &[PullRequestRef]) -> Vec<i64> {
+    pull_requests
+        .iter()
+        .map(|pull_request| pull_request.number)
+        .collect()
+}
+
+/// Triggered when a check suite is `completed`, `requested`, or `rerequested`. The checks permission
+/// allows you to use the Checks API. If you plan to create or modify check runs, your GitHub
+/// App will need to have the checks:write permission. If you only plan to consume check runs,
+/// your GitHub App only needs the checks:read permission.
+///
+/// GitHub Apps with the checks:write permission will receive the requested and `rerequested`
+/// action payloads without subscribing to the `check_suite` webhook event.
+/// The `requested` action
+/// triggers when new code is pushed to the app's repository. A `rerequested` action occurs when
+/// someone requests to re-run the entire check suite from the pull request UI. See "[About
+/// status checks](https://help.github.com/articles/about-status-checks#checks)" for more
+/// details about the GitHub UI. When you receive the `requested` or
+/// `rerequested` action events, you'll need to
+/// [create a new check run](https://developer.github.com/v3/checks/runs/#create-a-check-run).
+/// Only the GitHub App that
+/// is being asked to run a check will receive the `requested` and `rerequested` payloads.
+///
+/// GitHub Apps that have the `checks:read` permission and
+/// subscribe to the `check_suite` webhook
+/// event receive the completed action payload for all check suites in the app's repository.
+/// Repositories and organizations that subscribe to the `check_suite`
+/// webhook event only receive
+/// the `completed` event action.
+#[cfg(feature = "checks")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct CheckSuiteEvent {
+    /// The action performed.
+    /// Can be `Created,` `Rerequested,` `Completed,` or `RequestedAction.`
+    pub action: actions::Check,
+    /// The [check_suite](https://developer.github.com/v3/checks/suites/).
+    pub check_suite: CheckSuite,
+    #[serde(default)]
+    pub organization: Option<Organization>,
+    #[serde(default)]
+    pub installation: Option<InstallationRef>,
+}
+
+#[cfg(feature = "checks")]
+impl CheckSuiteEvent {
+    /// The numbers of the pull requests the check suite is for.
+    pub fn pull_request_numbers(&self) -> Vec<i64> {
+        pull_request_numbers(&self.check_suite.pull_requests)
+    }
+
+    /// Whether someone asked for the whole suite to be run again; the app should create new
+    /// check runs.
+    pub fn is_rerun(&self) -> bool {
+        self.action == actions::Check::Rerequested
+    }
+}
+
+/// Triggered when a
+/// [commit comment](https://developer.github.com/v3/repos/comments/#list-commit-comments-for-a-repository) is created.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct CommitCommentEvent {
+    pub action: actions::Created,
+    /// The [comment](https://developer.github.com/v3/repos/comments/#list-commit-comments-for-a-repository) itself.
+    // FIXME
+    pub comment: Comment,
+    pub repository: Repository,
+    #[serde(default)]
+    pub organization: Option<Organization>,
+    pub sender: Sender,
+    #[serde(default)]
+    pub installation: Option<InstallationRef>,
+}
+
+/// Represents a created repository, branch, or tag.
+/// Note: webhooks will not receive this event for created repositories.
+/// Additionally, webhooks will not receive this event for tags
+/// if more than three tags are pushed at once.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct CreateEvent {
+    /// The short name of the branch or tag created (or `null` if only a repository was
+    /// created); see [`git_ref`](#method.git_ref) for the full ref.
+    #[serde(rename = "ref")]
+    pub ref_field: String,
+    /// The object that was created. Can be one of "repository", "branch", or "tag"
+    pub ref_type: String,
+    /// The name of the repository's default branch (usually `master`).
+    pub master_branch: String,
+    /// The repository's current description.
+    #[serde(default, with = "crate::untyped::Json")]
+    #[cfg_attr(feature = "fuzz", arbitrary(with = crate::fuzz::value))]
+    pub description: ::serde_json::Value,
+    pub pusher_type: String,
+    pub repository: Repository,
+    #[serde(default)]
+    pub organization: Option<Organization>,
+    pub sender: Sender,
+    #[serde(default)]
+    pub installation: Option<InstallationRef>,
+}
+
+impl CreateEvent {
+    /// The full ref of the branch or tag created; `None` if a repository was created.
+    pub fn git_ref(&self) -> Option<GitRef> {
+        full_ref(&self.ref_type, &self.ref_field)
+    }
+}
+
+/// Represents a [deleted branch or tag](https://developer.github.com/v3/git/refs/#delete-a-reference).
+/// Note: webhooks will not receive this event for tags
+/// if more than three tags are deleted at once.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct DeleteEvent {
+    /// The short name of the branch or tag deleted, e.g. `simple-tag`; see
+    /// [`git_ref`](#method.git_ref) for the full ref.
+    #[serde(rename = "ref")]
+    pub ref_field: String,
+    /// The object that was deleted. Can be "branch" or "tag".
+    pub ref_type: String,
+    pub pusher_type: String,
+    #[serde(default)]
+    pub organization: Option<Organization>,
+    #[serde(default)]
+    pub installation: Option<InstallationRef>,
+}
+
+impl DeleteEvent {
+    /// The full ref of the branch or tag deleted.
+    pub fn git_ref(&self) -> Option<GitRef> {
+        full_ref(&self.ref_type, &self.ref_field)
+    }
+}
+
+/// The full ref of a `ref_type` (`branch` or `tag`) named `name`.
+fn full_ref(ref_type: &str, name: &str) -> Option<GitRef> {
+    match ref_type {
+        "branch" => Some(GitRef::branch(name)),
+        "tag" => Some(GitRef::tag(name)),
+        _ => None,
+    }
+}
+
+/// Represents a [deployment](https://developer.github.com/v3/repos/deployments/#list-deployments).
+///
+/// Events of this type are not visible in timelines. These events are only used to trigger hooks.
+#[cfg(feature = "deployments")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct DeploymentEvent {
+    /// The [deployment](https://developer.github.com/v3/repos/deployments/#list-deployments).
+    pub deployment: Deployment,
+    /// The [repository](https://developer.github.com/v3/repos/) for this deployment.
+    pub repository: Repository,
+    #[serde(default)]
+    pub organization: Option<Organization>,
+    pub sender: Sender,
+    #[serde(default)]
+    pub installation: Option<InstallationRef>,
+}
+
+/// Represents a [deployment status](https://developer.github.com/v3/repos/deployments/#list-deployment-statuses).
+///
+/// Events of this type are not visible in timelines.
+/// These events are only used to trigger hooks.
+#[cfg(feature = "deployments")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct DeploymentStatusEvent {
+    /// The [deployment status](https://developer.github.com/v3/repos/deployments/#list-deployment-statuses).
+    pub deployment_status: DeploymentStatus,
+    /// The [deployment](https://developer.github.com/v3/repos/deployments/#list-deployments)
+    /// that this status is associated with.
+    pub deployment: Deployment,
+    /// The [repository](https://developer.github.com/v3/repos/) for this deployment.
+    pub repository: Repository,
+    #[serde(default)]
+    pub organization: Option<Organization>,
+    pub sender: Sender,
+    #[serde(default)]
+    pub installation: Option<InstallationRef>,
+}
+
+/// Triggered when a user [forks a
+/// repository](https://developer.github.com/v3/repos/forks/#create-a-fork).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct ForkEvent {
+    /// The created [repository](https://developer.github.com/v3/repos/).
+    pub forkee: Forkee,
+    pub repository: Repository,
+    #[serde(default)]
+    pub organization: Option<Organization>,
+    pub sender: Sender,
+    #[serde(default)]
+    pub installation: Option<InstallationRef>,
+}
+
+/// Triggered when someone revokes their authorization of a GitHub App. A GitHub App receives
+/// this webhook by default and cannot unsubscribe from this event.
+/// This event is not available in the [Events API](https://developer.github.com/v3/activity/events/).
+///
+/// Anyone can revoke their authorization of a GitHub App from their
+/// [GitHub account settings page](https://github.com/settings/apps/authorizations).
+/// Revoking the authorization of a GitHub App does not uninstall the GitHub App.
+/// You should program your GitHub App so that when it receives this webhook,
+/// it stops calling the API on behalf of the person who revoked the token.
+/// If your GitHub App continues to use a revoked access token,
+/// it will receive the `401 Bad Credentials` error.
+/// For details about user-to-server requests, which require GitHub App authorization,
+/// see ["Identifying and authorizing users for GitHub Apps.](https://developer.github.com/apps/building-github-apps/identifying-and-authorizing-users-for-github-apps/)"
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct GitHubAppAuthorizationEvent {
+    pub action: actions::Revoked,
+    #[serde(default)]
+    pub organization: Option<Organization>,
+    pub sender: Sender,
+    #[serde(default)]
+    pub installation: Option<InstallationRef>,
+}
+
+/// Triggered when a Wiki page is created or updated.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct GollumEvent {
+    pub pages: Vec<Page>,
+    pub repository: Repository,
+    #[serde(default)]
+    pub organization: Option<Organization>,
+    pub sender: Sender,
+    #[serde(default)]
+    pub installation: Option<InstallationRef>,
+}
+
+/// Triggered when a GitHub App has been installed or uninstalled.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct InstallationEvent {
+    /// The action that was performed, e.g. `Created`, `Deleted`, or `NewPermissionsAccepted`.
+    pub action: actions::Installation,
+    /// The installation itself.
+    pub installation: Installation,
+    #[serde(default)]
+    pub repositories: Vec<PartialRepository>,
+    #[serde(default)]
+    pub organization: Option<Organization>,
+    pub sender: Sender,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct InstallationRepositoriesEvent {
+    /// The action that was performed. Can be either `Added` or `Removed`.
+    pub action: actions::AddedRemoved,
+    /// The installation itself.
+    pub installation: Installation,
+    /// The choice of repositories the installation is on. Can be either "selected" or "all".
+    pub repository_selection: String,
+    /// An array of repository objects, which were added to the installation.
+    pub repositories_added: Vec<PartialRepository>,
+    /// An array of repository objects, which were removed from the installation.
+    pub repositories_removed: Vec<RepositoriesRemoved>,
+    #[serde(default)]
+    pub organization: Option<Organization>,
+    pub sender: Sender,
+}
+
+/// Triggered when an [issue comment](https://developer.github.com/v3/issues/comments/) is created, edited, or deleted.
+#[cfg(feature = "issues")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct IssueCommentEvent {
+    /// The action that was performed on the comment.
+    /// Can be one of `Created`, `Edited`, or `Deleted`.
+    pub action: actions::CrEdDel,
+    /// The changes to the comment if the action was "edited".
+    pub changes: Option<CommentChanges>,
+    /// The [issue](https://developer.github.com/v3/issues/) the comment belongs to.
+    pub issue: Issue,
+    /// The [comment](https://developer.github.com/v3/issues/comments/) itself.
+    pub comment: Comment,
+    pub repository: Repository,
+    #[serde(default)]
+    pub organization: Option<Organization>,
+    pub sender: Sender,
+    #[serde(default)]
+    pub installation: Option<InstallationRef>,
+}
+
+#[cfg(feature = "issues")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct IssueEvent {
+    /// The action that was performed. Can be one of `opened`, `edited`, `deleted`, `transferred`, `closed`,
+    /// `reopened`, `assigned`, `unassigned`, `labeled`, `unlabeled`, `milestoned`, or `demilestoned`.
+    pub action: String,
+    /// The [issue](https://developer.github.com/v3/issues) itself.
+    pub issue: Issue,
+    /// The changes to the issue if the action was "edited".
+    pub changes: Option<IssueChanges>,
+    /// The label that was added or removed if the action was `labeled` or `unlabeled`.
+    #[serde(default)]
+    pub label: Option<Label>,
+    /// The user who was assigned or unassigned if the action was `assigned` or `unassigned`.
+    #[serde(default)]
+    pub assignee: Option<User>,
+    /// The milestone the issue was added to or removed from if the action was `milestoned` or
+    /// `demilestoned`.
+    #[serde(default)]
+    pub milestone: Option<Milestone>,
+    pub repository: Repository,
+    #[serde(default)]
+    pub organization: Option<Organization>,
+    pub sender: Sender,
+    #[serde(default)]
+    pub installation: Option<InstallationRef>,
+}
+
+#[cfg(feature = "issues")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct LabelEvent {
+    /// The action that was performed on the comment.
+    /// Can be one of `Created`, `Edited`, or `Deleted`.
+    pub action: actions::CrEdDel,
+    /// The label that was added.
+    pub label: Label,
+    /// The changes to the label if the action was "edited".
+    pub changes: Option<LabelChanges>,
+    pub repository: Repository,
+    #[serde(default)]
+    pub organization: Option<Organization>,
+    pub sender: Sender,
+    #[serde(default)]
+    pub installation: Option<InstallationRef>,
+}
+
+/// Triggered when a user accepts an invitation or is removed as a collaborator to a repository,
+/// or has their permissions changed.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct MemberEvent {
+    /// The action that was performed. Can be one of `added`, `deleted`, or `edited`.
+    pub action: String,
+    /// The user that was added.
+    pub member: Member,
+    /// The changes to the collaborator permissions if the action was `edited`.
+    pub changes: MemberEventChanges,
+    pub repository: Repository,
+    #[serde(default)]
+    pub organization: Option<Organization>,
+    pub sender: Sender,
+    #[serde(default)]
+    pub installation: Option<InstallationRef>,
+}
+
+/// Triggered when a user is added or removed from a team.
+///
+/// Events of this type are not visible in timelines.
+/// These events are only used to trigger hooks.
+#[cfg(feature = "orgs")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct MembershipEvent {
+    pub action: MembershipAction,
+    pub scope: MembershipScope,
+    /// The [user](https://developer.github.com/v3/users/) that was added or removed.
+    pub member: Member,
+    pub sender: Sender,
+    #[serde(default)]
+    pub installation: Option<InstallationRef>,
+    /// The [team](https://developer.github.com/v3/teams/) for the membership.
+    /// Not present when the scope is [`Organization`](enum.MembershipScope.html#variant.Organization).
+    #[serde(default)]
+    pub team: Option<Team>,
+    #[serde(default)]
+    pub organization: Option<Organization>,
+}
+
+/// Triggered when a milestone is created, closed, opened, edited, or deleted.
+///
+/// Events of this type are not visible in timelines.
+/// These events are only used to trigger hooks.
+#[cfg(feature = "issues")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct MilestoneEvent {
+    /// The action that was performed.
+    /// Can be one of `created`, `closed`, `opened`, `edited`, or `deleted`.
+    pub action: String,
+    /// The milestone itself.
+    pub milestone: Milestone,
+    /// The changes to the milestone if the action was `edited`.
+    pub changes: Option<MilestoneChanges>,
+    pub repository: Repository,
+    #[serde(default)]
+    pub organization: Option<Organization>,
+    pub sender: Sender,
+    #[serde(default)]
+    pub installation: Option<InstallationRef>,
+}
+
+/// Triggered when a user is added, removed, or invited to an Organization.
+/// Events of this type are not visible in timelines.
+/// These events are only used to trigger organization hooks.
+#[cfg(feature = "orgs")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct OrganizationEvent {
+    /// The action that was performed.
+    /// Can be one of: `member_added`, `member_removed`, `member_invited`, `renamed`, or `deleted`.
+    pub action: String,
+    /// The previous login of the organization if the action was `renamed`.
+    #[serde(default)]
+    pub changes: Option<OrganizationChanges>,
+    /// The invitation for the user or email if the action is member_invited.
+    // FIXME What is the structure of an invitation.
+    #[serde(default, with = "crate::untyped::OptionJson")]
+    #[cfg_attr(feature = "fuzz", arbitrary(with = crate::fuzz::option_value))]
+    pub invitation: Option<::serde_json::Value>,
+    /// The membership between the user and the organization.
+    /// Not present when the action is `member_invited` or `renamed`.
+    #[serde(default)]
+    pub membership: Option<Membership>,
+    /// The organization in question.
+    #[serde(default)]
+    pub organization: Option<Organization>,
+    pub sender: Sender,
+    #[serde(default)]
+    pub installation: Option<InstallationRef>,
+}
+
+/// Triggered when an organization blocks or unblocks a user.
+#[cfg(feature = "orgs")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct OrgBlockEvent {
+    /// The action performed. Can be `blocked` or `unblocked`.
+    pub action: String,
+    /// Information about the user that was blocked or unblocked.
+    pub blocked_user: User,
+    /// Information about the organization that blocked or unblocked the user.
+    #[serde(default)]
+    pub organization: Option<Organization>,
+    /// Information about the user who sent the blocking/unblocking request on behalf of the organization.
+    pub sender: Sender,
+    #[serde(default)]
+    pub installation: Option<InstallationRef>,
+}
+
+/// Represents an attempted build of a GitHub Pages site, whether successful or not.
+///
+/// Triggered on push to a GitHub Pages enabled branch
+/// (`gh-pages` for project pages, `master` for user and organization pages).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct PageBuildEvent {
+    pub id: i64,
+    /// The page [build](https://developer.github.com/v3/repos/pages/#list-pages-builds) itself.
+    pub build: Build,
+    pub repository: Repository,
+    #[serde(default)]
+    pub organization: Option<Organization>,
+    pub sender: Sender,
+    #[serde(default)]
+    pub installation: Option<InstallationRef>,
+}
+
+/// Sent when a webhook is created, or [pinged](https://docs.github.com/en/rest/webhooks/repos#ping-a-repository-webhook).
+/// Hooks can't subscribe to it; it is always delivered.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct PingEvent {
+    /// Random string of GitHub zen.
+    pub zen: String,
+    /// The id of the webhook that triggered the ping.
+    pub hook_id: HookId,
+    pub hook: hooks::Hook,
+    /// Set for repository hooks.
+    pub repository: Option<Repository>,
+    /// Set for organization hooks.
+    #[serde(default)]
+    pub organization: Option<Organization>,
+    #[serde(default)]
+    pub installation: Option<InstallationRef>,
+    pub sender: Option<Sender>,
+}
+
+/// Triggered when a [project card](https://developer.github.com/v3/projects/cards) is created, updated, moved, converted to an issue, or deleted.
+#[cfg(feature = "projects")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct ProjectCardEvent {
+    /// The action performed on the project card.
+    /// Can be "created", "edited", "converted", "moved", or "deleted".
+    pub action: String,
+    /// The changes to the project card if the action was "edited" or "converted".
+    /// `changes[note][from]: String` The previous version of the note if the action was "edited" or "converted".
+    // FIXME should be enum
+    #[serde(default, with = "crate::untyped::OptionJson")]
+    #[cfg_attr(feature = "fuzz", arbitrary(with = crate::fuzz::option_value))]
+    pub changes: Option<serde_json::Value>,
+    /// The id of the card that this card now follows if the action was "moved".
+    /// Will be `null` if it is the first card in a column.
+    pub after_id: Option<isize>,
+    /// The [project card](https://developer.github.com/v3/projects/cards) itself.
+    pub project_card: ProjectCard,
+    pub repository: Repository,
+    #[serde(default)]
+    pub organization: Option<Organization>,
+    pub sender: Sender,
+    #[serde(default)]
+    pub installation: Option<InstallationRef>,
+}
+
+/// Triggered when a [project column](https://developer.github.com/v3/projects/columns) is created, updated, moved, or deleted.
+#[cfg(feature = "projects")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct ProjectColumnEvent {
+    /// The action that was performed on the project column.
+    /// Can be one of "created", "edited", "moved" or "deleted".
+    pub action: String,
+    /// The changes to the project column if the action was "edited".
+    /// `changes[name][from]: String` The previous version of the name if the action was "edited".
+    #[serde(default, with = "crate::untyped::Json")]
+    #[cfg_attr(feature = "fuzz", arbitrary(with = crate::fuzz::value))]
+    pub changes: serde_json::Value,
+    /// The id of the column that this column now follows if the action was "moved". Will be null if it is the first column in a project.
+    pub after_id: Option<isize>,
+    /// The [project column](https://developer.github.com/v3/projects/columns) itself.
+    pub project_column: ProjectColumn,
+    pub repository: Repository,
+    #[serde(default)]
+    pub organization: Option<Organization>,
+    pub sender: Sender,
+    #[serde(default)]
+    pub installation: Option<InstallationRef>,
+}
+
+/// Triggered when a [project](https://developer.github.com/v3/projects/) is created, updated, closed, reopened, or deleted.
+#[cfg(feature = "projects")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct ProjectEvent {
+    /// The action that was performed on the project. Can be one of "created", "edited", "closed", "reopened", or "deleted".
+    pub action: String,
+    /// The changes to the project if the action was "edited".
+    /// `changes[name][from]: String` The previous version of the name if the action was "edited".
+    /// `changes[body][from]: String` The previous version of the body if the action was "edited".
+    #[serde(default, with = "crate::untyped::Json")]
+    #[cfg_attr(feature = "fuzz", arbitrary(with = crate::fuzz::value))]
+    pub changes: serde_json::Value,
+    /// The [project](https://developer.github.com/v3/projects/) itself.
+    pub project: Project,
+    pub repository: Repository,
+    #[serde(default)]
+    pub organization: Option<Organization>,
+    pub sender: Sender,
+    #[serde(default)]
+    pub installation: Option<InstallationRef>,
+}
+
+/// Triggered when a private repository is open sourced.
+/// Without a doubt: the best GitHub event.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct PublicEvent {
+    pub repository: Repository,
+    #[serde(default)]
+    pub organization: Option<Organization>,
+    pub sender: Sender,
+    #[serde(default)]
+    pub installation: Option<InstallationRef>,
+}
+
+/// Triggered when a pull request is assigned, unassigned, labeled, unlabeled,
+/// opened, edited, closed, reopened, or synchronized.
+/// Also triggered when a pull request review is requested,
+/// or when a review request is removed.
+#[cfg(feature = "issues")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct PullRequestEvent {
+    /// The action that was performed.
+    /// Can be one of "assigned", "unassigned", "review_requested",
+    /// "review_request_removed", "labeled", "unlabeled",
+    /// "opened", "edited", "closed", "reopened", "auto_merge_enabled", or "auto_merge_disabled".
     ///
-    /// Events of this type are not visible in timelines.
-    /// These events are only used to trigger hooks.
-    MilestoneEvent {
-        /// The action that was performed.
-        /// Can be one of `created`, `closed`, `opened`, `edited`, or `deleted`.
-        action: String,
-        /// The milestone itself.
-        milestone: Milestone,
-        /// The changes to the milestone if the action was edited.
-        /// changes[description][from]: String` The previous version of the description if the action was `edited`.
-        /// `changes[due_on][from]: String` The previous version of the due date if the action was `edited`.
-        /// `changes[title][from]: String` The previous version of the title if the action was `edited`.
-        changes: Option<::serde_json::Value>,
-        repository: Repository,
-        sender: Sender,
-    },
-
-    /// Triggered when a user is added, removed, or invited to an Organization.
-    /// Events of this type are not visible in timelines.
-    /// These events are only used to trigger organization hooks.
-    OrganizationEvent {
-        /// The action that was performed.
-        /// Can be one of: `member_added`, `member_removed`, or `member_invited`.
-        action: String,
-        /// The invitation for the user or email if the action is member_invited.
-        // FIXME What is the structure of an invitation.
-        invitation: Option<::serde_json::Value>,
-        /// The membership between the user and the organization.
-        /// Not present when the action is `member_invited`.
-        membership: Membership,
-        /// The organization in question.
-        organization: Organization,
-        sender: Sender,
-    },
-
-    /// Triggered when an organization blocks or unblocks a user.
-    OrgBlockEvent {
-        /// The action performed. Can be `blocked` or `unblocked`.
-        action: String,
-        /// Information about the user that was blocked or unblocked.
-        blocked_user: User,
-        /// Information about the organization that blocked or unblocked the user.
-        organization: Organization,
-        /// Information about the user who sent the blocking/unblocking request on behalf of the organization.
-        sender: Sender,
-    },
-
-    /// Represents an attempted build of a GitHub Pages site, whether successful or not.
+    /// If the action is "closed" and the `merged` key is `false`,
+    /// the pull request was closed with unmerged commits.
+    /// If the action is "closed" and the `merged` key is `true`,
+    /// the pull request was merged.
     ///
-    /// Triggered on push to a GitHub Pages enabled branch
-    /// (`gh-pages` for project pages, `master` for user and organization pages).
-    PageBuildEvent {
-        id: i64,
-        /// The page [build](https://developer.github.com/v3/repos/pages/#list-pages-builds) itself.
-        build: Build,
-        repository: Repository,
-        sender: Sender,
-    },
-
-    /// Triggered when a [project card](https://developer.github.com/v3/projects/cards) is created, updated, moved, converted to an issue, or deleted.
-    ProjectCardEvent {
-        /// The action performed on the project card.
-        /// Can be "created", "edited", "converted", "moved", or "deleted".
-        action: String,
-        /// The changes to the project card if the action was "edited" or "converted".
-        /// `changes[note][from]: String` The previous version of the note if the action was "edited" or "converted".
-        // FIXME should be enum
-        changes: Option<serde_json::Value>,
-        /// The id of the card that this card now follows if the action was "moved".
-        /// Will be `null` if it is the first card in a column.
-        after_id: Option<isize>,
-        /// The [project card](https://developer.github.com/v3/projects/cards) itself.
-        project_card: ProjectCard,
-        repository: Repository,
-        sender: Sender,
-    },
-
-    /// Triggered when a [project column](https://developer.github.com/v3/projects/columns) is created, updated, moved, or deleted.
-    ProjectColumnEvent {
-        /// The action that was performed on the project column.
-        /// Can be one of "created", "edited", "moved" or "deleted".
-        action: String,
-        /// The changes to the project column if the action was "edited".
-        /// `changes[name][from]: String` The previous version of the name if the action was "edited".
-        changes: serde_json::Value,
-        /// The id of the column that this column now follows if the action was "moved". Will be null if it is the first column in a project.
-        after_id: Option<isize>,
-        /// The [project column](https://developer.github.com/v3/projects/columns) itself.
-        project_column: ProjectColumn,
-        repository: Repository,
-        sender: Sender,
-    },
-
-    /// Triggered when a [project](https://developer.github.com/v3/projects/) is created, updated, closed, reopened, or deleted.
-    ProjectEvent {
-        /// The action that was performed on the project. Can be one of "created", "edited", "closed", "reopened", or "deleted".
-        action: String,
-        /// The changes to the project if the action was "edited".
-        /// `changes[name][from]: String` The previous version of the name if the action was "edited".
-        /// `changes[body][from]: String` The previous version of the body if the action was "edited".
-        changes: serde_json::Value,
-        /// The [project](https://developer.github.com/v3/projects/) itself.
-        project: Project,
-        repository: Repository,
-        sender: Sender,
-    },
-
-    /// Triggered when a private repository is open sourced.
-    /// Without a doubt: the best GitHub event.
-    PublicEvent {
-        repository: Repository,
-        sender: Sender,
-    },
-
-    /// Triggered when a pull request is assigned, unassigned, labeled, unlabeled,
-    /// opened, edited, closed, reopened, or synchronized.
-    /// Also triggered when a pull request review is requested,
-    /// or when a review request is removed.
-    PullRequestEvent {
-        /// The action that was performed.
-        /// Can be one of "assigned", "unassigned", "review_requested",
-        /// "review_request_removed", "labeled", "unlabeled",
-        /// "opened", "edited", "closed", or "reopened".
-        ///
-        /// If the action is "closed" and the `merged` key is `false`,
-        /// the pull request was closed with unmerged commits.
-        /// If the action is "closed" and the `merged` key is `true`,
-        /// the pull request was merged.
-        ///
-        /// While webhooks are also triggered when a pull request is synchronized,
-        /// Events API timelines don't include pull request events with the "synchronize" action.
-        action: String,
-        /// The pull request number.
-        number: i64,
-        /// The changes to the comment if the action was "edited".
-        /// `changes[title][from]: String` The previous version of the title if the action was "edited".
-        /// `changes[body][from]: String` The previous version of the body if the action was "edited".
-        changes: serde_json::Value,
-        /// The [pull request](https://developer.github.com/v3/pulls) itself.
-        pull_request: PullRequest,
-        repository: Repository,
-        sender: Sender,
-    },
-
-    /// Triggered when a pull request review is submitted into a non-pending state, the body is
-    /// edited, or the review is dismissed.
-    PullRequestReviewEvent {
-        /// The action that was performed on the comment. Can be one of "created", "edited", or "deleted".
-        action: String,
-        /// The changes to the comment if the action was "edited".
-        /// `changes[body][from]: String` The previous version of the body if the action was "edited".
-        changes: serde_json::Value,
-        review: Review,
-        /// The [pull request](https://developer.github.com/v3/pulls/) the comment belongs to.
-        pull_request: PullRequest,
-        /// The [comment](https://developer.github.com/v3/pulls/comments) itself.
-        repository: Repository,
-        sender: Sender,
-    },
-
-    /// Triggered when a [comment on a pull request's unified diff](https://developer.github.com/v3/pulls/comments) is created, edited, or deleted (in the Files Changed tab).
-    PullRequestReviewCommentEvent {
-        /// The action that was performed on the comment. Can be one of "created", "edited", or "deleted".
-        action: String,
-        /// The [comment](https://developer.github.com/v3/pulls/comments) itself.
-        comment: Comment,
-        /// The changes to the comment if the action was "edited".
-        /// `changes[body][from]: String` The previous version of the body if the action was "edited".
-        changes: serde_json::Value,
-        ///	The [pull request](https://developer.github.com/v3/pulls/) the comment belongs to.
-        pull_request: PullRequest,
-        repository: Repository,
-        sender: Sender,
-    },
-
-    /// Triggered on a push to a repository branch.
-    /// Branch pushes and repository tag pushes also trigger webhook [`push` events](https://developer.github.com/webhooks/#events).
-    ///		Note: The webhook payload example following the table differs significantly from
-    ///		theents API payload described in the table. Among other differences, the webhook
-    ///		payload includes both sender and pusher objects. Sender and pusher are the same user
-    ///		who initiated the push event, but the sender object contains more detail.
-    PushEvent {
-        // FIXME the note
-        /// The full Git ref that was pushed. Example: `refs/heads/master`.
-        #[serde(rename = "ref")]
-        ref_field: String,
-        /// The SHA of the most recent commit on `ref` after the push.
-        head: Option<String>,
-        /// The SHA of the most recent commit on `ref` before the push.
-        before: String,
-        after: String,
-        /// The number of commits in the push.
-        size: isize,
-        created: bool,
-        deleted: bool,
-        forced: bool,
-        base_ref: ::serde_json::Value,
-        compare: String,
-        /// An array of commit objects describing the pushed commits.
-        /// (The array includes a maximum of 20 commits.
-        /// If necessary, you can use the Commits API to fetch additional commits.
-        /// This limit is applied to timeline events only and isn't applied to webhook deliveries.)
-        commits: Vec<Commit>,
-        head_commit: ::serde_json::Value,
-        repository: Repository,
-        pusher: Pusher,
-        sender: Sender,
-    },
-
-    /// Triggered when a
-    /// [release](https://developer.github.com/v3/repos/releases/#get-a-single-release) is published.
-    ReleaseEvent {
-        /// The action that was performed. Currently, can only be "published".
-        action: String,
-        /// The [release](https://developer.github.com/v3/repos/releases/#get-a-single-release) itself.
-        release: Release,
-        repository: Repository,
-        sender: Sender,
-    },
-
-    /// Triggered when a repository is created, archived, unarchived, made public, or made private.
-    /// [Organization hooks](https://developer.github.com/v3/orgs/hooks/) are also triggered when a repository is deleted.
+    /// While webhooks are also triggered when a pull request is synchronized,
+    /// Events API timelines don't include pull request events with the "synchronize" action.
+    pub action: String,
+    /// The pull request number.
+    pub number: i64,
+    /// The changes to the pull request if the action was "edited".
+    #[serde(default)]
+    pub changes: Option<PullRequestChanges>,
+    /// The [pull request](https://developer.github.com/v3/pulls) itself.
+    pub pull_request: PullRequest,
+    /// The label that was added or removed if the action was "labeled" or "unlabeled".
+    #[serde(default)]
+    pub label: Option<Label>,
+    /// The user who was assigned or unassigned if the action was "assigned" or "unassigned".
+    #[serde(default)]
+    pub assignee: Option<User>,
+    /// The user whose review was requested, or no longer is, if the action was
+    /// "review_requested" or "review_request_removed" and the request was of a user.
+    #[serde(default)]
+    pub requested_reviewer: Option<User>,
+    /// The team whose review was requested, or no longer is, if the action was
+    /// "review_requested" or "review_request_removed" and the request was of a team.
+    #[serde(default)]
+    pub requested_team: Option<Team>,
+    /// Why auto-merge was enabled or disabled if the action was "auto_merge_enabled" or
+    /// "auto_merge_disabled", e.g. because the pull request was updated by a user without
+    /// permission to merge it.
+    #[serde(default)]
+    pub reason: Option<String>,
+    pub repository: Repository,
+    #[serde(default)]
+    pub organization: Option<Organization>,
+    pub sender: Sender,
+    #[serde(default)]
+    pub installation: Option<InstallationRef>,
+}
+
+#[cfg(feature = "issues")]
+impl PullRequestEvent {
+    /// Whether the pull request was merged: it was closed, and `merged`.
+    pub fn was_merged(&self) -> bool {
+        self.action == "closed" && self.pull_request.merged
+    }
+
+    /// Whether the pull request was closed without being merged.
+    pub fn was_closed_unmerged(&self) -> bool {
+        self.action == "closed" && !self.pull_request.merged
+    }
+
+    /// Whether the pull request just became ready for review: it was opened other than as a
+    /// draft, or was marked ready after being a draft.
+    pub fn is_ready_for_review(&self) -> bool {
+        match self.action.as_str() {
+            "opened" | "reopened" => !self.pull_request.draft,
+            "ready_for_review" => true,
+            _ => false,
+        }
+    }
+
+    /// Whether new commits were pushed to the head branch.
+    pub fn was_synchronized(&self) -> bool {
+        self.action == "synchronize"
+    }
+
+    /// The branch the pull request would be merged into.
+    pub fn target_branch(&self) -> &str {
+        &self.pull_request.base.ref_field
+    }
+
+    /// The branch holding the pull request's changes.
+    pub fn source_branch(&self) -> &str {
+        &self.pull_request.head.ref_field
+    }
+}
+
+/// Triggered when a pull request review is submitted into a non-pending state, the body is
+/// edited, or the review is dismissed.
+#[cfg(feature = "issues")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct PullRequestReviewEvent {
+    /// The action that was performed on the comment. Can be one of "created", "edited", or "deleted".
+    pub action: String,
+    /// The changes to the comment if the action was "edited".
+    /// `changes[body][from]: String` The previous version of the body if the action was "edited".
+    #[serde(default, with = "crate::untyped::Json")]
+    #[cfg_attr(feature = "fuzz", arbitrary(with = crate::fuzz::value))]
+    pub changes: serde_json::Value,
+    pub review: Review,
+    /// The [pull request](https://developer.github.com/v3/pulls/) the comment belongs to.
+    pub pull_request: PullRequest,
+    /// The [comment](https://developer.github.com/v3/pulls/comments) itself.
+    pub repository: Repository,
+    #[serde(default)]
+    pub organization: Option<Organization>,
+    pub sender: Sender,
+    #[serde(default)]
+    pub installation: Option<InstallationRef>,
+}
+
+/// Triggered when a [comment on a pull request's unified diff](https://developer.github.com/v3/pulls/comments) is created, edited, or deleted (in the Files Changed tab).
+#[cfg(feature = "issues")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct PullRequestReviewCommentEvent {
+    /// The action that was performed on the comment. Can be one of "created", "edited", or "deleted".
+    pub action: String,
+    /// The [comment](https://developer.github.com/v3/pulls/comments) itself.
+    pub comment: Comment,
+    /// The changes to the comment if the action was "edited".
+    #[serde(default)]
+    pub changes: Option<CommentChanges>,
+    /// The [pull request](https://developer.github.com/v3/pulls/) the comment belongs to.
+    pub pull_request: PullRequest,
+    pub repository: Repository,
+    #[serde(default)]
+    pub organization: Option<Organization>,
+    pub sender: Sender,
+    #[serde(default)]
+    pub installation: Option<InstallationRef>,
+}
+
+/// Triggered on a push to a repository branch.
+/// Branch pushes and repository tag pushes also trigger webhook [`push` events](https://developer.github.com/webhooks/#events).
+///     Note: The webhook payload example following the table differs significantly from
+///     theents API payload described in the table. Among other differences, the webhook
+///     payload includes both sender and pusher objects. Sender and pusher are the same user
+///     who initiated the push event, but the sender object contains more detail.
+#[derive(Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "debug-full", derive(Debug))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct PushEvent {
+    // FIXME the note
+    /// The full Git ref that was pushed. Example: `refs/heads/master`.
+    #[serde(rename = "ref")]
+    pub ref_field: GitRef,
+    /// The SHA of the most recent commit on `ref` after the push.
+    pub head: Option<Sha>,
+    /// The SHA of the most recent commit on `ref` before the push.
+    pub before: Sha,
+    pub after: Sha,
+    /// The number of commits in the push.
+    /// Only sent in the Events API; count the `commits` of a webhook instead.
+    pub size: Option<isize>,
+    pub created: bool,
+    pub deleted: bool,
+    pub forced: bool,
+    #[serde(default, with = "crate::untyped::Json")]
+    #[cfg_attr(feature = "fuzz", arbitrary(with = crate::fuzz::value))]
+    pub base_ref: ::serde_json::Value,
+    pub compare: String,
+    /// An array of commit objects describing the pushed commits.
+    /// (The array includes a maximum of 20 commits.
+    /// If necessary, you can use the Commits API to fetch additional commits.
+    /// This limit is applied to timeline events only and isn't applied to webhook deliveries.)
+    pub commits: Vec<Commit>,
+    #[serde(default, with = "crate::untyped::Json")]
+    #[cfg_attr(feature = "fuzz", arbitrary(with = crate::fuzz::value))]
+    pub head_commit: ::serde_json::Value,
+    pub repository: Repository,
+    /// The git identity that pushed.
+    pub pusher: Committer,
+    #[serde(default)]
+    pub organization: Option<Organization>,
+    pub sender: Sender,
+    #[serde(default)]
+    pub installation: Option<InstallationRef>,
+}
+
+debug_redacting!(PushEvent {
+    ref_field,
+    head,
+    before,
+    after,
+    size,
+    created,
+    deleted,
+    forced,
+    base_ref,
+    compare,
+    commits,
+    head_commit(redacted),
+    repository,
+    pusher,
+    organization,
+    sender,
+    installation,
+});
+
+impl PushEvent {
+    /// The branch pushed to, e.g. `main`; `None` for tags and other refs.
+    pub fn branch(&self) -> Option<&str> {
+        self.ref_field.branch_name()
+    }
+
+    /// Whether a tag was pushed, created, or deleted.
+    pub fn is_tag_push(&self) -> bool {
+        self.ref_field.is_tag()
+    }
+
+    /// Whether the push deleted a branch.
+    pub fn is_branch_deletion(&self) -> bool {
+        self.deleted && self.ref_field.is_branch()
+    }
+
+    /// Whether the push rewrote the ref's history, so that `before` may no longer be reachable.
+    pub fn is_force_push(&self) -> bool {
+        self.forced
+    }
+
+    /// The paths of the files added, removed, or modified by any of the pushed commits, sorted.
     ///
-    /// Events of this type are not visible in timelines. These events are only used to trigger hooks.
-    RepositoryEvent {
-        /// The action that was performed. This can be one of `created`, `deleted` (organization hooks only), `archived`, `unarchived`, `publicized`, or `privatized`.
-        action: String,
-        /// The [repository](https://developer.github.com/v3/repos/) itself.
-        repository: Repository,
-        sender: Sender,
-    },
-
-    /// Triggered when a successful or unsuccessful repository import finishes
-    /// for a GitHub organization or a personal repository.
-    /// To receive this event for a personal repository,
-    /// you must create an empty repository prior to the import.
-    /// This event can be triggered using either the [GitHub Importer](https://help.github.com/articles/importing-a-repository-with-github-importer/)
-    /// or the [Source imports API](https://developer.github.com/v3/migrations/source_imports/).
-    RepositoryImportEvent {
-        /// The final state of the import. This can be either `success` or `failure`.
-        status: String,
-        /// The [repository](https://developer.github.com/v3/repos/) you are importing.
-        repository: Repository,
-        /// The information about the organization where the imported repository will live.
-        organization: Organization,
-        /// The GitHub user who is importing the repository.
-        sender: Sender,
-    },
-
-    /// Triggered when a [security alert](https://help.github.com/articles/about-security-alerts-for-vulnerable-dependencies/) is created, dismissed, or resolved.
-    RepositoryVulnerabilityAlertEvent {
-        /// The action that was performed. This can be one of `create`, `dismiss`, or `resolve`.
-        action: String,
-
-        /// The security alert of the vulnerable dependency.
-        alert: Alert,
-    },
-
-    /// Triggered when a new security advisory is published, updated, or withdrawn.
-    /// A security advisory provides information about security-related vulnerabilities in software on GitHub.
-    /// Security Advisory webhooks are available to GitHub Apps only.
-    /// The security advisory dataset also powers the GitHub security alerts,
-    /// see "[About security alerts for vulnerable dependencies](https://help.github.com/articles/about-security-alerts-for-vulnerable-dependencies/)."
-    SecurityAdvisoryEvent {
-        /// The action that was performed. The action can be one of `published`, `updated`, or `performed` for all new events.
-        action: String,
-        /// The details of the security advisory, including summary, description, and severity.
-        security_advisory: SecurityAdvisory,
-    },
-
-    /// Triggered when the status of a Git commit changes.
-    /// Events of this type are not visible in timelines. These events are only used to trigger hooks.
-    StatusEvent {
-        id: i64,
-        /// The Commit SHA.
-        sha: String,
-        name: String,
-        /// The optional link added to the status.
-        // FIXME will Option parse {}?
-        target_url: Option<String>,
-        context: String,
-        /// The optional human-readable description added to the status.
-        // FIXME will Option parse {}?
-        description: Option<String>,
-        /// The new state. Can be `pending`, `success`, `failure`, or `error`.
-        state: String,
-        commit: Commit,
-        /// An array of branch objects containing the status' SHA.
-        /// Each branch contains the given SHA, but the SHA may or may not be the head of the branch.
-        /// The array includes a maximum of 10 branches.
-        branches: Vec<Bran>,
-        created_at: String,
-        updated_at: String,
-        repository: Repository,
-        sender: Sender,
-    },
-
-    /// Triggered when an organization's team is created or deleted.
+    /// Pushes of more than 20 commits list only the first 20, and commits list at most 3000
+    /// files, so for those the Commits API has the full list.
+    pub fn changed_files(&self) -> Vec<&str> {
+        let files: alloc::collections::BTreeSet<&str> = self
+            .commits
+            .iter()
+            .flat_map(|commit| {
+                commit
+                    .added
+                    .iter()
+                    .chain(&commit.removed)
+                    .chain(&commit.modified)
+            })
+            .map(String::as_str)
+            .collect();
+        files.into_iter().collect()
+    }
+}
+
+/// Triggered when a
+/// [release](https://developer.github.com/v3/repos/releases/#get-a-single-release) is published.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct ReleaseEvent {
+    /// The action that was performed. Currently, can only be "published".
+    pub action: String,
+    /// The [release](https://developer.github.com/v3/repos/releases/#get-a-single-release) itself.
+    pub release: Release,
+    pub repository: Repository,
+    #[serde(default)]
+    pub organization: Option<Organization>,
+    pub sender: Sender,
+    #[serde(default)]
+    pub installation: Option<InstallationRef>,
+}
+
+/// Triggered when a repository is created, archived, unarchived, made public, or made private.
+/// [Organization hooks](https://developer.github.com/v3/orgs/hooks/) are also triggered when a repository is deleted.
+///
+/// Events of this type are not visible in timelines. These events are only used to trigger hooks.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct RepositoryEvent {
+    /// The action that was performed. This can be one of `created`, `deleted` (organization hooks only), `archived`, `unarchived`, `publicized`, or `privatized`.
+    pub action: String,
+    /// The [repository](https://developer.github.com/v3/repos/) itself.
+    pub repository: Repository,
+    #[serde(default)]
+    pub organization: Option<Organization>,
+    pub sender: Sender,
+    #[serde(default)]
+    pub installation: Option<InstallationRef>,
+}
+
+/// Triggered when a GitHub App [creates a repository dispatch event](https://docs.github.com/en/rest/repos/repos#create-a-repository-dispatch-event),
+/// to trigger webhooks or workflows for activity that happens outside of GitHub.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct RepositoryDispatchEvent {
+    /// The `event_type` that was given when the dispatch was created.
+    pub action: String,
+    /// The default branch of the repository.
+    pub branch: String,
+    /// The `client_payload` that was given when the dispatch was created.
+    #[serde(default, with = "crate::untyped::Json")]
+    #[cfg_attr(feature = "fuzz", arbitrary(with = crate::fuzz::value))]
+    pub client_payload: ::serde_json::Value,
+    pub repository: Repository,
+    #[serde(default)]
+    pub organization: Option<Organization>,
+    pub sender: Sender,
+    pub installation: Option<Installation>,
+}
+
+/// Triggered when a successful or unsuccessful repository import finishes
+/// for a GitHub organization or a personal repository.
+/// To receive this event for a personal repository,
+/// you must create an empty repository prior to the import.
+/// This event can be triggered using either the [GitHub Importer](https://help.github.com/articles/importing-a-repository-with-github-importer/)
+/// or the [Source imports API](https://developer.github.com/v3/migrations/source_imports/).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct RepositoryImportEvent {
+    /// The final state of the import. This can be either `success` or `failure`.
+    pub status: String,
+    /// The [repository](https://developer.github.com/v3/repos/) you are importing.
+    pub repository: Repository,
+    /// The information about the organization where the imported repository will live.
+    #[serde(default)]
+    pub organization: Option<Organization>,
+    /// The GitHub user who is importing the repository.
+    pub sender: Sender,
+    #[serde(default)]
+    pub installation: Option<InstallationRef>,
+}
+
+/// Triggered when a [security alert](https://help.github.com/articles/about-security-alerts-for-vulnerable-dependencies/) is created, dismissed, or resolved.
+#[cfg(feature = "security")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct RepositoryVulnerabilityAlertEvent {
+    /// The action that was performed. This can be one of `create`, `dismiss`, or `resolve`.
+    pub action: String,
+
+    /// The security alert of the vulnerable dependency.
+    pub alert: Alert,
+    #[serde(default)]
+    pub organization: Option<Organization>,
+    #[serde(default)]
+    pub installation: Option<InstallationRef>,
+}
+
+/// Triggered when a new security advisory is published, updated, or withdrawn.
+/// A security advisory provides information about security-related vulnerabilities in software on GitHub.
+/// Security Advisory webhooks are available to GitHub Apps only.
+/// The security advisory dataset also powers the GitHub security alerts,
+/// see "[About security alerts for vulnerable dependencies](https://help.github.com/articles/about-security-alerts-for-vulnerable-dependencies/)."
+#[cfg(feature = "security")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct SecurityAdvisoryEvent {
+    /// The action that was performed. The action can be one of `published`, `updated`, or `performed` for all new events.
+    pub action: String,
+    /// The details of the security advisory, including summary, description, and severity.
+    pub security_advisory: SecurityAdvisory,
+    #[serde(default)]
+    pub organization: Option<Organization>,
+    #[serde(default)]
+    pub installation: Option<InstallationRef>,
+}
+
+/// Triggered when the status of a Git commit changes.
+/// Events of this type are not visible in timelines. These events are only used to trigger hooks.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct StatusEvent {
+    pub id: i64,
+    /// The Commit SHA.
+    pub sha: Sha,
+    pub name: String,
+    /// The optional link added to the status.
+    // FIXME will Option parse {}?
+    pub target_url: Option<String>,
+    pub context: String,
+    /// The optional human-readable description added to the status.
+    // FIXME will Option parse {}?
+    pub description: Option<String>,
+    /// The new state. Can be `pending`, `success`, `failure`, or `error`.
+    pub state: String,
+    pub commit: StatusEventCommitNode,
+    /// An array of branch objects containing the status' SHA.
+    /// Each branch contains the given SHA, but the SHA may or may not be the head of the branch.
+    /// The array includes a maximum of 10 branches.
+    pub branches: Vec<Bran>,
+    pub created_at: String,
+    pub updated_at: String,
+    pub repository: Repository,
+    #[serde(default)]
+    pub organization: Option<Organization>,
+    pub sender: Sender,
+    #[serde(default)]
+    pub installation: Option<InstallationRef>,
+}
+
+/// Triggered when an organization's team is created or deleted.
+///
+/// Events of this type are not visible in timelines. These events are only used to trigger organization hooks.
+#[cfg(feature = "orgs")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct TeamEvent {
+    /// The action that was performed.
+    /// Can be one of `Created`, `Deleted`, `Edited`, `AddedToRepository`, or `RemovedFromRepository`.
+    pub action: actions::TeamEvent,
+    /// The team itself.
+    pub team: Team,
+    /// The changes to the team if the action was "edited".
+    /// `changes[description][from]: String` The previous version of the description if the action was `edited`.
+    /// `changes[name][from]: String` The previous version of the name if the action was `edited`.
+    /// The previous version of the team's privacy if the action was `edited`.
     ///
-    /// Events of this type are not visible in timelines. These events are only used to trigger organization hooks.
-    TeamEvent {
-        /// The action that was performed.
-        /// Can be one of `Created`, `Deleted`, `Edited`, `AddedToRepository`, or `RemovedFromRepository`.
-        action: actions::TeamEvent,
-        /// The team itself.
-        team: Team,
-        /// The changes to the team if the action was "edited".
-        /// `changes[description][from]: String` The previous version of the description if the action was `edited`.
-        /// `changes[name][from]: String` The previous version of the name if the action was `edited`.
-        /// The previous version of the team's privacy if the action was `edited`.
-        ///
-        /// `changes[repository][permissions][from][admin]: bool`
-        /// The previous version of the team member's `admin` permission on a repository, if the action was `edited`.
-        ///
-        /// `changes[repository][permissions][from][pull]: bool`
-        /// The previous version of the team member's `pull` permission on a repository, if the action was `edited`.
-        ///
-        /// `changes[repository][permissions][from][push]: bool`
-        /// The previous version of the team member's `push` permission on a repository, if the action was `edited`.
-        changes: serde_json::Value,
-        /// The repository that was added or removed from to the team's purview if the action was `added_to_repository`, `removed_from_repository`, or `edited`. For `edited` actions, `repository` also contains the team's new permission levels for the repository.
-        repository: TeamEventRepository,
-        organization: Organization,
-        sender: Sender,
-    },
-
-    /// Triggered when a [repository is added to a
-    /// team](https://developer.github.com/v3/teams/#add-or-update-team-repository).
+    /// `changes[repository][permissions][from][admin]: bool`
+    /// The previous version of the team member's `admin` permission on a repository, if the action was `edited`.
     ///
-    /// Events of this type are not visible in timelines. These events are only used to trigger hooks.
-    TeamAddEvent {
-        /// The [team](https://developer.github.com/v3/teams/) that was modified. Note: older events may not include this in the payload.
-        team: Team,
-        /// The [repository](https://developer.github.com/v3/repos/) that was added to this team.
-        repository: Repository,
-        organization: Organization,
-        sender: Sender,
-    },
-
-    /// The WatchEvent is related to [starring a repository](https://developer.github.com/v3/activity/starring/#star-a-repository),
-    /// not [watching](https://developer.github.com/v3/activity/watching/).
-    /// See [this API blog post](https://developer.github.com/changes/2012-09-05-watcher-api/) for an explanation.
+    /// `changes[repository][permissions][from][pull]: bool`
+    /// The previous version of the team member's `pull` permission on a repository, if the action was `edited`.
     ///
-    /// The event’s actor is the [user](https://developer.github.com/v3/users/) who starred a repository,
-    /// and the event’s repository is the [repository](https://developer.github.com/v3/repos/) that was starred.
-    WatchEvent {
-        /// The action that was performed. Currently, can only be `started`.
-        action: String,
-        repository: Repository,
-        sender: Sender,
-    },
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct IssueEvent {
-    /// The action that was performed. Can be one of `opened`, `edited`, `deleted`, `transferred`, `closed`,
-    /// `reopened`, `assigned`, `unassigned`, `labeled`, `unlabeled`, `milestoned`, or `demilestoned`.
-    action: String,
-    /// The [issue](https://developer.github.com/v3/issues) itself.
-    issue: Issue,
-    /// The changes to the issue if the action was "edited".
-    /// `changes[title][from]: String` The previous version of the title if the action was "edited".
-    /// `changes[body][from]:String` The previous version of the body if the action was "edited".
-    changes: Option<::serde_json::Value>,
-    repository: Repository,
-    sender: Sender,
+    /// `changes[repository][permissions][from][push]: bool`
+    /// The previous version of the team member's `push` permission on a repository, if the action was `edited`.
+    #[serde(default, with = "crate::untyped::Json")]
+    #[cfg_attr(feature = "fuzz", arbitrary(with = crate::fuzz::value))]
+    pub changes: serde_json::Value,
+    /// The repository that was added or removed from to the team's purview if the action was `added_to_repository`, `removed_from_repository`, or `edited`. For `edited` actions, `repository` also contains the team's new permission levels for the repository.
+    pub repository: TeamEventRepository,
+    #[serde(default)]
+    pub organization: Option<Organization>,
+    pub sender: Sender,
+    #[serde(default)]
+    pub installation: Option<InstallationRef>,
+}
+
+/// Triggered when a [repository is added to a
+/// team](https://developer.github.com/v3/teams/#add-or-update-team-repository).
+///
+/// Events of this type are not visible in timelines. These events are only used to trigger hooks.
+#[cfg(feature = "orgs")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct TeamAddEvent {
+    /// The [team](https://developer.github.com/v3/teams/) that was modified. Note: older events may not include this in the payload.
+    pub team: Team,
+    /// The [repository](https://developer.github.com/v3/repos/) that was added to this team.
+    pub repository: Repository,
+    #[serde(default)]
+    pub organization: Option<Organization>,
+    pub sender: Sender,
+    #[serde(default)]
+    pub installation: Option<InstallationRef>,
+}
+
+/// The WatchEvent is related to [starring a repository](https://developer.github.com/v3/activity/starring/#star-a-repository),
+/// not [watching](https://developer.github.com/v3/activity/watching/).
+/// See [this API blog post](https://developer.github.com/changes/2012-09-05-watcher-api/) for an explanation.
+///
+/// The event’s actor is the [user](https://developer.github.com/v3/users/) who starred a repository,
+/// and the event’s repository is the [repository](https://developer.github.com/v3/repos/) that was starred.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct WatchEvent {
+    /// The action that was performed. Currently, can only be `started`.
+    pub action: String,
+    pub repository: Repository,
+    #[serde(default)]
+    pub organization: Option<Organization>,
+    pub sender: Sender,
+    #[serde(default)]
+    pub installation: Option<InstallationRef>,
+}
+
+/// The changes to a comment if the action was "edited".
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct CommentChanges {
+    /// The previous version of the body if the action was "edited".
+    pub body: Option<Change<String>>,
+}
+
+/// The changes to an issue if the action was "edited".
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct IssueChanges {
+    /// The previous version of the title if it was edited.
+    pub title: Option<Change<String>>,
+    /// The previous version of the body if it was edited.
+    pub body: Option<Change<Option<String>>>,
+}
+
+/// The changes to a pull request if the action was "edited".
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct PullRequestChanges {
+    /// The previous version of the title if it was edited.
+    pub title: Option<Change<String>>,
+    /// The previous version of the body if it was edited.
+    pub body: Option<Change<Option<String>>>,
+    /// The previous base branch if it was changed.
+    pub base: Option<BaseChanges>,
+}
+
+/// The previous base branch of a pull request.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct BaseChanges {
+    #[serde(rename = "ref")]
+    pub ref_field: Change<String>,
+    pub sha: Change<Sha>,
+}
+
+/// The changes to a label if the action was "edited".
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct LabelChanges {
+    /// The previous version of the name if it was edited.
+    pub name: Option<Change<String>>,
+    /// The previous version of the color if it was edited.
+    pub color: Option<Change<String>>,
+}
+
+/// The changes to a milestone if the action was `edited`.
+#[cfg(feature = "issues")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct MilestoneChanges {
+    /// The previous version of the title if it was edited.
+    pub title: Option<Change<String>>,
+    /// The previous version of the description if it was edited.
+    pub description: Option<Change<Option<String>>>,
+    /// The previous due date if it was changed.
+    pub due_on: Option<Change<Option<String>>>,
+}
+
+/// The changes to an organization if the action was `renamed`.
+#[cfg(feature = "orgs")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct OrganizationChanges {
+    /// The previous login of the organization.
+    pub login: Option<Change<String>>,
+}
+
+/// The previous value of an edited field.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct Change<T> {
+    pub from: T,
 }
 
 /// FIXME add docs [`check_run`](https://developer.github.com/v3/checks/runs/)
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct CheckRun {
-    /// The id of the check suite that this check run is part of.
-    id: i64,
-    head_sha: String,
-    external_id: String,
-    url: String,
-    html_url: String,
+#[cfg(feature = "checks")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct CheckRun {
+    /// The id of the check run.
+    pub id: CheckRunId,
+    pub head_sha: Sha,
+    pub external_id: String,
+    pub url: String,
+    pub html_url: String,
     /// The current status of the check run. Can be `queued,` `in_progress,` or `completed.`
     // FIXME should be enum
-    status: String,
+    pub status: String,
     /// The result of the completed `check` run.
     /// Can be one of `success,` `failure,` `neutral,` `cancelled,`
     /// timed_out, or `action_required.`
     /// This value will be `null` until the check run has `completed.`
     // FIXME should be enum
-    conclusion: Option<String>,
-    started_at: String,
-    completed_at: String,
-    output: Output,
+    pub conclusion: Option<String>,
+    pub started_at: String,
+    pub completed_at: String,
+    pub output: Output,
     /// The name of the check run.
-    name: String,
-    check_suite: CheckSuite,
-    app: App,
-    pull_requests: Vec<::serde_json::Value>,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct Output {
-    title: String,
-    summary: String,
-    text: String,
-    annotations_count: i64,
-    annotations_url: String,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct CheckSuite {
-    id: i64,
+    pub name: String,
+    /// The check suite that this check run is part of.
+    pub check_suite: CheckSuite,
+    pub app: App,
+    pub pull_requests: Vec<PullRequestRef>,
+}
+
+#[cfg(feature = "checks")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct Output {
+    pub title: String,
+    pub summary: String,
+    pub text: String,
+    pub annotations_count: i64,
+    pub annotations_url: String,
+}
+
+#[cfg(feature = "checks")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct CheckSuite {
+    pub id: i64,
     /// The head branch name the changes are on.
-    head_branch: String,
+    pub head_branch: String,
     /// The SHA of the most recent commit for this check suite.
-    head_sha: String,
+    pub head_sha: Sha,
     /// The summary status for all check runs that are part of the check suite.
     /// Can be `requested`, `in_progress`, or `completed`.
-    status: String,
+    pub status: String,
     /// The summary conclusion for all check runs that are part of the check suite. Can be one
     /// `success`, `failure`, `neutral`, `cancelled`, `timed_out`, or `action_required`.
     /// This value will be `null` until the check run has `completed`.
-    conclusion: String,
+    pub conclusion: String,
     /// URL that points to the check suite API resource.
-    url: String,
-    before: String,
-    after: String,
+    pub url: String,
+    pub before: Sha,
+    pub after: Sha,
     /// An array of pull requests that match this check suite. A pull request matches a check suite if
     /// they have the same `head_sha` and head_branch. When the check suite's `head_branch` is unknown
     /// (`null`) the `pull_requests` array will be empty.
-    pull_requests: Vec<::serde_json::Value>,
-    app: App,
-    created_at: String,
-    updated_at: String,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct App {
-    id: i64,
-    node_id: String,
-    owner: Owner,
-    name: String,
-    description: ::serde_json::Value,
-    external_url: String,
-    html_url: String,
-    created_at: String,
-    updated_at: String,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct Organization {
-    login: String,
-    id: i64,
-    node_id: String,
-    url: String,
-    repos_url: String,
-    events_url: String,
-    hooks_url: String,
-    issues_url: String,
-    members_url: String,
-    public_members_url: String,
-    avatar_url: String,
-    description: String,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct Sender {
-    login: String,
-    id: i64,
-    node_id: String,
-    avatar_url: String,
-    gravatar_id: String,
-    url: String,
-    html_url: String,
-    followers_url: String,
-    following_url: String,
-    gists_url: String,
-    starred_url: String,
-    subscriptions_url: String,
-    organizations_url: String,
-    repos_url: String,
-    events_url: String,
-    received_events_url: String,
-    #[serde(rename = "type")]
-    type_field: String,
-    site_admin: bool,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct Installation {
-    id: i64,
-    account: Account,
-    repository_selection: String,
-    access_tokens_url: String,
-    repositories_url: String,
-    html_url: String,
-    app_id: i64,
-    target_id: i64,
-    target_type: String,
-    permissions: Permissions,
-    events: Vec<String>,
-    created_at: i64,
-    updated_at: i64,
-    single_file_name: String,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct GeneratedType {
-    action: String,
-    check_suite: CheckSuite,
-    repository: Repository,
-    organization: Organization,
-    sender: Sender,
-    installation: Installation,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct HeadCommit {
-    id: String,
-    tree_id: String,
-    message: String,
-    timestamp: String,
-    author: Author,
-    committer: Committer,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct Author {
+    pub pull_requests: Vec<PullRequestRef>,
+    pub app: App,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[cfg(feature = "checks")]
+impl CheckSuite {
+    /// The full ref of the head branch; `None` if it's unknown.
+    pub fn head_ref(&self) -> Option<GitRef> {
+        Some(&self.head_branch)
+            .filter(|branch| !branch.is_empty())
+            .map(|branch| GitRef::branch(branch))
+    }
+}
+
+/// A GitHub App, such as the one that created a check run or acted on a user's behalf.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct App {
+    pub id: i64,
+    pub node_id: String,
+    pub owner: Owner,
+    pub name: String,
+    #[serde(default, with = "crate::untyped::Json")]
+    #[cfg_attr(feature = "fuzz", arbitrary(with = crate::fuzz::value))]
+    pub description: ::serde_json::Value,
+    pub external_url: String,
+    pub html_url: String,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// The organization that owns an event's repository, or that an organization event happened in.
+/// Every payload has one as its `organization` when there is one.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct Organization {
+    pub login: String,
+    pub id: i64,
+    pub node_id: String,
+    pub url: String,
+    pub repos_url: String,
+    pub events_url: String,
+    pub hooks_url: String,
+    pub issues_url: String,
+    pub members_url: String,
+    pub public_members_url: String,
+    pub avatar_url: String,
+    pub description: Option<String>,
+}
+
+/// The user that triggered an event; the same type as any other [`User`].
+pub type Sender = User;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct Installation {
+    pub id: InstallationId,
+    pub account: Account,
+    pub repository_selection: String,
+    pub access_tokens_url: String,
+    pub repositories_url: String,
+    pub html_url: String,
+    pub app_id: i64,
+    pub target_id: i64,
+    pub target_type: String,
+    pub permissions: Permissions,
+    pub events: Vec<String>,
+    #[serde(deserialize_with = "timestamp::deserialize")]
+    #[cfg_attr(feature = "schemars", schemars(schema_with = "timestamp::schema"))]
+    pub created_at: String,
+    #[serde(deserialize_with = "timestamp::deserialize")]
+    #[cfg_attr(feature = "schemars", schemars(schema_with = "timestamp::schema"))]
+    pub updated_at: String,
+    pub single_file_name: Option<String>,
+}
+
+/// The GitHub App installation an event was delivered to, as the payloads of events delivered to
+/// apps refer to it. Set only on events delivered to an app's webhook.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct InstallationRef {
+    pub id: InstallationId,
+    #[serde(default)]
+    pub node_id: Option<String>,
+}
+
+#[cfg(feature = "checks")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct GeneratedType {
+    pub action: String,
+    pub check_suite: CheckSuite,
+    pub repository: Repository,
+    pub organization: Organization,
+    pub sender: Sender,
+    pub installation: Installation,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct HeadCommit {
+    pub id: String,
+    pub tree_id: String,
+    pub message: String,
+    pub timestamp: String,
+    pub author: Author,
+    pub committer: Committer,
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "debug-full", derive(Debug))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct Author {
     /// The git author's name.
-    name: String,
+    pub name: String,
     /// The git author's email address.
-    email: String,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct Committer {
-    name: String,
-    email: String,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct User {
-    login: String,
-    id: i64,
-    node_id: String,
-    avatar_url: String,
-    gravatar_id: String,
-    url: String,
-    html_url: String,
-    followers_url: String,
-    following_url: String,
-    gists_url: String,
-    starred_url: String,
-    subscriptions_url: String,
-    organizations_url: String,
-    repos_url: String,
-    events_url: String,
-    received_events_url: String,
+    pub email: String,
+}
+
+debug_redacting!(Author { name, email(redacted) });
+
+#[derive(Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "debug-full", derive(Debug))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct Committer {
+    pub name: String,
+    pub email: String,
+}
+
+debug_redacting!(Committer { name, email(redacted) });
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct User {
+    pub login: IStr,
+    pub id: UserId,
+    pub node_id: IStr,
+    #[cfg(not(feature = "slim"))]
+    pub avatar_url: IStr,
+    pub gravatar_id: IStr,
+    pub url: IStr,
+    pub html_url: IStr,
+    #[cfg(not(feature = "slim"))]
+    pub followers_url: IStr,
+    #[cfg(not(feature = "slim"))]
+    pub following_url: IStr,
+    #[cfg(not(feature = "slim"))]
+    pub gists_url: IStr,
+    #[cfg(not(feature = "slim"))]
+    pub starred_url: IStr,
+    #[cfg(not(feature = "slim"))]
+    pub subscriptions_url: IStr,
+    #[cfg(not(feature = "slim"))]
+    pub organizations_url: IStr,
+    #[cfg(not(feature = "slim"))]
+    pub repos_url: IStr,
+    #[cfg(not(feature = "slim"))]
+    pub events_url: IStr,
+    #[cfg(not(feature = "slim"))]
+    pub received_events_url: IStr,
     #[serde(rename = "type")]
-    type_field: String,
-    site_admin: bool,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct Comment {
-    url: String,
-    html_url: String,
-    id: i64,
-    node_id: String,
-    user: User,
-    position: ::serde_json::Value,
-    line: ::serde_json::Value,
-    path: ::serde_json::Value,
-    commit_id: String,
-    created_at: String,
-    updated_at: String,
-    author_association: String,
-    body: String,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct Deployment {
-    url: String,
-    id: i64,
-    node_id: String,
-    sha: String,
+    pub type_field: IStr,
+    pub site_admin: bool,
+}
+
+impl User {
+    /// Whether the user is an app's bot account, such as `dependabot[bot]`.
+    pub fn is_bot(&self) -> bool {
+        self.type_field == "Bot" || self.login.ends_with("[bot]")
+    }
+
+    /// Whether the user is a GitHub staff member.
+    pub fn is_site_admin(&self) -> bool {
+        self.site_admin
+    }
+}
+
+/// `url`, unless it's empty.
+fn link(url: &str) -> Option<&str> {
+    Some(url).filter(|url| !url.is_empty())
+}
+
+/// Whether the user with `login` and `type_field` is the bot of the app `app`, given as its slug
+/// or its bot's login.
+pub(crate) fn is_app_bot(login: &str, type_field: &str, app: &str) -> bool {
+    let app = app.strip_suffix("[bot]").unwrap_or(app);
+    let bot = match login.strip_suffix("[bot]") {
+        Some(bot) => bot,
+        None if type_field == "Bot" => login,
+        None => return false,
+    };
+    bot.eq_ignore_ascii_case(app)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct Comment {
+    pub url: String,
+    pub html_url: String,
+    pub id: i64,
+    pub node_id: String,
+    pub user: User,
+    #[cfg_attr(feature = "fuzz", arbitrary(with = crate::fuzz::value))]
+    #[serde(default, with = "crate::untyped::Json")]
+    pub position: ::serde_json::Value,
+    #[cfg_attr(feature = "fuzz", arbitrary(with = crate::fuzz::value))]
+    #[serde(default, with = "crate::untyped::Json")]
+    pub line: ::serde_json::Value,
+    #[cfg_attr(feature = "fuzz", arbitrary(with = crate::fuzz::value))]
+    #[serde(default, with = "crate::untyped::Json")]
+    pub path: ::serde_json::Value,
+    /// `None` for comments on issues.
+    pub commit_id: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+    pub author_association: String,
+    pub body: String,
+    /// The app that did this on behalf of its user, if one did.
+    #[serde(default)]
+    pub performed_via_github_app: Option<App>,
+    #[serde(default)]
+    pub reactions: Option<Reactions>,
+}
+
+/// The counts of each [reaction](https://docs.github.com/en/rest/reactions) to an issue,
+/// comment, review, or release.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct Reactions {
+    pub total_count: i64,
+    /// 👍
+    #[serde(rename = "+1")]
+    pub plus_one: i64,
+    /// 👎
+    #[serde(rename = "-1")]
+    pub minus_one: i64,
+    pub laugh: i64,
+    pub hooray: i64,
+    pub confused: i64,
+    pub heart: i64,
+    pub rocket: i64,
+    pub eyes: i64,
+}
+
+#[cfg(feature = "deployments")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct Deployment {
+    pub url: String,
+    pub id: i64,
+    pub node_id: String,
+    pub sha: String,
     #[serde(rename = "ref")]
-    ref_field: String,
-    task: String,
-    payload: Payload,
-    environment: String,
-    description: ::serde_json::Value,
-    creator: Creator,
-    created_at: String,
-    updated_at: String,
-    statuses_url: String,
-    repository_url: String,
+    pub ref_field: String,
+    pub task: String,
+    pub payload: Payload,
+    pub environment: String,
+    #[serde(default, with = "crate::untyped::Json")]
+    #[cfg_attr(feature = "fuzz", arbitrary(with = crate::fuzz::value))]
+    pub description: ::serde_json::Value,
+    pub creator: Creator,
+    pub created_at: String,
+    pub updated_at: String,
+    pub statuses_url: String,
+    pub repository_url: String,
 }
 
 /// FIXME Empty?
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct Payload {}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct Creator {
-    login: String,
-    id: i64,
-    node_id: String,
-    avatar_url: String,
-    gravatar_id: String,
-    url: String,
-    html_url: String,
-    followers_url: String,
-    following_url: String,
-    gists_url: String,
-    starred_url: String,
-    subscriptions_url: String,
-    organizations_url: String,
-    repos_url: String,
-    events_url: String,
-    received_events_url: String,
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Payload {}
+
+#[cfg(any(feature = "deployments", feature = "issues", feature = "projects"))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct Creator {
+    pub login: String,
+    pub id: UserId,
+    pub node_id: String,
+    pub avatar_url: String,
+    pub gravatar_id: String,
+    pub url: String,
+    pub html_url: String,
+    pub followers_url: String,
+    pub following_url: String,
+    pub gists_url: String,
+    pub starred_url: String,
+    pub subscriptions_url: String,
+    pub organizations_url: String,
+    pub repos_url: String,
+    pub events_url: String,
+    pub received_events_url: String,
     #[serde(rename = "type")]
-    type_field: String,
-    site_admin: bool,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct DeploymentStatus {
-    url: String,
-    id: i64,
-    node_id: String,
+    pub type_field: String,
+    pub site_admin: bool,
+}
+
+#[cfg(feature = "deployments")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct DeploymentStatus {
+    pub url: String,
+    pub id: i64,
+    pub node_id: String,
     /// The new state. Can be `pending`, `success`, `failure`, or `error`.
-    state: String,
-    creator: Creator,
+    pub state: String,
+    pub creator: Creator,
     /// The optional human-readable description added to the status.
-    description: String,
+    pub description: String,
     /// The optional link added to the status.
-    target_url: String,
-    created_at: String,
-    updated_at: String,
-    deployment_url: String,
-    repository_url: String,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct Forkee {
-    id: i64,
-    node_id: String,
-    name: String,
-    full_name: String,
-    owner: Owner,
-    private: bool,
-    html_url: String,
-    description: ::serde_json::Value,
-    fork: bool,
-    url: String,
-    forks_url: String,
-    keys_url: String,
-    collaborators_url: String,
-    teams_url: String,
-    hooks_url: String,
-    issue_events_url: String,
-    events_url: String,
-    assignees_url: String,
-    branches_url: String,
-    tags_url: String,
-    blobs_url: String,
-    git_tags_url: String,
-    git_refs_url: String,
-    trees_url: String,
-    statuses_url: String,
-    languages_url: String,
-    stargazers_url: String,
-    contributors_url: String,
-    subscribers_url: String,
-    subscription_url: String,
-    commits_url: String,
-    git_commits_url: String,
-    comments_url: String,
-    issue_comment_url: String,
-    contents_url: String,
-    compare_url: String,
-    merges_url: String,
-    archive_url: String,
-    downloads_url: String,
-    issues_url: String,
-    pulls_url: String,
-    milestones_url: String,
-    notifications_url: String,
-    labels_url: String,
-    releases_url: String,
-    deployments_url: String,
-    created_at: String,
-    updated_at: String,
-    pushed_at: String,
-    git_url: String,
-    ssh_url: String,
-    clone_url: String,
-    svn_url: String,
-    homepage: ::serde_json::Value,
-    size: i64,
-    stargazers_count: i64,
-    watchers_count: i64,
-    language: ::serde_json::Value,
-    has_issues: bool,
-    has_projects: bool,
-    has_downloads: bool,
-    has_wiki: bool,
-    has_pages: bool,
-    forks_count: i64,
-    mirror_url: ::serde_json::Value,
-    archived: bool,
-    open_issues_count: i64,
-    license: ::serde_json::Value,
-    forks: i64,
-    open_issues: i64,
-    watchers: i64,
-    default_branch: String,
-    public: bool,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct Page {
+    pub target_url: String,
+    pub created_at: String,
+    pub updated_at: String,
+    pub deployment_url: String,
+    pub repository_url: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct Forkee {
+    pub id: RepositoryId,
+    pub node_id: String,
+    pub name: String,
+    pub full_name: String,
+    pub owner: Owner,
+    pub private: bool,
+    pub html_url: String,
+    #[serde(default, with = "crate::untyped::Json")]
+    #[cfg_attr(feature = "fuzz", arbitrary(with = crate::fuzz::value))]
+    pub description: ::serde_json::Value,
+    pub fork: bool,
+    pub url: String,
+    pub forks_url: String,
+    pub keys_url: String,
+    pub collaborators_url: String,
+    pub teams_url: String,
+    pub hooks_url: String,
+    pub issue_events_url: String,
+    pub events_url: String,
+    pub assignees_url: String,
+    pub branches_url: String,
+    pub tags_url: String,
+    pub blobs_url: String,
+    pub git_tags_url: String,
+    pub git_refs_url: String,
+    pub trees_url: String,
+    pub statuses_url: String,
+    pub languages_url: String,
+    pub stargazers_url: String,
+    pub contributors_url: String,
+    pub subscribers_url: String,
+    pub subscription_url: String,
+    pub commits_url: String,
+    pub git_commits_url: String,
+    pub comments_url: String,
+    pub issue_comment_url: String,
+    pub contents_url: String,
+    pub compare_url: String,
+    pub merges_url: String,
+    pub archive_url: String,
+    pub downloads_url: String,
+    pub issues_url: String,
+    pub pulls_url: String,
+    pub milestones_url: String,
+    pub notifications_url: String,
+    pub labels_url: String,
+    pub releases_url: String,
+    pub deployments_url: String,
+    pub created_at: String,
+    pub updated_at: String,
+    pub pushed_at: String,
+    pub git_url: String,
+    pub ssh_url: String,
+    pub clone_url: String,
+    pub svn_url: String,
+    #[serde(default, with = "crate::untyped::Json")]
+    #[cfg_attr(feature = "fuzz", arbitrary(with = crate::fuzz::value))]
+    pub homepage: ::serde_json::Value,
+    pub size: i64,
+    pub stargazers_count: i64,
+    pub watchers_count: i64,
+    #[serde(default, with = "crate::untyped::Json")]
+    #[cfg_attr(feature = "fuzz", arbitrary(with = crate::fuzz::value))]
+    pub language: ::serde_json::Value,
+    pub has_issues: bool,
+    pub has_projects: bool,
+    pub has_downloads: bool,
+    pub has_wiki: bool,
+    pub has_pages: bool,
+    pub forks_count: i64,
+    #[serde(default, with = "crate::untyped::Json")]
+    #[cfg_attr(feature = "fuzz", arbitrary(with = crate::fuzz::value))]
+    pub mirror_url: ::serde_json::Value,
+    pub archived: bool,
+    pub open_issues_count: i64,
+    #[serde(default, with = "crate::untyped::Json")]
+    #[cfg_attr(feature = "fuzz", arbitrary(with = crate::fuzz::value))]
+    pub license: ::serde_json::Value,
+    pub forks: i64,
+    pub open_issues: i64,
+    pub watchers: i64,
+    pub default_branch: String,
+    pub public: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct Page {
     /// The name of the page.
-    page_name: String,
+    pub page_name: String,
     /// The current page title.
-    title: String,
-    summary: ::serde_json::Value,
+    pub title: String,
+    #[serde(default, with = "crate::untyped::Json")]
+    #[cfg_attr(feature = "fuzz", arbitrary(with = crate::fuzz::value))]
+    pub summary: ::serde_json::Value,
     /// The action that was performed on the page. Can be "created" or "edited".
-    action: String,
+    pub action: String,
     /// The latest commit SHA of the page.
-    sha: String,
+    pub sha: String,
     /// Points to the HTML wiki page.
-    html_url: String,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct Account {
-    login: String,
-    id: i64,
-    node_id: String,
-    avatar_url: String,
-    gravatar_id: String,
-    url: String,
-    html_url: String,
-    followers_url: String,
-    following_url: String,
-    gists_url: String,
-    starred_url: String,
-    subscriptions_url: String,
-    organizations_url: String,
-    repos_url: String,
-    events_url: String,
-    received_events_url: String,
+    pub html_url: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct Account {
+    pub login: String,
+    pub id: UserId,
+    pub node_id: String,
+    pub avatar_url: String,
+    pub gravatar_id: String,
+    pub url: String,
+    pub html_url: String,
+    pub followers_url: String,
+    pub following_url: String,
+    pub gists_url: String,
+    pub starred_url: String,
+    pub subscriptions_url: String,
+    pub organizations_url: String,
+    pub repos_url: String,
+    pub events_url: String,
+    pub received_events_url: String,
     #[serde(rename = "type")]
-    type_field: String,
-    site_admin: bool,
+    pub type_field: String,
+    pub site_admin: bool,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct Permissions {
-    metadata: String,
-    contents: String,
-    issues: String,
-}
+/// The access an app has been granted, by permission, e.g. `contents` to `read`.
+pub type Permissions = ::alloc::collections::BTreeMap<String, String>;
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct PartialRepository {
-    id: i64,
-    name: String,
-    full_name: String,
-    private: bool,
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct PartialRepository {
+    pub id: RepositoryId,
+    pub name: String,
+    pub full_name: String,
+    pub private: bool,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct RepositoriesRemoved {
-    id: i64,
-    name: String,
-    full_name: String,
-    private: bool,
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct RepositoriesRemoved {
+    pub id: RepositoryId,
+    pub name: String,
+    pub full_name: String,
+    pub private: bool,
 }
 
 /// Triggered when an [issue comment](https://developer.github.com/v3/issues/comments/) is created, edited, or deleted.
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct Issue {
-    url: String,
-    repository_url: String,
-    labels_url: String,
-    comments_url: String,
-    events_url: String,
-    html_url: String,
-    id: i64,
-    node_id: String,
-    number: i64,
-    title: String,
-    user: User,
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct Issue {
+    pub url: String,
+    pub repository_url: String,
+    pub labels_url: String,
+    pub comments_url: String,
+    pub events_url: String,
+    pub html_url: String,
+    pub id: IssueId,
+    pub node_id: String,
+    pub number: i64,
+    pub title: String,
+    pub user: User,
     /// The optional labels that were added or removed from the issue.
-    labels: Vec<Label>,
-    state: String,
-    locked: bool,
+    pub labels: Vec<Label>,
+    pub state: String,
+    pub locked: bool,
     /// The optional user who was assigned or unassigned from the issue.
-    assignee: ::serde_json::Value,
-    assignees: Vec<::serde_json::Value>,
-    milestone: ::serde_json::Value,
-    comments: i64,
-    created_at: String,
-    updated_at: String,
-    closed_at: ::serde_json::Value,
-    author_association: String,
-    body: String,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct Label {
-    id: i64,
-    node_id: String,
-    url: String,
-    name: String,
-    color: String,
-    default: bool,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct Member {
-    login: String,
-    id: i64,
-    node_id: String,
-    avatar_url: String,
-    gravatar_id: String,
-    url: String,
-    html_url: String,
-    followers_url: String,
-    following_url: String,
-    gists_url: String,
-    starred_url: String,
-    subscriptions_url: String,
-    organizations_url: String,
-    repos_url: String,
-    events_url: String,
-    received_events_url: String,
+    #[serde(default, with = "crate::untyped::Json")]
+    #[cfg_attr(feature = "fuzz", arbitrary(with = crate::fuzz::value))]
+    pub assignee: ::serde_json::Value,
+    #[serde(with = "crate::untyped::JsonVec")]
+    #[cfg_attr(feature = "fuzz", arbitrary(with = crate::fuzz::values))]
+    pub assignees: Vec<::serde_json::Value>,
+    #[serde(default, with = "crate::untyped::Json")]
+    #[cfg_attr(feature = "fuzz", arbitrary(with = crate::fuzz::value))]
+    pub milestone: ::serde_json::Value,
+    pub comments: i64,
+    pub created_at: String,
+    pub updated_at: String,
+    #[serde(default, with = "crate::untyped::Json")]
+    #[cfg_attr(feature = "fuzz", arbitrary(with = crate::fuzz::value))]
+    pub closed_at: ::serde_json::Value,
+    pub author_association: String,
+    pub body: Option<String>,
+    /// The app that did this on behalf of its user, if one did.
+    #[serde(default)]
+    pub performed_via_github_app: Option<App>,
+    #[serde(default)]
+    pub reactions: Option<Reactions>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct Label {
+    pub id: i64,
+    pub node_id: String,
+    pub url: String,
+    pub name: String,
+    pub color: String,
+    pub default: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct Member {
+    pub login: String,
+    pub id: UserId,
+    pub node_id: String,
+    pub avatar_url: String,
+    pub gravatar_id: String,
+    pub url: String,
+    pub html_url: String,
+    pub followers_url: String,
+    pub following_url: String,
+    pub gists_url: String,
+    pub starred_url: String,
+    pub subscriptions_url: String,
+    pub organizations_url: String,
+    pub repos_url: String,
+    pub events_url: String,
+    pub received_events_url: String,
     #[serde(rename = "type")]
-    type_field: String,
-    site_admin: bool,
+    pub type_field: String,
+    pub site_admin: bool,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct MemberEventChanges {
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct MemberEventChanges {
     /// The previous permissions of the collaborator if the action was `edited`
-    permission: Permission,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct Permission {
-    from: String,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct Team {
-    name: String,
-    id: i64,
-    node_id: String,
-    slug: String,
-    description: String,
-    privacy: String,
-    url: String,
-    members_url: String,
-    repositories_url: String,
-    permission: String,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct Milestone {
-    url: String,
-    html_url: String,
-    labels_url: String,
-    id: i64,
-    node_id: String,
-    number: i64,
-    title: String,
-    description: String,
-    creator: Creator,
-    open_issues: i64,
-    closed_issues: i64,
-    state: String,
-    created_at: String,
-    updated_at: String,
-    due_on: String,
-    closed_at: ::serde_json::Value,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct Membership {
-    url: String,
-    state: String,
-    role: String,
-    organization_url: String,
-    user: User,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct Build {
-    url: String,
-    status: String,
-    error: Error,
-    pusher: Pusher,
-    commit: String,
-    duration: i64,
-    created_at: String,
-    updated_at: String,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct Error {
-    message: ::serde_json::Value,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct Pusher {
-    login: String,
-    id: i64,
-    node_id: String,
-    avatar_url: String,
-    gravatar_id: String,
-    url: String,
-    html_url: String,
-    followers_url: String,
-    following_url: String,
-    gists_url: String,
-    starred_url: String,
-    subscriptions_url: String,
-    organizations_url: String,
-    repos_url: String,
-    events_url: String,
-    received_events_url: String,
+    pub permission: Permission,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct Permission {
+    pub from: String,
+}
+
+#[cfg(any(feature = "orgs", feature = "issues"))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct Team {
+    pub name: String,
+    pub id: i64,
+    pub node_id: String,
+    pub slug: String,
+    pub description: Option<String>,
+    pub privacy: String,
+    pub url: String,
+    pub members_url: String,
+    pub repositories_url: String,
+    pub permission: String,
+    /// The team this one is nested in, if any.
+    #[serde(default)]
+    pub parent: Option<Box<Team>>,
+}
+
+#[cfg(feature = "issues")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct Milestone {
+    pub url: String,
+    pub html_url: String,
+    pub labels_url: String,
+    pub id: i64,
+    pub node_id: String,
+    pub number: i64,
+    pub title: String,
+    pub description: String,
+    pub creator: Creator,
+    pub open_issues: i64,
+    pub closed_issues: i64,
+    pub state: String,
+    pub created_at: String,
+    pub updated_at: String,
+    pub due_on: String,
+    #[serde(default, with = "crate::untyped::Json")]
+    #[cfg_attr(feature = "fuzz", arbitrary(with = crate::fuzz::value))]
+    pub closed_at: ::serde_json::Value,
+}
+
+#[cfg(feature = "orgs")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct Membership {
+    pub url: String,
+    pub state: String,
+    pub role: String,
+    pub organization_url: String,
+    pub user: User,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct Build {
+    pub url: String,
+    pub status: String,
+    pub error: Error,
+    pub pusher: Pusher,
+    pub commit: String,
+    pub duration: i64,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct Error {
+    #[serde(default, with = "crate::untyped::Json")]
+    #[cfg_attr(feature = "fuzz", arbitrary(with = crate::fuzz::value))]
+    pub message: ::serde_json::Value,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct Pusher {
+    pub login: String,
+    pub id: UserId,
+    pub node_id: String,
+    pub avatar_url: String,
+    pub gravatar_id: String,
+    pub url: String,
+    pub html_url: String,
+    pub followers_url: String,
+    pub following_url: String,
+    pub gists_url: String,
+    pub starred_url: String,
+    pub subscriptions_url: String,
+    pub organizations_url: String,
+    pub repos_url: String,
+    pub events_url: String,
+    pub received_events_url: String,
     #[serde(rename = "type")]
-    type_field: String,
-    site_admin: bool,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct ProjectCard {
-    url: String,
-    project_url: String,
-    column_url: String,
-    column_id: i64,
-    id: i64,
-    node_id: String,
-    note: String,
-    creator: Creator,
-    created_at: String,
-    updated_at: String,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct ProjectColumn {
-    url: String,
-    project_url: String,
-    cards_url: String,
-    id: i64,
-    node_id: String,
-    name: String,
-    created_at: String,
-    updated_at: String,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct Project {
-    owner_url: String,
-    url: String,
-    html_url: String,
-    columns_url: String,
-    id: i64,
-    node_id: String,
-    name: String,
-    body: String,
-    number: i64,
-    state: String,
-    creator: Creator,
-    created_at: String,
-    updated_at: String,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct PullRequest {
-    url: String,
-    id: i64,
-    node_id: String,
-    html_url: String,
-    diff_url: String,
-    patch_url: String,
-    issue_url: String,
-    number: i64,
-    state: String,
-    locked: bool,
-    title: String,
-    user: User,
-    body: String,
-    created_at: String,
-    updated_at: String,
-    closed_at: String,
-    merged_at: ::serde_json::Value,
-    merge_commit_sha: String,
-    assignee: ::serde_json::Value,
-    assignees: Vec<::serde_json::Value>,
-    requested_reviewers: Vec<::serde_json::Value>,
-    requested_teams: Vec<::serde_json::Value>,
-    labels: Vec<::serde_json::Value>,
-    milestone: ::serde_json::Value,
-    commits_url: String,
-    review_comments_url: String,
-    review_comment_url: String,
-    comments_url: String,
-    statuses_url: String,
-    head: Head,
-    base: Base,
-    _links: Links,
-    author_association: String,
-    merged: bool,
-    mergeable: bool,
-    rebaseable: bool,
-    mergeable_state: String,
-    merged_by: ::serde_json::Value,
-    comments: i64,
-    review_comments: i64,
-    maintainer_can_modify: bool,
-    commits: i64,
-    additions: i64,
-    deletions: i64,
-    changed_files: i64,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct Head {
-    label: String,
+    pub type_field: String,
+    pub site_admin: bool,
+}
+
+#[cfg(feature = "projects")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct ProjectCard {
+    pub url: String,
+    pub project_url: String,
+    pub column_url: String,
+    pub column_id: i64,
+    pub id: i64,
+    pub node_id: String,
+    pub note: String,
+    pub creator: Creator,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[cfg(feature = "projects")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct ProjectColumn {
+    pub url: String,
+    pub project_url: String,
+    pub cards_url: String,
+    pub id: i64,
+    pub node_id: String,
+    pub name: String,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[cfg(feature = "projects")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct Project {
+    pub owner_url: String,
+    pub url: String,
+    pub html_url: String,
+    pub columns_url: String,
+    pub id: i64,
+    pub node_id: String,
+    pub name: String,
+    pub body: String,
+    pub number: i64,
+    pub state: String,
+    pub creator: Creator,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct PullRequest {
+    pub url: String,
+    pub id: i64,
+    pub node_id: String,
+    pub html_url: String,
+    pub diff_url: String,
+    pub patch_url: String,
+    pub issue_url: String,
+    pub number: i64,
+    pub state: String,
+    pub locked: bool,
+    /// Whether the pull request is a draft, not yet ready for review.
+    #[serde(default)]
+    pub draft: bool,
+    pub title: String,
+    pub user: User,
+    pub body: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+    pub closed_at: Option<String>,
+    #[serde(default, with = "crate::untyped::Json")]
+    #[cfg_attr(feature = "fuzz", arbitrary(with = crate::fuzz::value))]
+    pub merged_at: ::serde_json::Value,
+    pub merge_commit_sha: Option<String>,
+    #[serde(default, with = "crate::untyped::Json")]
+    #[cfg_attr(feature = "fuzz", arbitrary(with = crate::fuzz::value))]
+    pub assignee: ::serde_json::Value,
+    #[serde(with = "crate::untyped::JsonVec")]
+    #[cfg_attr(feature = "fuzz", arbitrary(with = crate::fuzz::values))]
+    pub assignees: Vec<::serde_json::Value>,
+    #[serde(with = "crate::untyped::JsonVec")]
+    #[cfg_attr(feature = "fuzz", arbitrary(with = crate::fuzz::values))]
+    pub requested_reviewers: Vec<::serde_json::Value>,
+    #[serde(with = "crate::untyped::JsonVec")]
+    #[cfg_attr(feature = "fuzz", arbitrary(with = crate::fuzz::values))]
+    pub requested_teams: Vec<::serde_json::Value>,
+    #[serde(with = "crate::untyped::JsonVec")]
+    #[cfg_attr(feature = "fuzz", arbitrary(with = crate::fuzz::values))]
+    pub labels: Vec<::serde_json::Value>,
+    #[serde(default, with = "crate::untyped::Json")]
+    #[cfg_attr(feature = "fuzz", arbitrary(with = crate::fuzz::value))]
+    pub milestone: ::serde_json::Value,
+    pub commits_url: String,
+    pub review_comments_url: String,
+    pub review_comment_url: String,
+    pub comments_url: String,
+    pub statuses_url: String,
+    pub head: Head,
+    pub base: Base,
+    pub _links: Links,
+    pub author_association: String,
+    pub merged: bool,
+    /// `None` while GitHub is still computing it.
+    pub mergeable: Option<bool>,
+    pub rebaseable: Option<bool>,
+    pub mergeable_state: MergeableState,
+    #[serde(default, with = "crate::untyped::Json")]
+    #[cfg_attr(feature = "fuzz", arbitrary(with = crate::fuzz::value))]
+    pub merged_by: ::serde_json::Value,
+    pub comments: i64,
+    pub review_comments: i64,
+    pub maintainer_can_modify: bool,
+    pub commits: i64,
+    pub additions: i64,
+    pub deletions: i64,
+    pub changed_files: i64,
+    /// The pull request's auto-merge settings, if it will be merged once its requirements are
+    /// met.
+    #[serde(default)]
+    pub auto_merge: Option<AutoMerge>,
+}
+
+/// How a pull request will be merged automatically.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct AutoMerge {
+    /// The user who enabled auto-merge.
+    pub enabled_by: User,
+    pub merge_method: MergeMethod,
+    /// The title of the merge commit, if it was set.
+    pub commit_title: Option<String>,
+    /// The message of the merge commit, if it was set.
+    pub commit_message: Option<String>,
+}
+
+/// Whether a pull request can be merged, as of when GitHub last checked.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum MergeableState {
+    /// The head branch is out of date with the base branch.
+    Behind,
+    /// Branch protection blocks the merge, e.g. for want of an approving review.
+    Blocked,
+    /// It can be merged, and its checks passed.
+    Clean,
+    /// It has merge conflicts.
+    Dirty,
+    /// It's a draft.
+    Draft,
+    /// It can be merged, and its checks passed, but pre-receive hooks may still reject it.
+    HasHooks,
+    /// It can be merged, but checks that aren't required failed.
+    Unstable,
+    /// GitHub hasn't checked yet, as it doesn't until asked, or this crate doesn't know the state.
+    #[default]
+    #[serde(other)]
+    Unknown,
+}
+
+/// What happened to a [`MembershipEvent`](struct.MembershipEvent.html)'s member.
+#[cfg(feature = "orgs")]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum MembershipAction {
+    Added,
+    Removed,
+    /// An action this crate doesn't know.
+    #[default]
+    #[serde(other)]
+    Unknown,
+}
+
+/// What a [`MembershipEvent`](struct.MembershipEvent.html)'s member was added to or removed from.
+#[cfg(feature = "orgs")]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum MembershipScope {
+    /// The event's team.
+    Team,
+    /// The event's organization.
+    Organization,
+    /// A scope this crate doesn't know.
+    #[default]
+    #[serde(other)]
+    Unknown,
+}
+
+/// How a pull request's commits are added to its base branch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum MergeMethod {
+    Merge,
+    Squash,
+    Rebase,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct Head {
+    pub label: String,
     #[serde(rename = "ref")]
-    ref_field: String,
-    sha: String,
-    user: User,
-    repo: Repository,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct Base {
-    label: String,
+    pub ref_field: String,
+    pub sha: Sha,
+    pub user: User,
+    pub repo: Repository,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct Base {
+    pub label: String,
     #[serde(rename = "ref")]
-    ref_field: String,
-    sha: String,
-    user: User,
-    repo: Repository,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct Links {
+    pub ref_field: String,
+    pub sha: Sha,
+    pub user: User,
+    pub repo: Repository,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct Links {
     #[serde(rename = "self")]
-    self_field: Link,
-    html: Link,
-    issue: Link,
-    comments: Link,
-    review_comments: Link,
-    review_comment: Link,
-    commits: Link,
-    statuses: Link,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct Link {
-    href: String,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct Review {
-    id: i64,
-    node_id: String,
-    user: User,
-    body: ::serde_json::Value,
-    commit_id: String,
-    submitted_at: String,
-    state: String,
-    html_url: String,
-    pull_request_url: String,
-    author_association: String,
-    _links: ReviewLinks,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct ReviewLinks {
-    html: Link,
-    pull_request: PullRequest,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct Commit {
-    /// The SHA of the commit.
-    sha: String,
+    pub self_field: Link,
+    pub html: Link,
+    pub issue: Link,
+    pub comments: Link,
+    pub review_comments: Link,
+    pub review_comment: Link,
+    pub commits: Link,
+    pub statuses: Link,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct Link {
+    pub href: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct Review {
+    pub id: i64,
+    pub node_id: String,
+    pub user: User,
+    #[serde(default, with = "crate::untyped::Json")]
+    #[cfg_attr(feature = "fuzz", arbitrary(with = crate::fuzz::value))]
+    pub body: ::serde_json::Value,
+    pub commit_id: String,
+    pub submitted_at: String,
+    pub state: String,
+    pub html_url: String,
+    pub pull_request_url: String,
+    pub author_association: String,
+    pub _links: ReviewLinks,
+    /// The app that did this on behalf of its user, if one did.
+    #[serde(default)]
+    pub performed_via_github_app: Option<App>,
+    #[serde(default)]
+    pub reactions: Option<Reactions>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct ReviewLinks {
+    pub html: Link,
+    pub pull_request: Link,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct Commit {
+    /// The SHA of the commit. Webhooks, GHES's among them, call it `id`, and the Events API `sha`.
+    #[serde(alias = "id")]
+    pub sha: Sha,
     /// The commit message.
-    message: String,
+    pub message: String,
     /// The git author of the commit.
-    author: Author,
+    pub author: Author,
     /// URL that points to the commit API resource.
-    url: String,
+    pub url: String,
     /// Whether this commit is distinct from any that have been pushed before.
-    distinct: bool,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct Release {
-    url: String,
-    assets_url: String,
-    upload_url: String,
-    html_url: String,
-    id: i64,
-    node_id: String,
-    tag_name: String,
-    target_commitish: String,
-    name: ::serde_json::Value,
-    draft: bool,
-    author: ReleaseAuthor,
-    prerelease: bool,
-    created_at: String,
-    published_at: String,
-    assets: Vec<::serde_json::Value>,
-    tarball_url: String,
-    zipball_url: String,
-    body: ::serde_json::Value,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct ReleaseAuthor {
-    login: String,
-    id: i64,
-    node_id: String,
-    avatar_url: String,
-    gravatar_id: String,
-    url: String,
-    html_url: String,
-    followers_url: String,
-    following_url: String,
-    gists_url: String,
-    starred_url: String,
-    subscriptions_url: String,
-    organizations_url: String,
-    repos_url: String,
-    events_url: String,
-    received_events_url: String,
+    pub distinct: bool,
+    /// The paths of the files the commit added.
+    #[serde(default)]
+    pub added: Vec<String>,
+    /// The paths of the files the commit removed.
+    #[serde(default)]
+    pub removed: Vec<String>,
+    /// The paths of the files the commit modified.
+    #[serde(default)]
+    pub modified: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct Release {
+    pub url: String,
+    pub assets_url: String,
+    pub upload_url: String,
+    pub html_url: String,
+    pub id: i64,
+    pub node_id: String,
+    pub tag_name: String,
+    pub target_commitish: String,
+    #[serde(default, with = "crate::untyped::Json")]
+    #[cfg_attr(feature = "fuzz", arbitrary(with = crate::fuzz::value))]
+    pub name: ::serde_json::Value,
+    pub draft: bool,
+    pub author: ReleaseAuthor,
+    pub prerelease: bool,
+    pub created_at: String,
+    pub published_at: String,
+    #[serde(with = "crate::untyped::JsonVec")]
+    #[cfg_attr(feature = "fuzz", arbitrary(with = crate::fuzz::values))]
+    pub assets: Vec<::serde_json::Value>,
+    pub tarball_url: String,
+    pub zipball_url: String,
+    #[serde(default, with = "crate::untyped::Json")]
+    #[cfg_attr(feature = "fuzz", arbitrary(with = crate::fuzz::value))]
+    pub body: ::serde_json::Value,
+    /// The app that did this on behalf of its user, if one did.
+    #[serde(default)]
+    pub performed_via_github_app: Option<App>,
+    #[serde(default)]
+    pub reactions: Option<Reactions>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct ReleaseAuthor {
+    pub login: String,
+    pub id: UserId,
+    pub node_id: String,
+    pub avatar_url: String,
+    pub gravatar_id: String,
+    pub url: String,
+    pub html_url: String,
+    pub followers_url: String,
+    pub following_url: String,
+    pub gists_url: String,
+    pub starred_url: String,
+    pub subscriptions_url: String,
+    pub organizations_url: String,
+    pub repos_url: String,
+    pub events_url: String,
+    pub received_events_url: String,
     #[serde(rename = "type")]
-    type_field: String,
-    site_admin: bool,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct Alert {
-    id: i64,
-    affected_range: String,
-    affected_package_name: String,
-    external_reference: String,
-    external_identifier: String,
-    fixed_in: String,
-    dismisser: User,
-    dismiss_reason: String,
-    dismissed_at: String,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct SecurityAdvisory {
-    ghsa_id: String,
-    summary: String,
-    description: String,
-    severity: String,
-    identifiers: Vec<Identifier>,
-    references: Vec<Reference>,
-    published_at: String,
-    updated_at: String,
-    withdrawn_at: ::serde_json::Value,
-    vulnerabilities: Vec<Vulnerability>,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct Identifier {
-    value: String,
+    pub type_field: String,
+    pub site_admin: bool,
+}
+
+#[cfg(feature = "security")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct Alert {
+    pub id: i64,
+    pub affected_range: String,
+    pub affected_package_name: String,
+    pub external_reference: String,
+    pub external_identifier: String,
+    pub fixed_in: String,
+    pub dismisser: User,
+    pub dismiss_reason: String,
+    pub dismissed_at: String,
+}
+
+#[cfg(feature = "security")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct SecurityAdvisory {
+    pub ghsa_id: String,
+    pub summary: String,
+    pub description: String,
+    pub severity: String,
+    pub identifiers: Vec<Identifier>,
+    pub references: Vec<Reference>,
+    pub published_at: String,
+    pub updated_at: String,
+    #[serde(default, with = "crate::untyped::Json")]
+    #[cfg_attr(feature = "fuzz", arbitrary(with = crate::fuzz::value))]
+    pub withdrawn_at: ::serde_json::Value,
+    pub vulnerabilities: Vec<Vulnerability>,
+}
+
+#[cfg(feature = "security")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct Identifier {
+    pub value: String,
     #[serde(rename = "type")]
-    type_field: String,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct Reference {
-    url: String,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct Vulnerability {
-    package: Package,
-    severity: String,
-    vulnerable_version_range: String,
-    first_patched_version: FirstPatchedVersion,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct Package {
-    ecosystem: String,
-    name: String,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct FirstPatchedVersion {
-    identifier: String,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct StatusEventCommitNode {
-    sha: String,
-    node_id: String,
-    commit: CommitTree,
-    url: String,
-    html_url: String,
-    comments_url: String,
-    author: AuthorDate,
-    committer: CommitterDate,
-    parents: Vec<::serde_json::Value>,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct CommitTree {
-    author: AuthorDate,
-    committer: CommitterDate,
-    message: String,
-    tree: Tree,
-    url: String,
-    comment_count: i64,
-    verification: Verification,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct AuthorDate {
-    name: String,
-    email: String,
-    date: String,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct CommitterDate {
-    name: String,
-    email: String,
-    date: String,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct Tree {
-    sha: String,
-    url: String,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct Verification {
-    verified: bool,
-    reason: String,
-    signature: String,
-    payload: String,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct Bran {
-    name: String,
-    commit: Commit,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct TeamEventRepository {
-    id: i64,
-    node_id: String,
-    name: String,
-    full_name: String,
-    owner: Owner,
-    private: bool,
-    html_url: String,
-    description: ::serde_json::Value,
-    fork: bool,
-    url: String,
-    forks_url: String,
-    keys_url: String,
-    collaborators_url: String,
-    teams_url: String,
-    hooks_url: String,
-    issue_events_url: String,
-    events_url: String,
-    assignees_url: String,
-    branches_url: String,
-    tags_url: String,
-    blobs_url: String,
-    git_tags_url: String,
-    git_refs_url: String,
-    trees_url: String,
-    statuses_url: String,
-    languages_url: String,
-    stargazers_url: String,
-    contributors_url: String,
-    subscribers_url: String,
-    subscription_url: String,
-    commits_url: String,
-    git_commits_url: String,
-    comments_url: String,
-    issue_comment_url: String,
-    contents_url: String,
-    compare_url: String,
-    merges_url: String,
-    archive_url: String,
-    downloads_url: String,
-    issues_url: String,
-    pulls_url: String,
-    milestones_url: String,
-    notifications_url: String,
-    labels_url: String,
-    releases_url: String,
-    deployments_url: String,
-    created_at: String,
-    updated_at: String,
-    pushed_at: String,
-    git_url: String,
-    ssh_url: String,
-    clone_url: String,
-    svn_url: String,
-    homepage: ::serde_json::Value,
-    size: i64,
-    stargazers_count: i64,
-    watchers_count: i64,
-    language: ::serde_json::Value,
-    has_issues: bool,
-    has_projects: bool,
-    has_downloads: bool,
-    has_wiki: bool,
-    has_pages: bool,
-    forks_count: i64,
-    mirror_url: ::serde_json::Value,
-    archived: bool,
-    open_issues_count: i64,
-    license: ::serde_json::Value,
-    forks: i64,
-    open_issues: i64,
-    watchers: i64,
-    default_branch: String,
-    permissions: TeamEventPermissions,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct TeamEventPermissions {
-    pull: bool,
-    push: bool,
-    admin: bool,
+    pub type_field: String,
+}
+
+#[cfg(feature = "security")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct Reference {
+    pub url: String,
+}
+
+#[cfg(feature = "security")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct Vulnerability {
+    pub package: Package,
+    pub severity: String,
+    pub vulnerable_version_range: String,
+    pub first_patched_version: FirstPatchedVersion,
+}
+
+#[cfg(feature = "security")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct Package {
+    pub ecosystem: String,
+    pub name: String,
+}
+
+#[cfg(feature = "security")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct FirstPatchedVersion {
+    pub identifier: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct StatusEventCommitNode {
+    pub sha: Sha,
+    pub node_id: String,
+    pub commit: CommitTree,
+    pub url: String,
+    pub html_url: String,
+    pub comments_url: String,
+    /// The GitHub user of the git author, if GitHub knows one with their email.
+    pub author: Option<User>,
+    /// The GitHub user of the git committer, if GitHub knows one with their email.
+    pub committer: Option<User>,
+    pub parents: Vec<CommitRef>,
+}
+
+/// A commit referred to by its SHA, such as a parent of a commit or the head of a branch.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct CommitRef {
+    pub sha: Sha,
+    /// URL that points to the commit API resource.
+    pub url: String,
+    /// Not present for the heads of branches.
+    #[serde(default)]
+    pub html_url: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct CommitTree {
+    pub author: AuthorDate,
+    pub committer: CommitterDate,
+    pub message: String,
+    pub tree: Tree,
+    pub url: String,
+    pub comment_count: i64,
+    pub verification: Verification,
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "debug-full", derive(Debug))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct AuthorDate {
+    pub name: String,
+    pub email: String,
+    pub date: String,
+}
+
+debug_redacting!(AuthorDate { name, email(redacted), date });
+
+#[derive(Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "debug-full", derive(Debug))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct CommitterDate {
+    pub name: String,
+    pub email: String,
+    pub date: String,
+}
+
+debug_redacting!(CommitterDate { name, email(redacted), date });
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct Tree {
+    pub sha: Sha,
+    pub url: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct Verification {
+    pub verified: bool,
+    pub reason: VerificationReason,
+    /// The signature, or `None` if the commit is unsigned.
+    pub signature: Option<String>,
+    /// The signed part of the commit object, or `None` if the commit is unsigned.
+    pub payload: Option<String>,
+    /// When GitHub verified the signature, if it did.
+    #[serde(default)]
+    pub verified_at: Option<String>,
+}
+
+/// Why a commit's signature was or wasn't [verified](https://docs.github.com/en/rest/commits/commits#signature-verification-object).
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum VerificationReason {
+    /// The signature was verified.
+    Valid,
+    /// The commit isn't signed.
+    Unsigned,
+    /// The key that made the signature expired.
+    ExpiredKey,
+    /// The key that made the signature isn't a signing key.
+    NotSigningKey,
+    /// The signature server couldn't be reached.
+    GpgverifyUnavailable,
+    /// The signature server failed to verify the signature.
+    GpgverifyError,
+    /// The signature is of a type GitHub doesn't support.
+    UnknownSignatureType,
+    /// No GitHub user has the committer's email.
+    NoUser,
+    /// The committer's email isn't verified on their account.
+    UnverifiedEmail,
+    /// The committer's email isn't one of the key's.
+    BadEmail,
+    /// The key isn't registered to any GitHub account.
+    UnknownKey,
+    /// The signature couldn't be parsed.
+    MalformedSignature,
+    /// The signature doesn't match the commit.
+    Invalid,
+    /// The signing certificate couldn't be verified.
+    BadCert,
+    /// The signing certificate's revocation status is still being checked.
+    OcspPending,
+    /// A reason this crate doesn't know.
+    #[default]
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct Bran {
+    pub name: String,
+    /// The head of the branch.
+    pub commit: CommitRef,
+}
+
+#[cfg(feature = "orgs")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct TeamEventRepository {
+    pub id: RepositoryId,
+    pub node_id: String,
+    pub name: String,
+    pub full_name: String,
+    pub owner: Owner,
+    pub private: bool,
+    pub html_url: String,
+    #[serde(default, with = "crate::untyped::Json")]
+    #[cfg_attr(feature = "fuzz", arbitrary(with = crate::fuzz::value))]
+    pub description: ::serde_json::Value,
+    pub fork: bool,
+    pub url: String,
+    pub forks_url: String,
+    pub keys_url: String,
+    pub collaborators_url: String,
+    pub teams_url: String,
+    pub hooks_url: String,
+    pub issue_events_url: String,
+    pub events_url: String,
+    pub assignees_url: String,
+    pub branches_url: String,
+    pub tags_url: String,
+    pub blobs_url: String,
+    pub git_tags_url: String,
+    pub git_refs_url: String,
+    pub trees_url: String,
+    pub statuses_url: String,
+    pub languages_url: String,
+    pub stargazers_url: String,
+    pub contributors_url: String,
+    pub subscribers_url: String,
+    pub subscription_url: String,
+    pub commits_url: String,
+    pub git_commits_url: String,
+    pub comments_url: String,
+    pub issue_comment_url: String,
+    pub contents_url: String,
+    pub compare_url: String,
+    pub merges_url: String,
+    pub archive_url: String,
+    pub downloads_url: String,
+    pub issues_url: String,
+    pub pulls_url: String,
+    pub milestones_url: String,
+    pub notifications_url: String,
+    pub labels_url: String,
+    pub releases_url: String,
+    pub deployments_url: String,
+    pub created_at: String,
+    pub updated_at: String,
+    pub pushed_at: String,
+    pub git_url: String,
+    pub ssh_url: String,
+    pub clone_url: String,
+    pub svn_url: String,
+    #[serde(default, with = "crate::untyped::Json")]
+    #[cfg_attr(feature = "fuzz", arbitrary(with = crate::fuzz::value))]
+    pub homepage: ::serde_json::Value,
+    pub size: i64,
+    pub stargazers_count: i64,
+    pub watchers_count: i64,
+    #[serde(default, with = "crate::untyped::Json")]
+    #[cfg_attr(feature = "fuzz", arbitrary(with = crate::fuzz::value))]
+    pub language: ::serde_json::Value,
+    pub has_issues: bool,
+    pub has_projects: bool,
+    pub has_downloads: bool,
+    pub has_wiki: bool,
+    pub has_pages: bool,
+    pub forks_count: i64,
+    #[serde(default, with = "crate::untyped::Json")]
+    #[cfg_attr(feature = "fuzz", arbitrary(with = crate::fuzz::value))]
+    pub mirror_url: ::serde_json::Value,
+    pub archived: bool,
+    pub open_issues_count: i64,
+    #[serde(default, with = "crate::untyped::Json")]
+    #[cfg_attr(feature = "fuzz", arbitrary(with = crate::fuzz::value))]
+    pub license: ::serde_json::Value,
+    pub forks: i64,
+    pub open_issues: i64,
+    pub watchers: i64,
+    pub default_branch: String,
+    pub permissions: TeamEventPermissions,
+}
+
+#[cfg(feature = "orgs")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct TeamEventPermissions {
+    pub pull: bool,
+    pub push: bool,
+    pub admin: bool,
 }
@@ -0,0 +1,99 @@
+//! Metrics about the events this crate parses and the requests it makes, recorded through the
+//! [metrics](https://docs.rs/metrics) facade. Enabled by the `metrics` feature.
+//!
+//! Nothing is exported until an application installs a recorder, such as
+//! [metrics-exporter-prometheus](https://docs.rs/metrics-exporter-prometheus); call
+//! [`describe`](fn.describe.html) after installing it to give the metrics help texts.
+//!
+//! Parsing a webhook body with [`Event::from_slice`](../enum.Event.html#method.from_slice) and
+//! its siblings counts it, and checking a signature with the `signature` feature counts
+//! failures. Wrap handlers in [`time_handler`](fn.time_handler.html) to time them.
+use std::future::Future;
+use std::time::Instant;
+
+use crate::{Event, EventType};
+
+/// Counter of webhook payloads parsed, labelled with `event` and `action`, which is empty for
+/// events without one.
+pub const EVENTS_RECEIVED: &str = "github_events_received_total";
+/// Counter of webhook payloads that failed to parse, labelled with `event`.
+pub const PARSE_FAILURES: &str = "github_events_parse_failures_total";
+/// Counter of deliveries whose signature didn't match.
+pub const SIGNATURE_FAILURES: &str = "github_events_signature_failures_total";
+/// Histogram of the seconds handlers took, labelled with `event`.
+pub const HANDLER_DURATION: &str = "github_events_handler_duration_seconds";
+/// Counter of events yielded by the client's event streams, labelled with `event`, which is the
+/// Events API `type`, e.g. `GistEvent`, for legacy events without a webhook name.
+pub const EVENTS_POLLED: &str = "github_events_polled_total";
+/// Counter of the client's requests, labelled with the response's `status`, or `error` if none
+/// came.
+pub const API_REQUESTS: &str = "github_events_api_requests_total";
+/// Histogram of the seconds the client's requests took.
+pub const API_REQUEST_DURATION: &str = "github_events_api_request_duration_seconds";
+
+/// Describe every metric to the installed recorder.
+pub fn describe() {
+    ::metrics::describe_counter!(EVENTS_RECEIVED, "Webhook payloads parsed.");
+    ::metrics::describe_counter!(PARSE_FAILURES, "Webhook payloads that failed to parse.");
+    ::metrics::describe_counter!(
+        SIGNATURE_FAILURES,
+        "Deliveries whose signature didn't match."
+    );
+    ::metrics::describe_histogram!(
+        HANDLER_DURATION,
+        ::metrics::Unit::Seconds,
+        "Time spent handling events."
+    );
+    ::metrics::describe_counter!(EVENTS_POLLED, "Events received from the Events API.");
+    ::metrics::describe_counter!(API_REQUESTS, "Requests made to GitHub's API.");
+    ::metrics::describe_histogram!(
+        API_REQUEST_DURATION,
+        ::metrics::Unit::Seconds,
+        "Time spent on requests to GitHub's API."
+    );
+}
+
+/// Run `handler` for `event`, recording how long it took in
+/// [`HANDLER_DURATION`](constant.HANDLER_DURATION.html).
+pub async fn time_handler<F: Future>(event: &Event, handler: F) -> F::Output {
+    let event_type = event.event_type().as_str();
+    let start = Instant::now();
+    let output = handler.await;
+    ::metrics::histogram!(HANDLER_DURATION, "event" => event_type)
+        .record(start.elapsed().as_secs_f64());
+    output
+}
+
+pub(crate) fn parsed(event_type: EventType, action: Option<&str>, ok: bool) {
+    if ok {
+        ::metrics::counter!(
+            EVENTS_RECEIVED,
+            "event" => event_type.as_str(),
+            "action" => action.unwrap_or_default().to_owned()
+        )
+        .increment(1);
+    } else {
+        ::metrics::counter!(PARSE_FAILURES, "event" => event_type.as_str()).increment(1);
+    }
+}
+
+#[cfg(feature = "signature")]
+pub(crate) fn signature_failed() {
+    ::metrics::counter!(SIGNATURE_FAILURES).increment(1);
+}
+
+#[cfg(feature = "client")]
+pub(crate) fn polled(event: &crate::timeline::TimelineEvent) {
+    // Legacy events have no webhook name, so they go by their Events API one.
+    let event_type = event
+        .event_type()
+        .map_or_else(|| event.type_name(), EventType::as_str);
+    ::metrics::counter!(EVENTS_POLLED, "event" => event_type).increment(1);
+}
+
+#[cfg(feature = "client")]
+pub(crate) fn requested(status: Option<u16>, start: Instant) {
+    let status = status.map_or_else(|| "error".to_owned(), |status| status.to_string());
+    ::metrics::counter!(API_REQUESTS, "status" => status).increment(1);
+    ::metrics::histogram!(API_REQUEST_DURATION).record(start.elapsed().as_secs_f64());
+}
@@ -0,0 +1,138 @@
+//! Decoding the `node_id`s of payload objects, which identify them in the
+//! [GraphQL API](https://docs.github.com/en/graphql/guides/using-global-node-ids), into their
+//! type and the database `id` the REST API and webhooks use.
+//!
+//! Objects created before 2021 have legacy ids, e.g. `MDQ6VXNlcjE=` for the user with id 1: the
+//! base64 of `04:User1`, the length of the type, the type, and the id. Newer ones have ids
+//! like `I_kwDOABCD…`: a prefix naming the type, and the URL-safe base64 of a MessagePack array
+//! ending in the id. Ids of either kind can be passed to GraphQL queries and mutations as they
+//! are, e.g. to add a pull request to a project.
+//!
+//! ```
+//! use github_events::node_id;
+//!
+//! assert_eq!(node_id::decode("MDQ6VXNlcjE="), Some(("User".to_owned(), 1)));
+//! assert_eq!(node_id::legacy("User", 1), "MDQ6VXNlcjE=");
+//! ```
+use core::convert::TryFrom;
+
+use crate::prelude::*;
+
+/// The types of objects in payloads, by the prefixes of their new-style node ids.
+const PREFIXES: &[(&str, &str)] = &[
+    ("U", "User"),
+    ("O", "Organization"),
+    ("R", "Repository"),
+    ("I", "Issue"),
+    ("PR", "PullRequest"),
+    ("IC", "IssueComment"),
+    ("PRR", "PullRequestReview"),
+    ("PRRC", "PullRequestReviewComment"),
+    ("CC", "CommitComment"),
+    ("LA", "Label"),
+    ("MI", "Milestone"),
+    ("RE", "Release"),
+    ("T", "Team"),
+    ("BOT", "Bot"),
+    ("CR", "CheckRun"),
+    ("CS", "CheckSuite"),
+    ("DE", "Deployment"),
+    ("DES", "DeploymentStatus"),
+    ("PVT", "ProjectV2"),
+    ("PVTI", "ProjectV2Item"),
+];
+
+const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// The GraphQL type and database id of the object with `node_id`, e.g. `("User", 1)` for
+/// `MDQ6VXNlcjE=`. `None` for ids of types this module doesn't know, or that aren't node ids.
+pub fn decode(node_id: &str) -> Option<(String, i64)> {
+    match node_id.split_once('_') {
+        Some((prefix, data)) => {
+            let (_, type_name) = PREFIXES.iter().find(|&&(p, _)| p == prefix)?;
+            let id = last_integer(&base64_decode(data)?)?;
+            Some(((*type_name).to_owned(), i64::try_from(id).ok()?))
+        }
+        None => {
+            let decoded = String::from_utf8(base64_decode(node_id)?).ok()?;
+            let (len, rest) = decoded.split_once(':')?;
+            let len: usize = len.parse().ok()?;
+            let (type_name, id) = (rest.get(..len)?, rest.get(len..)?);
+            if type_name.is_empty() || !id.bytes().all(|b| b.is_ascii_digit()) {
+                return None;
+            }
+            Some((type_name.to_owned(), id.parse().ok()?))
+        }
+    }
+}
+
+/// The legacy node id of the object of GraphQL type `type_name` with database id `id`, which
+/// GraphQL still accepts, e.g. `MDQ6VXNlcjE=` for the user with id 1.
+pub fn legacy(type_name: &str, id: i64) -> String {
+    let raw = format!("0{}:{}{}", type_name.len(), type_name, id);
+    let mut encoded = String::new();
+    for chunk in raw.as_bytes().chunks(3) {
+        let n = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |n, (i, &b)| n | u32::from(b) << (16 - 8 * i));
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(ALPHABET[(n >> (18 - 6 * i) & 63) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+/// Standard or URL-safe base64, with or without padding.
+fn base64_decode(s: &str) -> Option<Vec<u8>> {
+    let s = s.trim_end_matches('=');
+    let mut bytes = Vec::with_capacity(s.len() * 3 / 4);
+    let (mut n, mut bits) = (0u32, 0);
+    for c in s.bytes() {
+        let value = match c {
+            b'-' => 62,
+            b'_' => 63,
+            c => ALPHABET.iter().position(|&a| a == c)? as u32,
+        };
+        n = n << 6 | value;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((n >> bits) as u8);
+            n &= (1 << bits) - 1;
+        }
+    }
+    Some(bytes)
+}
+
+/// The last element of a MessagePack array of unsigned integers.
+fn last_integer(bytes: &[u8]) -> Option<u64> {
+    let (&header, mut rest) = bytes.split_first()?;
+    if header & 0xf0 != 0x90 {
+        return None;
+    }
+    let mut last = None;
+    for _ in 0..header & 0x0f {
+        let (&tag, tail) = rest.split_first()?;
+        let width = match tag {
+            0x00..=0x7f => 0,
+            0xcc => 1,
+            0xcd => 2,
+            0xce => 4,
+            0xcf => 8,
+            _ => return None,
+        };
+        let digits = tail.get(..width)?;
+        last = Some(if width == 0 {
+            u64::from(tag)
+        } else {
+            digits.iter().fold(0, |n, &b| n << 8 | u64::from(b))
+        });
+        rest = &tail[width..];
+    }
+    last
+}
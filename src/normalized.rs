@@ -0,0 +1,621 @@
+//! One shape for activity on GitHub, whether it was delivered by a webhook or polled from the
+//! Events API, for analytics pipelines that want both.
+//!
+//! An [`Activity`](struct.Activity.html) says who did what to which object, in the manner of
+//! [Activity Streams](https://www.w3.org/TR/activitystreams-core/), and keeps the event it was
+//! made from.
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::prelude::*;
+use crate::timeline::TimelineEvent;
+use crate::{git_ref, Event, EventType};
+
+/// Something that was done on GitHub.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Activity {
+    /// The login of the user who did it.
+    pub actor: Option<String>,
+    /// What was done: the event's action, such as `opened` or `created`, or for events without
+    /// one a verb such as `pushed`, `forked`, or `starred`. Merged pull requests are `merged`,
+    /// and statuses and builds have their state, such as `success`.
+    pub verb: String,
+    /// What it was done to, e.g. the comment that was created.
+    pub object: Object,
+    /// What the object belongs to, if not just the repository, e.g. the issue the comment is on.
+    pub target: Option<Object>,
+    /// When it happened, as GitHub wrote it. Webhooks don't say, so for them this is when their
+    /// object was last updated, if it says.
+    pub timestamp: Option<String>,
+    /// The repository's full name, e.g. `octocat/Hello-World`.
+    pub repo: Option<String>,
+    /// `None` for the legacy Events API events that no webhook event corresponds to, such as
+    /// `GistEvent`.
+    pub event_type: Option<EventType>,
+    /// The event the activity was made from, whole.
+    pub source: Source,
+}
+
+/// The event an [`Activity`](struct.Activity.html) was made from.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Source {
+    Webhook(Box<Event>),
+    Timeline(Box<TimelineEvent>),
+}
+
+/// The object or target of an activity.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Object {
+    pub kind: ObjectKind,
+    /// What GitHub identifies it by within its kind: the number of issues, pull requests, and
+    /// milestones, the full name of repositories, the login of users, the SHA of commits, the
+    /// full ref of branches and tags, and the id of anything else.
+    pub id: Option<String>,
+    /// Its page on GitHub.
+    pub url: Option<String>,
+    /// Its title or name, for display.
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ObjectKind {
+    Advisory,
+    Alert,
+    CheckRun,
+    CheckSuite,
+    Comment,
+    Commit,
+    Deployment,
+    Download,
+    Gist,
+    Hook,
+    Installation,
+    Issue,
+    Label,
+    Milestone,
+    Organization,
+    PagesBuild,
+    Project,
+    ProjectCard,
+    ProjectColumn,
+    PullRequest,
+    /// A branch or tag.
+    Ref,
+    Release,
+    Repository,
+    Review,
+    Team,
+    User,
+    WikiPage,
+}
+
+impl Activity {
+    pub fn from_event(event: Event) -> Self {
+        let payload = match ::serde_json::to_value(&event) {
+            Ok(Value::Object(tagged)) => tagged.into_iter().next().map(|(_, payload)| payload),
+            _ => None,
+        }
+        .unwrap_or(Value::Null);
+        let event_type = event.event_type();
+        let (verb, object, target) = describe(event_type, &payload);
+        Activity {
+            actor: text(&payload["sender"]["login"]),
+            verb,
+            object,
+            target,
+            timestamp: updated_at(&payload),
+            repo: text(&payload["repository"]["full_name"]),
+            event_type: Some(event_type),
+            source: Source::Webhook(Box::new(event)),
+        }
+    }
+
+    pub fn from_timeline(event: TimelineEvent) -> Self {
+        let value = ::serde_json::to_value(&event).unwrap_or(Value::Null);
+        let event_type = event.event_type();
+        let mut payload = value["payload"].clone();
+        // Timeline payloads leave out the repository, which describing some events needs.
+        payload["repository"]["full_name"] = value["repo"]["name"].clone();
+        payload["repository"]["html_url"] = text(&value["repo"]["name"])
+            .map(|name| format!("https://github.com/{}", name))
+            .into();
+        let (verb, object, target) = match event_type {
+            Some(event_type) => describe(event_type, &payload),
+            None => describe_legacy(event.type_name(), &payload),
+        };
+        Activity {
+            actor: Some(event.actor.login.to_string()),
+            verb,
+            object,
+            target,
+            timestamp: Some(event.created_at.clone()),
+            repo: Some(event.repo.name.to_string()),
+            event_type,
+            source: Source::Timeline(Box::new(event)),
+        }
+    }
+}
+
+impl From<Event> for Activity {
+    fn from(event: Event) -> Self {
+        Activity::from_event(event)
+    }
+}
+
+impl From<TimelineEvent> for Activity {
+    fn from(event: TimelineEvent) -> Self {
+        Activity::from_timeline(event)
+    }
+}
+
+fn text(value: &Value) -> Option<String> {
+    value.as_str().filter(|s| !s.is_empty()).map(String::from)
+}
+
+/// A string id as is, and a numeric one as a string.
+fn identifier(value: &Value) -> Option<String> {
+    match value {
+        Value::Number(n) => Some(n.to_string()),
+        value => text(value),
+    }
+}
+
+fn object(kind: ObjectKind, id: &Value, url: &Value, name: &Value) -> Object {
+    Object {
+        kind,
+        id: identifier(id),
+        url: text(url),
+        name: text(name),
+    }
+}
+
+fn repository(p: &Value) -> Object {
+    let repository = &p["repository"];
+    object(
+        ObjectKind::Repository,
+        &repository["full_name"],
+        &repository["html_url"],
+        &repository["full_name"],
+    )
+}
+
+fn user(user: &Value) -> Object {
+    object(
+        ObjectKind::User,
+        &user["login"],
+        &user["html_url"],
+        &user["login"],
+    )
+}
+
+fn organization(p: &Value) -> Object {
+    let org = &p["organization"];
+    Object {
+        kind: ObjectKind::Organization,
+        id: text(&org["login"]),
+        url: text(&org["login"]).map(|login| format!("https://github.com/{}", login)),
+        name: text(&org["login"]),
+    }
+}
+
+fn team(team: &Value) -> Object {
+    object(
+        ObjectKind::Team,
+        &team["id"],
+        &team["html_url"],
+        &team["name"],
+    )
+}
+
+/// An issue, or the pull request it is.
+fn issue(issue: &Value) -> Object {
+    let kind = if issue["pull_request"].is_object() || issue["head"].is_object() {
+        ObjectKind::PullRequest
+    } else {
+        ObjectKind::Issue
+    };
+    object(kind, &issue["number"], &issue["html_url"], &issue["title"])
+}
+
+fn comment(comment: &Value) -> Object {
+    object(
+        ObjectKind::Comment,
+        &comment["id"],
+        &comment["html_url"],
+        &Value::Null,
+    )
+}
+
+/// The verb, object, and target of the event of type `event_type` with payload `p`.
+fn describe(event_type: EventType, p: &Value) -> (String, Object, Option<Object>) {
+    let action = text(&p["action"]);
+    let verb = |default: &str| action.clone().unwrap_or_else(|| default.to_owned());
+    match event_type {
+        EventType::CheckRun => {
+            let run = &p["check_run"];
+            (
+                verb("created"),
+                object(
+                    ObjectKind::CheckRun,
+                    &run["id"],
+                    &run["html_url"],
+                    &run["name"],
+                ),
+                None,
+            )
+        }
+        EventType::CheckSuite => {
+            let suite = &p["check_suite"];
+            (
+                verb("created"),
+                object(
+                    ObjectKind::CheckSuite,
+                    &suite["id"],
+                    &Value::Null,
+                    &suite["head_branch"],
+                ),
+                None,
+            )
+        }
+        EventType::CommitComment => {
+            let sha = &p["comment"]["commit_id"];
+            let commit = Object {
+                kind: ObjectKind::Commit,
+                id: text(sha),
+                url: text(&p["repository"]["html_url"])
+                    .zip(text(sha))
+                    .map(|(repo, sha)| format!("{}/commit/{}", repo, sha)),
+                name: text(sha).map(|sha| sha.chars().take(7).collect()),
+            };
+            (verb("created"), comment(&p["comment"]), Some(commit))
+        }
+        EventType::Create | EventType::Delete => {
+            let verb = if event_type == EventType::Create {
+                "created"
+            } else {
+                "deleted"
+            };
+            let object = if p["ref_type"] == "repository" {
+                repository(p)
+            } else {
+                object(ObjectKind::Ref, &p["ref"], &Value::Null, &p["ref"])
+            };
+            (verb.to_owned(), object, None)
+        }
+        EventType::Deployment | EventType::DeploymentStatus => {
+            let deployment = &p["deployment"];
+            let verb = match event_type {
+                EventType::Deployment => verb("created"),
+                _ => text(&p["deployment_status"]["state"]).unwrap_or_else(|| verb("created")),
+            };
+            (
+                verb,
+                object(
+                    ObjectKind::Deployment,
+                    &deployment["id"],
+                    &Value::Null,
+                    &deployment["environment"],
+                ),
+                None,
+            )
+        }
+        EventType::Fork => {
+            let forkee = &p["forkee"];
+            (
+                "forked".to_owned(),
+                object(
+                    ObjectKind::Repository,
+                    &forkee["full_name"],
+                    &forkee["html_url"],
+                    &forkee["full_name"],
+                ),
+                None,
+            )
+        }
+        EventType::GithubAppAuthorization => (verb("revoked"), user(&p["sender"]), None),
+        EventType::Gollum => {
+            let page = &p["pages"][0];
+            (
+                text(&page["action"]).unwrap_or_else(|| "edited".to_owned()),
+                object(
+                    ObjectKind::WikiPage,
+                    &page["sha"],
+                    &page["html_url"],
+                    &page["title"],
+                ),
+                None,
+            )
+        }
+        EventType::Installation | EventType::InstallationRepositories => {
+            let installation = &p["installation"];
+            (
+                verb("created"),
+                object(
+                    ObjectKind::Installation,
+                    &installation["id"],
+                    &installation["html_url"],
+                    &installation["account"]["login"],
+                ),
+                None,
+            )
+        }
+        EventType::IssueComment => (
+            verb("created"),
+            comment(&p["comment"]),
+            Some(issue(&p["issue"])),
+        ),
+        EventType::Issues => (verb("opened"), issue(&p["issue"]), None),
+        EventType::Label => {
+            let label = &p["label"];
+            (
+                verb("created"),
+                object(
+                    ObjectKind::Label,
+                    &label["id"],
+                    &Value::Null,
+                    &label["name"],
+                ),
+                None,
+            )
+        }
+        EventType::Member => (verb("added"), user(&p["member"]), None),
+        EventType::Membership => match &p["team"] {
+            Value::Null => (verb("added"), user(&p["member"]), Some(organization(p))),
+            t => (verb("added"), user(&p["member"]), Some(team(t))),
+        },
+        EventType::Milestone => {
+            let milestone = &p["milestone"];
+            (
+                verb("created"),
+                object(
+                    ObjectKind::Milestone,
+                    &milestone["number"],
+                    &milestone["html_url"],
+                    &milestone["title"],
+                ),
+                None,
+            )
+        }
+        EventType::Organization => match &p["membership"]["user"] {
+            Value::Null => (verb("renamed"), organization(p), None),
+            member => (verb("member_added"), user(member), Some(organization(p))),
+        },
+        EventType::OrgBlock => (
+            verb("blocked"),
+            user(&p["blocked_user"]),
+            Some(organization(p)),
+        ),
+        EventType::PageBuild => (
+            text(&p["build"]["status"]).unwrap_or_else(|| "built".to_owned()),
+            object(ObjectKind::PagesBuild, &p["id"], &Value::Null, &Value::Null),
+            None,
+        ),
+        EventType::Ping => (
+            "pinged".to_owned(),
+            object(ObjectKind::Hook, &p["hook_id"], &Value::Null, &Value::Null),
+            None,
+        ),
+        EventType::ProjectCard => {
+            let card = &p["project_card"];
+            (
+                verb("created"),
+                object(
+                    ObjectKind::ProjectCard,
+                    &card["id"],
+                    &Value::Null,
+                    &card["note"],
+                ),
+                None,
+            )
+        }
+        EventType::ProjectColumn => {
+            let column = &p["project_column"];
+            (
+                verb("created"),
+                object(
+                    ObjectKind::ProjectColumn,
+                    &column["id"],
+                    &Value::Null,
+                    &column["name"],
+                ),
+                None,
+            )
+        }
+        EventType::Project => {
+            let project = &p["project"];
+            (
+                verb("created"),
+                object(
+                    ObjectKind::Project,
+                    &project["id"],
+                    &project["html_url"],
+                    &project["name"],
+                ),
+                None,
+            )
+        }
+        EventType::Public => ("publicized".to_owned(), repository(p), None),
+        EventType::PullRequest => {
+            let pull_request = &p["pull_request"];
+            let verb = if action.as_deref() == Some("closed") && pull_request["merged"] == true {
+                "merged".to_owned()
+            } else {
+                verb("opened")
+            };
+            (verb, issue(pull_request), None)
+        }
+        EventType::PullRequestReview => {
+            let review = &p["review"];
+            (
+                verb("submitted"),
+                object(
+                    ObjectKind::Review,
+                    &review["id"],
+                    &review["html_url"],
+                    &review["state"],
+                ),
+                Some(issue(&p["pull_request"])),
+            )
+        }
+        EventType::PullRequestReviewComment => (
+            verb("created"),
+            comment(&p["comment"]),
+            Some(issue(&p["pull_request"])),
+        ),
+        EventType::Push => {
+            let git_ref = text(&p["ref"]).unwrap_or_default();
+            let short = git_ref::short_name(&git_ref).to_owned();
+            (
+                "pushed".to_owned(),
+                Object {
+                    kind: ObjectKind::Ref,
+                    id: Some(git_ref.clone()).filter(|r| !r.is_empty()),
+                    url: text(&p["compare"]),
+                    name: Some(short).filter(|s| !s.is_empty()),
+                },
+                None,
+            )
+        }
+        EventType::Release => {
+            let release = &p["release"];
+            let name = match &release["name"] {
+                Value::String(name) if !name.is_empty() => &release["name"],
+                _ => &release["tag_name"],
+            };
+            (
+                verb("published"),
+                object(
+                    ObjectKind::Release,
+                    &release["id"],
+                    &release["html_url"],
+                    name,
+                ),
+                None,
+            )
+        }
+        EventType::Repository => (verb("created"), repository(p), None),
+        EventType::RepositoryDispatch => ("dispatched".to_owned(), repository(p), None),
+        EventType::RepositoryImport => (
+            text(&p["status"]).unwrap_or_else(|| "imported".to_owned()),
+            repository(p),
+            None,
+        ),
+        EventType::RepositoryVulnerabilityAlert => {
+            let alert = &p["alert"];
+            (
+                verb("create"),
+                object(
+                    ObjectKind::Alert,
+                    &alert["id"],
+                    &alert["external_reference"],
+                    &alert["affected_package_name"],
+                ),
+                None,
+            )
+        }
+        EventType::SecurityAdvisory => {
+            let advisory = &p["security_advisory"];
+            (
+                verb("published"),
+                object(
+                    ObjectKind::Advisory,
+                    &advisory["ghsa_id"],
+                    &advisory["references"][0]["url"],
+                    &advisory["summary"],
+                ),
+                None,
+            )
+        }
+        EventType::Status => (
+            text(&p["state"]).unwrap_or_else(|| "pending".to_owned()),
+            object(
+                ObjectKind::Commit,
+                &p["sha"],
+                &p["commit"]["html_url"],
+                &p["context"],
+            ),
+            None,
+        ),
+        EventType::Team => (
+            verb("created"),
+            team(&p["team"]),
+            text(&p["organization"]["login"]).map(|_| organization(p)),
+        ),
+        EventType::TeamAdd => ("added".to_owned(), repository(p), Some(team(&p["team"]))),
+        EventType::Watch => ("starred".to_owned(), repository(p), None),
+    }
+}
+
+/// Like [`describe`](fn.describe.html), for the legacy timeline events of type `type_name`.
+fn describe_legacy(type_name: &str, p: &Value) -> (String, Object, Option<Object>) {
+    match type_name {
+        "DownloadEvent" => {
+            let download = &p["download"];
+            (
+                "uploaded".to_owned(),
+                object(
+                    ObjectKind::Download,
+                    &download["id"],
+                    &download["html_url"],
+                    &download["name"],
+                ),
+                None,
+            )
+        }
+        "FollowEvent" => ("followed".to_owned(), user(&p["target"]), None),
+        "ForkApplyEvent" => (
+            "applied".to_owned(),
+            object(ObjectKind::Ref, &p["head"], &Value::Null, &p["head"]),
+            None,
+        ),
+        "GistEvent" => {
+            let gist = &p["gist"];
+            let verb = match p["action"].as_str() {
+                Some("create") => "created".to_owned(),
+                Some("update") => "updated".to_owned(),
+                action => action.unwrap_or("updated").to_owned(),
+            };
+            (
+                verb,
+                object(
+                    ObjectKind::Gist,
+                    &gist["id"],
+                    &gist["html_url"],
+                    &gist["description"],
+                ),
+                None,
+            )
+        }
+        _ => ("updated".to_owned(), repository(p), None),
+    }
+}
+
+/// When the object of a webhook's event was last updated, if its payload says.
+fn updated_at(p: &Value) -> Option<String> {
+    [
+        &p["comment"]["updated_at"],
+        &p["review"]["submitted_at"],
+        &p["pull_request"]["updated_at"],
+        &p["issue"]["updated_at"],
+        &p["release"]["published_at"],
+        &p["head_commit"]["timestamp"],
+        &p["check_run"]["completed_at"],
+        &p["check_run"]["started_at"],
+        &p["check_suite"]["updated_at"],
+        &p["deployment_status"]["updated_at"],
+        &p["deployment"]["updated_at"],
+        &p["milestone"]["updated_at"],
+        &p["project_card"]["updated_at"],
+        &p["project_column"]["updated_at"],
+        &p["project"]["updated_at"],
+        &p["security_advisory"]["updated_at"],
+        &p["build"]["updated_at"],
+        &p["updated_at"],
+    ]
+    .iter()
+    .find_map(|value| text(value))
+}
@@ -0,0 +1,99 @@
+//! [Notifications](https://docs.github.com/en/rest/activity/notifications) types.
+//!
+//! A notification is a [`Thread`](struct.Thread.html): the issue, pull request, commit, or
+//! other subject a user is subscribed to, and the [`Reason`](enum.Reason.html) they were last
+//! notified about it. A thread stays the same as activity goes on, with a new `updated_at`.
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+use crate::{RepositoryId, User};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Thread {
+    /// Unique identifier of the thread, a number in a string.
+    pub id: String,
+    pub repository: ThreadRepository,
+    pub subject: Subject,
+    pub reason: Reason,
+    pub unread: bool,
+    pub updated_at: String,
+    /// When the user last read the thread, if ever.
+    pub last_read_at: Option<String>,
+    /// The API url of the thread.
+    pub url: String,
+    pub subscription_url: String,
+}
+
+/// What a thread is about.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[non_exhaustive]
+pub struct Subject {
+    /// The title of the issue, pull request, release, or discussion, or the message of the
+    /// commit.
+    pub title: String,
+    /// The API url of the subject, if it has one; discussions don't.
+    pub url: Option<String>,
+    /// The API url of the latest comment on the subject, if any.
+    pub latest_comment_url: Option<String>,
+    /// `Issue`, `PullRequest`, `Commit`, `Release`, `Discussion`, `CheckSuite`,
+    /// `RepositoryVulnerabilityAlert`, or another kind.
+    #[serde(rename = "type")]
+    pub type_field: String,
+}
+
+/// The repository of a thread, with fewer fields than elsewhere.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct ThreadRepository {
+    pub id: RepositoryId,
+    pub node_id: String,
+    pub name: String,
+    pub full_name: String,
+    pub owner: User,
+    pub private: bool,
+    pub html_url: String,
+    pub description: Option<String>,
+    pub fork: bool,
+    pub url: String,
+}
+
+/// Why the user was notified.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Reason {
+    /// The user was assigned to the issue or pull request.
+    Assign,
+    /// The user created the thread.
+    Author,
+    /// The user commented on the thread.
+    Comment,
+    /// A workflow run the user triggered completed.
+    CiActivity,
+    /// The user accepted an invitation to contribute to the repository.
+    Invitation,
+    /// The user subscribed to the thread.
+    Manual,
+    /// An organization owner asked the user to enable a feature.
+    MemberFeatureRequested,
+    /// The user was `@mentioned`.
+    Mention,
+    /// The user, or a team they're on, was asked to review the pull request.
+    ReviewRequested,
+    /// GitHub found a vulnerability in the repository.
+    SecurityAlert,
+    /// The user was credited for contributing to a security advisory.
+    SecurityAdvisoryCredit,
+    /// The user changed the thread's state, e.g. by closing the issue.
+    StateChange,
+    /// The user is watching the repository.
+    Subscribed,
+    /// A team the user is on was `@mentioned`.
+    TeamMention,
+    /// The user was asked to approve a deployment.
+    ApprovalRequested,
+    /// A reason this crate doesn't know of yet. Serialized as `other`.
+    #[serde(other)]
+    Other,
+}
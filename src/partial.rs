@@ -0,0 +1,173 @@
+//! Abbreviated pull requests and issues, for payloads that refer to one without including it.
+//!
+//! Check runs and suites list their pull requests by number and branches only, and recent
+//! Events API payloads abbreviate their pull requests and issues, although older events, such
+//! as those in archives of it, have them in full. [`Partial`] holds whichever a payload has.
+use serde::de::{DeserializeOwned, Error};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+
+use crate::prelude::*;
+use crate::{Issue, IssueId, PullRequest, RepositoryId, Sha};
+
+/// A pull request as a payload that only refers to it has it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct PullRequestRef {
+    pub id: i64,
+    pub number: i64,
+    /// URL that points to the pull request API resource.
+    pub url: String,
+    /// The branch holding the pull request's changes.
+    pub head: BranchRef,
+    /// The branch the pull request would be merged into.
+    pub base: BranchRef,
+}
+
+/// A branch of a [`PullRequestRef`](struct.PullRequestRef.html), as of the event.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct BranchRef {
+    /// The name of the branch, e.g. `main`.
+    #[serde(rename = "ref")]
+    pub ref_field: String,
+    pub sha: Sha,
+    pub repo: RepoRef,
+}
+
+/// The repository of a [`BranchRef`](struct.BranchRef.html).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct RepoRef {
+    pub id: RepositoryId,
+    /// URL that points to the repository API resource.
+    pub url: String,
+    /// The repository's name, without its owner.
+    pub name: String,
+}
+
+/// An issue as a payload that only refers to it has it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct IssueRef {
+    pub id: IssueId,
+    pub number: i64,
+    /// URL that points to the issue API resource.
+    pub url: String,
+}
+
+/// An object a payload has either in full, or abbreviated to `R`.
+///
+/// A payload with every field of `T` is taken to be full; any other is abbreviated.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Partial<T, R> {
+    Full(Box<T>),
+    Ref(R),
+}
+
+impl<T, R> Partial<T, R> {
+    /// The whole object, if the payload had it.
+    pub fn full(&self) -> Option<&T> {
+        match self {
+            Partial::Full(full) => Some(full),
+            Partial::Ref(_) => None,
+        }
+    }
+}
+
+#[cfg(feature = "test-defaults")]
+impl<T: Default, R> Default for Partial<T, R> {
+    fn default() -> Self {
+        Partial::Full(Box::default())
+    }
+}
+
+macro_rules! partial_fields {
+    ($full:ty, $abbreviated:ty, $id:ty) => {
+        impl Partial<$full, $abbreviated> {
+            pub fn id(&self) -> $id {
+                match self {
+                    Partial::Full(full) => full.id,
+                    Partial::Ref(abbreviated) => abbreviated.id,
+                }
+            }
+
+            pub fn number(&self) -> i64 {
+                match self {
+                    Partial::Full(full) => full.number,
+                    Partial::Ref(abbreviated) => abbreviated.number,
+                }
+            }
+
+            /// URL that points to the API resource.
+            pub fn url(&self) -> &str {
+                match self {
+                    Partial::Full(full) => &full.url,
+                    Partial::Ref(abbreviated) => &abbreviated.url,
+                }
+            }
+        }
+    };
+}
+
+partial_fields!(PullRequest, PullRequestRef, i64);
+partial_fields!(Issue, IssueRef, IssueId);
+
+// JSON has the object as it is, whichever it is. Formats that aren't self-describing can't
+// tell which it is by trying, so they get it tagged.
+#[derive(Serialize)]
+#[serde(rename = "Partial")]
+enum TaggedRef<'a, T, R> {
+    Full(&'a T),
+    Ref(&'a R),
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "Partial")]
+enum Tagged<T, R> {
+    Full(Box<T>),
+    Ref(R),
+}
+
+impl<T: Serialize, R: Serialize> Serialize for Partial<T, R> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match (self, serializer.is_human_readable()) {
+            (Partial::Full(full), true) => full.serialize(serializer),
+            (Partial::Ref(abbreviated), true) => abbreviated.serialize(serializer),
+            (Partial::Full(full), false) => TaggedRef::<T, R>::Full(full).serialize(serializer),
+            (Partial::Ref(abbreviated), false) => {
+                TaggedRef::<T, R>::Ref(abbreviated).serialize(serializer)
+            }
+        }
+    }
+}
+
+impl<'de, T: DeserializeOwned, R: DeserializeOwned> Deserialize<'de> for Partial<T, R> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if !deserializer.is_human_readable() {
+            return Ok(match Tagged::deserialize(deserializer)? {
+                Tagged::Full(full) => Partial::Full(full),
+                Tagged::Ref(abbreviated) => Partial::Ref(abbreviated),
+            });
+        }
+        let value = Value::deserialize(deserializer)?;
+        if let Ok(full) = T::deserialize(&value) {
+            return Ok(Partial::Full(Box::new(full)));
+        }
+        R::deserialize(&value)
+            .map(Partial::Ref)
+            .map_err(D::Error::custom)
+    }
+}
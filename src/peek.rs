@@ -0,0 +1,224 @@
+//! Extracting routing keys from a webhook body without parsing the whole payload.
+//!
+//! These skim the top level of the JSON, skipping over nested objects without allocating,
+//! which makes them cheap enough for queue partitioning and sampling.
+use core::fmt;
+
+use serde::de::{Deserializer, IgnoredAny, MapAccess, Visitor};
+use serde::Deserialize;
+
+use crate::prelude::*;
+use crate::{EventType, InstallationId};
+
+#[derive(Deserialize)]
+struct Action<'a> {
+    #[serde(borrow)]
+    action: Option<&'a str>,
+}
+
+/// The `action` of a payload, e.g. `opened`.
+///
+/// `None` if the payload has no action, or isn't a JSON object.
+pub fn action(body: &[u8]) -> Option<&str> {
+    ::serde_json::from_slice::<Action>(body).ok()?.action
+}
+
+#[derive(Deserialize)]
+struct Installation {
+    installation: Option<Id>,
+}
+
+#[derive(Deserialize)]
+struct Id {
+    id: InstallationId,
+}
+
+/// The id of the GitHub App installation a payload was delivered for.
+///
+/// `None` if the payload wasn't delivered to an app, or isn't a JSON object.
+pub fn installation_id(body: &[u8]) -> Option<InstallationId> {
+    Some(
+        ::serde_json::from_slice::<Installation>(body)
+            .ok()?
+            .installation?
+            .id,
+    )
+}
+
+#[derive(Deserialize)]
+struct Repository<'a> {
+    #[serde(borrow)]
+    repository: Option<FullName<'a>>,
+}
+
+#[derive(Deserialize)]
+struct FullName<'a> {
+    full_name: &'a str,
+}
+
+/// The full name of a payload's repository, e.g. `octocat/Hello-World`.
+///
+/// `None` if the payload has no repository, or isn't a JSON object.
+pub fn repository(body: &[u8]) -> Option<&str> {
+    Some(
+        ::serde_json::from_slice::<Repository>(body)
+            .ok()?
+            .repository?
+            .full_name,
+    )
+}
+
+/// Guess the type of a payload from its top-level keys.
+///
+/// Payloads don't say what event they are, so this is a best-effort fallback for when the
+/// `X-GitHub-Event` header isn't at hand; prefer the header when it is. A few events
+/// can't be told apart by their shape alone: a `repository` event with an `action` of `started`
+/// is taken to be a [`Watch`](../enum.EventType.html#variant.Watch).
+///
+/// `None` if the payload isn't a JSON object, or its shape isn't recognised.
+pub fn event_type(body: &[u8]) -> Option<EventType> {
+    let top: TopLevel = ::serde_json::from_slice(body).ok()?;
+    let has = |key: &str| top.keys.contains(&key);
+    let event_type = if has("zen") {
+        EventType::Ping
+    } else if has("check_run") {
+        EventType::CheckRun
+    } else if has("check_suite") {
+        EventType::CheckSuite
+    } else if has("client_payload") {
+        EventType::RepositoryDispatch
+    } else if has("pusher") {
+        EventType::Push
+    } else if has("ref_type") {
+        if has("master_branch") {
+            EventType::Create
+        } else {
+            EventType::Delete
+        }
+    } else if has("pull_request") {
+        if has("review") {
+            EventType::PullRequestReview
+        } else if has("comment") {
+            EventType::PullRequestReviewComment
+        } else {
+            EventType::PullRequest
+        }
+    } else if has("issue") {
+        if has("comment") {
+            EventType::IssueComment
+        } else {
+            EventType::Issues
+        }
+    } else if has("comment") {
+        EventType::CommitComment
+    } else if has("deployment_status") {
+        EventType::DeploymentStatus
+    } else if has("deployment") {
+        EventType::Deployment
+    } else if has("forkee") {
+        EventType::Fork
+    } else if has("pages") {
+        EventType::Gollum
+    } else if has("repositories_added") || has("repositories_removed") {
+        EventType::InstallationRepositories
+    } else if has("repositories") && has("installation") {
+        EventType::Installation
+    } else if has("label") {
+        EventType::Label
+    } else if has("scope") && has("member") {
+        EventType::Membership
+    } else if has("member") {
+        EventType::Member
+    } else if has("milestone") {
+        EventType::Milestone
+    } else if has("membership")
+        || has("invitation")
+        || top.action == Some("renamed") && has("organization") && !has("repository")
+    {
+        EventType::Organization
+    } else if has("blocked_user") {
+        EventType::OrgBlock
+    } else if has("build") {
+        EventType::PageBuild
+    } else if has("project_card") {
+        EventType::ProjectCard
+    } else if has("project_column") {
+        EventType::ProjectColumn
+    } else if has("project") {
+        EventType::Project
+    } else if has("release") {
+        EventType::Release
+    } else if has("alert") {
+        EventType::RepositoryVulnerabilityAlert
+    } else if has("security_advisory") {
+        EventType::SecurityAdvisory
+    } else if has("sha") && has("state") {
+        EventType::Status
+    } else if has("team") {
+        if has("action") {
+            EventType::Team
+        } else {
+            EventType::TeamAdd
+        }
+    } else if has("repository") {
+        match top.action {
+            Some("started") => EventType::Watch,
+            Some(_) => EventType::Repository,
+            None if has("status") => EventType::RepositoryImport,
+            None => EventType::Public,
+        }
+    } else if has("action") && has("sender") {
+        // Installations that list no repositories, e.g. when new permissions are accepted.
+        if has("installation") {
+            EventType::Installation
+        } else {
+            EventType::GithubAppAuthorization
+        }
+    } else {
+        return None;
+    };
+    Some(event_type)
+}
+
+/// The keys of a JSON object that aren't `null`, and its `action`.
+struct TopLevel<'a> {
+    keys: Vec<&'a str>,
+    action: Option<&'a str>,
+}
+
+impl<'de> Deserialize<'de> for TopLevel<'de> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_map(TopLevelVisitor)
+    }
+}
+
+struct TopLevelVisitor;
+
+impl<'de> Visitor<'de> for TopLevelVisitor {
+    type Value = TopLevel<'de>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a JSON object")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<TopLevel<'de>, A::Error> {
+        let mut top = TopLevel {
+            keys: Vec::new(),
+            action: None,
+        };
+        while let Some(key) = map.next_key::<&str>()? {
+            let present = if key == "action" {
+                top.action = map.next_value()?;
+                top.action.is_some()
+            } else {
+                map.next_value::<Option<IgnoredAny>>()?.is_some()
+            };
+            // A key that's `null` is as good as missing, as when a payload was serialized with
+            // its optional fields.
+            if present {
+                top.keys.push(key);
+            }
+        }
+        Ok(top)
+    }
+}
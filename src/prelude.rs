@@ -0,0 +1,7 @@
+//! The parts of the standard prelude that come from `alloc`, which `no_std` builds have to
+//! import. Modules that build without `std` glob-import this.
+pub(crate) use alloc::borrow::ToOwned;
+pub(crate) use alloc::boxed::Box;
+pub(crate) use alloc::string::{String, ToString};
+pub(crate) use alloc::vec::Vec;
+pub(crate) use alloc::{format, vec};
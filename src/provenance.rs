@@ -0,0 +1,209 @@
+//! Where an event came from, kept with it from the moment it's received, so that events stored
+//! or passed on can be traced back to how they entered the system.
+//!
+//! [`WebhookDelivery`](../router/struct.WebhookDelivery.html)s carry a [`Provenance`], the
+//! `sink` module's messages carry it in their headers, and log entries of the `jsonl` module
+//! record it. Between services, an [`Envelope<Provenance>`](../envelope/struct.Envelope.html)
+//! carries it beside the event.
+//!
+//! ```
+//! use github_events::provenance::{Provenance, Source};
+//! use github_events::HookId;
+//!
+//! let provenance = Provenance::from_headers([
+//!     ("X-GitHub-Delivery", "72d3162e-cc78-11e3-81ab-4c9367dc0958"),
+//!     ("X-GitHub-Hook-ID", "292430182"),
+//! ]);
+//! assert_eq!(provenance.source, Source::Webhook);
+//! assert_eq!(provenance.hook_id, Some(HookId::new(292430182)));
+//! ```
+use std::fmt;
+use std::str::FromStr;
+use std::time::SystemTime;
+
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{timestamp, HookId};
+
+/// The header [`Provenance::headers`](struct.Provenance.html#method.headers) writes the
+/// [`Source`] to.
+pub const SOURCE_HEADER: &str = "X-Event-Source";
+/// The header [`Provenance::headers`](struct.Provenance.html#method.headers) writes the time
+/// the event was received to.
+pub const RECEIVED_AT_HEADER: &str = "X-Received-At";
+
+/// How an event entered the system.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum Source {
+    /// Delivered to a webhook.
+    #[default]
+    Webhook,
+    /// Polled from the Events API.
+    Poll,
+    /// Replayed from a log, a store, or GitHub's list of a hook's deliveries.
+    Replay,
+    /// Read from an archive such as GH Archive.
+    Archive,
+}
+
+impl Source {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Source::Webhook => "webhook",
+            Source::Poll => "poll",
+            Source::Replay => "replay",
+            Source::Archive => "archive",
+        }
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Source {
+    type Err = UnknownSource;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "webhook" => Ok(Source::Webhook),
+            "poll" => Ok(Source::Poll),
+            "replay" => Ok(Source::Replay),
+            "archive" => Ok(Source::Archive),
+            _ => Err(UnknownSource(s.to_owned())),
+        }
+    }
+}
+
+/// A string that isn't the name of a [`Source`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownSource(pub String);
+
+impl fmt::Display for UnknownSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unknown event source `{}`", self.0)
+    }
+}
+
+impl std::error::Error for UnknownSource {}
+
+/// Where an event came from.
+///
+/// Serialized with `received_at` as a timestamp like `2019-05-15T15:20:33Z`, to the second.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Provenance {
+    /// The GUID of the delivery, from the `X-GitHub-Delivery` header, or for events polled from
+    /// the Events API or read from an archive, the event's id.
+    pub delivery_id: String,
+    #[serde(
+        serialize_with = "serialize_received_at",
+        deserialize_with = "deserialize_received_at"
+    )]
+    pub received_at: SystemTime,
+    pub source: Source,
+    /// The webhook that delivered the event, from the `X-GitHub-Hook-ID` header.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hook_id: Option<HookId>,
+}
+
+impl Provenance {
+    /// An event from `source`, received now.
+    pub fn new(source: Source, delivery_id: impl Into<String>) -> Self {
+        Provenance {
+            delivery_id: delivery_id.into(),
+            received_at: SystemTime::now(),
+            source,
+            hook_id: None,
+        }
+    }
+
+    /// A webhook delivery, received now.
+    pub fn webhook(delivery_id: impl Into<String>) -> Self {
+        Provenance::new(Source::Webhook, delivery_id)
+    }
+
+    pub fn with_received_at(mut self, received_at: SystemTime) -> Self {
+        self.received_at = received_at;
+        self
+    }
+
+    pub fn with_hook_id(mut self, hook_id: HookId) -> Self {
+        self.hook_id = Some(hook_id);
+        self
+    }
+
+    /// The same event, replayed now.
+    pub fn replayed(&self) -> Self {
+        Provenance {
+            received_at: SystemTime::now(),
+            source: Source::Replay,
+            ..self.clone()
+        }
+    }
+
+    /// Read from the headers of a webhook request or of a message written with
+    /// [`headers`](#method.headers), compared case-insensitively. Without the headers this crate
+    /// adds, the event is a webhook delivery received now; without `X-GitHub-Delivery`, its
+    /// delivery id is empty.
+    pub fn from_headers<N, V>(headers: impl IntoIterator<Item = (N, V)>) -> Self
+    where
+        N: AsRef<str>,
+        V: AsRef<str>,
+    {
+        let mut provenance = Provenance::webhook("");
+        for (name, value) in headers {
+            let (name, value) = (name.as_ref(), value.as_ref().trim());
+            if name.eq_ignore_ascii_case("X-GitHub-Delivery") {
+                provenance.delivery_id = value.to_owned();
+            } else if name.eq_ignore_ascii_case("X-GitHub-Hook-ID") {
+                provenance.hook_id = value.parse().ok().map(HookId::new);
+            } else if name.eq_ignore_ascii_case(SOURCE_HEADER) {
+                if let Ok(source) = value.parse() {
+                    provenance.source = source;
+                }
+            } else if name.eq_ignore_ascii_case(RECEIVED_AT_HEADER) {
+                if let Some(received_at) = timestamp::to_system_time(value) {
+                    provenance.received_at = received_at;
+                }
+            }
+        }
+        provenance
+    }
+
+    /// `X-GitHub-Delivery`, `X-GitHub-Hook-ID` if the hook is known, [`SOURCE_HEADER`], and
+    /// [`RECEIVED_AT_HEADER`].
+    pub fn headers(&self) -> Vec<(String, String)> {
+        let mut headers = vec![("X-GitHub-Delivery".to_owned(), self.delivery_id.clone())];
+        if let Some(hook_id) = self.hook_id {
+            headers.push(("X-GitHub-Hook-ID".to_owned(), hook_id.to_string()));
+        }
+        headers.push((SOURCE_HEADER.to_owned(), self.source.as_str().to_owned()));
+        headers.push((
+            RECEIVED_AT_HEADER.to_owned(),
+            timestamp::from_system_time(self.received_at),
+        ));
+        headers
+    }
+}
+
+fn serialize_received_at<S: Serializer>(
+    received_at: &SystemTime,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&timestamp::from_system_time(*received_at))
+}
+
+fn deserialize_received_at<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<SystemTime, D::Error> {
+    let received_at = String::deserialize(deserializer)?;
+    timestamp::to_system_time(&received_at)
+        .ok_or_else(|| D::Error::custom(format_args!("invalid received_at `{}`", received_at)))
+}
@@ -0,0 +1,154 @@
+//! Scrubbing personal data out of events, e.g. to share a payload in a bug report or to store
+//! events under the GDPR.
+//!
+//! Logins, emails, the names that go with them, avatars, and account ids are replaced by
+//! pseudonyms made from 64 bits of an HMAC-SHA256 of the original, so a person gets the same
+//! pseudonym everywhere they appear, in every event and every run. Logins are replaced in urls and repositories' full
+//! names too, but not in other strings, which may be values such as a merge method that happen
+//! to equal a login. Free text such as issue bodies and commit messages is left as is.
+//!
+//! Anyone can recover a pseudonym by hashing candidate logins, so events meant to stay
+//! anonymous should be redacted with a secret salt using [`redact_salted`](fn.redact_salted.html),
+//! which keys the HMAC with it. Even then, free text and the shape of the activity are kept, so
+//! redacted events are pseudonymous rather than anonymous.
+use alloc::collections::BTreeSet;
+
+use hmac::{Hmac, Mac};
+use serde_json::{Map, Value};
+use sha2::Sha256;
+
+use crate::prelude::*;
+use crate::Event;
+
+/// `event` with its personal data replaced by pseudonyms.
+///
+/// Fails if the redacted payload no longer parses as `event`'s type, e.g. because a pseudonym
+/// is longer than a field allows.
+pub fn redact(event: &Event) -> Result<Event, ::serde_json::Error> {
+    redact_salted(event, "")
+}
+
+/// `event` with its personal data replaced by pseudonyms that depend on `salt`, which should be
+/// a secret with enough entropy that it can't be guessed.
+pub fn redact_salted(event: &Event, salt: &str) -> Result<Event, ::serde_json::Error> {
+    let mut payload = match ::serde_json::to_value(event)? {
+        Value::Object(tagged) => tagged.into_iter().next().map(|(_, payload)| payload),
+        _ => None,
+    }
+    .unwrap_or(Value::Null);
+
+    let mut redactor = Redactor {
+        salt,
+        logins: BTreeSet::new(),
+    };
+    redactor.collect_logins(&payload);
+    redactor.redact(&mut payload);
+    Event::from_value(event.event_type(), payload)
+}
+
+/// Whether `s` looks like a url, including the `git@github.com:` form of SSH urls.
+fn is_url(s: &str) -> bool {
+    s.contains("://") || s.starts_with("git@")
+}
+
+struct Redactor<'a> {
+    salt: &'a str,
+    logins: BTreeSet<String>,
+}
+
+impl Redactor<'_> {
+    fn collect_logins(&mut self, value: &Value) {
+        match value {
+            Value::Object(fields) => {
+                for (key, value) in fields {
+                    match (key.as_str(), value) {
+                        ("login", Value::String(login)) | ("username", Value::String(login)) => {
+                            self.logins.insert(login.clone());
+                        }
+                        _ => self.collect_logins(value),
+                    }
+                }
+            }
+            Value::Array(elements) => elements.iter().for_each(|e| self.collect_logins(e)),
+            _ => {}
+        }
+    }
+
+    fn redact(&self, value: &mut Value) {
+        match value {
+            Value::Object(fields) => self.redact_object(fields),
+            Value::Array(elements) => elements.iter_mut().for_each(|e| self.redact(e)),
+            _ => {}
+        }
+    }
+
+    fn redact_object(&self, fields: &mut Map<String, Value>) {
+        // Users have a login, and git identities an email.
+        let is_user = fields.contains_key("login");
+        let is_person = is_user || fields.contains_key("email");
+        for (key, value) in fields.iter_mut() {
+            match (key.as_str(), &mut *value) {
+                ("login", Value::String(s)) | ("username", Value::String(s)) => {
+                    *s = self.login(s);
+                }
+                ("email", Value::String(s)) => {
+                    *s = format!("user-{:016x}@example.com", self.hash(s));
+                }
+                ("name", Value::String(s)) if is_person => {
+                    *s = format!("User {:016x}", self.hash(s));
+                }
+                ("avatar_url", Value::String(s)) => {
+                    *s = format!("https://avatars.githubusercontent.com/u/{}", self.hash(s));
+                }
+                ("gravatar_id", Value::String(s)) => s.clear(),
+                (key, Value::String(s)) if key == "full_name" || is_url(s) => {
+                    *s = self.replace_logins(s);
+                }
+                ("id", Value::Number(n)) if is_user => {
+                    *value = Value::from(self.hash(&n.to_string()) >> 1);
+                }
+                ("node_id", Value::String(s)) if is_user => {
+                    *s = format!("U_{:016x}", self.hash(s));
+                }
+                _ => self.redact(value),
+            }
+        }
+    }
+
+    fn login(&self, login: &str) -> String {
+        format!("user-{:016x}", self.hash(login))
+    }
+
+    /// `s` with the path segments that are logins replaced, e.g. in `https://github.com/octocat`
+    /// or `octocat/Hello-World`.
+    fn replace_logins(&self, s: &str) -> String {
+        if self.logins.is_empty() {
+            return s.to_owned();
+        }
+        let mut out = String::with_capacity(s.len());
+        for segment in s.split_inclusive(['/', ':']) {
+            let (name, delimiter) = match segment.strip_suffix(['/', ':']) {
+                Some(name) => (name, &segment[name.len()..]),
+                None => (segment, ""),
+            };
+            if self.logins.contains(name) {
+                out.push_str(&self.login(name));
+            } else {
+                out.push_str(name);
+            }
+            out.push_str(delimiter);
+        }
+        out
+    }
+
+    /// The first 64 bits of the HMAC-SHA256 of `s` keyed with the salt, which unlike `std`'s
+    /// hashers is stable across releases.
+    fn hash(&self, s: &str) -> u64 {
+        let mut mac = Hmac::<Sha256>::new_from_slice(self.salt.as_bytes()).unwrap();
+        mac.update(s.as_bytes());
+        let digest = mac.finalize().into_bytes();
+        let mut bytes = [0; 8];
+        bytes.copy_from_slice(&digest[..8]);
+        u64::from_be_bytes(bytes)
+    }
+}
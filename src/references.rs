@@ -0,0 +1,210 @@
+//! Finding `@mentions`, issue references, and closing keywords in the Markdown bodies of issues,
+//! pull requests, and comments, the way GitHub links them.
+//!
+//! Text in code blocks and code spans is skipped, since GitHub doesn't link it either.
+use core::fmt;
+
+use crate::prelude::*;
+
+const CLOSING_KEYWORDS: &[&str] = &[
+    "close", "closes", "closed", "fix", "fixes", "fixed", "resolve", "resolves", "resolved",
+];
+
+/// Someone notified by a comment.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Mention {
+    /// `@octocat`
+    User(String),
+    /// `@github/justice-league`
+    Team { org: String, team: String },
+}
+
+/// A reference to an issue or pull request.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IssueReference {
+    /// `owner/repo` for references to another repository, `None` for ones in the same
+    /// repository as the text.
+    pub repository: Option<String>,
+    pub number: u64,
+}
+
+impl IssueReference {
+    /// The full name of the referenced repository, `repository` for references within it.
+    pub fn repository_or<'a>(&'a self, repository: &'a str) -> &'a str {
+        self.repository.as_deref().unwrap_or(repository)
+    }
+}
+
+impl fmt::Display for IssueReference {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.repository {
+            Some(repository) => write!(f, "{}#{}", repository, self.number),
+            None => write!(f, "#{}", self.number),
+        }
+    }
+}
+
+/// The users and teams mentioned in `body`, in order and without repeats.
+pub fn mentions(body: &str) -> Vec<Mention> {
+    let mut mentions = Vec::new();
+    for word in words(body) {
+        if let Some(mention) = parse_mention(word) {
+            if !mentions.contains(&mention) {
+                mentions.push(mention);
+            }
+        }
+    }
+    mentions
+}
+
+/// The issues and pull requests referenced in `body`, as `#123`, `GH-123`, `owner/repo#123`, or
+/// a link, in order and without repeats.
+pub fn issue_references(body: &str) -> Vec<IssueReference> {
+    let mut references = Vec::new();
+    for word in words(body) {
+        if let Some(reference) = parse_reference(word) {
+            if !references.contains(&reference) {
+                references.push(reference);
+            }
+        }
+    }
+    references
+}
+
+/// The issues that merging a pull request with `body` closes: references directly after a
+/// closing keyword, as in `Fixes #12` or `closes: octocat/Hello-World#3`.
+///
+/// As on GitHub, each reference needs its own keyword; `Fixes #1, #2` closes only `#1`.
+pub fn closing_references(body: &str) -> Vec<IssueReference> {
+    let mut references = Vec::new();
+    let words = words(body);
+    for pair in words.windows(2) {
+        if !CLOSING_KEYWORDS
+            .iter()
+            .any(|k| k.eq_ignore_ascii_case(pair[0]))
+        {
+            continue;
+        }
+        if let Some(reference) = parse_reference(pair[1]) {
+            if !references.contains(&reference) {
+                references.push(reference);
+            }
+        }
+    }
+    references
+}
+
+/// The whitespace-separated words of `body` outside code, without the punctuation around them.
+fn words(body: &str) -> Vec<&str> {
+    let mut words = Vec::new();
+    let mut fence: Option<&str> = None;
+    for line in body.lines() {
+        let trimmed = line.trim();
+        if let Some(marker) = fence {
+            if trimmed.starts_with(marker) {
+                fence = None;
+            }
+            continue;
+        }
+        if let Some(marker) = ["```", "~~~"].iter().find(|m| trimmed.starts_with(**m)) {
+            fence = Some(marker);
+            continue;
+        }
+        // Every other piece between backticks is a code span.
+        for text in line.split('`').step_by(2) {
+            words.extend(
+                text.split_whitespace()
+                    .map(|word| word.trim_matches(|c: char| "()[]{}<>,.:;!?\"'*_~".contains(c)))
+                    .filter(|word| !word.is_empty()),
+            );
+        }
+    }
+    words
+}
+
+/// The mention at the start of `word`, which may go on after it, as in `@octocat's`.
+fn parse_mention(word: &str) -> Option<Mention> {
+    let name = word.strip_prefix('@')?;
+    let login = &name[..name.find(|c: char| !is_login_char(c)).unwrap_or(name.len())];
+    if !is_login(login) {
+        return None;
+    }
+    let rest = &name[login.len()..];
+    if let Some(rest) = rest.strip_prefix('/') {
+        let team = &rest[..rest
+            .find(|c: char| !(is_login_char(c) || c == '_'))
+            .unwrap_or(rest.len())];
+        if !team.is_empty() {
+            return Some(Mention::Team {
+                org: login.to_owned(),
+                team: team.to_owned(),
+            });
+        }
+    }
+    Some(Mention::User(login.to_owned()))
+}
+
+fn parse_reference(word: &str) -> Option<IssueReference> {
+    if let Some(path) = word
+        .strip_prefix("https://github.com/")
+        .or_else(|| word.strip_prefix("http://github.com/"))
+    {
+        let mut parts = path.trim_end_matches('/').splitn(4, '/');
+        let (owner, repo, kind, number) =
+            (parts.next()?, parts.next()?, parts.next()?, parts.next()?);
+        if !(kind == "issues" || kind == "pull") || !is_login(owner) || !is_repo(repo) {
+            return None;
+        }
+        return Some(IssueReference {
+            repository: Some(format!("{}/{}", owner, repo)),
+            number: parse_number(number)?,
+        });
+    }
+    if let Some(number) = word
+        .strip_prefix("GH-")
+        .or_else(|| word.strip_prefix("gh-"))
+    {
+        return Some(IssueReference {
+            repository: None,
+            number: parse_number(number)?,
+        });
+    }
+    let (repository, number) = word.split_once('#')?;
+    let repository = match repository.split_once('/') {
+        None if repository.is_empty() => None,
+        Some((owner, repo)) if is_login(owner) && is_repo(repo) => Some(repository.to_owned()),
+        _ => return None,
+    };
+    Some(IssueReference {
+        repository,
+        number: parse_number(number)?,
+    })
+}
+
+fn parse_number(digits: &str) -> Option<u64> {
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    digits.parse().ok().filter(|&n| n > 0)
+}
+
+/// Logins are up to 39 letters, digits, and inner hyphens; apps' bots end in `[bot]`.
+fn is_login(login: &str) -> bool {
+    let login = login.strip_suffix("[bot]").unwrap_or(login);
+    !login.is_empty()
+        && login.len() <= 39
+        && !login.starts_with('-')
+        && !login.ends_with('-')
+        && login.chars().all(is_login_char)
+}
+
+fn is_login_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '-'
+}
+
+fn is_repo(repo: &str) -> bool {
+    !repo.is_empty()
+        && repo
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_' || b == b'.')
+}
@@ -1,62 +1,136 @@
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+use crate::{IStr, MergeMethod, RepositoryId, UserId};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub struct Repository {
-    pub id: i64,
-    pub node_id: String,
-    pub name: String,
-    pub full_name: String,
+    pub id: RepositoryId,
+    pub node_id: IStr,
+    pub name: IStr,
+    pub full_name: IStr,
     pub owner: Owner,
     pub private: bool,
-    pub html_url: String,
+    pub html_url: IStr,
+    #[serde(default, with = "crate::untyped::Json")]
+    #[cfg_attr(feature = "fuzz", arbitrary(with = crate::fuzz::value))]
     pub description: ::serde_json::Value,
     pub fork: bool,
-    pub url: String,
-    pub forks_url: String,
-    pub keys_url: String,
-    pub collaborators_url: String,
-    pub teams_url: String,
-    pub hooks_url: String,
-    pub issue_events_url: String,
-    pub events_url: String,
-    pub assignees_url: String,
-    pub branches_url: String,
-    pub tags_url: String,
-    pub blobs_url: String,
-    pub git_tags_url: String,
-    pub git_refs_url: String,
-    pub trees_url: String,
-    pub statuses_url: String,
-    pub languages_url: String,
-    pub stargazers_url: String,
-    pub contributors_url: String,
-    pub subscribers_url: String,
-    pub subscription_url: String,
-    pub commits_url: String,
-    pub git_commits_url: String,
-    pub comments_url: String,
-    pub issue_comment_url: String,
-    pub contents_url: String,
-    pub compare_url: String,
-    pub merges_url: String,
-    pub archive_url: String,
-    pub downloads_url: String,
-    pub issues_url: String,
-    pub pulls_url: String,
-    pub milestones_url: String,
-    pub notifications_url: String,
-    pub labels_url: String,
-    pub releases_url: String,
-    pub deployments_url: String,
-    pub created_at: String,
-    pub updated_at: String,
-    pub pushed_at: String,
-    pub git_url: String,
-    pub ssh_url: String,
-    pub clone_url: String,
-    pub svn_url: String,
+    pub url: IStr,
+    #[cfg(not(feature = "slim"))]
+    pub forks_url: IStr,
+    #[cfg(not(feature = "slim"))]
+    pub keys_url: IStr,
+    #[cfg(not(feature = "slim"))]
+    pub collaborators_url: IStr,
+    #[cfg(not(feature = "slim"))]
+    pub teams_url: IStr,
+    #[cfg(not(feature = "slim"))]
+    pub hooks_url: IStr,
+    #[cfg(not(feature = "slim"))]
+    pub issue_events_url: IStr,
+    #[cfg(not(feature = "slim"))]
+    pub events_url: IStr,
+    #[cfg(not(feature = "slim"))]
+    pub assignees_url: IStr,
+    #[cfg(not(feature = "slim"))]
+    pub branches_url: IStr,
+    #[cfg(not(feature = "slim"))]
+    pub tags_url: IStr,
+    #[cfg(not(feature = "slim"))]
+    pub blobs_url: IStr,
+    #[cfg(not(feature = "slim"))]
+    pub git_tags_url: IStr,
+    #[cfg(not(feature = "slim"))]
+    pub git_refs_url: IStr,
+    #[cfg(not(feature = "slim"))]
+    pub trees_url: IStr,
+    #[cfg(not(feature = "slim"))]
+    pub statuses_url: IStr,
+    #[cfg(not(feature = "slim"))]
+    pub languages_url: IStr,
+    #[cfg(not(feature = "slim"))]
+    pub stargazers_url: IStr,
+    #[cfg(not(feature = "slim"))]
+    pub contributors_url: IStr,
+    #[cfg(not(feature = "slim"))]
+    pub subscribers_url: IStr,
+    #[cfg(not(feature = "slim"))]
+    pub subscription_url: IStr,
+    #[cfg(not(feature = "slim"))]
+    pub commits_url: IStr,
+    #[cfg(not(feature = "slim"))]
+    pub git_commits_url: IStr,
+    #[cfg(not(feature = "slim"))]
+    pub comments_url: IStr,
+    #[cfg(not(feature = "slim"))]
+    pub issue_comment_url: IStr,
+    #[cfg(not(feature = "slim"))]
+    pub contents_url: IStr,
+    #[cfg(not(feature = "slim"))]
+    pub compare_url: IStr,
+    #[cfg(not(feature = "slim"))]
+    pub merges_url: IStr,
+    #[cfg(not(feature = "slim"))]
+    pub archive_url: IStr,
+    #[cfg(not(feature = "slim"))]
+    pub downloads_url: IStr,
+    #[cfg(not(feature = "slim"))]
+    pub issues_url: IStr,
+    #[cfg(not(feature = "slim"))]
+    pub pulls_url: IStr,
+    #[cfg(not(feature = "slim"))]
+    pub milestones_url: IStr,
+    #[cfg(not(feature = "slim"))]
+    pub notifications_url: IStr,
+    #[cfg(not(feature = "slim"))]
+    pub labels_url: IStr,
+    #[cfg(not(feature = "slim"))]
+    pub releases_url: IStr,
+    #[cfg(not(feature = "slim"))]
+    pub deployments_url: IStr,
+    #[serde(deserialize_with = "crate::timestamp::deserialize")]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(schema_with = "crate::timestamp::schema")
+    )]
+    pub created_at: IStr,
+    #[serde(deserialize_with = "crate::timestamp::deserialize")]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(schema_with = "crate::timestamp::schema")
+    )]
+    pub updated_at: IStr,
+    #[serde(deserialize_with = "crate::timestamp::deserialize")]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(schema_with = "crate::timestamp::schema")
+    )]
+    pub pushed_at: IStr,
+    #[cfg(not(feature = "slim"))]
+    pub git_url: IStr,
+    #[cfg(not(feature = "slim"))]
+    pub ssh_url: IStr,
+    #[cfg(not(feature = "slim"))]
+    pub clone_url: IStr,
+    #[cfg(not(feature = "slim"))]
+    pub svn_url: IStr,
+    #[serde(default, with = "crate::untyped::Json")]
+    #[cfg_attr(feature = "fuzz", arbitrary(with = crate::fuzz::value))]
     pub homepage: ::serde_json::Value,
     pub size: i64,
     pub stargazers_count: i64,
     pub watchers_count: i64,
+    #[serde(default, with = "crate::untyped::Json")]
+    #[cfg_attr(feature = "fuzz", arbitrary(with = crate::fuzz::value))]
     pub language: ::serde_json::Value,
     pub has_issues: bool,
     pub has_projects: bool,
@@ -64,35 +138,186 @@ pub struct Repository {
     pub has_wiki: bool,
     pub has_pages: bool,
     pub forks_count: i64,
+    #[serde(default, with = "crate::untyped::Json")]
+    #[cfg(not(feature = "slim"))]
+    #[cfg_attr(feature = "fuzz", arbitrary(with = crate::fuzz::value))]
     pub mirror_url: ::serde_json::Value,
     pub archived: bool,
     pub open_issues_count: i64,
+    #[serde(default, with = "crate::untyped::Json")]
+    #[cfg_attr(feature = "fuzz", arbitrary(with = crate::fuzz::value))]
     pub license: ::serde_json::Value,
     pub forks: i64,
     pub open_issues: i64,
     pub watchers: i64,
-    pub default_branch: String,
+    pub default_branch: IStr,
+    #[serde(default)]
+    pub topics: Vec<String>,
+    /// Payloads from before GitHub added this leave it `Public`; check `private` for those.
+    #[serde(default)]
+    pub visibility: Visibility,
+    /// Whether new repositories can be generated from this one.
+    #[serde(default)]
+    pub is_template: bool,
+    /// The template this repository was generated from, if any. Only some payloads say.
+    #[serde(default)]
+    pub template_repository: Option<Box<Repository>>,
+    /// Whether pull requests can be merged with a merge commit. Only the repositories of pull
+    /// requests say.
+    #[serde(default)]
+    pub allow_merge_commit: Option<bool>,
+    /// Whether pull requests can be squashed and merged.
+    #[serde(default)]
+    pub allow_squash_merge: Option<bool>,
+    /// Whether pull requests can be rebased and merged.
+    #[serde(default)]
+    pub allow_rebase_merge: Option<bool>,
+    /// Whether pull requests can be set to merge automatically.
+    #[serde(default)]
+    pub allow_auto_merge: Option<bool>,
+    /// Whether head branches are deleted once their pull requests are merged.
+    #[serde(default)]
+    pub delete_branch_on_merge: Option<bool>,
+}
+
+/// Who can see a repository.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum Visibility {
+    #[default]
+    Public,
+    Private,
+    /// Every member of the repository's enterprise.
+    Internal,
+}
+
+impl Repository {
+    /// The ways pull requests can be merged, as far as the payload says.
+    pub fn merge_methods(&self) -> Vec<MergeMethod> {
+        [
+            (self.allow_merge_commit, MergeMethod::Merge),
+            (self.allow_squash_merge, MergeMethod::Squash),
+            (self.allow_rebase_merge, MergeMethod::Rebase),
+        ]
+        .iter()
+        .filter(|(allowed, _)| *allowed == Some(true))
+        .map(|&(_, method)| method)
+        .collect()
+    }
+
+    /// The owner and name of the repository, from its `full_name`.
+    pub fn repo_id(&self) -> RepoId {
+        self.full_name
+            .parse()
+            .unwrap_or_else(|_| RepoId::new(&self.owner.login, &self.name))
+    }
+}
+
+/// A repository's owner and name, written `owner/name`, for keying routing tables and
+/// configuration by repository.
+///
+/// Compared case-insensitively, as GitHub does.
+#[derive(Debug, Clone, Default)]
+pub struct RepoId {
+    pub owner: String,
+    pub name: String,
+}
+
+impl RepoId {
+    pub fn new(owner: &str, name: &str) -> Self {
+        RepoId {
+            owner: owner.to_owned(),
+            name: name.to_owned(),
+        }
+    }
+}
+
+impl PartialEq for RepoId {
+    fn eq(&self, other: &RepoId) -> bool {
+        self.owner.eq_ignore_ascii_case(&other.owner) && self.name.eq_ignore_ascii_case(&other.name)
+    }
+}
+
+impl Eq for RepoId {}
+
+impl Hash for RepoId {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for part in [&self.owner, &self.name] {
+            for b in part.bytes() {
+                state.write_u8(b.to_ascii_lowercase());
+            }
+            state.write_u8(b'/');
+        }
+    }
+}
+
+impl fmt::Display for RepoId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{}", self.owner, self.name)
+    }
+}
+
+/// The error returned when parsing something that isn't `owner/name`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseRepoIdError(pub String);
+
+impl fmt::Display for ParseRepoIdError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "`{}` is not `owner/name`", self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseRepoIdError {}
+
+impl FromStr for RepoId {
+    type Err = ParseRepoIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once('/') {
+            Some((owner, name)) if !owner.is_empty() && !name.is_empty() && !name.contains('/') => {
+                Ok(RepoId::new(owner, name))
+            }
+            _ => Err(ParseRepoIdError(s.to_owned())),
+        }
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub struct Owner {
-    pub login: String,
-    pub id: i64,
-    pub node_id: String,
-    pub avatar_url: String,
-    pub gravatar_id: String,
-    pub url: String,
-    pub html_url: String,
-    pub followers_url: String,
-    pub following_url: String,
-    pub gists_url: String,
-    pub starred_url: String,
-    pub subscriptions_url: String,
-    pub organizations_url: String,
-    pub repos_url: String,
-    pub events_url: String,
-    pub received_events_url: String,
+    pub login: IStr,
+    pub id: UserId,
+    pub node_id: IStr,
+    #[cfg(not(feature = "slim"))]
+    pub avatar_url: IStr,
+    pub gravatar_id: IStr,
+    pub url: IStr,
+    pub html_url: IStr,
+    #[cfg(not(feature = "slim"))]
+    pub followers_url: IStr,
+    #[cfg(not(feature = "slim"))]
+    pub following_url: IStr,
+    #[cfg(not(feature = "slim"))]
+    pub gists_url: IStr,
+    #[cfg(not(feature = "slim"))]
+    pub starred_url: IStr,
+    #[cfg(not(feature = "slim"))]
+    pub subscriptions_url: IStr,
+    #[cfg(not(feature = "slim"))]
+    pub organizations_url: IStr,
+    #[cfg(not(feature = "slim"))]
+    pub repos_url: IStr,
+    #[cfg(not(feature = "slim"))]
+    pub events_url: IStr,
+    #[cfg(not(feature = "slim"))]
+    pub received_events_url: IStr,
     #[serde(rename = "type")]
-    pub type_field: String,
+    pub type_field: IStr,
     pub site_admin: bool,
 }
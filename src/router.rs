@@ -0,0 +1,751 @@
+//! Dispatching webhook deliveries to handlers by event, enabled by the `router` feature.
+//!
+//! An [`EventRouter`] runs the handlers registered for a delivery's event, wrapped in
+//! [`Middleware`]: hooks that run before and after the handlers of every delivery, for logging,
+//! metrics, deduplication, and policies such as which senders to act for. Unlike tower layers,
+//! which see HTTP requests, middleware sees the parsed event.
+//!
+//! A failing handler can be retried with backoff according to a [`RetryPolicy`], and a delivery
+//! whose handler failed for good handed to a [`DeadLetter`] hook, to be stored and replayed later.
+//!
+//! A router handles each delivery as soon as it's given it. A [`Dispatcher`] handles deliveries
+//! concurrently too, but those of the same repository one at a time, in the order they came, so
+//! that the handlers of a pull request's events don't race each other. Before a deploy,
+//! [`Dispatcher::shutdown`] stops it taking deliveries and waits for those in flight.
+//!
+//! ```
+//! use std::sync::Arc;
+//!
+//! use futures::executor::block_on;
+//! use github_events::router::{EventRouter, HandlerError, Outcome, WebhookDelivery};
+//! use github_events::{Event, EventType};
+//!
+//! async fn on_push(delivery: Arc<WebhookDelivery>) -> Result<(), HandlerError> {
+//!     println!("{}", delivery.event.summary());
+//!     Ok(())
+//! }
+//!
+//! let router = EventRouter::new().on(EventType::Push, on_push);
+//! let delivery = WebhookDelivery::new(
+//!     "72d3162e-cc78-11e3-81ab-4c9367dc0958",
+//!     Event::sample(EventType::Push).unwrap(),
+//! );
+//! assert_eq!(block_on(router.dispatch(delivery)).unwrap(), Outcome::Handled);
+//! ```
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::error::Error;
+use std::fmt;
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use futures::channel::oneshot;
+use futures::future::{self, BoxFuture, FutureExt};
+use futures::stream::{self, AbortHandle, Stream, StreamExt};
+
+use crate::filter::EventFilter;
+use crate::provenance::Provenance;
+use crate::wait;
+pub use crate::wait::SleepFn;
+use crate::{Event, EventType};
+
+/// Any failure of a handler or middleware.
+pub type HandlerError = Box<dyn Error + Send + Sync>;
+
+/// A webhook delivery: an event, the GUID of its delivery from the `X-GitHub-Delivery`
+/// header, and where it came from.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct WebhookDelivery {
+    pub id: String,
+    pub event: Event,
+    pub provenance: Provenance,
+}
+
+impl WebhookDelivery {
+    /// A delivery to a webhook, received now.
+    pub fn new(id: impl Into<String>, event: Event) -> Self {
+        WebhookDelivery::from_provenance(Provenance::webhook(id), event)
+    }
+
+    /// A delivery of `event` with the id of `provenance`, e.g. a replayed one.
+    pub fn from_provenance(provenance: Provenance, event: Event) -> Self {
+        WebhookDelivery {
+            id: provenance.delivery_id.clone(),
+            event,
+            provenance,
+        }
+    }
+
+    /// The delivery of a webhook request, received now, once its signature has been checked
+    /// against the secret `resolver` finds for it. Fails with a
+    /// [`VerifyError`](../signature/enum.VerifyError.html) if the signature doesn't check out.
+    ///
+    /// With the `compression` feature, a body compressed on the way is
+    /// [decompressed](../hooks/fn.decompress.html) first.
+    #[cfg(feature = "signature")]
+    pub async fn from_request<N, V>(
+        resolver: &dyn crate::signature::SecretResolver,
+        headers: &[(N, V)],
+        body: &[u8],
+    ) -> Result<Self, HandlerError>
+    where
+        N: AsRef<str>,
+        V: AsRef<str>,
+    {
+        let header = |name: &str| {
+            headers
+                .iter()
+                .find(|(n, _)| n.as_ref().eq_ignore_ascii_case(name))
+                .map(|(_, v)| v.as_ref())
+        };
+        #[cfg(feature = "compression")]
+        let body = &*crate::hooks::decompress(header("Content-Encoding"), body)?;
+        crate::signature::verify_request(resolver, headers, body).await?;
+        let event_type: EventType = header("X-GitHub-Event")
+            .ok_or("the delivery has no X-GitHub-Event header")?
+            .parse()?;
+        let event = Event::from_body(event_type, header("Content-Type"), body)?;
+        let provenance =
+            Provenance::from_headers(headers.iter().map(|(n, v)| (n.as_ref(), v.as_ref())));
+        Ok(WebhookDelivery::from_provenance(provenance, event))
+    }
+}
+
+/// Handles the deliveries routed to it. Implemented by async functions and closures taking an
+/// `Arc<WebhookDelivery>`.
+pub trait Handler: Send + Sync {
+    fn handle(
+        &self,
+        delivery: Arc<WebhookDelivery>,
+    ) -> BoxFuture<'static, Result<(), HandlerError>>;
+}
+
+impl<F, Fut> Handler for F
+where
+    F: Fn(Arc<WebhookDelivery>) -> Fut + Send + Sync,
+    Fut: Future<Output = Result<(), HandlerError>> + Send + 'static,
+{
+    fn handle(
+        &self,
+        delivery: Arc<WebhookDelivery>,
+    ) -> BoxFuture<'static, Result<(), HandlerError>> {
+        Box::pin(self(delivery))
+    }
+}
+
+/// What became of a delivery that didn't fail.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    /// At least one handler ran, and they all succeeded.
+    Handled,
+    /// No handler is registered for the delivery's event.
+    Unrouted,
+    /// A middleware skipped the delivery before it reached the handlers.
+    Skipped,
+}
+
+/// Whether a delivery goes on to the rest of the middleware and the handlers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Control {
+    Continue,
+    /// Stop here, with the outcome [`Skipped`](enum.Outcome.html#variant.Skipped).
+    Skip,
+}
+
+/// Hooks around the handlers of every delivery an [`EventRouter`] dispatches.
+///
+/// `before` hooks run in the order middleware was added; if one skips the delivery or fails, the
+/// rest of them and the handlers don't run. Then the `after` hooks of every middleware whose
+/// `before` ran get the result, in reverse order.
+pub trait Middleware: Send + Sync {
+    fn before<'a>(
+        &'a self,
+        delivery: &'a WebhookDelivery,
+    ) -> BoxFuture<'a, Result<Control, HandlerError>> {
+        let _ = delivery;
+        Box::pin(future::ready(Ok(Control::Continue)))
+    }
+
+    fn after<'a>(
+        &'a self,
+        delivery: &'a WebhookDelivery,
+        result: &'a Result<Outcome, HandlerError>,
+    ) -> BoxFuture<'a, ()> {
+        let _ = (delivery, result);
+        Box::pin(future::ready(()))
+    }
+}
+
+/// Skips deliveries the guard has already seen.
+#[cfg(feature = "dedup")]
+impl<S: crate::dedup::DeliveryStore> Middleware for crate::dedup::DeliveryGuard<S> {
+    fn before<'a>(
+        &'a self,
+        delivery: &'a WebhookDelivery,
+    ) -> BoxFuture<'a, Result<Control, HandlerError>> {
+        Box::pin(async move {
+            if self.check(&delivery.id).await?.is_new() {
+                Ok(Control::Continue)
+            } else {
+                Ok(Control::Skip)
+            }
+        })
+    }
+}
+
+/// Receives the deliveries whose handler failed on its last attempt, with the error, e.g. to store
+/// them for replay. Called before the `after` hooks of middleware.
+pub trait DeadLetter: Send + Sync {
+    fn dead_letter<'a>(
+        &'a self,
+        delivery: &'a WebhookDelivery,
+        error: &'a HandlerError,
+    ) -> BoxFuture<'a, ()>;
+}
+
+/// How often, and how long apart, a failing handler is tried.
+///
+/// Each handler of a delivery is retried on its own, so handlers that already succeeded don't run
+/// again. Waits grow exponentially from the base backoff up to the maximum, with jitter.
+#[derive(Clone)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    base_backoff: Duration,
+    max_backoff: Duration,
+    sleep: Arc<SleepFn>,
+}
+
+impl RetryPolicy {
+    /// Try each handler up to `max_attempts` times, waiting between attempts with `sleep`.
+    /// Backs off from a second up to a minute.
+    pub fn new(
+        max_attempts: u32,
+        sleep: impl Fn(Duration) -> BoxFuture<'static, ()> + Send + Sync + 'static,
+    ) -> Self {
+        RetryPolicy {
+            max_attempts: max_attempts.max(1),
+            base_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(60),
+            sleep: Arc::new(sleep),
+        }
+    }
+
+    pub fn with_backoff(mut self, base: Duration, max: Duration) -> Self {
+        self.base_backoff = base;
+        self.max_backoff = max;
+        self
+    }
+
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// How long to wait before the retry after `retries` retries: at least half the exponential
+    /// backoff, so that deliveries failing together don't retry in lockstep.
+    pub fn backoff(&self, retries: u32) -> Duration {
+        wait::backoff(self.base_backoff, self.max_backoff, retries)
+    }
+}
+
+impl fmt::Debug for RetryPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RetryPolicy")
+            .field("max_attempts", &self.max_attempts)
+            .field("base_backoff", &self.base_backoff)
+            .field("max_backoff", &self.max_backoff)
+            .finish()
+    }
+}
+
+/// Skips deliveries of events that were already stale when they were received.
+impl Middleware for crate::stale::StalePolicy {
+    fn before<'a>(
+        &'a self,
+        delivery: &'a WebhookDelivery,
+    ) -> BoxFuture<'a, Result<Control, HandlerError>> {
+        let received_at = delivery.provenance.received_at;
+        let control = if self.check(&delivery.event, received_at).is_stale() {
+            Control::Skip
+        } else {
+            Control::Continue
+        };
+        Box::pin(future::ready(Ok(control)))
+    }
+}
+
+enum Route {
+    Event(EventType),
+    Filter(EventFilter),
+    Any,
+}
+
+impl Route {
+    fn matches(&self, event: &Event) -> bool {
+        match self {
+            Route::Event(event_type) => event.event_type() == *event_type,
+            Route::Filter(filter) => filter.matches(event),
+            Route::Any => true,
+        }
+    }
+}
+
+/// Dispatches deliveries to the handlers registered for their events.
+///
+/// Every handler whose route matches runs, one after another in the order they were
+/// registered, until one fails, after any retries.
+#[derive(Default)]
+pub struct EventRouter {
+    routes: Vec<(Route, Arc<dyn Handler>)>,
+    middleware: Vec<Arc<dyn Middleware>>,
+    retry: Option<RetryPolicy>,
+    dead_letter: Option<Arc<dyn DeadLetter>>,
+}
+
+impl EventRouter {
+    pub fn new() -> Self {
+        EventRouter::default()
+    }
+
+    /// Handle events of type `event_type` with `handler`.
+    pub fn on(self, event_type: EventType, handler: impl Handler + 'static) -> Self {
+        self.route(Route::Event(event_type), handler)
+    }
+
+    /// Handle the events `filter` matches with `handler`.
+    pub fn on_filter(self, filter: EventFilter, handler: impl Handler + 'static) -> Self {
+        self.route(Route::Filter(filter), handler)
+    }
+
+    /// Handle every event with `handler`.
+    pub fn on_any(self, handler: impl Handler + 'static) -> Self {
+        self.route(Route::Any, handler)
+    }
+
+    fn route(mut self, route: Route, handler: impl Handler + 'static) -> Self {
+        self.routes.push((route, Arc::new(handler)));
+        self
+    }
+
+    /// Wrap the handlers in `middleware`, inside any added before it.
+    pub fn with_middleware(mut self, middleware: impl Middleware + 'static) -> Self {
+        self.middleware.push(Arc::new(middleware));
+        self
+    }
+
+    /// Retry failing handlers according to `policy`. Without one, handlers are tried once.
+    pub fn with_retry(mut self, policy: RetryPolicy) -> Self {
+        self.retry = Some(policy);
+        self
+    }
+
+    /// Hand deliveries whose handler failed on its last attempt to `dead_letter`.
+    pub fn with_dead_letter(mut self, dead_letter: impl DeadLetter + 'static) -> Self {
+        self.dead_letter = Some(Arc::new(dead_letter));
+        self
+    }
+
+    /// Run `delivery` through the middleware and the handlers of its event.
+    pub async fn dispatch(&self, delivery: WebhookDelivery) -> Result<Outcome, HandlerError> {
+        let delivery = Arc::new(delivery);
+        let mut entered = 0;
+        let mut stopped = None;
+        for middleware in &self.middleware {
+            entered += 1;
+            match middleware.before(&delivery).await {
+                Ok(Control::Continue) => {}
+                Ok(Control::Skip) => {
+                    stopped = Some(Ok(Outcome::Skipped));
+                    break;
+                }
+                Err(e) => {
+                    stopped = Some(Err(e));
+                    break;
+                }
+            }
+        }
+        let result = match stopped {
+            Some(result) => result,
+            None => self.handle(&delivery).await,
+        };
+        for middleware in self.middleware[..entered].iter().rev() {
+            middleware.after(&delivery, &result).await;
+        }
+        result
+    }
+
+    async fn handle(&self, delivery: &Arc<WebhookDelivery>) -> Result<Outcome, HandlerError> {
+        let mut outcome = Outcome::Unrouted;
+        for (route, handler) in &self.routes {
+            if route.matches(&delivery.event) {
+                if let Err(e) = self.attempt(&**handler, delivery).await {
+                    if let Some(dead_letter) = &self.dead_letter {
+                        dead_letter.dead_letter(delivery, &e).await;
+                    }
+                    return Err(e);
+                }
+                outcome = Outcome::Handled;
+            }
+        }
+        Ok(outcome)
+    }
+
+    async fn attempt(
+        &self,
+        handler: &dyn Handler,
+        delivery: &Arc<WebhookDelivery>,
+    ) -> Result<(), HandlerError> {
+        let mut retries = 0;
+        loop {
+            let result = handler.handle(delivery.clone()).await;
+            match &self.retry {
+                Some(retry) if result.is_err() && retries + 1 < retry.max_attempts => {
+                    (retry.sleep)(retry.backoff(retries)).await;
+                    retries += 1;
+                }
+                _ => return result,
+            }
+        }
+    }
+}
+
+impl fmt::Debug for EventRouter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("EventRouter")
+            .field("routes", &self.routes.len())
+            .field("middleware", &self.middleware.len())
+            .field("retry", &self.retry)
+            .field("dead_letter", &self.dead_letter.is_some())
+            .finish()
+    }
+}
+
+/// The error of deliveries given to a [`Dispatcher`] after [`shutdown`](struct.Dispatcher.html#method.shutdown)
+/// was called. They aren't handled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShuttingDown;
+
+impl fmt::Display for ShuttingDown {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("the dispatcher is shutting down")
+    }
+}
+
+impl Error for ShuttingDown {}
+
+/// How a [`Dispatcher`] shut down.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Shutdown {
+    /// The ids of the deliveries still being handled, or waiting their turn, when the timeout
+    /// ran out, in the order they were dispatched.
+    pub unprocessed: Vec<String>,
+}
+
+impl Shutdown {
+    /// Whether every delivery was handled in time.
+    pub fn is_clean(&self) -> bool {
+        self.unprocessed.is_empty()
+    }
+}
+
+/// What a [`Dispatcher`] orders deliveries by.
+pub type KeyFn = dyn Fn(&WebhookDelivery) -> Option<String> + Send + Sync;
+
+/// Dispatches deliveries to an [`EventRouter`] concurrently, except that deliveries with the same
+/// key are handled one at a time, in the order they were passed to
+/// [`dispatch`](#method.dispatch).
+///
+/// The key is the repository's full name, or for events outside a repository the
+/// organization's login; deliveries with neither aren't ordered.
+/// [`with_key`](#method.with_key) orders by something else, e.g. the pull request.
+#[derive(Clone)]
+pub struct Dispatcher {
+    router: Arc<EventRouter>,
+    key: Arc<KeyFn>,
+    queues: Arc<Queues>,
+    in_flight: Arc<Mutex<InFlight>>,
+}
+
+impl Dispatcher {
+    pub fn new(router: EventRouter) -> Self {
+        Dispatcher {
+            router: Arc::new(router),
+            key: Arc::new(repository_key),
+            queues: Arc::default(),
+            in_flight: Arc::default(),
+        }
+    }
+
+    /// Order deliveries by `key` instead of by repository. Deliveries it returns `None` for
+    /// aren't ordered.
+    pub fn with_key(
+        mut self,
+        key: impl Fn(&WebhookDelivery) -> Option<String> + Send + Sync + 'static,
+    ) -> Self {
+        self.key = Arc::new(key);
+        self
+    }
+
+    pub fn router(&self) -> &EventRouter {
+        &self.router
+    }
+
+    /// Handle `delivery` once the deliveries with its key that were dispatched before it have
+    /// been handled. The delivery takes its place in line when this is called, not when the
+    /// future is first polled; dropping the future gives the place up.
+    ///
+    /// The future can be spawned, or driven together with others, e.g. by a
+    /// `FuturesUnordered`.
+    pub fn dispatch(
+        &self,
+        delivery: WebhookDelivery,
+    ) -> BoxFuture<'static, Result<Outcome, HandlerError>> {
+        let tracked = match InFlight::track(&self.in_flight, &delivery.id) {
+            Some(tracked) => tracked,
+            None => return Box::pin(future::ready(Err(ShuttingDown.into()))),
+        };
+        let router = self.router.clone();
+        let turn = (self.key)(&delivery).map(|key| self.queues.line_up(key));
+        Box::pin(async move {
+            let _tracked = tracked;
+            let _turn = match turn {
+                Some(turn) => Some(turn.wait().await),
+                None => None,
+            };
+            router.dispatch(delivery).await
+        })
+    }
+
+    /// Handle every delivery of `deliveries`, up to `limit` at a time, yielding each one's id and
+    /// result as it's handled.
+    ///
+    /// Deliveries waiting on earlier ones with their key count towards the limit, so a burst of
+    /// one repository's deliveries can hold up the others until it's been worked through.
+    ///
+    /// Once [`shutdown`](#method.shutdown) is called, no more deliveries are taken from
+    /// `deliveries`, and the stream ends when those taken have been handled.
+    pub fn run(
+        &self,
+        deliveries: impl Stream<Item = WebhookDelivery> + Send + 'static,
+        limit: usize,
+    ) -> impl Stream<Item = (String, Result<Outcome, HandlerError>)> + Send + 'static {
+        let (deliveries, abort) = stream::abortable(deliveries);
+        let running = InFlight::run(&self.in_flight, abort);
+        let dispatcher = self.clone();
+        deliveries
+            .map(move |delivery| {
+                let _running = &running;
+                let id = delivery.id.clone();
+                dispatcher.dispatch(delivery).map(|result| (id, result))
+            })
+            .buffer_unordered(limit.max(1))
+    }
+
+    /// Stop taking deliveries, and wait until those already dispatched have been handled or
+    /// `timeout` completes, e.g. `tokio::time::sleep(Duration::from_secs(30))`.
+    ///
+    /// Deliveries dispatched from then on fail with [`ShuttingDown`]. Deliveries still in flight
+    /// when the timeout runs out are reported, and go on being handled for as long as their
+    /// futures are driven; dropping them cancels them.
+    pub async fn shutdown(&self, timeout: impl Future<Output = ()>) -> Shutdown {
+        let idle = {
+            let mut in_flight = self.in_flight.lock().unwrap();
+            in_flight.closed = true;
+            for (_, run) in in_flight.runs.drain() {
+                run.abort();
+            }
+            if in_flight.deliveries.is_empty() {
+                None
+            } else {
+                let (idle, idled) = oneshot::channel();
+                in_flight.idle.push(idle);
+                Some(idled)
+            }
+        };
+        if let Some(idled) = idle {
+            future::select(idled, std::pin::pin!(timeout)).await;
+        }
+        let in_flight = self.in_flight.lock().unwrap();
+        Shutdown {
+            unprocessed: in_flight.deliveries.values().cloned().collect(),
+        }
+    }
+}
+
+impl fmt::Debug for Dispatcher {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let in_flight = self.in_flight.lock().unwrap();
+        f.debug_struct("Dispatcher")
+            .field("router", &self.router)
+            .field("queues", &self.queues.lines.lock().unwrap().len())
+            .field("in_flight", &in_flight.deliveries.len())
+            .field("runs", &in_flight.runs.len())
+            .finish()
+    }
+}
+
+/// The repository's full name, or failing that the organization's login.
+fn repository_key(delivery: &WebhookDelivery) -> Option<String> {
+    let event = &delivery.event;
+    match (event.repository(), event.organization()) {
+        (Some(repository), _) => Some(repository.full_name.to_lowercase()),
+        (None, Some(organization)) => Some(organization.login.to_lowercase()),
+        (None, None) => None,
+    }
+}
+
+/// The deliveries a [`Dispatcher`] has been given and not yet handled, by when they were
+/// dispatched, and whether it's still taking more.
+#[derive(Default)]
+struct InFlight {
+    closed: bool,
+    next: u64,
+    deliveries: BTreeMap<u64, String>,
+    next_run: u64,
+    /// Streams being [`run`](struct.Dispatcher.html#method.run), to stop on shutdown.
+    runs: HashMap<u64, AbortHandle>,
+    /// Told once there are no deliveries left.
+    idle: Vec<oneshot::Sender<()>>,
+}
+
+impl InFlight {
+    /// Count `id` in flight until the returned guard is dropped, unless shutting down.
+    fn track(in_flight: &Arc<Mutex<InFlight>>, id: &str) -> Option<Tracked> {
+        let mut guard = in_flight.lock().unwrap();
+        if guard.closed {
+            return None;
+        }
+        let position = guard.next;
+        guard.next += 1;
+        guard.deliveries.insert(position, id.to_owned());
+        Some(Tracked {
+            in_flight: in_flight.clone(),
+            position,
+        })
+    }
+
+    /// Keep `abort` to stop a run on shutdown until the returned guard is dropped, or stop it
+    /// right away if already shutting down.
+    fn run(in_flight: &Arc<Mutex<InFlight>>, abort: AbortHandle) -> Running {
+        let mut guard = in_flight.lock().unwrap();
+        let id = guard.next_run;
+        guard.next_run += 1;
+        if guard.closed {
+            abort.abort();
+        } else {
+            guard.runs.insert(id, abort);
+        }
+        Running {
+            in_flight: in_flight.clone(),
+            id,
+        }
+    }
+}
+
+/// A stream being run.
+struct Running {
+    in_flight: Arc<Mutex<InFlight>>,
+    id: u64,
+}
+
+impl Drop for Running {
+    fn drop(&mut self) {
+        self.in_flight.lock().unwrap().runs.remove(&self.id);
+    }
+}
+
+/// A delivery in flight.
+struct Tracked {
+    in_flight: Arc<Mutex<InFlight>>,
+    position: u64,
+}
+
+impl Drop for Tracked {
+    fn drop(&mut self) {
+        let mut in_flight = self.in_flight.lock().unwrap();
+        in_flight.deliveries.remove(&self.position);
+        if in_flight.deliveries.is_empty() {
+            for idle in in_flight.idle.drain(..) {
+                let _ = idle.send(());
+            }
+        }
+    }
+}
+
+/// The deliveries in line for a key, by position. The first is being handled, and the others
+/// are told when it's their turn.
+type Line = VecDeque<(u64, Option<oneshot::Sender<()>>)>;
+
+/// The line of each key.
+#[derive(Default)]
+struct Queues {
+    lines: Mutex<HashMap<String, Line>>,
+    next: AtomicU64,
+}
+
+impl Queues {
+    fn line_up(self: &Arc<Self>, key: String) -> Turn {
+        let mut lines = self.lines.lock().unwrap();
+        let position = self.next.fetch_add(1, Ordering::Relaxed);
+        let line = lines.entry(key.clone()).or_default();
+        let waiting = if line.is_empty() {
+            line.push_back((position, None));
+            None
+        } else {
+            let (go, waiting) = oneshot::channel();
+            line.push_back((position, Some(go)));
+            Some(waiting)
+        };
+        Turn {
+            queues: self.clone(),
+            key,
+            position,
+            waiting,
+        }
+    }
+}
+
+/// A delivery's place in line. Dropping it lets the next one go if it was first, or else gives
+/// the place up.
+struct Turn {
+    queues: Arc<Queues>,
+    key: String,
+    position: u64,
+    waiting: Option<oneshot::Receiver<()>>,
+}
+
+impl Turn {
+    async fn wait(mut self) -> Self {
+        if let Some(waiting) = self.waiting.take() {
+            let _ = waiting.await;
+        }
+        self
+    }
+}
+
+impl Drop for Turn {
+    fn drop(&mut self) {
+        let mut lines = self.queues.lines.lock().unwrap();
+        let line = match lines.get_mut(&self.key) {
+            Some(line) => line,
+            None => return,
+        };
+        if let Some(index) = line
+            .iter()
+            .position(|&(position, _)| position == self.position)
+        {
+            line.remove(index);
+            // One giving up its place behind another mustn't let the next overtake that one.
+            if let (0, Some((_, go))) = (index, line.front_mut()) {
+                if let Some(go) = go.take() {
+                    let _ = go.send(());
+                }
+            }
+        }
+        if line.is_empty() {
+            lines.remove(&self.key);
+        }
+    }
+}
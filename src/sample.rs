@@ -0,0 +1,1163 @@
+//! Realistic example payloads, modelled on the examples in GitHub's webhook documentation.
+//!
+//! Every sample happens in `octocat/Hello-World`, is sent by `octocat`, and refers to the same
+//! commit, issue, and pull request, so the urls and ids in one agree with those in the others.
+use serde_json::{json, Value};
+
+use crate::deliveries::{HookDelivery, HookDeliveryDetail};
+use crate::prelude::*;
+use crate::{
+    node_id, App, Comment, Commit, Event, EventType, Installation, Issue, Label, Organization,
+    Owner, PullRequest, PullRequestRef, Repository, User,
+};
+
+const API: &str = "https://api.github.com";
+const WEB: &str = "https://github.com";
+const REPO: &str = "octocat/Hello-World";
+const SHA: &str = "6dcb09b5b57875f334f61aebed695e2e4193db5e";
+const BEFORE: &str = "9049f1265b7d61be4a8904a9a27120d2064dab3b";
+const CREATED_AT: &str = "2011-01-26T19:01:12Z";
+const UPDATED_AT: &str = "2011-01-26T19:14:43Z";
+
+pub(crate) fn parse<T: serde::de::DeserializeOwned>(value: Value) -> T {
+    ::serde_json::from_value(value).expect("samples match their types")
+}
+
+impl Repository {
+    /// The `octocat/Hello-World` repository.
+    pub fn sample() -> Self {
+        parse(repository())
+    }
+}
+
+impl User {
+    /// The `octocat` user.
+    pub fn sample() -> Self {
+        parse(user("octocat", 1))
+    }
+}
+
+impl Organization {
+    /// The `github` organization.
+    pub fn sample() -> Self {
+        parse(organization())
+    }
+}
+
+impl Installation {
+    /// An installation of an app on `octocat`'s account.
+    pub fn sample() -> Self {
+        parse(installation())
+    }
+}
+
+impl Owner {
+    /// `octocat`, as the owner of `octocat/Hello-World`.
+    pub fn sample() -> Self {
+        parse(user("octocat", 1))
+    }
+}
+
+impl App {
+    /// The `Super CI` app, owned by `octocat`.
+    pub fn sample() -> Self {
+        parse(app())
+    }
+}
+
+impl Commit {
+    /// The commit that updated the README of `octocat/Hello-World`, as a push lists it.
+    pub fn sample() -> Self {
+        parse(commit())
+    }
+}
+
+impl Label {
+    /// The `bug` label of `octocat/Hello-World`.
+    pub fn sample() -> Self {
+        parse(label())
+    }
+}
+
+impl Issue {
+    /// Issue #1347 of `octocat/Hello-World`, opened by `octocat`.
+    pub fn sample() -> Self {
+        parse(issue())
+    }
+}
+
+impl Comment {
+    /// `octocat`'s comment on issue #1347 of `octocat/Hello-World`.
+    pub fn sample() -> Self {
+        parse(comment())
+    }
+}
+
+impl PullRequest {
+    /// Pull request #1347 of `octocat/Hello-World`, opened by `octocat`.
+    pub fn sample() -> Self {
+        parse(pull_request())
+    }
+}
+
+impl PullRequestRef {
+    /// Pull request #1347 of `octocat/Hello-World`, as a check suite lists it.
+    pub fn sample() -> Self {
+        parse(pull_request_ref())
+    }
+}
+
+impl HookDelivery {
+    /// A successful delivery of the sample `push` event to `https://example.com/webhook`.
+    pub fn sample() -> Self {
+        parse(delivery())
+    }
+}
+
+impl HookDeliveryDetail {
+    /// [`HookDelivery::sample`](deliveries/struct.HookDelivery.html#method.sample), with the
+    /// request GitHub sent and the response it received.
+    pub fn sample() -> Self {
+        let mut detail = delivery();
+        detail["url"] = json!("https://example.com/webhook");
+        detail["request"] = json!({
+            "headers": {
+                "Content-Type": "application/json",
+                "X-GitHub-Delivery": "0b989ba4-242f-11e5-81e1-c7b6966d2516",
+                "X-GitHub-Event": "push",
+                "X-GitHub-Hook-ID": "1",
+            },
+            "payload": payload(EventType::Push),
+        });
+        detail["response"] = json!({
+            "headers": { "Content-Type": "text/plain" },
+            "payload": "ok",
+        });
+        parse(detail)
+    }
+}
+
+impl Event {
+    /// A fully populated example of an event of type `event_type`, for use as a test baseline.
+    ///
+    /// `None` if the event's family is turned off.
+    pub fn sample(event_type: EventType) -> Option<Event> {
+        Event::from_value(event_type, payload(event_type)).ok()
+    }
+}
+
+/// The webhook body of the sample event of type `event_type`.
+pub(crate) fn payload(event_type: EventType) -> Value {
+    match event_type {
+        EventType::CheckRun => json!({
+            "action": "created",
+            "check_run": check_run(),
+            "repository": repository(),
+            "organization": organization(),
+            "sender": user("octocat", 1),
+            "installation": installation(),
+        }),
+        EventType::CheckSuite => json!({
+            "action": "completed",
+            "check_suite": check_suite(),
+        }),
+        EventType::CommitComment => json!({
+            "action": "created",
+            "comment": commit_comment(),
+            "repository": repository(),
+            "sender": user("octocat", 1),
+        }),
+        EventType::Create => json!({
+            "ref": "simple-tag",
+            "ref_type": "tag",
+            "master_branch": "master",
+            "description": null,
+            "pusher_type": "user",
+            "repository": repository(),
+            "sender": user("octocat", 1),
+        }),
+        EventType::Delete => json!({
+            "ref": "simple-tag",
+            "ref_type": "tag",
+            "pusher_type": "user",
+        }),
+        EventType::Deployment => json!({
+            "deployment": deployment(),
+            "repository": repository(),
+            "sender": user("octocat", 1),
+        }),
+        EventType::DeploymentStatus => json!({
+            "deployment_status": {
+                "url": format!("{}/repos/{}/deployments/1/statuses/1", API, REPO),
+                "id": 1,
+                "node_id": "MDE2OkRlcGxveW1lbnRTdGF0dXMx",
+                "state": "success",
+                "creator": user("octocat", 1),
+                "description": "Deployment finished successfully.",
+                "target_url": "https://example.com/deployment/1/output",
+                "created_at": CREATED_AT,
+                "updated_at": UPDATED_AT,
+                "deployment_url": format!("{}/repos/{}/deployments/1", API, REPO),
+                "repository_url": format!("{}/repos/{}", API, REPO),
+            },
+            "deployment": deployment(),
+            "repository": repository(),
+            "sender": user("octocat", 1),
+        }),
+        EventType::Fork => {
+            let mut forkee = repository_named("hubot", 2, "Hello-World", 1296270);
+            forkee["fork"] = json!(true);
+            forkee["public"] = json!(true);
+            json!({
+                "forkee": forkee,
+                "repository": repository(),
+                "sender": user("hubot", 2),
+            })
+        }
+        EventType::GithubAppAuthorization => json!({
+            "action": "revoked",
+            "sender": user("octocat", 1),
+        }),
+        EventType::Gollum => json!({
+            "pages": [{
+                "page_name": "Home",
+                "title": "Home",
+                "summary": null,
+                "action": "created",
+                "sha": "91ea1bd42aa2ba166b86e8aefe049e9837214e67",
+                "html_url": format!("{}/{}/wiki/Home", WEB, REPO),
+            }],
+            "repository": repository(),
+            "sender": user("octocat", 1),
+        }),
+        EventType::Installation => json!({
+            "action": "created",
+            "installation": installation(),
+            "repositories": [partial_repository()],
+            "sender": user("octocat", 1),
+        }),
+        EventType::InstallationRepositories => json!({
+            "action": "added",
+            "installation": installation(),
+            "repository_selection": "selected",
+            "repositories_added": [partial_repository()],
+            "repositories_removed": [],
+            "sender": user("octocat", 1),
+        }),
+        EventType::IssueComment => json!({
+            "action": "created",
+            "changes": null,
+            "issue": issue(),
+            "comment": comment(),
+            "repository": repository(),
+            "sender": user("octocat", 1),
+        }),
+        EventType::Issues => json!({
+            "action": "opened",
+            "issue": issue(),
+            "changes": null,
+            "repository": repository(),
+            "sender": user("octocat", 1),
+        }),
+        EventType::Label => json!({
+            "action": "created",
+            "label": label(),
+            "changes": null,
+            "repository": repository(),
+            "sender": user("octocat", 1),
+        }),
+        EventType::Member => json!({
+            "action": "added",
+            "member": user("hubot", 2),
+            "changes": {"permission": {"from": "read"}},
+            "repository": repository(),
+            "sender": user("octocat", 1),
+        }),
+        EventType::Membership => json!({
+            "action": "added",
+            "scope": "team",
+            "member": user("hubot", 2),
+            "sender": user("octocat", 1),
+            "team": team(),
+            "organization": organization(),
+        }),
+        EventType::Milestone => json!({
+            "action": "created",
+            "milestone": milestone(),
+            "changes": null,
+            "repository": repository(),
+            "sender": user("octocat", 1),
+        }),
+        EventType::Organization => json!({
+            "action": "member_added",
+            "invitation": null,
+            "membership": {
+                "url": format!("{}/orgs/github/memberships/hubot", API),
+                "state": "active",
+                "role": "member",
+                "organization_url": format!("{}/orgs/github", API),
+                "user": user("hubot", 2),
+            },
+            "organization": organization(),
+            "sender": user("octocat", 1),
+        }),
+        EventType::OrgBlock => json!({
+            "action": "blocked",
+            "blocked_user": user("hubot", 2),
+            "organization": organization(),
+            "sender": user("octocat", 1),
+        }),
+        EventType::PageBuild => json!({
+            "id": 1,
+            "build": {
+                "url": format!("{}/repos/{}/pages/builds/1", API, REPO),
+                "status": "built",
+                "error": {"message": null},
+                "pusher": user("octocat", 1),
+                "commit": SHA,
+                "duration": 2104,
+                "created_at": CREATED_AT,
+                "updated_at": UPDATED_AT,
+            },
+            "repository": repository(),
+            "sender": user("octocat", 1),
+        }),
+        EventType::Ping => json!({
+            "zen": "Keep it logically awesome.",
+            "hook_id": 1,
+            "hook": {
+                "type": "Repository",
+                "id": 1,
+                "name": "web",
+                "active": true,
+                "events": ["push", "pull_request"],
+                "config": {
+                    "content_type": "json",
+                    "insecure_ssl": "0",
+                    "url": "https://example.com/webhook",
+                },
+                "created_at": CREATED_AT,
+                "updated_at": UPDATED_AT,
+                "url": format!("{}/repos/{}/hooks/1", API, REPO),
+                "ping_url": format!("{}/repos/{}/hooks/1/pings", API, REPO),
+                "deliveries_url": format!("{}/repos/{}/hooks/1/deliveries", API, REPO),
+            },
+            "repository": repository(),
+            "sender": user("octocat", 1),
+        }),
+        EventType::ProjectCard => json!({
+            "action": "created",
+            "changes": null,
+            "after_id": null,
+            "project_card": {
+                "url": format!("{}/projects/columns/cards/1", API),
+                "project_url": format!("{}/projects/1", API),
+                "column_url": format!("{}/projects/columns/1", API),
+                "column_id": 1,
+                "id": 1,
+                "node_id": "MDExOlByb2plY3RDYXJkMQ==",
+                "note": "Add payload for delete Project column",
+                "creator": user("octocat", 1),
+                "created_at": CREATED_AT,
+                "updated_at": UPDATED_AT,
+            },
+            "repository": repository(),
+            "sender": user("octocat", 1),
+        }),
+        EventType::ProjectColumn => json!({
+            "action": "created",
+            "changes": null,
+            "after_id": null,
+            "project_column": {
+                "url": format!("{}/projects/columns/1", API),
+                "project_url": format!("{}/projects/1", API),
+                "cards_url": format!("{}/projects/columns/1/cards", API),
+                "id": 1,
+                "node_id": "MDEzOlByb2plY3RDb2x1bW4x",
+                "name": "To Do",
+                "created_at": CREATED_AT,
+                "updated_at": UPDATED_AT,
+            },
+            "repository": repository(),
+            "sender": user("octocat", 1),
+        }),
+        EventType::Project => json!({
+            "action": "created",
+            "changes": null,
+            "project": {
+                "owner_url": format!("{}/repos/{}", API, REPO),
+                "url": format!("{}/projects/1", API),
+                "html_url": format!("{}/{}/projects/1", WEB, REPO),
+                "columns_url": format!("{}/projects/1/columns", API),
+                "id": 1,
+                "node_id": "MDc6UHJvamVjdDE=",
+                "name": "Space 2.0",
+                "body": "Project tasks for a trip to Space",
+                "number": 1,
+                "state": "open",
+                "creator": user("octocat", 1),
+                "created_at": CREATED_AT,
+                "updated_at": UPDATED_AT,
+            },
+            "repository": repository(),
+            "sender": user("octocat", 1),
+        }),
+        EventType::Public => json!({
+            "repository": repository(),
+            "sender": user("octocat", 1),
+        }),
+        EventType::PullRequest => json!({
+            "action": "opened",
+            "number": 1347,
+            "changes": null,
+            "pull_request": pull_request(),
+            "repository": repository(),
+            "sender": user("octocat", 1),
+        }),
+        EventType::PullRequestReview => json!({
+            "action": "submitted",
+            "changes": null,
+            "review": {
+                "id": 80,
+                "node_id": "MDE3OlB1bGxSZXF1ZXN0UmV2aWV3ODA=",
+                "user": user("octocat", 1),
+                "body": "Here is the body for the review.",
+                "commit_id": SHA,
+                "submitted_at": CREATED_AT,
+                "state": "approved",
+                "html_url": format!("{}/{}/pull/1347#pullrequestreview-80", WEB, REPO),
+                "pull_request_url": format!("{}/repos/{}/pulls/1347", API, REPO),
+                "author_association": "OWNER",
+                "_links": {
+                    "html": {"href": format!("{}/{}/pull/1347#pullrequestreview-80", WEB, REPO)},
+                    "pull_request": {"href": format!("{}/repos/{}/pulls/1347", API, REPO)},
+                },
+            },
+            "pull_request": pull_request(),
+            "repository": repository(),
+            "sender": user("octocat", 1),
+        }),
+        EventType::PullRequestReviewComment => json!({
+            "action": "created",
+            "comment": {
+                "url": format!("{}/repos/{}/pulls/comments/1", API, REPO),
+                "html_url": format!("{}/{}/pull/1347#discussion-diff-1", WEB, REPO),
+                "id": 1,
+                "node_id": "MDI0OlB1bGxSZXF1ZXN0UmV2aWV3Q29tbWVudDE=",
+                "user": user("octocat", 1),
+                "position": 1,
+                "line": 1,
+                "path": "README",
+                "commit_id": SHA,
+                "created_at": CREATED_AT,
+                "updated_at": UPDATED_AT,
+                "author_association": "OWNER",
+                "body": "Great stuff!",
+            },
+            "changes": null,
+            "pull_request": pull_request(),
+            "repository": repository(),
+            "sender": user("octocat", 1),
+        }),
+        EventType::Push => json!({
+            "ref": "refs/heads/master",
+            "head": SHA,
+            "before": BEFORE,
+            "after": SHA,
+            "created": false,
+            "deleted": false,
+            "forced": false,
+            "base_ref": null,
+            "compare": format!("{}/{}/compare/{}...{}", WEB, REPO, &BEFORE[..12], &SHA[..12]),
+            "commits": [commit()],
+            "head_commit": {
+                "id": SHA,
+                "tree_id": "f9d2a07e9488b91af2641b26b9407fe22a451433",
+                "distinct": true,
+                "message": "Update README",
+                "timestamp": CREATED_AT,
+                "url": format!("{}/{}/commit/{}", WEB, REPO, SHA),
+                "author": author(),
+                "committer": author(),
+                "added": [],
+                "removed": [],
+                "modified": ["README"],
+            },
+            "repository": repository(),
+            "pusher": author(),
+            "sender": user("octocat", 1),
+        }),
+        EventType::Release => json!({
+            "action": "published",
+            "release": {
+                "url": format!("{}/repos/{}/releases/1", API, REPO),
+                "assets_url": format!("{}/repos/{}/releases/1/assets", API, REPO),
+                "upload_url": "https://uploads.github.com/repos/octocat/Hello-World/releases/1/assets{?name,label}",
+                "html_url": format!("{}/{}/releases/tag/v1.0.0", WEB, REPO),
+                "id": 1,
+                "node_id": "MDc6UmVsZWFzZTE=",
+                "tag_name": "v1.0.0",
+                "target_commitish": "master",
+                "name": "v1.0.0",
+                "draft": false,
+                "author": user("octocat", 1),
+                "prerelease": false,
+                "created_at": CREATED_AT,
+                "published_at": UPDATED_AT,
+                "assets": [],
+                "tarball_url": format!("{}/repos/{}/tarball/v1.0.0", API, REPO),
+                "zipball_url": format!("{}/repos/{}/zipball/v1.0.0", API, REPO),
+                "body": "Description of the release",
+            },
+            "repository": repository(),
+            "sender": user("octocat", 1),
+        }),
+        EventType::Repository => json!({
+            "action": "publicized",
+            "repository": repository(),
+            "sender": user("octocat", 1),
+        }),
+        EventType::RepositoryDispatch => json!({
+            "action": "deploy",
+            "branch": "master",
+            "client_payload": {"environment": "production"},
+            "repository": repository(),
+            "sender": user("octocat", 1),
+            "installation": installation(),
+        }),
+        EventType::RepositoryImport => json!({
+            "status": "success",
+            "repository": repository(),
+            "organization": organization(),
+            "sender": user("octocat", 1),
+        }),
+        EventType::RepositoryVulnerabilityAlert => json!({
+            "action": "dismiss",
+            "alert": {
+                "id": 91095730,
+                "affected_range": ">= 2.0.4, < 2.0.6",
+                "affected_package_name": "rack",
+                "external_reference": "https://nvd.nist.gov/vuln/detail/CVE-2018-16470",
+                "external_identifier": "CVE-2018-16470",
+                "fixed_in": "2.0.6",
+                "dismisser": user("octocat", 1),
+                "dismiss_reason": "No bandwidth to fix this",
+                "dismissed_at": UPDATED_AT,
+            },
+        }),
+        EventType::SecurityAdvisory => json!({
+            "action": "published",
+            "security_advisory": {
+                "ghsa_id": "GHSA-rf4j-j272-fj86",
+                "summary": "Moderate severity vulnerability that affects rack",
+                "description": "Rack before 2.0.6 has a possible XSS vulnerability.",
+                "severity": "moderate",
+                "identifiers": [
+                    {"value": "GHSA-rf4j-j272-fj86", "type": "GHSA"},
+                    {"value": "CVE-2018-16470", "type": "CVE"},
+                ],
+                "references": [{"url": "https://nvd.nist.gov/vuln/detail/CVE-2018-16470"}],
+                "published_at": CREATED_AT,
+                "updated_at": UPDATED_AT,
+                "withdrawn_at": null,
+                "vulnerabilities": [{
+                    "package": {"ecosystem": "rubygems", "name": "rack"},
+                    "severity": "moderate",
+                    "vulnerable_version_range": ">= 2.0.4, < 2.0.6",
+                    "first_patched_version": {"identifier": "2.0.6"},
+                }],
+            },
+        }),
+        EventType::Status => json!({
+            "id": 1,
+            "sha": SHA,
+            "name": REPO,
+            "target_url": "https://ci.example.com/builds/1",
+            "context": "ci/build",
+            "description": "The build succeeded",
+            "state": "success",
+            "commit": status_commit(),
+            "branches": [{
+                "name": "master",
+                "commit": {"sha": SHA, "url": format!("{}/repos/{}/commits/{}", API, REPO, SHA)},
+            }],
+            "created_at": CREATED_AT,
+            "updated_at": UPDATED_AT,
+            "repository": repository(),
+            "sender": user("octocat", 1),
+        }),
+        EventType::Team => {
+            let mut repository = repository();
+            repository["permissions"] = json!({"pull": true, "push": true, "admin": false});
+            json!({
+                "action": "added_to_repository",
+                "team": team(),
+                "changes": null,
+                "repository": repository,
+                "organization": organization(),
+                "sender": user("octocat", 1),
+            })
+        }
+        EventType::TeamAdd => json!({
+            "team": team(),
+            "repository": repository(),
+            "organization": organization(),
+            "sender": user("octocat", 1),
+        }),
+        EventType::Watch => json!({
+            "action": "started",
+            "repository": repository(),
+            "sender": user("octocat", 1),
+        }),
+    }
+}
+
+/// A user, with every url that comes with one.
+pub(crate) fn user(login: &str, id: i64) -> Value {
+    let url = format!("{}/users/{}", API, login);
+    json!({
+        "login": login,
+        "id": id,
+        "node_id": node_id::legacy("User", id),
+        "avatar_url": format!("https://avatars.githubusercontent.com/u/{}?v=4", id),
+        "gravatar_id": "",
+        "url": url,
+        "html_url": format!("{}/{}", WEB, login),
+        "followers_url": format!("{}/followers", url),
+        "following_url": format!("{}/following{{/other_user}}", url),
+        "gists_url": format!("{}/gists{{/gist_id}}", url),
+        "starred_url": format!("{}/starred{{/owner}}{{/repo}}", url),
+        "subscriptions_url": format!("{}/subscriptions", url),
+        "organizations_url": format!("{}/orgs", url),
+        "repos_url": format!("{}/repos", url),
+        "events_url": format!("{}/events{{/privacy}}", url),
+        "received_events_url": format!("{}/received_events", url),
+        "type": "User",
+        "site_admin": false,
+    })
+}
+
+fn delivery() -> Value {
+    json!({
+        "id": 12345678,
+        "guid": "0b989ba4-242f-11e5-81e1-c7b6966d2516",
+        "delivered_at": UPDATED_AT,
+        "redelivery": false,
+        "duration": 0.27,
+        "status": "OK",
+        "status_code": 200,
+        "event": "push",
+        "action": null,
+        "installation_id": null,
+        "repository_id": 1296269,
+    })
+}
+
+fn repository() -> Value {
+    repository_named("octocat", 1, "Hello-World", 1296269)
+}
+
+/// The hypermedia urls of a repository, relative to its API url.
+const REPOSITORY_URLS: &[(&str, &str)] = &[
+    ("forks_url", "/forks"),
+    ("keys_url", "/keys{/key_id}"),
+    ("collaborators_url", "/collaborators{/collaborator}"),
+    ("teams_url", "/teams"),
+    ("hooks_url", "/hooks"),
+    ("issue_events_url", "/issues/events{/number}"),
+    ("events_url", "/events"),
+    ("assignees_url", "/assignees{/user}"),
+    ("branches_url", "/branches{/branch}"),
+    ("tags_url", "/tags"),
+    ("blobs_url", "/git/blobs{/sha}"),
+    ("git_tags_url", "/git/tags{/sha}"),
+    ("git_refs_url", "/git/refs{/sha}"),
+    ("trees_url", "/git/trees{/sha}"),
+    ("statuses_url", "/statuses/{sha}"),
+    ("languages_url", "/languages"),
+    ("stargazers_url", "/stargazers"),
+    ("contributors_url", "/contributors"),
+    ("subscribers_url", "/subscribers"),
+    ("subscription_url", "/subscription"),
+    ("commits_url", "/commits{/sha}"),
+    ("git_commits_url", "/git/commits{/sha}"),
+    ("comments_url", "/comments{/number}"),
+    ("issue_comment_url", "/issues/comments{/number}"),
+    ("contents_url", "/contents/{+path}"),
+    ("compare_url", "/compare/{base}...{head}"),
+    ("merges_url", "/merges"),
+    ("archive_url", "/{archive_format}{/ref}"),
+    ("downloads_url", "/downloads"),
+    ("issues_url", "/issues{/number}"),
+    ("pulls_url", "/pulls{/number}"),
+    ("milestones_url", "/milestones{/number}"),
+    (
+        "notifications_url",
+        "/notifications{?since,all,participating}",
+    ),
+    ("labels_url", "/labels{/name}"),
+    ("releases_url", "/releases{/id}"),
+    ("deployments_url", "/deployments"),
+];
+
+pub(crate) fn repository_named(owner: &str, owner_id: i64, name: &str, id: i64) -> Value {
+    let full_name = format!("{}/{}", owner, name);
+    let url = format!("{}/repos/{}", API, full_name);
+    let html_url = format!("{}/{}", WEB, full_name);
+    let mut repository = json!({
+        "id": id,
+        "node_id": node_id::legacy("Repository", id),
+        "name": name,
+        "full_name": full_name,
+        "owner": user(owner, owner_id),
+        "private": false,
+        "html_url": html_url,
+        "description": "This your first repo!",
+        "fork": false,
+        "url": url,
+        "created_at": CREATED_AT,
+        "updated_at": UPDATED_AT,
+        "pushed_at": UPDATED_AT,
+        "git_url": format!("git://github.com/{}.git", full_name),
+        "ssh_url": format!("git@github.com:{}.git", full_name),
+        "clone_url": format!("{}.git", html_url),
+        "svn_url": html_url,
+        "homepage": "https://github.com",
+        "size": 108,
+        "stargazers_count": 80,
+        "watchers_count": 80,
+        "language": "C",
+        "has_issues": true,
+        "has_projects": true,
+        "has_downloads": true,
+        "has_wiki": true,
+        "has_pages": false,
+        "forks_count": 9,
+        "mirror_url": null,
+        "archived": false,
+        "open_issues_count": 0,
+        "license": {
+            "key": "mit",
+            "name": "MIT License",
+            "spdx_id": "MIT",
+            "url": format!("{}/licenses/mit", API),
+            "node_id": "MDc6TGljZW5zZW1pdA==",
+        },
+        "forks": 9,
+        "open_issues": 0,
+        "watchers": 80,
+        "default_branch": "master",
+    });
+    for (key, path) in REPOSITORY_URLS {
+        repository[*key] = json!(format!("{}{}", url, path));
+    }
+    repository
+}
+
+fn partial_repository() -> Value {
+    json!({
+        "id": 1296269,
+        "name": "Hello-World",
+        "full_name": REPO,
+        "private": false,
+    })
+}
+
+fn organization() -> Value {
+    let url = format!("{}/orgs/github", API);
+    json!({
+        "login": "github",
+        "id": 1,
+        "node_id": node_id::legacy("Organization", 1),
+        "url": url,
+        "repos_url": format!("{}/repos", url),
+        "events_url": format!("{}/events", url),
+        "hooks_url": format!("{}/hooks", url),
+        "issues_url": format!("{}/issues", url),
+        "members_url": format!("{}/members{{/member}}", url),
+        "public_members_url": format!("{}/public_members{{/member}}", url),
+        "avatar_url": "https://github.com/images/error/octocat_happy.gif",
+        "description": "A great organization",
+    })
+}
+
+fn installation() -> Value {
+    json!({
+        "id": 1,
+        "account": user("octocat", 1),
+        "repository_selection": "selected",
+        "access_tokens_url": format!("{}/app/installations/1/access_tokens", API),
+        "repositories_url": format!("{}/installation/repositories", API),
+        "html_url": format!("{}/settings/installations/1", WEB),
+        "app_id": 1,
+        "target_id": 1,
+        "target_type": "User",
+        "permissions": {"metadata": "read", "contents": "read", "issues": "write"},
+        "events": ["push", "pull_request"],
+        "created_at": CREATED_AT,
+        "updated_at": UPDATED_AT,
+        "single_file_name": "config.yaml",
+    })
+}
+
+fn app() -> Value {
+    json!({
+        "id": 1,
+        "node_id": "MDExOkludGVncmF0aW9uMQ==",
+        "owner": user("octocat", 1),
+        "name": "Super CI",
+        "description": "",
+        "external_url": "https://example.com",
+        "html_url": format!("{}/apps/super-ci", WEB),
+        "created_at": CREATED_AT,
+        "updated_at": UPDATED_AT,
+    })
+}
+
+fn check_suite() -> Value {
+    json!({
+        "id": 5,
+        "head_branch": "master",
+        "head_sha": SHA,
+        "status": "completed",
+        "conclusion": "success",
+        "url": format!("{}/repos/{}/check-suites/5", API, REPO),
+        "before": BEFORE,
+        "after": SHA,
+        "pull_requests": [],
+        "app": app(),
+        "created_at": CREATED_AT,
+        "updated_at": UPDATED_AT,
+    })
+}
+
+fn check_run() -> Value {
+    json!({
+        "id": 4,
+        "head_sha": SHA,
+        "external_id": "42",
+        "url": format!("{}/repos/{}/check-runs/4", API, REPO),
+        "html_url": format!("{}/{}/runs/4", WEB, REPO),
+        "status": "completed",
+        "conclusion": "neutral",
+        "started_at": CREATED_AT,
+        "completed_at": UPDATED_AT,
+        "output": {
+            "title": "Mighty Readme report",
+            "summary": "There are 0 failures, 2 warnings, and 1 notice.",
+            "text": "You may have some misspelled words on lines 2 and 4.",
+            "annotations_count": 2,
+            "annotations_url": format!("{}/repos/{}/check-runs/4/annotations", API, REPO),
+        },
+        "name": "mighty_readme",
+        "check_suite": check_suite(),
+        "app": app(),
+        "pull_requests": [],
+    })
+}
+
+fn commit_comment() -> Value {
+    json!({
+        "url": format!("{}/repos/{}/comments/1", API, REPO),
+        "html_url": format!("{}/{}/commit/{}#commitcomment-1", WEB, REPO, SHA),
+        "id": 1,
+        "node_id": "MDEzOkNvbW1pdENvbW1lbnQx",
+        "user": user("octocat", 1),
+        "position": null,
+        "line": null,
+        "path": null,
+        "commit_id": SHA,
+        "created_at": CREATED_AT,
+        "updated_at": UPDATED_AT,
+        "author_association": "OWNER",
+        "body": "This is a really good change! :+1:",
+    })
+}
+
+fn author() -> Value {
+    json!({"name": "Monalisa Octocat", "email": "support@github.com"})
+}
+
+fn commit() -> Value {
+    json!({
+        "sha": SHA,
+        "message": "Update README",
+        "author": author(),
+        "url": format!("{}/repos/{}/commits/{}", API, REPO, SHA),
+        "distinct": true,
+    })
+}
+
+/// The commit of a `status` event, as the commits API returns it.
+fn status_commit() -> Value {
+    let url = format!("{}/repos/{}", API, REPO);
+    let signature = json!({
+        "name": "Monalisa Octocat",
+        "email": "support@github.com",
+        "date": CREATED_AT,
+    });
+    json!({
+        "sha": SHA,
+        "node_id": "MDY6Q29tbWl0NmRjYjA5YjViNTc4NzVmMzM0ZjYxYWViZWQ2OTVlMmU0MTkzZGI1ZQ==",
+        "commit": {
+            "author": signature,
+            "committer": signature,
+            "message": "Update README",
+            "tree": {"sha": BEFORE, "url": format!("{}/git/trees/{}", url, BEFORE)},
+            "url": format!("{}/git/commits/{}", url, SHA),
+            "comment_count": 0,
+            "verification": {
+                "verified": false,
+                "reason": "unsigned",
+                "signature": null,
+                "payload": null,
+                "verified_at": null,
+            },
+        },
+        "url": format!("{}/commits/{}", url, SHA),
+        "html_url": format!("{}/{}/commit/{}", WEB, REPO, SHA),
+        "comments_url": format!("{}/commits/{}/comments", url, SHA),
+        "author": user("octocat", 1),
+        "committer": user("octocat", 1),
+        "parents": [{
+            "sha": BEFORE,
+            "url": format!("{}/commits/{}", url, BEFORE),
+            "html_url": format!("{}/{}/commit/{}", WEB, REPO, BEFORE),
+        }],
+    })
+}
+
+fn deployment() -> Value {
+    json!({
+        "url": format!("{}/repos/{}/deployments/1", API, REPO),
+        "id": 1,
+        "node_id": "MDEwOkRlcGxveW1lbnQx",
+        "sha": SHA,
+        "ref": "master",
+        "task": "deploy",
+        "payload": {},
+        "environment": "production",
+        "description": "Deploy request from hubot",
+        "creator": user("octocat", 1),
+        "created_at": CREATED_AT,
+        "updated_at": UPDATED_AT,
+        "statuses_url": format!("{}/repos/{}/deployments/1/statuses", API, REPO),
+        "repository_url": format!("{}/repos/{}", API, REPO),
+    })
+}
+
+fn label() -> Value {
+    json!({
+        "id": 208045946,
+        "node_id": "MDU6TGFiZWwyMDgwNDU5NDY=",
+        "url": format!("{}/repos/{}/labels/bug", API, REPO),
+        "name": "bug",
+        "color": "f29513",
+        "default": true,
+    })
+}
+
+fn milestone() -> Value {
+    json!({
+        "url": format!("{}/repos/{}/milestones/1", API, REPO),
+        "html_url": format!("{}/{}/milestones/v1.0", WEB, REPO),
+        "labels_url": format!("{}/repos/{}/milestones/1/labels", API, REPO),
+        "id": 1002604,
+        "node_id": "MDk6TWlsZXN0b25lMTAwMjYwNA==",
+        "number": 1,
+        "title": "v1.0",
+        "description": "Tracking milestone for version 1.0",
+        "creator": user("octocat", 1),
+        "open_issues": 4,
+        "closed_issues": 8,
+        "state": "open",
+        "created_at": CREATED_AT,
+        "updated_at": UPDATED_AT,
+        "due_on": "2012-10-09T23:39:01Z",
+        "closed_at": null,
+    })
+}
+
+fn issue() -> Value {
+    let url = format!("{}/repos/{}/issues/1347", API, REPO);
+    json!({
+        "url": url,
+        "repository_url": format!("{}/repos/{}", API, REPO),
+        "labels_url": format!("{}/labels{{/name}}", url),
+        "comments_url": format!("{}/comments", url),
+        "events_url": format!("{}/events", url),
+        "html_url": format!("{}/{}/issues/1347", WEB, REPO),
+        "id": 1,
+        "node_id": "MDU6SXNzdWUx",
+        "number": 1347,
+        "title": "Found a bug",
+        "user": user("octocat", 1),
+        "labels": [label()],
+        "state": "open",
+        "locked": false,
+        "assignee": user("octocat", 1),
+        "assignees": [user("octocat", 1)],
+        "milestone": milestone(),
+        "comments": 0,
+        "created_at": CREATED_AT,
+        "updated_at": UPDATED_AT,
+        "closed_at": null,
+        "author_association": "OWNER",
+        "body": "I'm having a problem with this.",
+        "performed_via_github_app": null,
+        "reactions": reactions(),
+    })
+}
+
+fn comment() -> Value {
+    json!({
+        "url": format!("{}/repos/{}/issues/comments/1", API, REPO),
+        "html_url": format!("{}/{}/issues/1347#issuecomment-1", WEB, REPO),
+        "id": 1,
+        "node_id": "MDEyOklzc3VlQ29tbWVudDE=",
+        "user": user("octocat", 1),
+        "position": null,
+        "line": null,
+        "path": null,
+        "commit_id": SHA,
+        "created_at": CREATED_AT,
+        "updated_at": UPDATED_AT,
+        "author_association": "OWNER",
+        "body": "Me too",
+        "performed_via_github_app": null,
+        "reactions": reactions(),
+    })
+}
+
+fn reactions() -> Value {
+    json!({
+        "total_count": 3,
+        "+1": 2,
+        "-1": 0,
+        "laugh": 0,
+        "hooray": 1,
+        "confused": 0,
+        "heart": 0,
+        "rocket": 0,
+        "eyes": 0,
+    })
+}
+
+fn pull_request() -> Value {
+    let url = format!("{}/repos/{}/pulls/1347", API, REPO);
+    let html_url = format!("{}/{}/pull/1347", WEB, REPO);
+    let issue_url = format!("{}/repos/{}/issues/1347", API, REPO);
+    let comments_url = format!("{}/comments", issue_url);
+    let review_comments_url = format!("{}/comments", url);
+    let review_comment_url = format!("{}/repos/{}/pulls/comments{{/number}}", API, REPO);
+    let commits_url = format!("{}/commits", url);
+    let statuses_url = format!("{}/repos/{}/statuses/{}", API, REPO, SHA);
+    let head = json!({
+        "label": "octocat:new-topic",
+        "ref": "new-topic",
+        "sha": SHA,
+        "user": user("octocat", 1),
+        "repo": repository(),
+    });
+    let base = json!({
+        "label": "octocat:master",
+        "ref": "master",
+        "sha": BEFORE,
+        "user": user("octocat", 1),
+        "repo": repository(),
+    });
+    let links = json!({
+        "self": {"href": url},
+        "html": {"href": html_url},
+        "issue": {"href": issue_url},
+        "comments": {"href": comments_url},
+        "review_comments": {"href": review_comments_url},
+        "review_comment": {"href": review_comment_url},
+        "commits": {"href": commits_url},
+        "statuses": {"href": statuses_url},
+    });
+    let mut pull_request = json!({
+        "url": url,
+        "id": 1,
+        "node_id": "MDExOlB1bGxSZXF1ZXN0MQ==",
+        "html_url": html_url,
+        "diff_url": format!("{}.diff", html_url),
+        "patch_url": format!("{}.patch", html_url),
+        "issue_url": issue_url,
+        "number": 1347,
+        "state": "open",
+        "locked": false,
+        "title": "Amazing new feature",
+        "user": user("octocat", 1),
+        "body": "Please pull these awesome changes in!",
+        "created_at": CREATED_AT,
+        "updated_at": UPDATED_AT,
+        "closed_at": null,
+        "merged_at": null,
+        "merge_commit_sha": "e5bd3914e2e596debea16f433f57875b5b90bcd6",
+        "assignee": null,
+        "assignees": [],
+        "requested_reviewers": [user("hubot", 2)],
+        "requested_teams": [],
+        "labels": [label()],
+        "milestone": null,
+        "commits_url": commits_url,
+        "review_comments_url": review_comments_url,
+        "review_comment_url": review_comment_url,
+        "comments_url": comments_url,
+        "statuses_url": statuses_url,
+        "head": head,
+        "base": base,
+        "_links": links,
+    });
+    let status = json!({
+        "author_association": "OWNER",
+        "merged": false,
+        "mergeable": true,
+        "rebaseable": true,
+        "mergeable_state": "clean",
+        "merged_by": null,
+        "comments": 10,
+        "review_comments": 0,
+        "maintainer_can_modify": true,
+        "commits": 3,
+        "additions": 100,
+        "deletions": 3,
+        "changed_files": 5,
+    });
+    if let (Some(pull_request), Value::Object(status)) = (pull_request.as_object_mut(), status) {
+        pull_request.extend(status);
+    }
+    pull_request
+}
+
+fn pull_request_ref() -> Value {
+    let repo = json!({
+        "id": 1296269,
+        "url": format!("{}/repos/{}", API, REPO),
+        "name": "Hello-World",
+    });
+    json!({
+        "url": format!("{}/repos/{}/pulls/1347", API, REPO),
+        "id": 1,
+        "number": 1347,
+        "head": {"ref": "new-topic", "sha": SHA, "repo": repo},
+        "base": {"ref": "master", "sha": BEFORE, "repo": repo},
+    })
+}
+
+fn team() -> Value {
+    let url = format!("{}/teams/1", API);
+    json!({
+        "name": "Justice League",
+        "id": 1,
+        "node_id": "MDQ6VGVhbTE=",
+        "slug": "justice-league",
+        "description": "A great team.",
+        "privacy": "closed",
+        "url": url,
+        "members_url": format!("{}/members{{/member}}", url),
+        "repositories_url": format!("{}/repos", url),
+        "permission": "admin",
+    })
+}
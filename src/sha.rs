@@ -0,0 +1,177 @@
+use core::convert::TryFrom;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::Deref;
+use core::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+/// The SHA of a git object: 40 hex digits, or 64 in SHA-256 repositories.
+///
+/// Payloads are checked when deserialized, and fail to parse if they hold anything else; parse
+/// a SHA from elsewhere, e.g. a comment or a url, with [`FromStr`](#impl-FromStr-for-Sha).
+/// Comparisons take the same time wherever two SHAs differ, so comparing a SHA from a request
+/// to a known one doesn't tell an attacker how much of it they got right.
+#[derive(Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[serde(try_from = "String", into = "String")]
+pub struct Sha(String);
+
+/// The SHA GitHub sends for a ref that doesn't exist, such as `before` when a branch is created.
+const NULL: &str = "0000000000000000000000000000000000000000";
+
+impl Sha {
+    /// `sha`, if it's 40 or 64 hex digits. Uppercase digits are lowercased.
+    pub fn new(sha: &str) -> Option<Self> {
+        if matches!(sha.len(), 40 | 64) && sha.bytes().all(|b| b.is_ascii_hexdigit()) {
+            Some(Sha(sha.to_ascii_lowercase()))
+        } else {
+            None
+        }
+    }
+
+    /// `sha` as it is, even if it isn't a SHA, e.g. to look up a commit by whatever a user
+    /// wrote.
+    pub fn new_unchecked(sha: String) -> Self {
+        Sha(sha)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// The first 7 digits, as GitHub abbreviates SHAs.
+    pub fn short(&self) -> &str {
+        self.0.get(..7).unwrap_or(&self.0)
+    }
+
+    /// Whether this is the all-zero SHA, which stands for a missing commit.
+    pub fn is_null(&self) -> bool {
+        self.0 == NULL
+    }
+}
+
+/// Whether `a` and `b` are equal, in a time that depends only on their lengths.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |diff, (a, b)| diff | (a ^ b)) == 0
+}
+
+impl PartialEq for Sha {
+    fn eq(&self, other: &Sha) -> bool {
+        constant_time_eq(self.0.as_bytes(), other.0.as_bytes())
+    }
+}
+
+impl Eq for Sha {}
+
+impl PartialEq<str> for Sha {
+    fn eq(&self, other: &str) -> bool {
+        constant_time_eq(self.0.as_bytes(), other.as_bytes())
+    }
+}
+
+impl<'a> PartialEq<&'a str> for Sha {
+    fn eq(&self, other: &&'a str) -> bool {
+        constant_time_eq(self.0.as_bytes(), other.as_bytes())
+    }
+}
+
+impl Hash for Sha {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state)
+    }
+}
+
+impl Deref for Sha {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for Sha {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<String> for Sha {
+    type Error = ParseShaError;
+
+    fn try_from(sha: String) -> Result<Self, Self::Error> {
+        Sha::new(&sha).ok_or(ParseShaError(sha))
+    }
+}
+
+impl TryFrom<&str> for Sha {
+    type Error = ParseShaError;
+
+    fn try_from(sha: &str) -> Result<Self, Self::Error> {
+        sha.parse()
+    }
+}
+
+impl From<Sha> for String {
+    fn from(sha: Sha) -> Self {
+        sha.0
+    }
+}
+
+#[cfg(feature = "fuzz")]
+impl<'a> arbitrary::Arbitrary<'a> for Sha {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let bytes: [u8; 20] = u.arbitrary()?;
+        Ok(Sha(bytes.iter().map(|b| format!("{:02x}", b)).collect()))
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for Sha {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        String::schema_name()
+    }
+
+    fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        String::json_schema(generator)
+    }
+}
+
+impl fmt::Debug for Sha {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+impl fmt::Display for Sha {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// The error returned when parsing something that isn't a SHA.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseShaError(pub String);
+
+impl fmt::Display for ParseShaError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "`{}` is not a SHA", self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseShaError {}
+
+impl FromStr for Sha {
+    type Err = ParseShaError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Sha::new(s).ok_or_else(|| ParseShaError(s.to_owned()))
+    }
+}
@@ -0,0 +1,264 @@
+//! Signing webhook payloads the way GitHub does, with a hook's
+//! [`secret`](../hooks/struct.HookConfig.html#structfield.secret). Enabled by the `signature`
+//! feature.
+//!
+//! GitHub sends the HMAC-SHA256 of each payload in the `X-Hub-Signature-256` header. Receivers
+//! check it with [`verify`](fn.verify.html); tests and local simulators can make deliveries that
+//! pass the check with [`sign`](fn.sign.html), or with the `client` feature build and send whole
+//! deliveries with [`test_delivery`](fn.test_delivery.html) and
+//! [`send_test_delivery`](fn.send_test_delivery.html).
+//!
+//! Receivers of many customers' webhooks, each with its own secret, look the secret of each
+//! delivery up with a [`SecretResolver`](trait.SecretResolver.html) by its hook, app
+//! installation, or repository, and check it with [`verify_request`](fn.verify_request.html).
+use std::error::Error;
+use std::fmt;
+use std::future::Future;
+
+use futures::future::BoxFuture;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+#[cfg(feature = "client")]
+use crate::client::backend::{BackendError, HttpBackend, Method, Request, Response};
+use crate::hooks::ContentType;
+#[cfg(feature = "client")]
+use crate::Event;
+use crate::{peek, HookId, InstallationId};
+
+/// The header GitHub sends signatures in.
+pub const HEADER: &str = "X-Hub-Signature-256";
+
+/// The `X-Hub-Signature-256` header of a delivery of `body`: `sha256=` and the hex-encoded
+/// HMAC-SHA256 of `body`, keyed by `secret`.
+pub fn sign(secret: impl AsRef<[u8]>, body: &[u8]) -> String {
+    let mut signature = String::with_capacity(71);
+    signature.push_str("sha256=");
+    for byte in mac(secret.as_ref(), body).finalize().into_bytes() {
+        signature.push(char::from(b"0123456789abcdef"[usize::from(byte >> 4)]));
+        signature.push(char::from(b"0123456789abcdef"[usize::from(byte & 0xf)]));
+    }
+    signature
+}
+
+/// Whether `signature`, from a delivery's `X-Hub-Signature-256` header, is that of `body` keyed
+/// by `secret`. Compares in constant time, so that the timing doesn't give the signature away.
+pub fn verify(secret: impl AsRef<[u8]>, body: &[u8], signature: &str) -> bool {
+    let verified = matches(secret.as_ref(), body, signature);
+    #[cfg(feature = "metrics")]
+    if !verified {
+        crate::metrics::signature_failed();
+    }
+    verified
+}
+
+/// What a [`SecretResolver`](trait.SecretResolver.html) looks a delivery's secret up by.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct SecretKey {
+    /// The hook that delivered it, from the `X-GitHub-Hook-ID` header.
+    pub hook_id: Option<HookId>,
+    /// The GitHub App installation it was delivered for.
+    pub installation_id: Option<InstallationId>,
+    /// The full name of the repository it's about, e.g. `octocat/Hello-World`.
+    pub repository: Option<String>,
+}
+
+impl SecretKey {
+    /// The key of a webhook request, from its `X-GitHub-Hook-ID` header and the installation
+    /// and repository of its body, which is decoded as its `Content-Type` header says. Headers
+    /// are compared case-insensitively.
+    pub fn from_request<N, V>(headers: &[(N, V)], body: &[u8]) -> Self
+    where
+        N: AsRef<str>,
+        V: AsRef<str>,
+    {
+        let hook_id = header(headers, "X-GitHub-Hook-ID")
+            .and_then(|id| id.trim().parse().ok())
+            .map(HookId::new);
+        let content_type = header(headers, "Content-Type")
+            .and_then(ContentType::from_header)
+            .unwrap_or(ContentType::Json);
+        let payload = content_type.payload(body).unwrap_or_default();
+        SecretKey {
+            hook_id,
+            installation_id: peek::installation_id(&payload),
+            repository: peek::repository(&payload).map(String::from),
+        }
+    }
+}
+
+/// Any failure of a [`SecretResolver`](trait.SecretResolver.html).
+pub type ResolveError = Box<dyn Error + Send + Sync>;
+
+/// Looks up the secret of a delivery, e.g. in a database of customers' hooks, or `None` if it
+/// doesn't know the delivery. Implemented by async functions and closures taking a
+/// [`SecretKey`](struct.SecretKey.html).
+pub trait SecretResolver: Send + Sync {
+    fn resolve<'a>(
+        &'a self,
+        key: &'a SecretKey,
+    ) -> BoxFuture<'a, Result<Option<Vec<u8>>, ResolveError>>;
+}
+
+impl<F, Fut> SecretResolver for F
+where
+    F: Fn(SecretKey) -> Fut + Send + Sync,
+    Fut: Future<Output = Result<Option<Vec<u8>>, ResolveError>> + Send + 'static,
+{
+    fn resolve<'a>(
+        &'a self,
+        key: &'a SecretKey,
+    ) -> BoxFuture<'a, Result<Option<Vec<u8>>, ResolveError>> {
+        Box::pin(self(key.clone()))
+    }
+}
+
+/// Why a request failed [`verify_request`](fn.verify_request.html).
+#[derive(Debug)]
+pub enum VerifyError {
+    /// The request has no `X-Hub-Signature-256` header.
+    Unsigned,
+    /// The resolver knows no secret for the request.
+    UnknownSecret(SecretKey),
+    /// The signature isn't that of the body keyed by the secret.
+    Mismatch,
+    /// The resolver failed.
+    Resolve(ResolveError),
+}
+
+impl fmt::Display for VerifyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            VerifyError::Unsigned => write!(f, "the delivery has no {} header", HEADER),
+            VerifyError::UnknownSecret(key) => write!(f, "no secret is known for {:?}", key),
+            VerifyError::Mismatch => f.write_str("the delivery's signature doesn't match"),
+            VerifyError::Resolve(e) => write!(f, "could not look the secret up: {}", e),
+        }
+    }
+}
+
+impl Error for VerifyError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            VerifyError::Resolve(e) => Some(&**e),
+            _ => None,
+        }
+    }
+}
+
+/// Check the `X-Hub-Signature-256` header of a webhook request against the secret `resolver`
+/// finds for its [`SecretKey`](struct.SecretKey.html).
+pub async fn verify_request<N, V>(
+    resolver: &dyn SecretResolver,
+    headers: &[(N, V)],
+    body: &[u8],
+) -> Result<(), VerifyError>
+where
+    N: AsRef<str>,
+    V: AsRef<str>,
+{
+    let signature = header(headers, HEADER).ok_or(VerifyError::Unsigned)?;
+    let key = SecretKey::from_request(headers, body);
+    let secret = match resolver.resolve(&key).await {
+        Ok(Some(secret)) => secret,
+        Ok(None) => return Err(VerifyError::UnknownSecret(key)),
+        Err(e) => return Err(VerifyError::Resolve(e)),
+    };
+    if verify(secret, body, signature) {
+        Ok(())
+    } else {
+        Err(VerifyError::Mismatch)
+    }
+}
+
+/// The value of the first header called `name`, compared case-insensitively.
+fn header<'a, N, V>(headers: &'a [(N, V)], name: &str) -> Option<&'a str>
+where
+    N: AsRef<str>,
+    V: AsRef<str>,
+{
+    headers
+        .iter()
+        .find(|(n, _)| n.as_ref().eq_ignore_ascii_case(name))
+        .map(|(_, v)| v.as_ref())
+}
+
+fn matches(secret: &[u8], body: &[u8], signature: &str) -> bool {
+    let hex = match signature.trim().strip_prefix("sha256=") {
+        Some(hex) if hex.len() == 64 => hex.as_bytes(),
+        _ => return false,
+    };
+    let mut expected = [0; 32];
+    for (byte, pair) in expected.iter_mut().zip(hex.chunks(2)) {
+        match (hex_digit(pair[0]), hex_digit(pair[1])) {
+            (Some(high), Some(low)) => *byte = high << 4 | low,
+            _ => return false,
+        }
+    }
+    mac(secret, body).verify_slice(&expected).is_ok()
+}
+
+fn mac(secret: &[u8], body: &[u8]) -> Hmac<Sha256> {
+    // HMAC takes keys of any length.
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret).unwrap();
+    mac.update(body);
+    mac
+}
+
+fn hex_digit(c: u8) -> Option<u8> {
+    char::from(c).to_digit(16).map(|d| d as u8)
+}
+
+/// A request delivering `event` to `url` as GitHub would, signed with `secret`, under a new
+/// random GUID.
+#[cfg(feature = "client")]
+pub fn test_delivery(url: &str, secret: impl AsRef<[u8]>, event: &Event) -> Request {
+    let payload = match ::serde_json::to_value(event) {
+        Ok(::serde_json::Value::Object(tagged)) => {
+            tagged.into_iter().next().map(|(_, payload)| payload)
+        }
+        _ => None,
+    }
+    .unwrap_or(::serde_json::Value::Null);
+    let body = payload.to_string().into_bytes();
+    Request::new(Method::Post, url)
+        .header("Content-Type", "application/json")
+        .header("User-Agent", "GitHub-Hookshot/github-events")
+        .header("X-GitHub-Event", event.event_type().as_str())
+        .header("X-GitHub-Delivery", guid())
+        .header(HEADER, sign(secret, &body))
+        .body(body)
+}
+
+/// Send a [`test_delivery`](fn.test_delivery.html) of `event` to `url` with `http`, such as
+/// [`Reqwest`](../client/backend/struct.Reqwest.html) or
+/// [`Ureq`](../client/backend/struct.Ureq.html).
+#[cfg(feature = "client")]
+pub async fn send_test_delivery(
+    http: &dyn HttpBackend,
+    url: &str,
+    secret: impl AsRef<[u8]>,
+    event: &Event,
+) -> Result<Response, BackendError> {
+    http.send(test_delivery(url, secret, event)).await
+}
+
+/// A random version 4 UUID, like GitHub's delivery GUIDs.
+#[cfg(feature = "client")]
+fn guid() -> String {
+    use std::collections::hash_map::RandomState;
+    use std::hash::{BuildHasher, Hasher};
+
+    // Every `RandomState` is keyed differently.
+    let random = || RandomState::new().build_hasher().finish();
+    let (high, low) = (random(), random());
+    format!(
+        "{:08x}-{:04x}-4{:03x}-{:04x}-{:012x}",
+        high >> 32,
+        (high >> 16) & 0xffff,
+        high & 0xfff,
+        0x8000 | (low >> 48) & 0x3fff,
+        low & 0xffff_ffff_ffff
+    )
+}
@@ -0,0 +1,349 @@
+//! Publishing events to a message broker, so that services can consume webhooks without each
+//! running a server. Enabled by the `sink` feature.
+//!
+//! A [`Publisher`](struct.Publisher.html) turns events into [`Message`](struct.Message.html)s and
+//! sends them to an [`EventSink`](trait.EventSink.html); the `kafka` feature provides one built on
+//! [rdkafka](https://docs.rs/rdkafka), and the `nats` feature one built on
+//! [async-nats](https://docs.rs/async-nats).
+use std::error::Error;
+use std::fmt;
+
+use futures::future::BoxFuture;
+use futures::stream::{Stream, StreamExt};
+use serde_json::Value;
+
+use crate::provenance::Provenance;
+use crate::Event;
+
+/// Used for subjects when [`Publisher::with_subject`](struct.Publisher.html#method.with_subject)
+/// isn't called.
+pub const DEFAULT_SUBJECT: &str = "github.{owner}.{repo}.{event}";
+
+/// Any failure to publish an event.
+#[derive(Debug)]
+pub enum SinkError {
+    /// The event could not be serialized.
+    Json(serde_json::Error),
+    /// The broker did not accept the message.
+    Send(Box<dyn Error + Send + Sync>),
+}
+
+impl fmt::Display for SinkError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SinkError::Json(e) => write!(f, "could not serialize event: {}", e),
+            SinkError::Send(e) => write!(f, "could not publish event: {}", e),
+        }
+    }
+}
+
+impl Error for SinkError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            SinkError::Json(e) => Some(e),
+            SinkError::Send(e) => Some(&**e),
+        }
+    }
+}
+
+impl From<serde_json::Error> for SinkError {
+    fn from(e: serde_json::Error) -> Self {
+        SinkError::Json(e)
+    }
+}
+
+/// An event, ready to be sent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Message {
+    /// The Kafka topic or NATS subject.
+    pub subject: String,
+    /// `<owner>/<repo>`, used as the Kafka key so that a repository's events stay in order.
+    /// `None` for events outside a repository.
+    pub key: Option<String>,
+    /// `X-GitHub-Delivery`, `X-GitHub-Event`, and `Content-Type`, and those of the event's
+    /// [`Provenance`](../provenance/struct.Provenance.html#method.headers) if it was published
+    /// with it.
+    pub headers: Vec<(String, String)>,
+    pub payload: Vec<u8>,
+}
+
+impl Message {
+    /// The value of the first header called `name`, compared case-insensitively.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Where the message's event came from, as far as its headers say.
+    pub fn provenance(&self) -> Provenance {
+        Provenance::from_headers(self.headers.iter().map(|(n, v)| (n, v)))
+    }
+}
+
+/// Sends messages to a broker on behalf of a [`Publisher`](struct.Publisher.html).
+pub trait EventSink: fmt::Debug + Send + Sync {
+    /// Send `message`, resolving once the broker has accepted it.
+    fn send(&self, message: Message) -> BoxFuture<'_, Result<(), SinkError>>;
+}
+
+/// How events are serialized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// The payload as GitHub delivered it.
+    Json,
+    /// A [`CloudEvent`](../cloudevents/struct.CloudEvent.html) in structured mode.
+    #[cfg(feature = "cloudevents")]
+    CloudEvents,
+}
+
+impl Format {
+    fn content_type(self) -> &'static str {
+        match self {
+            Format::Json => "application/json",
+            #[cfg(feature = "cloudevents")]
+            Format::CloudEvents => "application/cloudevents+json",
+        }
+    }
+}
+
+/// Publishes events to an [`EventSink`](trait.EventSink.html).
+#[derive(Debug)]
+pub struct Publisher<S> {
+    sink: S,
+    subject: String,
+    format: Format,
+}
+
+impl<S: EventSink> Publisher<S> {
+    /// Publish JSON payloads to subjects named by [`DEFAULT_SUBJECT`](constant.DEFAULT_SUBJECT.html).
+    pub fn new(sink: S) -> Self {
+        Publisher {
+            sink,
+            subject: DEFAULT_SUBJECT.to_owned(),
+            format: Format::Json,
+        }
+    }
+
+    /// Name subjects after `template`, replacing `{owner}`, `{repo}`, `{event}`, and `{action}`.
+    ///
+    /// Characters other than ASCII letters, digits, `-`, and `_` are replaced by `_` in the
+    /// values, since `.` separates the tokens of NATS subjects. Events outside a repository,
+    /// or without an action, have `_` in its place; organization events have the organization
+    /// as their `{owner}`.
+    pub fn with_subject(mut self, template: impl Into<String>) -> Self {
+        self.subject = template.into();
+        self
+    }
+
+    pub fn with_format(mut self, format: Format) -> Self {
+        self.format = format;
+        self
+    }
+
+    pub fn sink(&self) -> &S {
+        &self.sink
+    }
+
+    /// The message for `event`, delivered with the GUID `delivery`.
+    pub fn message(&self, event: &Event, delivery: &str) -> Result<Message, SinkError> {
+        let event_type = event.event_type();
+        let data = match ::serde_json::to_value(event)? {
+            Value::Object(tagged) => tagged.into_iter().next().map(|(_, data)| data),
+            _ => None,
+        }
+        .unwrap_or(Value::Null);
+
+        let repository = &data["repository"];
+        let owner = repository["owner"]["login"]
+            .as_str()
+            .or_else(|| data["organization"]["login"].as_str());
+        let repo = repository["name"].as_str();
+        let key = owner
+            .zip(repo)
+            .map(|(owner, repo)| format!("{}/{}", owner, repo));
+        let subject = self
+            .subject
+            .replace("{owner}", &token(owner))
+            .replace("{repo}", &token(repo))
+            .replace("{event}", &token(Some(event_type.as_str())))
+            .replace("{action}", &token(data["action"].as_str()));
+
+        let payload = match self.format {
+            Format::Json => ::serde_json::to_vec(&data)?,
+            #[cfg(feature = "cloudevents")]
+            Format::CloudEvents => {
+                ::serde_json::to_vec(&crate::cloudevents::CloudEvent::wrap(event, delivery)?)?
+            }
+        };
+
+        Ok(Message {
+            subject,
+            key,
+            headers: vec![
+                ("X-GitHub-Delivery".to_owned(), delivery.to_owned()),
+                ("X-GitHub-Event".to_owned(), event_type.as_str().to_owned()),
+                (
+                    "Content-Type".to_owned(),
+                    self.format.content_type().to_owned(),
+                ),
+            ],
+            payload,
+        })
+    }
+
+    /// The message for `event`, with the headers of its `provenance`.
+    pub fn message_with_provenance(
+        &self,
+        event: &Event,
+        provenance: &Provenance,
+    ) -> Result<Message, SinkError> {
+        let mut message = self.message(event, &provenance.delivery_id)?;
+        for (name, value) in provenance.headers() {
+            if message.header(&name).is_none() {
+                message.headers.push((name, value));
+            }
+        }
+        Ok(message)
+    }
+
+    /// Publish `event`, delivered with the GUID `delivery`.
+    pub async fn publish(&self, event: &Event, delivery: &str) -> Result<(), SinkError> {
+        let message = self.message(event, delivery)?;
+        self.sink.send(message).await
+    }
+
+    /// Publish `event`, with the headers of its `provenance`.
+    pub async fn publish_with_provenance(
+        &self,
+        event: &Event,
+        provenance: &Provenance,
+    ) -> Result<(), SinkError> {
+        let message = self.message_with_provenance(event, provenance)?;
+        self.sink.send(message).await
+    }
+
+    /// Publish each event of `events`, paired with its delivery's GUID, in order.
+    /// Stops at the first event that can't be published.
+    pub async fn publish_all(
+        &self,
+        events: impl Stream<Item = (Event, String)>,
+    ) -> Result<(), SinkError> {
+        futures::pin_mut!(events);
+        while let Some((event, delivery)) = events.next().await {
+            self.publish(&event, &delivery).await?;
+        }
+        Ok(())
+    }
+}
+
+/// `value` as a single token of a subject.
+fn token(value: Option<&str>) -> String {
+    match value {
+        Some(value) if !value.is_empty() => value
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect(),
+        _ => "_".to_owned(),
+    }
+}
+
+/// A sink built on [rdkafka](https://docs.rs/rdkafka). Subjects are topics.
+#[cfg(feature = "kafka")]
+#[derive(Clone)]
+pub struct Kafka {
+    producer: rdkafka::producer::FutureProducer,
+    queue_timeout: std::time::Duration,
+}
+
+#[cfg(feature = "kafka")]
+impl Kafka {
+    /// Wait at most `queue_timeout` for room in the producer's queue when it's full.
+    pub fn new(
+        producer: rdkafka::producer::FutureProducer,
+        queue_timeout: std::time::Duration,
+    ) -> Self {
+        Kafka {
+            producer,
+            queue_timeout,
+        }
+    }
+}
+
+#[cfg(feature = "kafka")]
+impl fmt::Debug for Kafka {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Kafka")
+            .field("queue_timeout", &self.queue_timeout)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "kafka")]
+impl EventSink for Kafka {
+    fn send(&self, message: Message) -> BoxFuture<'_, Result<(), SinkError>> {
+        use rdkafka::message::{Header, OwnedHeaders};
+        use rdkafka::producer::FutureRecord;
+
+        Box::pin(async move {
+            let mut headers = OwnedHeaders::new_with_capacity(message.headers.len());
+            for (name, value) in &message.headers {
+                headers = headers.insert(Header {
+                    key: name,
+                    value: Some(value),
+                });
+            }
+            let mut record = FutureRecord::to(&message.subject)
+                .payload(&message.payload)
+                .headers(headers);
+            if let Some(key) = &message.key {
+                record = record.key(key);
+            }
+            self.producer
+                .send(record, self.queue_timeout)
+                .await
+                .map(drop)
+                .map_err(|(e, _)| SinkError::Send(Box::new(e)))
+        })
+    }
+}
+
+/// A sink built on [async-nats](https://docs.rs/async-nats). Must be used within a tokio runtime.
+///
+/// Messages are buffered by the client; flush it before exiting.
+#[cfg(feature = "nats")]
+#[derive(Debug, Clone)]
+pub struct Nats {
+    client: async_nats::Client,
+}
+
+#[cfg(feature = "nats")]
+impl Nats {
+    pub fn new(client: async_nats::Client) -> Self {
+        Nats { client }
+    }
+}
+
+#[cfg(feature = "nats")]
+impl EventSink for Nats {
+    fn send(&self, message: Message) -> BoxFuture<'_, Result<(), SinkError>> {
+        Box::pin(async move {
+            let mut headers = async_nats::HeaderMap::new();
+            for (name, value) in &message.headers {
+                headers.insert(name.as_str(), value.as_str());
+            }
+            self.client
+                .publish_with_headers(message.subject, headers, message.payload.into())
+                .await
+                .map_err(|e| SinkError::Send(Box::new(e)))
+        })
+    }
+}
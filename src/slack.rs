@@ -0,0 +1,100 @@
+//! Slack messages for events, built from [Block Kit](https://api.slack.com/block-kit) blocks,
+//! for posting to an incoming webhook or `chat.postMessage`. Enabled by the `slack` feature.
+use serde_json::{json, Value};
+
+use crate::card::Card;
+use crate::Event;
+
+/// Slack truncates the text of sections longer than this.
+const MAX_SECTION: usize = 3000;
+
+/// A message describing `event`: its summary linked to the pull request, issue, commit, or other
+/// page it's about, any body or commits it introduces, and its sender and repository, in an
+/// attachment colored by its action. The summary doubles as the notification's text.
+pub fn message(event: &Event) -> Value {
+    let card = Card::new(event);
+    json!({
+        "text": escape(&card.title),
+        "attachments": [{
+            "color": format!("#{:06x}", card.color),
+            "blocks": blocks_of(&card),
+        }],
+    })
+}
+
+/// The blocks of [`message`](fn.message.html), for composing messages of your own.
+pub fn blocks(event: &Event) -> Value {
+    Value::Array(blocks_of(&Card::new(event)))
+}
+
+fn blocks_of(card: &Card) -> Vec<Value> {
+    let title = match &card.url {
+        Some(url) => format!("*<{}|{}>*", url, escape(&card.title)),
+        None => format!("*{}*", escape(&card.title)),
+    };
+    let mut blocks = vec![section(title)];
+
+    let mut details = Vec::new();
+    if let Some(body) = &card.body {
+        details.push(
+            body.lines()
+                .map(|line| format!(">{}", escape(line)))
+                .collect::<Vec<_>>()
+                .join("\n"),
+        );
+    }
+    for commit in &card.commits {
+        details.push(format!(
+            "<{}|`{}`> {}",
+            commit.url,
+            commit.sha,
+            escape(&commit.message)
+        ));
+    }
+    if card.more_commits > 0 {
+        details.push(format!("…and {} more", card.more_commits));
+    }
+    if !details.is_empty() {
+        blocks.push(section(details.join("\n")));
+    }
+
+    let mut context = Vec::new();
+    if let Some(author) = &card.author {
+        context.push(json!({
+            "type": "image",
+            "image_url": author.avatar_url,
+            "alt_text": author.login,
+        }));
+        context.push(mrkdwn(format!(
+            "<{}|{}>",
+            author.url,
+            escape(&author.login)
+        )));
+    }
+    if let Some((name, url)) = &card.repository {
+        context.push(mrkdwn(format!("<{}|{}>", url, escape(name))));
+    }
+    if !context.is_empty() {
+        blocks.push(json!({"type": "context", "elements": context}));
+    }
+    blocks
+}
+
+fn section(text: String) -> Value {
+    let text = match text.char_indices().nth(MAX_SECTION - 1) {
+        Some((end, _)) if text[end..].chars().nth(1).is_some() => format!("{}…", &text[..end]),
+        _ => text,
+    };
+    json!({"type": "section", "text": mrkdwn(text)})
+}
+
+fn mrkdwn(text: String) -> Value {
+    json!({"type": "mrkdwn", "text": text})
+}
+
+/// `text` with the characters that Slack treats as markup escaped.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
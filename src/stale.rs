@@ -0,0 +1,106 @@
+//! Recognizing events that arrive long after what they describe happened.
+//!
+//! GitHub redelivers failed webhooks by hand or through the API, sometimes hours later, and
+//! queues can back up; by then the pull request an event describes may have changed again. A
+//! [`StalePolicy`] compares when an event happened, as its payload says, with when its delivery
+//! was received, so that handlers can skip acting on outdated state.
+//!
+//! An event happened when [`Event::occurred_at`](../enum.Event.html#method.occurred_at) says.
+//! For pushes that's when the head commit was made, which can be long before it was pushed, so
+//! pushes are usually better left out with [`without`](struct.StalePolicy.html#method.without).
+//!
+//! ```
+//! use std::time::{Duration, SystemTime};
+//!
+//! use github_events::stale::StalePolicy;
+//! use github_events::{Event, EventType};
+//!
+//! let policy = StalePolicy::new(Duration::from_secs(60 * 60))
+//!     .with(EventType::CheckRun, Duration::from_secs(10 * 60))
+//!     .without(EventType::Push);
+//! let event = Event::sample(EventType::PullRequest).unwrap();
+//! assert!(policy.check(&event, SystemTime::now()).is_stale());
+//! ```
+use std::collections::BTreeMap;
+use std::time::{Duration, SystemTime};
+
+use crate::{timestamp, Event, EventType};
+
+/// How old an event was when its delivery was received.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Freshness {
+    /// Younger than its threshold, or of a type without one.
+    Fresh { age: Duration },
+    /// At least as old as its threshold.
+    Stale { age: Duration, threshold: Duration },
+    /// The payload doesn't say when the event happened.
+    Unknown,
+}
+
+impl Freshness {
+    pub fn is_stale(&self) -> bool {
+        matches!(self, Freshness::Stale { .. })
+    }
+
+    /// How long before its delivery was received the event happened, if the payload says.
+    pub fn age(&self) -> Option<Duration> {
+        match *self {
+            Freshness::Fresh { age } | Freshness::Stale { age, .. } => Some(age),
+            Freshness::Unknown => None,
+        }
+    }
+}
+
+/// How old events of each type may be before they're stale.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StalePolicy {
+    default: Duration,
+    thresholds: BTreeMap<EventType, Option<Duration>>,
+}
+
+impl StalePolicy {
+    /// Events are stale once they're `threshold` old.
+    pub fn new(threshold: Duration) -> Self {
+        StalePolicy {
+            default: threshold,
+            thresholds: BTreeMap::new(),
+        }
+    }
+
+    /// Events of type `event_type` are stale once they're `threshold` old.
+    pub fn with(mut self, event_type: EventType, threshold: Duration) -> Self {
+        self.thresholds.insert(event_type, Some(threshold));
+        self
+    }
+
+    /// Events of type `event_type` are never stale.
+    pub fn without(mut self, event_type: EventType) -> Self {
+        self.thresholds.insert(event_type, None);
+        self
+    }
+
+    /// How old events of type `event_type` may be, or `None` if they're never stale.
+    pub fn threshold(&self, event_type: EventType) -> Option<Duration> {
+        match self.thresholds.get(&event_type) {
+            Some(&threshold) => threshold,
+            None => Some(self.default),
+        }
+    }
+
+    /// How old `event` was when its delivery was received at `received_at`.
+    ///
+    /// Events that seem to have happened after they were received, because of clock skew, are
+    /// taken to be brand new.
+    pub fn check(&self, event: &Event, received_at: SystemTime) -> Freshness {
+        let age = match event.timestamp().and_then(timestamp::to_system_time) {
+            Some(occurred_at) => received_at
+                .duration_since(occurred_at)
+                .unwrap_or(Duration::ZERO),
+            None => return Freshness::Unknown,
+        };
+        match self.threshold(event.event_type()) {
+            Some(threshold) if age >= threshold => Freshness::Stale { age, threshold },
+            _ => Freshness::Fresh { age },
+        }
+    }
+}
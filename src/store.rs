@@ -0,0 +1,363 @@
+//! Persisting events to a database, to replay a bot's history or audit what it was sent.
+//! Enabled by the `store` feature.
+//!
+//! An [`EventStore`](trait.EventStore.html) keeps each event with its delivery's GUID and the
+//! time it was received. The `sqlite` and `postgres` features provide stores built on
+//! [sqlx](https://docs.rs/sqlx), which create their own table; call `migrate` once at startup.
+use std::error::Error;
+use std::fmt;
+use std::time::SystemTime;
+#[cfg(any(feature = "sqlite", feature = "postgres"))]
+use std::time::{Duration, UNIX_EPOCH};
+
+use futures::future::BoxFuture;
+#[cfg(any(feature = "sqlite", feature = "postgres"))]
+use serde_json::Value;
+
+use crate::{Event, EventType};
+
+/// Any failure to store or load an event.
+#[derive(Debug)]
+pub enum StoreError {
+    /// The database could not be reached or rejected a statement.
+    Database(Box<dyn Error + Send + Sync>),
+    /// An event could not be serialized, or a stored one no longer parses.
+    Json(serde_json::Error),
+}
+
+impl fmt::Display for StoreError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StoreError::Database(e) => write!(f, "database error: {}", e),
+            StoreError::Json(e) => write!(f, "invalid event: {}", e),
+        }
+    }
+}
+
+impl Error for StoreError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            StoreError::Database(e) => Some(&**e),
+            StoreError::Json(e) => Some(e),
+        }
+    }
+}
+
+impl From<serde_json::Error> for StoreError {
+    fn from(e: serde_json::Error) -> Self {
+        StoreError::Json(e)
+    }
+}
+
+#[cfg(any(feature = "sqlite", feature = "postgres"))]
+impl From<sqlx::Error> for StoreError {
+    fn from(e: sqlx::Error) -> Self {
+        StoreError::Database(Box::new(e))
+    }
+}
+
+/// An event as it was delivered.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct StoredEvent {
+    /// The delivery's GUID, from the `X-GitHub-Delivery` header.
+    pub delivery_id: String,
+    /// Stored to the millisecond.
+    pub received_at: SystemTime,
+    pub event: Event,
+}
+
+impl StoredEvent {
+    /// `event`, received now.
+    pub fn new(delivery_id: impl Into<String>, event: Event) -> Self {
+        StoredEvent {
+            delivery_id: delivery_id.into(),
+            received_at: SystemTime::now(),
+            event,
+        }
+    }
+}
+
+/// Which events to [`query`](trait.EventStore.html#tymethod.query). Every condition must hold;
+/// an empty query matches every event.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Query {
+    /// The repository's full name, e.g. `octocat/Hello-World`.
+    pub repository: Option<String>,
+    /// Any of these event types, or any type if empty.
+    pub event_types: Vec<EventType>,
+    /// Received at or after.
+    pub since: Option<SystemTime>,
+    /// Received before.
+    pub until: Option<SystemTime>,
+    /// At most this many events, the earliest first.
+    pub limit: Option<u32>,
+}
+
+impl Query {
+    pub fn new() -> Self {
+        Query::default()
+    }
+
+    pub fn repository(mut self, full_name: impl Into<String>) -> Self {
+        self.repository = Some(full_name.into());
+        self
+    }
+
+    pub fn event_type(mut self, event_type: EventType) -> Self {
+        self.event_types.push(event_type);
+        self
+    }
+
+    pub fn since(mut self, since: SystemTime) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    pub fn until(mut self, until: SystemTime) -> Self {
+        self.until = Some(until);
+        self
+    }
+
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+/// Where events are kept.
+pub trait EventStore: fmt::Debug + Send + Sync {
+    /// Store `event`. Returns `false`, storing nothing, if its delivery was already stored,
+    /// as happens when a webhook is redelivered.
+    fn append<'a>(&'a self, event: &'a StoredEvent) -> BoxFuture<'a, Result<bool, StoreError>>;
+
+    fn get_by_delivery_id<'a>(
+        &'a self,
+        delivery_id: &'a str,
+    ) -> BoxFuture<'a, Result<Option<StoredEvent>, StoreError>>;
+
+    /// The events matching `query`, in the order they were received.
+    fn query<'a>(&'a self, query: &'a Query)
+        -> BoxFuture<'a, Result<Vec<StoredEvent>, StoreError>>;
+}
+
+/// The columns of an event's row, other than its delivery's GUID.
+#[cfg(any(feature = "sqlite", feature = "postgres"))]
+struct Row {
+    event_type: &'static str,
+    repository: Option<String>,
+    received_at: i64,
+    payload: String,
+}
+
+#[cfg(any(feature = "sqlite", feature = "postgres"))]
+impl Row {
+    fn new(event: &StoredEvent) -> Result<Self, StoreError> {
+        let data = match ::serde_json::to_value(&event.event)? {
+            Value::Object(tagged) => tagged.into_iter().next().map(|(_, data)| data),
+            _ => None,
+        }
+        .unwrap_or(Value::Null);
+        Ok(Row {
+            event_type: event.event.event_type().as_str(),
+            repository: data["repository"]["full_name"].as_str().map(String::from),
+            received_at: millis(event.received_at),
+            payload: data.to_string(),
+        })
+    }
+}
+
+#[cfg(any(feature = "sqlite", feature = "postgres"))]
+fn millis(time: SystemTime) -> i64 {
+    match time.duration_since(UNIX_EPOCH) {
+        Ok(since) => since.as_millis() as i64,
+        Err(e) => -(e.duration().as_millis() as i64),
+    }
+}
+
+#[cfg(any(feature = "sqlite", feature = "postgres"))]
+fn from_row(
+    (delivery_id, event_type, received_at, payload): (String, String, i64, String),
+) -> Result<StoredEvent, StoreError> {
+    use serde::de::Error as _;
+
+    let event_type: EventType = event_type
+        .parse()
+        .map_err(|e| ::serde_json::Error::custom(format_args!("{}", e)))?;
+    let received_at = if received_at >= 0 {
+        UNIX_EPOCH + Duration::from_millis(received_at as u64)
+    } else {
+        UNIX_EPOCH - Duration::from_millis(received_at.unsigned_abs())
+    };
+    Ok(StoredEvent {
+        delivery_id,
+        received_at,
+        event: Event::from_slice(event_type, payload.as_bytes())?,
+    })
+}
+
+// The stores differ only in their SQL dialect: Postgres keeps payloads as `jsonb`, so that
+// they can be queried in place.
+macro_rules! sql_store {
+    (
+        $(#[$attr:meta])*
+        $store:ident($db:ty, $pool:ty),
+        feature = $feature:literal,
+        payload_type = $payload_type:literal,
+        payload_bind = $payload_bind:literal,
+        payload_select = $payload_select:literal,
+    ) => {
+        $(#[$attr])*
+        #[cfg(feature = $feature)]
+        #[derive(Debug, Clone)]
+        pub struct $store {
+            pool: $pool,
+        }
+
+        #[cfg(feature = $feature)]
+        impl $store {
+            pub fn new(pool: $pool) -> Self {
+                $store { pool }
+            }
+
+            /// Create the `github_events` table and its indexes, unless they already exist.
+            pub async fn migrate(&self) -> Result<(), StoreError> {
+                sqlx::query(concat!(
+                    "CREATE TABLE IF NOT EXISTS github_events (
+                        delivery_id TEXT PRIMARY KEY,
+                        event_type TEXT NOT NULL,
+                        repository TEXT,
+                        received_at BIGINT NOT NULL,
+                        payload ",
+                    $payload_type,
+                    " NOT NULL
+                    )"
+                ))
+                .execute(&self.pool)
+                .await?;
+                sqlx::query(
+                    "CREATE INDEX IF NOT EXISTS github_events_repository
+                    ON github_events (repository, received_at)",
+                )
+                .execute(&self.pool)
+                .await?;
+                sqlx::query(
+                    "CREATE INDEX IF NOT EXISTS github_events_received_at
+                    ON github_events (received_at)",
+                )
+                .execute(&self.pool)
+                .await?;
+                Ok(())
+            }
+
+            fn select<'args>() -> sqlx::QueryBuilder<'args, $db> {
+                sqlx::QueryBuilder::new(concat!(
+                    "SELECT delivery_id, event_type, received_at, ",
+                    $payload_select,
+                    " FROM github_events"
+                ))
+            }
+        }
+
+        #[cfg(feature = $feature)]
+        impl EventStore for $store {
+            fn append<'a>(
+                &'a self,
+                event: &'a StoredEvent,
+            ) -> BoxFuture<'a, Result<bool, StoreError>> {
+                Box::pin(async move {
+                    let row = Row::new(event)?;
+                    let result = sqlx::query(concat!(
+                        "INSERT INTO github_events
+                            (delivery_id, event_type, repository, received_at, payload)
+                        VALUES ($1, $2, $3, $4, ",
+                        $payload_bind,
+                        ")
+                        ON CONFLICT (delivery_id) DO NOTHING"
+                    ))
+                    .bind(&event.delivery_id)
+                    .bind(row.event_type)
+                    .bind(row.repository)
+                    .bind(row.received_at)
+                    .bind(row.payload)
+                    .execute(&self.pool)
+                    .await?;
+                    Ok(result.rows_affected() > 0)
+                })
+            }
+
+            fn get_by_delivery_id<'a>(
+                &'a self,
+                delivery_id: &'a str,
+            ) -> BoxFuture<'a, Result<Option<StoredEvent>, StoreError>> {
+                Box::pin(async move {
+                    let mut select = Self::select();
+                    select.push(" WHERE delivery_id = ").push_bind(delivery_id);
+                    select
+                        .build_query_as()
+                        .fetch_optional(&self.pool)
+                        .await?
+                        .map(from_row)
+                        .transpose()
+                })
+            }
+
+            fn query<'a>(
+                &'a self,
+                query: &'a Query,
+            ) -> BoxFuture<'a, Result<Vec<StoredEvent>, StoreError>> {
+                Box::pin(async move {
+                    let mut select = Self::select();
+                    select.push(" WHERE 1 = 1");
+                    if let Some(repository) = &query.repository {
+                        select.push(" AND repository = ").push_bind(repository.as_str());
+                    }
+                    if !query.event_types.is_empty() {
+                        select.push(" AND event_type IN (");
+                        let mut types = select.separated(", ");
+                        for event_type in &query.event_types {
+                            types.push_bind(event_type.as_str());
+                        }
+                        select.push(")");
+                    }
+                    if let Some(since) = query.since {
+                        select.push(" AND received_at >= ").push_bind(millis(since));
+                    }
+                    if let Some(until) = query.until {
+                        select.push(" AND received_at < ").push_bind(millis(until));
+                    }
+                    select.push(" ORDER BY received_at, delivery_id");
+                    if let Some(limit) = query.limit {
+                        select.push(" LIMIT ").push_bind(i64::from(limit));
+                    }
+                    select
+                        .build_query_as()
+                        .fetch_all(&self.pool)
+                        .await?
+                        .into_iter()
+                        .map(from_row)
+                        .collect()
+                })
+            }
+        }
+    };
+}
+
+sql_store! {
+    /// A store in a SQLite database.
+    SqliteStore(sqlx::Sqlite, sqlx::SqlitePool),
+    feature = "sqlite",
+    payload_type = "TEXT",
+    payload_bind = "$5",
+    payload_select = "payload",
+}
+
+sql_store! {
+    /// A store in a Postgres database. Payloads are `jsonb`.
+    PostgresStore(sqlx::Postgres, sqlx::PgPool),
+    feature = "postgres",
+    payload_type = "JSONB",
+    payload_bind = "$5::jsonb",
+    payload_select = "payload::text",
+}
@@ -0,0 +1,427 @@
+//! One-line descriptions of events, for chat notifications and activity feeds.
+use serde_json::Value;
+
+use crate::prelude::*;
+use crate::{git_ref, Event, EventType};
+
+impl Event {
+    /// A short description of the event, such as
+    /// `octocat opened pull request #42 in rust-lang/rust: Fix ICE`.
+    pub fn summary(&self) -> String {
+        let payload = match ::serde_json::to_value(self) {
+            Ok(Value::Object(tagged)) => tagged.into_iter().next().map(|(_, payload)| payload),
+            _ => None,
+        }
+        .unwrap_or(Value::Null);
+        summarize(self.event_type(), &payload)
+    }
+}
+
+pub(crate) fn summarize(event_type: EventType, p: &Value) -> String {
+    let actor = text(&p["sender"]["login"]).unwrap_or("someone");
+    let repo = text(&p["repository"]["full_name"]);
+    let org = text(&p["organization"]["login"]);
+    let place = match repo.or(org) {
+        Some(place) => format!(" in {}", place),
+        None => String::new(),
+    };
+    let action = text(&p["action"]).map(verb).unwrap_or_default();
+    let or_unknown = |value: &Value| text(value).unwrap_or("?").to_owned();
+
+    match event_type {
+        EventType::CheckRun | EventType::CheckSuite => {
+            let (kind, check, name) = match event_type {
+                EventType::CheckRun => (
+                    "check run",
+                    &p["check_run"],
+                    or_unknown(&p["check_run"]["name"]),
+                ),
+                _ => (
+                    "check suite",
+                    &p["check_suite"],
+                    format!("on {}", or_unknown(&p["check_suite"]["head_branch"])),
+                ),
+            };
+            match text(&check["conclusion"]) {
+                Some(conclusion) if action == "completed" => {
+                    format!(
+                        "{} {} {}{}: {}",
+                        capitalize(kind),
+                        name,
+                        action,
+                        place,
+                        conclusion
+                    )
+                }
+                _ => format!("{} {} {}{}", capitalize(kind), name, action, place),
+            }
+        }
+        EventType::CommitComment => format!(
+            "{} commented on commit {}{}",
+            actor,
+            short_sha(&p["comment"]["commit_id"]),
+            place
+        ),
+        EventType::Create | EventType::Delete => format!(
+            "{} {} {} {}{}",
+            actor,
+            if event_type == EventType::Create {
+                "created"
+            } else {
+                "deleted"
+            },
+            or_unknown(&p["ref_type"]),
+            or_unknown(&p["ref"]),
+            place
+        ),
+        EventType::Deployment => format!(
+            "{} deployed {} to {}{}",
+            actor,
+            or_unknown(&p["deployment"]["ref"]),
+            or_unknown(&p["deployment"]["environment"]),
+            place
+        ),
+        EventType::DeploymentStatus => format!(
+            "Deployment of {} to {} {}{}",
+            or_unknown(&p["deployment"]["ref"]),
+            or_unknown(&p["deployment"]["environment"]),
+            verb(text(&p["deployment_status"]["state"]).unwrap_or("?")),
+            place
+        ),
+        EventType::Fork => format!(
+            "{} forked {} to {}",
+            actor,
+            repo.unwrap_or("?"),
+            or_unknown(&p["forkee"]["full_name"])
+        ),
+        EventType::GithubAppAuthorization => {
+            format!("{} {} their authorization of the app", actor, action)
+        }
+        EventType::Gollum => {
+            let pages = p["pages"].as_array().map(Vec::as_slice).unwrap_or_default();
+            match pages {
+                [page] => format!(
+                    "{} {} wiki page {}{}",
+                    actor,
+                    verb(text(&page["action"]).unwrap_or("edited")),
+                    or_unknown(&page["title"]),
+                    place
+                ),
+                pages => format!("{} updated {} wiki pages{}", actor, pages.len(), place),
+            }
+        }
+        EventType::Installation => format!(
+            "{} {} the app on {}",
+            actor,
+            match action.as_str() {
+                "created" => "installed",
+                "deleted" => "uninstalled",
+                action => action,
+            },
+            or_unknown(&p["installation"]["account"]["login"])
+        ),
+        EventType::InstallationRepositories => {
+            let (count, direction) = if action == "removed" {
+                (&p["repositories_removed"], "from")
+            } else {
+                (&p["repositories_added"], "to")
+            };
+            format!(
+                "{} {} {} {} the app on {}",
+                actor,
+                action,
+                plural(
+                    count.as_array().map_or(0, Vec::len),
+                    "repository",
+                    "repositories"
+                ),
+                direction,
+                or_unknown(&p["installation"]["account"]["login"])
+            )
+        }
+        EventType::IssueComment => {
+            format!(
+                "{} {} on {}{}",
+                actor,
+                comment_verb(&action),
+                issue(&p["issue"]),
+                place
+            ) + &title(&p["issue"])
+        }
+        EventType::Issues => {
+            format!("{} {} {}{}", actor, action, issue(&p["issue"]), place) + &title(&p["issue"])
+        }
+        EventType::Label => format!(
+            "{} {} label {}{}",
+            actor,
+            action,
+            or_unknown(&p["label"]["name"]),
+            place
+        ),
+        EventType::Member => format!(
+            "{} {} {} {} {}",
+            actor,
+            action,
+            or_unknown(&p["member"]["login"]),
+            if action == "removed" { "from" } else { "to" },
+            repo.unwrap_or("?")
+        ),
+        EventType::Membership if p["scope"] == "organization" => format!(
+            "{} {} {} {} {}",
+            actor,
+            action,
+            or_unknown(&p["member"]["login"]),
+            if action == "removed" { "from" } else { "to" },
+            org.unwrap_or("?")
+        ),
+        EventType::Membership => format!(
+            "{} {} {} {} team {}{}",
+            actor,
+            action,
+            or_unknown(&p["member"]["login"]),
+            if action == "removed" { "from" } else { "to" },
+            or_unknown(&p["team"]["name"]),
+            place
+        ),
+        EventType::Milestone => format!(
+            "{} {} milestone {}{}",
+            actor,
+            action,
+            or_unknown(&p["milestone"]["title"]),
+            place
+        ),
+        EventType::Organization => match text(&p["membership"]["user"]["login"]) {
+            Some(member) if action == "member added" => {
+                format!("{} added {} to {}", actor, member, org.unwrap_or("?"))
+            }
+            Some(member) if action == "member removed" => {
+                format!("{} removed {} from {}", actor, member, org.unwrap_or("?"))
+            }
+            Some(member) if action == "member invited" => {
+                format!("{} invited {} to {}", actor, member, org.unwrap_or("?"))
+            }
+            _ if action == "renamed" => format!(
+                "{} renamed {} to {}",
+                actor,
+                or_unknown(&p["changes"]["login"]["from"]),
+                org.unwrap_or("?")
+            ),
+            _ => format!("{} {}{}", actor, action, place),
+        },
+        EventType::OrgBlock => format!(
+            "{} {} {}{}",
+            actor,
+            action,
+            or_unknown(&p["blocked_user"]["login"]),
+            place
+        ),
+        EventType::PageBuild => match text(&p["build"]["status"]) {
+            Some("built") => format!("GitHub Pages built{}", place),
+            Some("errored") => format!("GitHub Pages build failed{}", place),
+            status => format!("GitHub Pages build {}{}", status.unwrap_or("?"), place),
+        },
+        EventType::Ping => format!(
+            "Webhook {} pinged{}: {}",
+            p["hook_id"],
+            place,
+            or_unknown(&p["zen"])
+        ),
+        EventType::ProjectCard => format!("{} {} a project card{}", actor, action, place),
+        EventType::ProjectColumn => format!(
+            "{} {} project column {}{}",
+            actor,
+            action,
+            or_unknown(&p["project_column"]["name"]),
+            place
+        ),
+        EventType::Project => format!(
+            "{} {} project {}{}",
+            actor,
+            action,
+            or_unknown(&p["project"]["name"]),
+            place
+        ),
+        EventType::Public => format!("{} made {} public", actor, repo.unwrap_or("?")),
+        EventType::PullRequest => {
+            let pull_request = &p["pull_request"];
+            let action = if action == "closed" && pull_request["merged"] == true {
+                "merged".to_owned()
+            } else {
+                action
+            };
+            format!("{} {} {}{}", actor, action, issue(pull_request), place) + &title(pull_request)
+        }
+        EventType::PullRequestReview => {
+            let pull_request = &p["pull_request"];
+            let reviewed = match text(&p["review"]["state"]) {
+                _ if action != "submitted" => format!("{} a review on", action),
+                Some("approved") => "approved".to_owned(),
+                Some("changes_requested") => "requested changes on".to_owned(),
+                _ => "reviewed".to_owned(),
+            };
+            format!("{} {} {}{}", actor, reviewed, issue(pull_request), place)
+                + &title(pull_request)
+        }
+        EventType::PullRequestReviewComment => {
+            format!(
+                "{} {} on {}{}",
+                actor,
+                comment_verb(&action),
+                issue(&p["pull_request"]),
+                place
+            ) + &title(&p["pull_request"])
+        }
+        EventType::Push => {
+            let git_ref = text(&p["ref"]).unwrap_or("?");
+            let branch = git_ref::short_name(git_ref);
+            if p["deleted"] == true {
+                format!("{} deleted {}{}", actor, branch, place)
+            } else {
+                let commits = p["commits"].as_array().map_or(0, Vec::len);
+                format!(
+                    "{} {}pushed {} to {}{}",
+                    text(&p["pusher"]["name"]).unwrap_or(actor),
+                    if p["forced"] == true { "force-" } else { "" },
+                    plural(commits, "commit", "commits"),
+                    branch,
+                    place
+                )
+            }
+        }
+        EventType::Release => {
+            let release = &p["release"];
+            let name = text(&release["name"]).or_else(|| text(&release["tag_name"]));
+            format!(
+                "{} {} release {}{}",
+                actor,
+                action,
+                name.unwrap_or("?"),
+                place
+            )
+        }
+        EventType::Repository => format!("{} {} repository {}", actor, action, repo.unwrap_or("?")),
+        EventType::RepositoryDispatch => {
+            format!("{} dispatched {}{}", actor, or_unknown(&p["action"]), place)
+        }
+        EventType::RepositoryImport => format!(
+            "Import of {} {}",
+            repo.unwrap_or("?"),
+            verb(text(&p["status"]).unwrap_or("?"))
+        ),
+        EventType::RepositoryVulnerabilityAlert => format!(
+            "Vulnerability alert for {} {}{}",
+            or_unknown(&p["alert"]["affected_package_name"]),
+            match action.as_str() {
+                "create" => "opened",
+                "dismiss" => "dismissed",
+                "resolve" => "resolved",
+                action => action,
+            },
+            place
+        ),
+        EventType::SecurityAdvisory => format!(
+            "Security advisory {} {}: {}",
+            or_unknown(&p["security_advisory"]["ghsa_id"]),
+            action,
+            or_unknown(&p["security_advisory"]["summary"])
+        ),
+        EventType::Status => format!(
+            "{} {} for commit {}{}",
+            or_unknown(&p["context"]),
+            verb(text(&p["state"]).unwrap_or("?")),
+            short_sha(&p["sha"]),
+            place
+        ),
+        EventType::Team => {
+            let team = or_unknown(&p["team"]["name"]);
+            match action.as_str() {
+                "added to repository" => {
+                    format!("{} added {} to team {}", actor, repo.unwrap_or("?"), team)
+                }
+                "removed from repository" => {
+                    format!(
+                        "{} removed {} from team {}",
+                        actor,
+                        repo.unwrap_or("?"),
+                        team
+                    )
+                }
+                action => format!(
+                    "{} {} team {}{}",
+                    actor,
+                    action,
+                    team,
+                    org.map(|org| format!(" in {}", org)).unwrap_or_default()
+                ),
+            }
+        }
+        EventType::TeamAdd => format!(
+            "{} added {} to team {}",
+            actor,
+            repo.unwrap_or("?"),
+            or_unknown(&p["team"]["name"])
+        ),
+        EventType::Watch => format!("{} starred {}", actor, repo.unwrap_or("?")),
+    }
+}
+
+/// A non-empty string.
+fn text(value: &Value) -> Option<&str> {
+    value.as_str().filter(|s| !s.is_empty())
+}
+
+/// An action or state as a verb in the past tense.
+fn verb(action: &str) -> String {
+    match action {
+        "synchronize" => "updated".to_owned(),
+        "review_requested" => "requested a review on".to_owned(),
+        "review_request_removed" => "removed a review request from".to_owned(),
+        "ready_for_review" => "marked as ready for review".to_owned(),
+        "converted_to_draft" => "converted to a draft".to_owned(),
+        "success" => "succeeded".to_owned(),
+        "failure" => "failed".to_owned(),
+        "error" => "errored".to_owned(),
+        action => action.replace('_', " "),
+    }
+}
+
+fn comment_verb(action: &str) -> String {
+    match action {
+        "created" => "commented".to_owned(),
+        action => format!("{} a comment", action),
+    }
+}
+
+/// `issue #1347` or `pull request #1347`.
+fn issue(issue: &Value) -> String {
+    let kind = if issue["pull_request"].is_object() || issue["head"].is_object() {
+        "pull request"
+    } else {
+        "issue"
+    };
+    format!("{} #{}", kind, issue["number"])
+}
+
+fn title(issue: &Value) -> String {
+    match text(&issue["title"]) {
+        Some(title) => format!(": {}", title),
+        None => String::new(),
+    }
+}
+
+fn short_sha(sha: &Value) -> &str {
+    let sha = text(sha).unwrap_or("?");
+    sha.get(..7).unwrap_or(sha)
+}
+
+fn plural(count: usize, one: &str, many: &str) -> String {
+    format!("{} {}", count, if count == 1 { one } else { many })
+}
+
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
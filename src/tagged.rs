@@ -0,0 +1,66 @@
+//! Deserializing an [`Event`](enum.Event.html) from an untagged payload.
+//!
+//! Webhook payloads don't say which event they are; that comes from the `X-GitHub-Event` header.
+//! `Tagged` presents a payload to `Event`'s derived `Deserialize` as if it were the externally
+//! tagged `{"VariantName": payload}`, without copying or buffering the payload.
+use serde::de::{self, DeserializeSeed, EnumAccess, IntoDeserializer, VariantAccess, Visitor};
+
+pub(crate) struct Tagged<D> {
+    pub variant: &'static str,
+    pub payload: D,
+}
+
+impl<'de, D: de::Deserializer<'de>> de::Deserializer<'de> for Tagged<D> {
+    type Error = D::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, D::Error> {
+        visitor.visit_enum(self)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+impl<'de, D: de::Deserializer<'de>> EnumAccess<'de> for Tagged<D> {
+    type Error = D::Error;
+    type Variant = Payload<D>;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, Payload<D>), D::Error> {
+        let variant = seed.deserialize(IntoDeserializer::<D::Error>::into_deserializer(
+            self.variant,
+        ))?;
+        Ok((variant, Payload(self.payload)))
+    }
+}
+
+pub(crate) struct Payload<D>(D);
+
+impl<'de, D: de::Deserializer<'de>> VariantAccess<'de> for Payload<D> {
+    type Error = D::Error;
+
+    fn unit_variant(self) -> Result<(), D::Error> {
+        de::Deserialize::deserialize(self.0)
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value, D::Error> {
+        seed.deserialize(self.0)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, len: usize, visitor: V) -> Result<V::Value, D::Error> {
+        self.0.deserialize_tuple(len, visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, D::Error> {
+        self.0.deserialize_struct("", fields, visitor)
+    }
+}
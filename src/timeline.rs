@@ -0,0 +1,726 @@
+//! [Events API](https://developer.github.com/v3/activity/events/) timeline types.
+//!
+//! Timeline events wrap a `payload` in an envelope describing who did what to which repository.
+//! The payloads are smaller than their webhook counterparts: they omit `repository` and `sender`
+//! in favor of the envelope's `repo` and `actor`.
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::actions;
+use crate::prelude::*;
+use crate::EventType;
+use crate::{
+    Comment, CommentChanges, Commit, Forkee, IStr, Issue, IssueChanges, IssueRef, Label, Member,
+    Page, Partial, PullRequest, PullRequestChanges, PullRequestRef, Release, RepositoryId, Review,
+    User, UserId,
+};
+
+/// A single item returned by the [Events API](https://developer.github.com/v3/activity/events/).
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct TimelineEvent {
+    /// The unique identifier for the event.
+    pub id: String,
+    /// The user that triggered the event.
+    pub actor: Actor,
+    /// The repository the event occurred in.
+    pub repo: Repo,
+    /// The organization the repository belongs to, if any.
+    pub org: Option<Org>,
+    /// The `type` of the event and its `payload`.
+    pub payload: EventPayload,
+    /// Whether the event is visible to all users.
+    pub public: bool,
+    /// The date the event was triggered.
+    pub created_at: String,
+}
+
+impl TimelineEvent {
+    /// The type of the webhook event the payload corresponds to, or `None` for the legacy
+    /// events that have none.
+    pub fn event_type(&self) -> Option<EventType> {
+        Some(match self.payload {
+            EventPayload::CommitCommentEvent(_) => EventType::CommitComment,
+            EventPayload::CreateEvent(_) => EventType::Create,
+            EventPayload::DeleteEvent(_) => EventType::Delete,
+            EventPayload::ForkEvent(_) => EventType::Fork,
+            EventPayload::GollumEvent(_) => EventType::Gollum,
+            EventPayload::IssueCommentEvent(_) => EventType::IssueComment,
+            EventPayload::IssuesEvent(_) => EventType::Issues,
+            EventPayload::MemberEvent(_) => EventType::Member,
+            EventPayload::PublicEvent(_) => EventType::Public,
+            EventPayload::PullRequestEvent(_) => EventType::PullRequest,
+            EventPayload::PullRequestReviewEvent(_) => EventType::PullRequestReview,
+            EventPayload::PullRequestReviewCommentEvent(_) => EventType::PullRequestReviewComment,
+            EventPayload::PushEvent(_) => EventType::Push,
+            EventPayload::ReleaseEvent(_) => EventType::Release,
+            EventPayload::WatchEvent(_) => EventType::Watch,
+            EventPayload::DownloadEvent(_)
+            | EventPayload::FollowEvent(_)
+            | EventPayload::ForkApplyEvent(_)
+            | EventPayload::GistEvent(_) => return None,
+        })
+    }
+
+    /// The event's `type` in the Events API, e.g. `PushEvent`.
+    pub fn type_name(&self) -> &'static str {
+        self.payload.type_name()
+    }
+}
+
+// The API puts the payload's `type` and `payload` beside the other fields, which takes
+// `#[serde(flatten)]`. Formats that aren't self-describing can't flatten, so they get the
+// payload as a field of its own.
+macro_rules! envelopes {
+    ($($(#[$attr:meta])* $borrowed:ident, $owned:ident;)*) => {$(
+        #[derive(Serialize)]
+        #[serde(rename = "TimelineEvent")]
+        struct $borrowed<'a> {
+            id: &'a str,
+            actor: &'a Actor,
+            repo: &'a Repo,
+            org: &'a Option<Org>,
+            $(#[$attr])*
+            payload: &'a EventPayload,
+            public: bool,
+            created_at: &'a str,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename = "TimelineEvent")]
+        struct $owned {
+            id: String,
+            actor: Actor,
+            repo: Repo,
+            org: Option<Org>,
+            $(#[$attr])*
+            payload: EventPayload,
+            public: bool,
+            created_at: String,
+        }
+
+        impl<'a> From<&'a TimelineEvent> for $borrowed<'a> {
+            fn from(event: &'a TimelineEvent) -> Self {
+                $borrowed {
+                    id: &event.id,
+                    actor: &event.actor,
+                    repo: &event.repo,
+                    org: &event.org,
+                    payload: &event.payload,
+                    public: event.public,
+                    created_at: &event.created_at,
+                }
+            }
+        }
+
+        impl From<$owned> for TimelineEvent {
+            fn from(event: $owned) -> Self {
+                TimelineEvent {
+                    id: event.id,
+                    actor: event.actor,
+                    repo: event.repo,
+                    org: event.org,
+                    payload: event.payload,
+                    public: event.public,
+                    created_at: event.created_at,
+                }
+            }
+        }
+    )*};
+}
+
+envelopes! {
+    #[serde(flatten)]
+    FlatRef, Flat;
+    NestedRef, Nested;
+}
+
+impl Serialize for TimelineEvent {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            FlatRef::from(self).serialize(serializer)
+        } else {
+            NestedRef::from(self).serialize(serializer)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for TimelineEvent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            Flat::deserialize(deserializer).map(TimelineEvent::from)
+        } else {
+            Nested::deserialize(deserializer).map(TimelineEvent::from)
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[non_exhaustive]
+pub struct Actor {
+    pub id: UserId,
+    pub login: IStr,
+    pub display_login: Option<IStr>,
+    pub gravatar_id: IStr,
+    pub url: IStr,
+    pub avatar_url: IStr,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[non_exhaustive]
+pub struct Repo {
+    pub id: RepositoryId,
+    /// The repository's full name, e.g. `octocat/Hello-World`.
+    pub name: IStr,
+    pub url: IStr,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[non_exhaustive]
+pub struct Org {
+    pub id: i64,
+    pub login: IStr,
+    pub gravatar_id: IStr,
+    pub url: IStr,
+    pub avatar_url: IStr,
+}
+
+// The API tags payloads with an adjacent `type`, which formats that can't deserialize
+// identifiers, such as bincode, can't read; they get an externally tagged payload instead.
+macro_rules! event_payload {
+    ($($(#[$attr:meta])* $variant:ident($payload:ty),)*) => {
+        /// The payload of a [`TimelineEvent`], keyed by the event's `type`.
+        #[derive(Debug, Clone, PartialEq)]
+        #[allow(clippy::large_enum_variant)]
+        pub enum EventPayload {
+            $($(#[$attr])* $variant($payload),)*
+        }
+
+        #[derive(Serialize)]
+        #[serde(tag = "type", content = "payload", rename = "EventPayload")]
+        #[allow(clippy::enum_variant_names)]
+        enum AdjacentRef<'a> {
+            $($variant(&'a $payload),)*
+        }
+
+        #[derive(Deserialize)]
+        #[serde(tag = "type", content = "payload", rename = "EventPayload")]
+        #[allow(clippy::large_enum_variant, clippy::enum_variant_names)]
+        enum Adjacent {
+            $($variant($payload),)*
+        }
+
+        #[derive(Serialize)]
+        #[serde(rename = "EventPayload")]
+        #[allow(clippy::enum_variant_names)]
+        enum ExternalRef<'a> {
+            $($variant(&'a $payload),)*
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename = "EventPayload")]
+        #[allow(clippy::large_enum_variant, clippy::enum_variant_names)]
+        enum External {
+            $($variant($payload),)*
+        }
+
+        impl EventPayload {
+            /// The event's `type` in the Events API, e.g. `PushEvent`.
+            pub fn type_name(&self) -> &'static str {
+                match self {
+                    $(EventPayload::$variant(_) => stringify!($variant),)*
+                }
+            }
+        }
+
+        impl Serialize for EventPayload {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                if serializer.is_human_readable() {
+                    match self {
+                        $(EventPayload::$variant(payload) => AdjacentRef::$variant(payload),)*
+                    }
+                    .serialize(serializer)
+                } else {
+                    match self {
+                        $(EventPayload::$variant(payload) => ExternalRef::$variant(payload),)*
+                    }
+                    .serialize(serializer)
+                }
+            }
+        }
+
+        impl<'de> Deserialize<'de> for EventPayload {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                if deserializer.is_human_readable() {
+                    Ok(match Adjacent::deserialize(deserializer)? {
+                        $(Adjacent::$variant(payload) => EventPayload::$variant(payload),)*
+                    })
+                } else {
+                    Ok(match External::deserialize(deserializer)? {
+                        $(External::$variant(payload) => EventPayload::$variant(payload),)*
+                    })
+                }
+            }
+        }
+    };
+}
+
+event_payload! {
+    CommitCommentEvent(CommitCommentEventPayload),
+    CreateEvent(CreateEventPayload),
+    DeleteEvent(DeleteEventPayload),
+    ForkEvent(ForkEventPayload),
+    GollumEvent(GollumEventPayload),
+    IssueCommentEvent(IssueCommentEventPayload),
+    IssuesEvent(IssuesEventPayload),
+    MemberEvent(MemberEventPayload),
+    /// Without a doubt: the best GitHub event.
+    PublicEvent(PublicEventPayload),
+    PullRequestEvent(PullRequestEventPayload),
+    PullRequestReviewEvent(PullRequestReviewEventPayload),
+    PullRequestReviewCommentEvent(PullRequestReviewCommentEventPayload),
+    PushEvent(PushEventPayload),
+    ReleaseEvent(ReleaseEventPayload),
+    WatchEvent(WatchEventPayload),
+    // Events the API no longer sends, still found in archives of it such as GH Archive.
+    DownloadEvent(DownloadEventPayload),
+    FollowEvent(FollowEventPayload),
+    ForkApplyEvent(ForkApplyEventPayload),
+    GistEvent(GistEventPayload),
+}
+
+/// A [commit comment](https://developer.github.com/v3/repos/comments/) was created.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[non_exhaustive]
+pub struct CommitCommentEventPayload {
+    /// The action performed. Can be `created`.
+    pub action: Option<String>,
+    /// The [commit comment](https://developer.github.com/v3/repos/comments/) resource.
+    pub comment: Comment,
+}
+
+/// A git branch or tag was created.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[non_exhaustive]
+pub struct CreateEventPayload {
+    /// The git ref (or `null` if only a repository was created).
+    #[serde(rename = "ref")]
+    pub ref_field: Option<String>,
+    /// The type of git ref object created in the repository. Can be `branch`, `tag`, or `repository`.
+    pub ref_type: String,
+    /// The name of the repository's default branch (usually `master`).
+    pub master_branch: String,
+    /// The repository's current description.
+    pub description: Option<String>,
+    pub pusher_type: String,
+}
+
+/// A git branch or tag was deleted.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[non_exhaustive]
+pub struct DeleteEventPayload {
+    /// The full git ref.
+    #[serde(rename = "ref")]
+    pub ref_field: String,
+    /// The type of git ref object deleted in the repository. Can be `branch` or `tag`.
+    pub ref_type: String,
+    pub pusher_type: String,
+}
+
+/// A user forked a repository.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[non_exhaustive]
+pub struct ForkEventPayload {
+    /// The created [repository](https://developer.github.com/v3/repos/).
+    pub forkee: Forkee,
+}
+
+/// A wiki page was created or updated.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[non_exhaustive]
+pub struct GollumEventPayload {
+    /// The pages that were updated.
+    pub pages: Vec<Page>,
+}
+
+/// Activity related to an [issue or pull request comment](https://developer.github.com/v3/issues/comments/).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct IssueCommentEventPayload {
+    /// The action that was performed on the comment.
+    /// Can be one of `Created`, `Edited`, or `Deleted`.
+    pub action: actions::CrEdDel,
+    /// The changes to the comment if the action was "edited".
+    pub changes: Option<CommentChanges>,
+    /// The [issue](https://developer.github.com/v3/issues/) the comment belongs to.
+    pub issue: Partial<Issue, IssueRef>,
+    /// The [comment](https://developer.github.com/v3/issues/comments/) itself.
+    pub comment: Comment,
+}
+
+/// Activity related to an [issue](https://developer.github.com/v3/issues/).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[non_exhaustive]
+pub struct IssuesEventPayload {
+    /// The action that was performed. Can be one of `opened`, `edited`, `closed`, `reopened`,
+    /// `assigned`, `unassigned`, `labeled`, or `unlabeled`.
+    pub action: String,
+    /// The [issue](https://developer.github.com/v3/issues) itself.
+    pub issue: Partial<Issue, IssueRef>,
+    /// The changes to the issue if the action was "edited".
+    pub changes: Option<IssueChanges>,
+    /// The optional user who was assigned or unassigned from the issue.
+    pub assignee: Option<User>,
+    /// The optional label that was added or removed from the issue.
+    pub label: Option<Label>,
+}
+
+/// Activity related to repository collaborators.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[non_exhaustive]
+pub struct MemberEventPayload {
+    /// The action that was performed. Can be `added` to indicate a user accepted an invitation to a repository.
+    pub action: String,
+    /// The user that was added.
+    pub member: Member,
+    /// The changes to the collaborator permissions if the action was `edited`.
+    #[serde(default, with = "crate::untyped::OptionJson")]
+    pub changes: Option<::serde_json::Value>,
+}
+
+/// A private repository was made public. The payload is empty.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+pub struct PublicEventPayload {}
+
+/// Activity related to [pull requests](https://developer.github.com/v3/pulls).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[non_exhaustive]
+pub struct PullRequestEventPayload {
+    /// The action that was performed. Can be one of `opened`, `edited`, `closed`, `reopened`,
+    /// `assigned`, `unassigned`, `review_requested`, `review_request_removed`, `labeled`,
+    /// `unlabeled`, and `synchronize`.
+    pub action: String,
+    /// The pull request number.
+    pub number: i64,
+    /// The changes to the pull request if the action was "edited".
+    pub changes: Option<PullRequestChanges>,
+    /// The [pull request](https://developer.github.com/v3/pulls) itself.
+    pub pull_request: Partial<PullRequest, PullRequestRef>,
+}
+
+/// Activity related to [pull request reviews](https://developer.github.com/v3/pulls/reviews/).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[non_exhaustive]
+pub struct PullRequestReviewEventPayload {
+    /// The action that was performed. Can be `created`.
+    pub action: String,
+    /// The review that was affected.
+    pub review: Review,
+    /// The [pull request](https://developer.github.com/v3/pulls/) the review pertains to.
+    pub pull_request: Partial<PullRequest, PullRequestRef>,
+}
+
+/// Activity related to [pull request review comments](https://developer.github.com/v3/pulls/comments)
+/// in the pull request's unified diff.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[non_exhaustive]
+pub struct PullRequestReviewCommentEventPayload {
+    /// The action that was performed on the comment. Can be `created`.
+    pub action: String,
+    /// The changes to the comment if the action was "edited".
+    pub changes: Option<CommentChanges>,
+    /// The [pull request](https://developer.github.com/v3/pulls/) the comment belongs to.
+    pub pull_request: Partial<PullRequest, PullRequestRef>,
+    /// The [comment](https://developer.github.com/v3/pulls/comments) itself.
+    pub comment: Comment,
+}
+
+/// One or more commits are pushed to a repository branch or tag.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[non_exhaustive]
+pub struct PushEventPayload {
+    /// Unique identifier for the push.
+    pub push_id: i64,
+    /// The number of commits in the push.
+    pub size: i64,
+    /// The number of distinct commits in the push.
+    pub distinct_size: i64,
+    /// The full git ref that was pushed. Example: `refs/heads/main`.
+    #[serde(rename = "ref")]
+    pub ref_field: String,
+    /// The SHA of the most recent commit on `ref` after the push.
+    pub head: String,
+    /// The SHA of the most recent commit on `ref` before the push.
+    pub before: String,
+    /// An array of commit objects describing the pushed commits.
+    /// (The array includes a maximum of 20 commits.
+    /// If necessary, you can use the Commits API to fetch additional commits.)
+    pub commits: Vec<Commit>,
+}
+
+/// Activity related to a [release](https://developer.github.com/v3/repos/releases/).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[non_exhaustive]
+pub struct ReleaseEventPayload {
+    /// The action that was performed. Can be `published`.
+    pub action: String,
+    /// The changes to the release if the action was "edited".
+    #[serde(default, with = "crate::untyped::OptionJson")]
+    pub changes: Option<::serde_json::Value>,
+    /// The [release](https://developer.github.com/v3/repos/releases/#get-a-single-release) object.
+    pub release: Release,
+}
+
+/// When someone stars a repository.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[non_exhaustive]
+pub struct WatchEventPayload {
+    /// The action that was performed. Currently, can only be `started`.
+    pub action: String,
+}
+
+/// A file was uploaded to a repository's downloads. Legacy: downloads were retired in 2012.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[non_exhaustive]
+pub struct DownloadEventPayload {
+    /// The download that was created.
+    pub download: Download,
+}
+
+/// A file in a repository's downloads.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[non_exhaustive]
+pub struct Download {
+    #[serde(default)]
+    pub id: i64,
+    #[serde(default)]
+    pub url: String,
+    #[serde(default)]
+    pub html_url: String,
+    /// The file's name.
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// The file's size in bytes.
+    #[serde(default)]
+    pub size: i64,
+    #[serde(default)]
+    pub download_count: i64,
+    #[serde(default)]
+    pub content_type: Option<String>,
+}
+
+/// A user followed another. Legacy: the API stopped sending these in 2015.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[non_exhaustive]
+pub struct FollowEventPayload {
+    /// The user that was followed.
+    pub target: FollowTarget,
+}
+
+/// The user in a [`FollowEventPayload`](struct.FollowEventPayload.html), as the API described
+/// users at the time.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[non_exhaustive]
+pub struct FollowTarget {
+    #[serde(default)]
+    pub id: i64,
+    #[serde(default)]
+    pub login: IStr,
+    #[serde(default)]
+    pub gravatar_id: Option<IStr>,
+    #[serde(default)]
+    pub avatar_url: Option<IStr>,
+    #[serde(default)]
+    pub url: Option<IStr>,
+    #[serde(default)]
+    pub html_url: Option<IStr>,
+    /// `User` or `Organization`.
+    #[serde(default, rename = "type")]
+    pub type_field: Option<IStr>,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub company: Option<String>,
+    #[serde(default)]
+    pub blog: Option<String>,
+    #[serde(default)]
+    pub location: Option<String>,
+    #[serde(default)]
+    pub bio: Option<String>,
+    #[serde(default)]
+    pub public_repos: Option<i64>,
+    #[serde(default)]
+    pub public_gists: Option<i64>,
+    #[serde(default)]
+    pub followers: Option<i64>,
+    #[serde(default)]
+    pub following: Option<i64>,
+    #[serde(default)]
+    pub created_at: Option<String>,
+}
+
+/// A patch from a fork was applied in the fork queue. Legacy: the fork queue was retired in
+/// 2012.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[non_exhaustive]
+pub struct ForkApplyEventPayload {
+    /// The branch name the patch was applied to.
+    pub head: String,
+    /// The SHA of the branch before the patch was applied.
+    pub before: String,
+    /// The SHA of the branch after the patch was applied.
+    pub after: String,
+}
+
+/// A gist was created or updated. Legacy: the API stopped sending these in 2015.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[non_exhaustive]
+pub struct GistEventPayload {
+    /// The action that was performed. Can be `create` or `update`.
+    pub action: String,
+    /// The gist itself.
+    pub gist: Gist,
+}
+
+/// A [gist](https://docs.github.com/en/rest/gists/gists), without its files.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "test-defaults", derive(Default))]
+#[non_exhaustive]
+pub struct Gist {
+    #[serde(default)]
+    pub id: String,
+    #[serde(default)]
+    pub url: String,
+    #[serde(default)]
+    pub html_url: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub public: bool,
+    /// The number of comments on the gist.
+    #[serde(default)]
+    pub comments: i64,
+    #[serde(default)]
+    pub created_at: String,
+    #[serde(default)]
+    pub updated_at: String,
+}
+
+/// Iterate over the events in a page of the Events API, a JSON array of
+/// [`TimelineEvent`](struct.TimelineEvent.html)s, parsing each one as it is reached.
+///
+/// An event that is valid JSON but doesn't match `TimelineEvent` is returned as an error
+/// without affecting the events after it. Malformed JSON ends the iteration with an error.
+pub fn iter_page(body: &[u8]) -> PageIter<'_> {
+    PageIter {
+        body,
+        pos: 0,
+        started: false,
+        done: false,
+        pending: None,
+    }
+}
+
+/// An iterator over the events in a page of the Events API, created by [`iter_page`](fn.iter_page.html).
+#[derive(Debug)]
+pub struct PageIter<'a> {
+    body: &'a [u8],
+    pos: usize,
+    started: bool,
+    done: bool,
+    /// A framing error found after the last event, returned once that event has been.
+    pending: Option<::serde_json::Error>,
+}
+
+impl<'a> PageIter<'a> {
+    fn skip_whitespace(&mut self) {
+        while let Some(b' ' | b'\n' | b'\r' | b'\t') = self.body.get(self.pos) {
+            self.pos += 1;
+        }
+    }
+
+    fn fail(&mut self, msg: &str) -> Option<Result<TimelineEvent, ::serde_json::Error>> {
+        self.done = true;
+        Some(Err(serde::de::Error::custom(format_args!(
+            "{} at byte {}",
+            msg, self.pos
+        ))))
+    }
+}
+
+impl<'a> Iterator for PageIter<'a> {
+    type Item = Result<TimelineEvent, ::serde_json::Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(e) = self.pending.take() {
+            self.done = true;
+            return Some(Err(e));
+        }
+        if self.done {
+            return None;
+        }
+        if !self.started {
+            self.started = true;
+            self.skip_whitespace();
+            if self.body.get(self.pos) != Some(&b'[') {
+                return self.fail("expected a JSON array");
+            }
+            self.pos += 1;
+            self.skip_whitespace();
+            if self.body.get(self.pos) == Some(&b']') {
+                self.done = true;
+                return None;
+            }
+        }
+
+        let mut values = ::serde_json::Deserializer::from_slice(&self.body[self.pos..])
+            .into_iter::<&::serde_json::value::RawValue>();
+        let raw = match values.next() {
+            Some(Ok(raw)) => raw,
+            Some(Err(e)) => {
+                self.done = true;
+                return Some(Err(e));
+            }
+            None => return self.fail("unexpected end of array"),
+        };
+        self.pos += values.byte_offset();
+        let event = ::serde_json::from_str(raw.get());
+
+        self.skip_whitespace();
+        match self.body.get(self.pos) {
+            Some(b',') => self.pos += 1,
+            Some(b']') => self.done = true,
+            _ => {
+                self.pending = Some(serde::de::Error::custom(format_args!(
+                    "expected `,` or `]` at byte {}",
+                    self.pos
+                )))
+            }
+        }
+        Some(event)
+    }
+}
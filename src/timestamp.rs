@@ -0,0 +1,175 @@
+//! Timestamps that GitHub sends either as ISO 8601 strings or as seconds since the Unix epoch.
+//!
+//! The same field is a string in most payloads and a number in others (e.g. a repository's
+//! `created_at` in `push` events), so both are read into the string form.
+use core::convert::TryFrom;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::de::{self, Deserializer, Visitor};
+
+use crate::prelude::*;
+
+pub(crate) fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: From<String>,
+{
+    // Formats that aren't self-describing only ever hold the string form written by `Serialize`.
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_any(TimestampVisitor).map(T::from)
+    } else {
+        deserializer
+            .deserialize_string(TimestampVisitor)
+            .map(T::from)
+    }
+}
+
+/// The schema of a timestamp as GitHub sends it.
+#[cfg(feature = "schemars")]
+pub(crate) fn schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+    schemars::json_schema!({
+        "type": ["string", "integer"],
+        "description": "An ISO 8601 timestamp or seconds since the Unix epoch",
+    })
+}
+
+struct TimestampVisitor;
+
+impl<'de> Visitor<'de> for TimestampVisitor {
+    type Value = String;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an ISO 8601 timestamp or seconds since the Unix epoch")
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<String, E> {
+        Ok(s.to_owned())
+    }
+
+    fn visit_string<E: de::Error>(self, s: String) -> Result<String, E> {
+        Ok(s)
+    }
+
+    fn visit_i64<E: de::Error>(self, secs: i64) -> Result<String, E> {
+        Ok(from_unix(secs))
+    }
+
+    fn visit_u64<E: de::Error>(self, secs: u64) -> Result<String, E> {
+        i64::try_from(secs)
+            .map(from_unix)
+            .map_err(|_| E::invalid_value(de::Unexpected::Unsigned(secs), &self))
+    }
+}
+
+/// `timestamp`, unless the payload left it empty, as it does e.g. for the `completed_at` of
+/// check runs still in progress.
+pub(crate) fn given(timestamp: &str) -> Option<&str> {
+    Some(timestamp).filter(|timestamp| !timestamp.is_empty())
+}
+
+/// `secs` as a UTC timestamp like `2011-01-26T19:01:12Z`.
+pub(crate) fn from_unix(secs: i64) -> String {
+    let (days, secs) = (secs.div_euclid(86_400), secs.rem_euclid(86_400));
+    // The proleptic Gregorian date of a day number, after Howard Hinnant's `civil_from_days`.
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        secs / 3600,
+        secs / 60 % 60,
+        secs % 60
+    )
+}
+
+/// The seconds since the Unix epoch of a timestamp like `2011-01-26T19:01:12Z`, as written by
+/// [`from_unix`], or with an offset like `2011-01-26T11:01:12-08:00`, as in commits.
+/// Fractions of a second are ignored.
+#[cfg(feature = "std")]
+pub(crate) fn to_unix(timestamp: &str) -> Option<i64> {
+    let number = |range: core::ops::Range<usize>| -> Option<i64> {
+        let digits = timestamp.get(range)?;
+        if digits.bytes().all(|b| b.is_ascii_digit()) {
+            digits.parse().ok()
+        } else {
+            None
+        }
+    };
+    let separators = [(4, b'-'), (7, b'-'), (10, b'T'), (13, b':'), (16, b':')];
+    if !separators
+        .iter()
+        .all(|&(i, c)| timestamp.as_bytes().get(i) == Some(&c))
+    {
+        return None;
+    }
+    let offset = if timestamp.ends_with('Z') {
+        0
+    } else {
+        // `±HH:MM` after the seconds.
+        let start = timestamp
+            .len()
+            .checked_sub(6)
+            .filter(|&start| start >= 19)?;
+        let sign = match timestamp.as_bytes()[start] {
+            b'+' => 1,
+            b'-' => -1,
+            _ => return None,
+        };
+        if timestamp.as_bytes()[start + 3] != b':' {
+            return None;
+        }
+        let (hours, minutes) = (number(start + 1..start + 3)?, number(start + 4..start + 6)?);
+        sign * (hours * 3600 + minutes * 60)
+    };
+    let (year, month, day) = (number(0..4)?, number(5..7)?, number(8..10)?);
+    let (hour, minute, second) = (number(11..13)?, number(14..16)?, number(17..19)?);
+    if !(1..=12).contains(&month)
+        || !(1..=31).contains(&day)
+        || hour > 23
+        || minute > 59
+        || second > 60
+    {
+        return None;
+    }
+
+    // The day number of a proleptic Gregorian date, after Howard Hinnant's `days_from_civil`.
+    let year = year - i64::from(month <= 2);
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let mp = (month + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era * 146_097 + doe - 719_468;
+    Some(days * 86_400 + hour * 3600 + minute * 60 + second - offset)
+}
+
+/// [`to_unix`] as a `SystemTime`.
+#[cfg(feature = "std")]
+pub(crate) fn to_system_time(timestamp: &str) -> Option<SystemTime> {
+    let secs = to_unix(timestamp)?;
+    Some(if secs >= 0 {
+        UNIX_EPOCH + Duration::from_secs(secs as u64)
+    } else {
+        UNIX_EPOCH - Duration::from_secs(secs.unsigned_abs())
+    })
+}
+
+/// `time` as a UTC timestamp like `2011-01-26T19:01:12Z`, to the second.
+#[cfg(feature = "std")]
+pub(crate) fn from_system_time(time: SystemTime) -> String {
+    from_unix(match time.duration_since(UNIX_EPOCH) {
+        Ok(since) => since.as_secs() as i64,
+        Err(e) => -(e.duration().as_secs() as i64),
+    })
+}
@@ -0,0 +1,103 @@
+//! Spans and events for [tracing](https://docs.rs/tracing). Enabled by the `tracing` feature.
+//!
+//! Handling an event in [`event_span`](fn.event_span.html) or
+//! [`timeline_span`](fn.timeline_span.html) tags everything logged meanwhile with the event's
+//! delivery, type, action, repository, and sender. Async handlers should be
+//! [instrumented](https://docs.rs/tracing/0.1/tracing/trait.Instrument.html) with the span
+//! rather than entering it, so that it follows them across `.await`s:
+//!
+//! ```ignore
+//! use tracing::Instrument;
+//!
+//! handle(&event).instrument(github_events::tracing::event_span(&delivery_id, &event)).await;
+//! ```
+//!
+//! The client's API requests are in `github_api_request` spans, with their method, url, and
+//! status, which nest in whatever span is current; payloads that fail to parse are logged at
+//! the `debug` level.
+//!
+//! With the `otel` feature, the [`otel`](otel/index.html) module's spans follow OpenTelemetry's
+//! conventions for messaging, and link handlers to the requests their deliveries came in.
+use std::fmt;
+
+use ::tracing::field::Empty;
+use ::tracing::Span;
+use serde_json::Value;
+
+#[cfg(feature = "client")]
+use crate::client::backend::Request;
+use crate::timeline::TimelineEvent;
+use crate::{Event, EventType};
+
+#[cfg(feature = "otel")]
+pub mod otel;
+
+/// An `INFO` span named `github_event` for handling `event`, delivered with the GUID
+/// `delivery_id`, with the fields `delivery_id`, `event_type`, `action`, `repo`, and `sender`.
+pub fn event_span(delivery_id: &str, event: &Event) -> Span {
+    let payload = payload(event);
+    let span = ::tracing::info_span!(
+        "github_event",
+        delivery_id,
+        event_type = event.event_type().as_str(),
+        action = Empty,
+        repo = Empty,
+        sender = Empty,
+    );
+    record(&span, "action", &payload["action"]);
+    record(&span, "repo", &payload["repository"]["full_name"]);
+    record(&span, "sender", &payload["sender"]["login"]);
+    span
+}
+
+/// Like [`event_span`](fn.event_span.html), for an event from the Events API, with its id as
+/// `event_id` rather than a `delivery_id`. Legacy events, which have no webhook name, record
+/// their Events API `type` as `event_type`.
+pub fn timeline_span(event: &TimelineEvent) -> Span {
+    let payload = ::serde_json::to_value(&event.payload).unwrap_or(Value::Null);
+    let span = ::tracing::info_span!(
+        "github_event",
+        event_id = event.id.as_str(),
+        event_type = event
+            .event_type()
+            .map_or_else(|| event.type_name(), EventType::as_str),
+        action = Empty,
+        repo = event.repo.name.as_str(),
+        sender = event.actor.login.as_str(),
+    );
+    record(&span, "action", &payload["payload"]["action"]);
+    span
+}
+
+/// The payload of `event`, without the variant's tag.
+fn payload(event: &Event) -> Value {
+    match ::serde_json::to_value(event) {
+        Ok(Value::Object(tagged)) => tagged.into_iter().next().map(|(_, payload)| payload),
+        _ => None,
+    }
+    .unwrap_or(Value::Null)
+}
+
+fn record(span: &Span, field: &str, value: &Value) {
+    if let Some(value) = value.as_str() {
+        span.record(field, value);
+    }
+}
+
+pub(crate) fn parse_failed(event_type: EventType, error: &dyn fmt::Display) {
+    ::tracing::debug!(
+        event_type = event_type.as_str(),
+        error = %error,
+        "webhook payload failed to parse"
+    );
+}
+
+#[cfg(feature = "client")]
+pub(crate) fn request_span(request: &Request) -> Span {
+    ::tracing::debug_span!(
+        "github_api_request",
+        method = request.method.as_str(),
+        url = request.url.as_str(),
+        status = Empty,
+    )
+}
@@ -0,0 +1,153 @@
+//! Spans following OpenTelemetry's [semantic conventions for
+//! messaging](https://opentelemetry.io/docs/specs/semconv/messaging/messaging-spans/), exported
+//! through [tracing-opentelemetry](https://docs.rs/tracing-opentelemetry). Enabled by the `otel`
+//! feature.
+//!
+//! A delivery is received in a [`receive_span`], which continues the trace of the request's
+//! `traceparent` header if a proxy or relay in front of the receiver sent one. Handlers usually
+//! run after the request has been answered, so rather than nesting in the receive span, their
+//! [`process_span`] starts a trace of its own with a link to it. Both have `messaging.system`
+//! `github_webhooks` and the delivery's GUID as `messaging.message.id`.
+//!
+//! Trace context is read and written with the global propagator, so one must be set, e.g.
+//! `opentelemetry::global::set_text_map_propagator(TraceContextPropagator::new())`. To hand a
+//! delivery to another service, send the [`headers`] of its receive span with it, and link to
+//! them with `process_span(&delivery_id, &event, &extract(&headers))`.
+//!
+//! ```ignore
+//! use github_events::tracing::otel;
+//! use tracing::Instrument;
+//!
+//! let receive = otel::receive_span(&headers);
+//! let delivery = receive.in_scope(|| parse(&headers, &body))?;
+//! let process = otel::process_span(&delivery.id, &delivery.event, &otel::context(&receive));
+//! tokio::spawn(handle(delivery).instrument(process));
+//! ```
+use ::tracing::field::Empty;
+use ::tracing::Span;
+use opentelemetry::propagation::{Extractor, Injector};
+use opentelemetry::trace::TraceContextExt;
+use opentelemetry::{global, Context};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+use super::{payload, record};
+use crate::Event;
+
+/// The `messaging.system` of GitHub's webhooks.
+pub const MESSAGING_SYSTEM: &str = "github_webhooks";
+
+/// An `INFO` span named `github_webhook_receive` for receiving a webhook request with
+/// `headers`, compared case-insensitively, of kind `server` and in the trace of their
+/// `traceparent`, if any.
+///
+/// Its `messaging.message.id` is the `X-GitHub-Delivery` header, and its
+/// `messaging.destination.name` the `X-GitHub-Event` header, which also names the span
+/// `receive push` and so on.
+pub fn receive_span<N, V>(headers: &[(N, V)]) -> Span
+where
+    N: AsRef<str>,
+    V: AsRef<str>,
+{
+    let headers = Headers(headers);
+    let event_type = headers.get("X-GitHub-Event").unwrap_or("unknown");
+    let span = ::tracing::info_span!(
+        "github_webhook_receive",
+        otel.name = format!("receive {}", event_type),
+        otel.kind = "server",
+        messaging.system = MESSAGING_SYSTEM,
+        messaging.operation.type = "receive",
+        messaging.operation.name = "receive",
+        messaging.destination.name = event_type,
+        messaging.message.id = Empty,
+    );
+    if let Some(delivery_id) = headers.get("X-GitHub-Delivery") {
+        span.record("messaging.message.id", delivery_id);
+    }
+    let parent = global::get_text_map_propagator(|propagator| propagator.extract(&headers));
+    if parent.span().span_context().is_valid() {
+        let _ = span.set_parent(parent);
+    }
+    span
+}
+
+/// An `INFO` span named `github_event` for handling `event`, delivered with the GUID
+/// `delivery_id`, of kind `consumer` and linked to the span whose context is `received`, e.g.
+/// that of the [`receive_span`] of its delivery.
+///
+/// Besides the fields of [`event_span`](../fn.event_span.html), it has `messaging.system`,
+/// `messaging.operation.type` `process`, `messaging.destination.name` the event's type, and
+/// `messaging.message.id` the delivery's GUID, and is named `process push` and so on.
+pub fn process_span(delivery_id: &str, event: &Event, received: &Context) -> Span {
+    let payload = payload(event);
+    let event_type = event.event_type().as_str();
+    let span = ::tracing::info_span!(
+        "github_event",
+        delivery_id,
+        event_type,
+        action = Empty,
+        repo = Empty,
+        sender = Empty,
+        otel.name = format!("process {}", event_type),
+        otel.kind = "consumer",
+        messaging.system = MESSAGING_SYSTEM,
+        messaging.operation.type = "process",
+        messaging.operation.name = "process",
+        messaging.destination.name = event_type,
+        messaging.message.id = delivery_id,
+    );
+    record(&span, "action", &payload["action"]);
+    record(&span, "repo", &payload["repository"]["full_name"]);
+    record(&span, "sender", &payload["sender"]["login"]);
+    let received = received.span().span_context().clone();
+    if received.is_valid() {
+        span.add_link(received);
+    }
+    span
+}
+
+/// The OpenTelemetry context of `span`, to link a [`process_span`] to.
+pub fn context(span: &Span) -> Context {
+    span.context()
+}
+
+/// The trace context in `headers`, compared case-insensitively, e.g. those a delivery was passed
+/// on with from another service.
+pub fn extract<N, V>(headers: &[(N, V)]) -> Context
+where
+    N: AsRef<str>,
+    V: AsRef<str>,
+{
+    global::get_text_map_propagator(|propagator| propagator.extract(&Headers(headers)))
+}
+
+/// Headers carrying the trace context of `span`, e.g. `traceparent`, to send on with a delivery.
+pub fn headers(span: &Span) -> Vec<(String, String)> {
+    let mut headers = HeaderVec(Vec::new());
+    global::get_text_map_propagator(|propagator| {
+        propagator.inject_context(&span.context(), &mut headers)
+    });
+    headers.0
+}
+
+struct Headers<'a, N, V>(&'a [(N, V)]);
+
+impl<N: AsRef<str>, V: AsRef<str>> Extractor for Headers<'_, N, V> {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|(name, _)| name.as_ref().eq_ignore_ascii_case(key))
+            .map(|(_, value)| value.as_ref())
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.0.iter().map(|(name, _)| name.as_ref()).collect()
+    }
+}
+
+struct HeaderVec(Vec<(String, String)>);
+
+impl Injector for HeaderVec {
+    fn set(&mut self, key: &str, value: String) {
+        self.0.push((key.to_owned(), value));
+    }
+}
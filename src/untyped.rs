@@ -0,0 +1,125 @@
+//! Serializing untyped JSON fields in formats that aren't self-describing.
+//!
+//! Formats such as bincode can't deserialize a `serde_json::Value`, since they don't record the
+//! type of what they encode. Formats that aren't human readable therefore get untyped JSON as a
+//! string of JSON, while JSON itself is unaffected. Used as `#[serde(with = "...")]`; these are
+//! types rather than modules so that `schemars` can describe the fields as the JSON they hold.
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+
+use crate::prelude::*;
+
+fn parse<E: serde::de::Error>(json: &str) -> Result<Value, E> {
+    ::serde_json::from_str(json).map_err(E::custom)
+}
+
+/// A `Value` field.
+pub(crate) struct Json;
+
+impl Json {
+    pub(crate) fn serialize<S: Serializer>(
+        value: &Value,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            value.serialize(serializer)
+        } else {
+            serializer.serialize_str(&value.to_string())
+        }
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Value, D::Error> {
+        if deserializer.is_human_readable() {
+            Value::deserialize(deserializer)
+        } else {
+            parse(&String::deserialize(deserializer)?)
+        }
+    }
+}
+
+/// A `Vec<Value>` field.
+pub(crate) struct JsonVec;
+
+impl JsonVec {
+    pub(crate) fn serialize<S: Serializer>(
+        values: &[Value],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            values.serialize(serializer)
+        } else {
+            serializer.collect_seq(values.iter().map(Value::to_string))
+        }
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<Value>, D::Error> {
+        if deserializer.is_human_readable() {
+            Vec::deserialize(deserializer)
+        } else {
+            Vec::<String>::deserialize(deserializer)?
+                .iter()
+                .map(|json| parse(json))
+                .collect()
+        }
+    }
+}
+
+/// An `Option<Value>` field.
+pub(crate) struct OptionJson;
+
+impl OptionJson {
+    pub(crate) fn serialize<S: Serializer>(
+        value: &Option<Value>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            value.serialize(serializer)
+        } else {
+            value.as_ref().map(Value::to_string).serialize(serializer)
+        }
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Value>, D::Error> {
+        if deserializer.is_human_readable() {
+            Option::deserialize(deserializer)
+        } else {
+            Option::<String>::deserialize(deserializer)?
+                .map(|json| parse(&json))
+                .transpose()
+        }
+    }
+}
+
+#[cfg(feature = "schemars")]
+macro_rules! json_schema_as {
+    ($($ty:ident => $as:ty,)*) => {
+        $(
+            impl schemars::JsonSchema for $ty {
+                fn inline_schema() -> bool {
+                    true
+                }
+
+                fn schema_name() -> alloc::borrow::Cow<'static, str> {
+                    <$as>::schema_name()
+                }
+
+                fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+                    <$as>::json_schema(generator)
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "schemars")]
+json_schema_as! {
+    Json => Value,
+    JsonVec => Vec<Value>,
+    OptionJson => Option<Value>,
+}
@@ -0,0 +1,90 @@
+//! Waiting on whatever runtime the caller drives futures with.
+#[cfg(any(feature = "client", feature = "router"))]
+use std::collections::hash_map::RandomState;
+#[cfg(any(feature = "client", feature = "router"))]
+use std::hash::{BuildHasher, Hasher};
+#[cfg(any(feature = "batch", feature = "debounce"))]
+use std::sync::Arc;
+use std::time::Duration;
+#[cfg(any(feature = "batch", feature = "debounce"))]
+use std::time::Instant;
+
+#[cfg(any(feature = "batch", feature = "debounce", feature = "router"))]
+use futures::future::BoxFuture;
+#[cfg(any(feature = "batch", feature = "debounce"))]
+use futures::future::{self, Either};
+#[cfg(any(feature = "batch", feature = "debounce"))]
+use futures::stream::{self, BoxStream, StreamExt};
+
+/// Waits for a duration, on whatever runtime the router or stream is driven by, e.g.
+/// `|duration| Box::pin(tokio::time::sleep(duration))`.
+#[cfg(any(feature = "batch", feature = "debounce", feature = "router"))]
+pub type SleepFn = dyn Fn(Duration) -> BoxFuture<'static, ()> + Send + Sync;
+
+/// Exponential backoff from `base` up to `max` before the retry after `retries` retries, with
+/// jitter: at least half of it, so that many clients or deliveries failing at once don't retry
+/// in lockstep.
+#[cfg(any(feature = "client", feature = "router"))]
+pub(crate) fn backoff(base: Duration, max: Duration, retries: u32) -> Duration {
+    let max = base.saturating_mul(1 << retries.min(16)).min(max);
+    let random = RandomState::new().build_hasher().finish();
+    max / 2 + Duration::from_nanos(random % (max.as_nanos() as u64 / 2 + 1))
+}
+
+/// Items of a stream held back until they're due, e.g. the open batches of a `Batcher` or the
+/// events a `Debouncer` holds back.
+#[cfg(any(feature = "batch", feature = "debounce"))]
+pub(crate) trait Window: Send + 'static {
+    type Item: Send + 'static;
+    type Output: Send + 'static;
+
+    /// Take `item` in, and return what's to be yielded right away, if anything.
+    fn push(&mut self, item: Self::Item) -> Option<Self::Output>;
+
+    /// When the next held back output is due, if there is one.
+    fn due(&self) -> Option<Instant>;
+
+    /// Give up the output due soonest.
+    fn release(&mut self) -> Option<Self::Output>;
+}
+
+/// Feed `items` through `window`, yielding its outputs as they're pushed out or come due, and
+/// once `items` ends, those still held back right away.
+#[cfg(any(feature = "batch", feature = "debounce"))]
+pub(crate) fn windowed<W: Window>(
+    items: BoxStream<'static, W::Item>,
+    window: W,
+    sleep: Arc<SleepFn>,
+) -> BoxStream<'static, W::Output> {
+    let state = (items, window, sleep, false);
+    let outputs = stream::unfold(state, |mut state| async move {
+        let (items, window, sleep, done) = &mut state;
+        loop {
+            if *done {
+                return window.release().map(|output| (output, state));
+            }
+            let next = match window.due() {
+                Some(due) => {
+                    let wait = due.saturating_duration_since(Instant::now());
+                    match future::select(items.next(), sleep(wait)).await {
+                        Either::Left((next, _)) => next,
+                        Either::Right(_) => match window.release() {
+                            Some(output) => return Some((output, state)),
+                            None => continue,
+                        },
+                    }
+                }
+                None => items.next().await,
+            };
+            match next {
+                Some(item) => {
+                    if let Some(output) = window.push(item) {
+                        return Some((output, state));
+                    }
+                }
+                None => *done = true,
+            }
+        }
+    });
+    outputs.boxed()
+}
@@ -0,0 +1,33 @@
+//! Receiving webhooks in JavaScript, in browsers and in workers such as Cloudflare's, through
+//! [wasm-bindgen](https://docs.rs/wasm-bindgen), e.g. after `wasm-pack build --features wasm`.
+//!
+//! The rest of the crate builds for `wasm32-unknown-unknown` too, without this feature: parsing,
+//! the helpers on events, and the `signature` module are plain Rust; `cargo xtask wasm` checks
+//! which features build. The features that do I/O, such as the clients, sinks, and stores, need
+//! a native target, and `simd-json` needs a source of randomness that target lacks.
+use serde::Serialize;
+use wasm_bindgen::prelude::*;
+
+use crate::envelope::Envelope;
+use crate::signature;
+
+/// Parse the `body` of a webhook request, given its `X-GitHub-Event` header `event`.
+///
+/// Exported as `parseWebhook`, which returns `{event_type, payload}`, or throws if the body
+/// isn't an event of that type.
+#[wasm_bindgen(js_name = parseWebhook)]
+pub fn parse_webhook(event: &str, body: &str) -> Result<JsValue, JsError> {
+    let envelope = Envelope::new(crate::parse_webhook(event, body)?, ());
+    envelope
+        .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+        .map_err(|e| JsError::new(&e.to_string()))
+}
+
+/// Whether `signature`, from a delivery's `X-Hub-Signature-256` header, is that of `body` keyed
+/// by `secret`; see [`signature::verify`](../signature/fn.verify.html).
+///
+/// Exported as `verifySignature`.
+#[wasm_bindgen(js_name = verifySignature)]
+pub fn verify_signature(secret: &str, body: &str, signature: &str) -> bool {
+    signature::verify(secret, body.as_bytes(), signature)
+}
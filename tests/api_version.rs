@@ -0,0 +1,98 @@
+use github_events::{ApiVersion, Event, EventType};
+
+#[test]
+fn parsing() {
+    let version: ApiVersion = "2022-11-28".parse().unwrap();
+    assert_eq!(version, ApiVersion::V2022_11_28);
+    assert_eq!(version.to_string(), "2022-11-28");
+    assert_eq!(ApiVersion::default(), version);
+    assert!(version.is_known());
+    assert!(ApiVersion::new(2099, 1, 1).unwrap() > version);
+    assert!(!ApiVersion::new(2099, 1, 1).unwrap().is_known());
+
+    for invalid in [
+        "2022-11",
+        "2022-13-01",
+        "2022-11-28T00:00",
+        "22-11-28",
+        "2022-+1-28",
+    ] {
+        assert!(invalid.parse::<ApiVersion>().is_err(), "{}", invalid);
+    }
+}
+
+#[test]
+fn versioned_parsing() {
+    let event = Event::sample(EventType::Watch).unwrap();
+    let payload = match &event {
+        Event::WatchEvent(watch) => serde_json::to_vec(watch).unwrap(),
+        _ => unreachable!(),
+    };
+    assert_eq!(
+        Event::from_slice_versioned(EventType::Watch, &payload, ApiVersion::V2022_11_28).unwrap(),
+        event
+    );
+    let err = Event::from_slice_versioned(
+        EventType::Watch,
+        &payload,
+        ApiVersion::new(2099, 1, 1).unwrap(),
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("2099-01-01"), "{}", err);
+}
+
+#[cfg(feature = "client")]
+#[test]
+fn client() {
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    use futures::executor::block_on;
+    use github_events::client::backend::{BackendError, BoxFuture, HttpBackend, Request, Response};
+    use github_events::client::{Client, ClientError};
+
+    /// Answers in the version it's asked for.
+    #[derive(Debug, Default)]
+    struct Echo {
+        requests: Arc<Mutex<Vec<Request>>>,
+    }
+
+    impl HttpBackend for Echo {
+        fn send(&self, request: Request) -> BoxFuture<'_, Result<Response, BackendError>> {
+            let version = request
+                .headers
+                .iter()
+                .find(|(name, _)| name == ApiVersion::HEADER)
+                .map(|(_, value)| value.clone())
+                .unwrap_or_default();
+            self.requests.lock().unwrap().push(request);
+            Box::pin(async move {
+                Ok(Response {
+                    status: 200,
+                    headers: vec![(ApiVersion::SELECTED_HEADER.to_owned(), version)],
+                    body: b"[]".to_vec(),
+                })
+            })
+        }
+
+        fn sleep(&self, _: Duration) -> BoxFuture<'_, ()> {
+            Box::pin(async {})
+        }
+    }
+
+    let backend = Echo::default();
+    let requests = backend.requests.clone();
+    let client = Client::with_backend(backend);
+    assert_eq!(client.api_version(), ApiVersion::V2022_11_28);
+    assert!(block_on(client.events().poll()).unwrap().is_empty());
+    assert!(requests.lock().unwrap()[0]
+        .headers
+        .contains(&(ApiVersion::HEADER.to_owned(), "2022-11-28".to_owned())));
+
+    let future = ApiVersion::new(2099, 1, 1).unwrap();
+    let client = client.with_api_version(future);
+    match block_on(client.events().poll()) {
+        Err(ClientError::UnsupportedApiVersion(version)) => assert_eq!(version, future),
+        other => panic!("{:?}", other),
+    }
+}
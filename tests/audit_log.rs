@@ -0,0 +1,136 @@
+use std::time::{Duration, UNIX_EPOCH};
+
+use github_events::audit_log::AuditLogEntry;
+use serde_json::json;
+
+// From GitHub's documentation of the audit log API.
+const ENTRY: &str = r#"{
+  "@timestamp": 1606929874512,
+  "action": "team.add_member",
+  "actor": "octocat",
+  "created_at": 1606929874512,
+  "_document_id": "xJJFlFOhQ6b-5vaAFy9Rjw",
+  "org": "octo-corp",
+  "team": "octo-corp/example-team",
+  "user": "monalisa",
+  "actor_location": {
+    "country_code": "US",
+    "country_name": "United States"
+  },
+  "business": "octo-business"
+}"#;
+
+#[test]
+fn entry() {
+    let entry: AuditLogEntry = serde_json::from_str(ENTRY).unwrap();
+    assert_eq!(entry.document_id.as_deref(), Some("xJJFlFOhQ6b-5vaAFy9Rjw"));
+    assert_eq!(entry.category(), "team");
+    assert_eq!(entry.operation(), "add_member");
+    assert_eq!(entry.actor.as_deref(), Some("octocat"));
+    assert_eq!(entry.user.as_deref(), Some("monalisa"));
+    assert_eq!(entry.business.as_deref(), Some("octo-business"));
+    let location = entry.actor_location.as_ref().unwrap();
+    assert_eq!(location.country_code.as_deref(), Some("US"));
+    assert_eq!(
+        entry.time(),
+        Some(UNIX_EPOCH + Duration::from_millis(1606929874512))
+    );
+    assert_eq!(entry.other["team"], "octo-corp/example-team");
+    assert_eq!(entry.other.len(), 1);
+
+    let value = serde_json::to_value(&entry).unwrap();
+    assert_eq!(value["team"], "octo-corp/example-team");
+    assert_eq!(value["@timestamp"], 1606929874512_i64);
+    assert_eq!(
+        serde_json::from_value::<AuditLogEntry>(value).unwrap(),
+        entry
+    );
+}
+
+#[test]
+fn minimal_entry() {
+    let entry: AuditLogEntry = serde_json::from_value(json!({"action": "git.clone"})).unwrap();
+    assert_eq!(entry.category(), "git");
+    assert_eq!(entry.time(), None);
+    assert!(entry.other.is_empty());
+}
+
+#[cfg(feature = "client")]
+#[test]
+fn stream() {
+    use std::sync::{Arc, Mutex};
+
+    use futures::executor::block_on_stream;
+    use github_events::client::backend::{BackendError, BoxFuture, HttpBackend, Request, Response};
+    use github_events::client::{Client, Include};
+
+    /// Serves the audit log's newest page, growing by two entries per poll, and the page
+    /// before it.
+    #[derive(Debug, Default)]
+    struct AuditLog {
+        polls: Mutex<u32>,
+        urls: Arc<Mutex<Vec<String>>>,
+    }
+
+    fn page(ids: &[u32], next: Option<&str>) -> Response {
+        let entries: Vec<_> = ids
+            .iter()
+            .map(|id| json!({"_document_id": id.to_string(), "action": "repo.create"}))
+            .collect();
+        Response {
+            status: 200,
+            headers: next
+                .map(|next| ("Link".to_owned(), format!("<{}>; rel=\"next\"", next)))
+                .into_iter()
+                .collect(),
+            body: serde_json::to_vec(&entries).unwrap(),
+        }
+    }
+
+    impl HttpBackend for AuditLog {
+        fn send(&self, request: Request) -> BoxFuture<'_, Result<Response, BackendError>> {
+            self.urls.lock().unwrap().push(request.url.clone());
+            let response = if request.url.ends_with("before=older") {
+                page(&[3, 2, 1], None)
+            } else {
+                let mut polls = self.polls.lock().unwrap();
+                *polls += 1;
+                let newest = *polls * 2;
+                // Pages hold two entries, so the newest page alone always skips some.
+                page(
+                    &[newest, newest - 1],
+                    Some("https://api.github.com/before=older"),
+                )
+            };
+            Box::pin(async { Ok(response) })
+        }
+
+        fn sleep(&self, _: Duration) -> BoxFuture<'_, ()> {
+            Box::pin(async {})
+        }
+    }
+
+    let log = AuditLog::default();
+    let urls = log.urls.clone();
+    let client = Client::with_backend(log);
+    let stream = client
+        .org_audit_log("octo-corp")
+        .phrase("action:repo.create actor:octocat")
+        .include(Include::All)
+        .into_stream();
+    let ids: Vec<_> = block_on_stream(stream)
+        .take(4)
+        .map(|entry| entry.unwrap().document_id.unwrap())
+        .collect();
+    // The first poll starts from its newest page; the second catches up on 3 through the
+    // page before its own.
+    assert_eq!(ids, ["1", "2", "3", "4"]);
+
+    let urls = urls.lock().unwrap();
+    assert_eq!(
+        urls[0],
+        "https://api.github.com/orgs/octo-corp/audit-log?per_page=100\
+         &phrase=action%3Arepo.create%20actor%3Aoctocat&include=all"
+    );
+    assert_eq!(urls[2], "https://api.github.com/before=older");
+}
@@ -0,0 +1,59 @@
+#![cfg(feature = "avro")]
+
+use github_events::avro;
+use github_events::EventType;
+use serde_json::json;
+
+#[test]
+fn schemas_parse() {
+    for &event_type in EventType::ALL {
+        if let Some(schema) = avro::schema(event_type) {
+            if let Err(e) = apache_avro::Schema::parse(&schema) {
+                panic!("{}: {}\n{:#}", event_type, e, schema);
+            }
+        }
+    }
+}
+
+#[test]
+fn push_schema() {
+    let schema = avro::schema(EventType::Push).unwrap();
+    assert_eq!(schema["type"], "record");
+    assert_eq!(schema["name"], "PushEvent");
+    assert_eq!(schema["namespace"], avro::NAMESPACE);
+
+    assert_eq!(field(&schema, "ref")["type"], "string");
+    // Untyped JSON is kept as a string.
+    assert_eq!(
+        field(&schema, "head_commit")["type"],
+        json!(["null", "string"])
+    );
+    assert_eq!(field(&schema, "head_commit")["default"], json!(null));
+
+    let repository = field(&schema, "repository")["type"].clone();
+    assert_eq!(repository["name"], "Repository");
+    assert_eq!(
+        field(&repository, "created_at")["type"],
+        json!(["string", "long"])
+    );
+}
+
+#[test]
+fn reaction_counts_get_distinct_names() {
+    let schema = avro::schema(EventType::IssueComment).unwrap();
+    let comment = field(&schema, "comment")["type"].clone();
+    // Reactions are optional, so their type is `["null", record]`.
+    let reactions = field(&comment, "reactions")["type"][1].clone();
+    assert_eq!(field(&reactions, "_1")["type"], "long");
+    assert_eq!(field(&reactions, "_1_2")["type"], "long");
+}
+
+fn field(record: &serde_json::Value, name: &str) -> serde_json::Value {
+    record["fields"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|field| field["name"] == name)
+        .unwrap_or_else(|| panic!("no {} field", name))
+        .clone()
+}
@@ -0,0 +1,86 @@
+#![cfg(feature = "batch")]
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use futures::executor::block_on;
+use futures::future;
+use futures::stream::{self, StreamExt};
+use github_events::batch::Batcher;
+use github_events::{Event, EventType};
+
+/// An event of type `event_type` in `repository`.
+fn event(event_type: EventType, repository: &str) -> Event {
+    let mut event = Event::sample(event_type).unwrap();
+    match &mut event {
+        Event::PushEvent(e) => e.repository.full_name = repository.into(),
+        Event::WatchEvent(e) => e.repository.full_name = repository.into(),
+        _ => unreachable!(),
+    }
+    event
+}
+
+/// The repository and type of each event of each batch.
+fn keys(batches: &[Vec<Event>]) -> Vec<Vec<String>> {
+    batches
+        .iter()
+        .map(|batch| {
+            batch
+                .iter()
+                .map(|e| format!("{} {}", e.repository().unwrap().full_name, e.event_type()))
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn groups_by_repository_and_type() {
+    let events = stream::iter([
+        event(EventType::Push, "octocat/hello-world"),
+        event(EventType::Push, "octocat/spoon-knife"),
+        event(EventType::Push, "Octocat/Hello-World"),
+        event(EventType::Push, "octocat/hello-world"),
+        event(EventType::Watch, "octocat/hello-world"),
+    ]);
+    let batcher =
+        Batcher::new(Duration::from_secs(60), |_| Box::pin(future::pending())).with_max_size(2);
+    let batches: Vec<_> = block_on(batcher.batch(events).collect());
+    assert_eq!(
+        keys(&batches),
+        [
+            vec!["octocat/hello-world push", "Octocat/Hello-World push"],
+            vec!["octocat/spoon-knife push"],
+            vec!["octocat/hello-world push"],
+            vec!["octocat/hello-world watch"],
+        ]
+    );
+}
+
+#[test]
+fn windows() {
+    let sleeps = Arc::new(Mutex::new(Vec::new()));
+    let slept = sleeps.clone();
+    let batcher = Batcher::new(Duration::from_secs(60), move |duration| {
+        slept.lock().unwrap().push(duration);
+        Box::pin(future::ready(()))
+    });
+    // The stream never ends, so only windows closing yield batches.
+    let events = stream::iter([
+        event(EventType::Push, "octocat/hello-world"),
+        event(EventType::Watch, "octocat/hello-world"),
+    ])
+    .chain(stream::pending());
+    let batches: Vec<_> = block_on(batcher.batch(events).take(2).collect());
+    assert_eq!(
+        keys(&batches),
+        [
+            vec!["octocat/hello-world push"],
+            vec!["octocat/hello-world watch"]
+        ]
+    );
+    assert!(sleeps
+        .lock()
+        .unwrap()
+        .iter()
+        .all(|&slept| slept <= Duration::from_secs(60)));
+}
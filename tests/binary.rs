@@ -0,0 +1,119 @@
+#![cfg(feature = "binary-formats")]
+
+use std::fmt::Debug;
+
+use github_events::deliveries::HookDeliveryDetail;
+use github_events::fixtures;
+use github_events::hooks::HookConfig;
+use github_events::timeline::TimelineEvent;
+use github_events::{Event, EventType};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// The formats `value` doesn't survive a round trip through.
+fn round_trip<T: Serialize + DeserializeOwned + PartialEq + Debug>(value: &T) -> Vec<String> {
+    let mut errors = Vec::new();
+    let mut check = |format: &str, back: Result<T, String>| match back {
+        Ok(back) if back == *value => {}
+        Ok(back) => errors.push(format!("{}: came back as {:?}", format, back)),
+        Err(e) => errors.push(format!("{}: {}", format, e)),
+    };
+
+    check(
+        "MessagePack",
+        rmp_serde::to_vec_named(value)
+            .map_err(|e| e.to_string())
+            .and_then(|bytes| rmp_serde::from_slice(&bytes).map_err(|e| e.to_string())),
+    );
+    let mut cbor = Vec::new();
+    check(
+        "CBOR",
+        ciborium::into_writer(value, &mut cbor)
+            .map_err(|e| e.to_string())
+            .and_then(|()| ciborium::from_reader(&cbor[..]).map_err(|e| e.to_string())),
+    );
+    check(
+        "bincode",
+        bincode::serialize(value)
+            .map_err(|e| e.to_string())
+            .and_then(|bytes| bincode::deserialize(&bytes).map_err(|e| e.to_string())),
+    );
+    errors
+}
+
+#[test]
+fn events() {
+    let mut errors = Vec::new();
+    for &event_type in EventType::ALL {
+        if let Some(event) = Event::sample(event_type) {
+            for e in round_trip(&event) {
+                errors.push(format!("{} sample, {}", event_type, e));
+            }
+        }
+    }
+    for fixture in fixtures::webhooks() {
+        let event = Event::from_slice(fixture.event_type, fixture.body).unwrap();
+        for e in round_trip(&event) {
+            errors.push(format!("{}, {}", fixture.name, e));
+        }
+    }
+    assert!(errors.is_empty(), "{:#?}", errors);
+}
+
+#[test]
+fn timeline_events() {
+    let mut errors = Vec::new();
+    for fixture in fixtures::timeline() {
+        let event: TimelineEvent = serde_json::from_slice(fixture.body).unwrap();
+        for e in round_trip(&event) {
+            errors.push(format!("{}, {}", fixture.name, e));
+        }
+    }
+    assert!(errors.is_empty(), "{:#?}", errors);
+}
+
+#[test]
+fn deliveries() {
+    let detail = HookDeliveryDetail::sample();
+    assert_eq!(round_trip(&detail), Vec::<String>::new());
+
+    // JSON keeps the summary's fields at the top level.
+    let json = serde_json::to_value(&detail).unwrap();
+    assert_eq!(json["guid"], "0b989ba4-242f-11e5-81e1-c7b6966d2516");
+    assert_eq!(
+        serde_json::from_value::<HookDeliveryDetail>(json).unwrap(),
+        detail
+    );
+}
+
+#[test]
+fn json_is_unchanged() {
+    // Unset fields are still left out of JSON requests.
+    let config = serde_json::to_value(HookConfig::new("https://example.com/hook")).unwrap();
+    assert_eq!(
+        config,
+        serde_json::json!({ "url": "https://example.com/hook", "content_type": "json" })
+    );
+    assert_eq!(
+        round_trip(&HookConfig::new("https://example.com/hook")),
+        Vec::<String>::new()
+    );
+
+    let page = fixtures::timeline_page();
+    let events: Vec<TimelineEvent> = serde_json::from_slice(&page).unwrap();
+    let json: serde_json::Value = serde_json::from_slice(fixtures::timeline()[0].body).unwrap();
+    assert_eq!(
+        json["type"],
+        serde_json::to_value(&events[0]).unwrap()["type"]
+    );
+}
+
+#[test]
+fn partial_pull_requests() {
+    use github_events::{Partial, PullRequest, PullRequestRef};
+
+    let full: Partial<PullRequest, PullRequestRef> = Partial::Full(Box::new(PullRequest::sample()));
+    let abbreviated: Partial<PullRequest, PullRequestRef> = Partial::Ref(PullRequestRef::sample());
+    let errors = [round_trip(&full), round_trip(&abbreviated)].concat();
+    assert!(errors.is_empty(), "{:#?}", errors);
+}
@@ -0,0 +1,101 @@
+#![cfg(feature = "issues")]
+
+use github_events::{Event, EventType};
+use serde_json::json;
+
+const OLD_BASE: &str = "9049f1265b7d61be4a8904a9a27120d2064dab3b";
+
+fn edited(event_type: EventType, changes: serde_json::Value) -> Event {
+    let mut value = serde_json::to_value(Event::sample(event_type).unwrap()).unwrap();
+    let payload = value.as_object_mut().unwrap().values_mut().next().unwrap();
+    payload["action"] = "edited".into();
+    payload["changes"] = changes;
+    serde_json::from_value(value).unwrap()
+}
+
+#[test]
+fn issues() {
+    let event = edited(
+        EventType::Issues,
+        json!({"title": {"from": "Old title"}, "body": {"from": null}}),
+    );
+    let issue = match event {
+        Event::IssueEvent(e) => e.issue.apply_changes_backwards(e.changes.as_ref().unwrap()),
+        _ => unreachable!(),
+    };
+    assert_eq!(issue.title, "Old title");
+    assert_eq!(issue.body, None);
+    assert_eq!(issue.number, 1347);
+}
+
+#[test]
+fn pull_requests() {
+    let event = edited(
+        EventType::PullRequest,
+        json!({
+            "body": {"from": "Old body"},
+            "base": {"ref": {"from": "develop"}, "sha": {"from": OLD_BASE}},
+        }),
+    );
+    let (before, after) = match event {
+        Event::PullRequestEvent(e) => (
+            e.pull_request
+                .apply_changes_backwards(e.changes.as_ref().unwrap()),
+            e.pull_request,
+        ),
+        _ => unreachable!(),
+    };
+    assert_eq!(before.title, after.title);
+    assert_eq!(before.body.as_deref(), Some("Old body"));
+    assert_eq!(before.base.ref_field, "develop");
+    assert_eq!(before.base.sha, OLD_BASE);
+    assert_eq!(before.base.label, "octocat:develop");
+}
+
+#[test]
+fn comments_labels_and_milestones() {
+    match edited(
+        EventType::IssueComment,
+        json!({"body": {"from": "Me three"}}),
+    ) {
+        Event::IssueCommentEvent(e) => assert_eq!(
+            e.comment
+                .apply_changes_backwards(e.changes.as_ref().unwrap())
+                .body,
+            "Me three"
+        ),
+        _ => unreachable!(),
+    }
+    match edited(
+        EventType::PullRequestReviewComment,
+        json!({"body": {"from": "Fine stuff"}}),
+    ) {
+        Event::PullRequestReviewCommentEvent(e) => assert_eq!(
+            e.comment
+                .apply_changes_backwards(e.changes.as_ref().unwrap())
+                .body,
+            "Fine stuff"
+        ),
+        _ => unreachable!(),
+    }
+    match edited(EventType::Label, json!({"color": {"from": "000000"}})) {
+        Event::LabelEvent(e) => {
+            let label = e.label.apply_changes_backwards(e.changes.as_ref().unwrap());
+            assert_eq!((label.name, label.color), (e.label.name, "000000".into()));
+        }
+        _ => unreachable!(),
+    }
+    match edited(
+        EventType::Milestone,
+        json!({"title": {"from": "v0.9"}, "due_on": {"from": null}}),
+    ) {
+        Event::MilestoneEvent(e) => {
+            let milestone = e
+                .milestone
+                .apply_changes_backwards(e.changes.as_ref().unwrap());
+            assert_eq!(milestone.title, "v0.9");
+            assert_eq!(milestone.due_on, "");
+        }
+        _ => unreachable!(),
+    }
+}
@@ -0,0 +1,96 @@
+#![cfg(feature = "checks")]
+
+use github_events::{Event, EventType};
+use serde_json::{json, Value};
+
+/// The sample event of `event_type`, with `fields` set on its payload.
+fn with(event_type: EventType, fields: Value) -> Event {
+    let mut value = serde_json::to_value(Event::sample(event_type).unwrap()).unwrap();
+    let payload = value.as_object_mut().unwrap().values_mut().next().unwrap();
+    for (field, v) in fields.as_object().unwrap() {
+        payload[field] = v.clone();
+    }
+    serde_json::from_value(value).unwrap()
+}
+
+fn pull_request(number: i64) -> Value {
+    json!({
+        "url": format!("https://api.github.com/repos/octocat/Hello-World/pulls/{}", number),
+        "id": number,
+        "number": number,
+        "head": {
+            "ref": "patch-1",
+            "sha": "6dcb09b5b57875f334f61aebed695e2e4193db5e",
+            "repo": repo(),
+        },
+        "base": {
+            "ref": "master",
+            "sha": "9049f1265b7d61be4a8904a9a27120d2064dab3b",
+            "repo": repo(),
+        },
+    })
+}
+
+fn repo() -> Value {
+    json!({
+        "id": 1296269,
+        "url": "https://api.github.com/repos/octocat/Hello-World",
+        "name": "Hello-World",
+    })
+}
+
+#[test]
+fn check_runs() {
+    let mut check_run = serde_json::to_value(Event::sample(EventType::CheckRun).unwrap()).unwrap()
+        ["CheckRunEvent"]["check_run"]
+        .clone();
+    check_run["pull_requests"] = json!([pull_request(1347), pull_request(1348)]);
+    let event = with(
+        EventType::CheckRun,
+        json!({
+            "action": "requested_action",
+            "check_run": check_run,
+            "requested_action": {"identifier": "fix_errors"},
+        }),
+    );
+    match event {
+        Event::CheckRunEvent(e) => {
+            assert_eq!(e.pull_request_numbers(), [1347, 1348]);
+            let head = &e.check_run.pull_requests[0].head;
+            assert_eq!(
+                (head.ref_field.as_str(), head.repo.name.as_str()),
+                ("patch-1", "Hello-World")
+            );
+            assert_eq!(e.requested_action_identifier(), Some("fix_errors"));
+            assert!(!e.is_rerun());
+        }
+        _ => unreachable!(),
+    }
+
+    match with(EventType::CheckRun, json!({"action": "rerequested"})) {
+        Event::CheckRunEvent(e) => {
+            assert!(e.is_rerun());
+            assert_eq!(e.requested_action_identifier(), None);
+            assert!(e.pull_request_numbers().is_empty());
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn check_suites() {
+    let mut check_suite = serde_json::to_value(Event::sample(EventType::CheckSuite).unwrap())
+        .unwrap()["CheckSuiteEvent"]["check_suite"]
+        .clone();
+    check_suite["pull_requests"] = json!([pull_request(1347)]);
+    match with(
+        EventType::CheckSuite,
+        json!({"action": "rerequested", "check_suite": check_suite}),
+    ) {
+        Event::CheckSuiteEvent(e) => {
+            assert!(e.is_rerun());
+            assert_eq!(e.pull_request_numbers(), [1347]);
+        }
+        _ => unreachable!(),
+    }
+}
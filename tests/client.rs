@@ -0,0 +1,347 @@
+#![cfg(feature = "client")]
+
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use futures::executor::block_on;
+use futures::stream::StreamExt;
+use github_events::client::backend::{BackendError, BoxFuture, HttpBackend, Request, Response};
+use github_events::client::{Authenticator, Client, ClientError};
+use github_events::timeline::TimelineEvent;
+
+/// Answers with scripted responses, the last one repeatedly, and records what it was asked for.
+#[derive(Debug, Clone, Default)]
+struct Script {
+    responses: Arc<Mutex<VecDeque<Response>>>,
+    requests: Arc<Mutex<Vec<Request>>>,
+    sleeps: Arc<Mutex<Vec<Duration>>>,
+}
+
+impl Script {
+    fn new(responses: Vec<Response>) -> Self {
+        Script {
+            responses: Arc::new(Mutex::new(responses.into())),
+            ..Script::default()
+        }
+    }
+
+    fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    fn sleeps(&self) -> Vec<Duration> {
+        self.sleeps.lock().unwrap().clone()
+    }
+}
+
+impl HttpBackend for Script {
+    fn send(&self, request: Request) -> BoxFuture<'_, Result<Response, BackendError>> {
+        self.requests.lock().unwrap().push(request);
+        let mut responses = self.responses.lock().unwrap();
+        let res = match responses.len() {
+            1 => responses[0].clone(),
+            _ => responses.pop_front().unwrap(),
+        };
+        Box::pin(async move { Ok(res) })
+    }
+
+    fn sleep(&self, duration: Duration) -> BoxFuture<'_, ()> {
+        self.sleeps.lock().unwrap().push(duration);
+        Box::pin(async {})
+    }
+}
+
+fn response(status: u16, headers: &[(&str, String)]) -> Response {
+    Response {
+        status,
+        headers: headers
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.clone()))
+            .collect(),
+        body: b"[]".to_vec(),
+    }
+}
+
+fn ok() -> Response {
+    response(200, &[])
+}
+
+/// A page of the Events API with watch events of the given ids.
+fn page(ids: &[&str], headers: &[(&str, String)]) -> Response {
+    let watch: serde_json::Value =
+        serde_json::from_str(include_str!("../fixtures/timeline/watch.json")).unwrap();
+    let events: Vec<_> = ids
+        .iter()
+        .map(|id| {
+            let mut event = watch.clone();
+            event["id"] = (*id).into();
+            event
+        })
+        .collect();
+    Response {
+        body: serde_json::to_vec(&events).unwrap(),
+        ..response(200, headers)
+    }
+}
+
+fn ids(events: &[TimelineEvent]) -> Vec<&str> {
+    events.iter().map(|event| event.id.as_str()).collect()
+}
+
+fn urls(script: &Script) -> Vec<String> {
+    script.requests().into_iter().map(|req| req.url).collect()
+}
+
+fn unix(time: SystemTime) -> String {
+    time.duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+        .to_string()
+}
+
+/// A primary rate limit rejection, resetting at `reset_at`.
+fn exhausted(reset_at: SystemTime) -> Response {
+    response(
+        403,
+        &[
+            ("X-RateLimit-Limit", "5000".to_owned()),
+            ("X-RateLimit-Remaining", "0".to_owned()),
+            ("X-RateLimit-Reset", unix(reset_at)),
+        ],
+    )
+}
+
+fn header<'a>(request: &'a Request, name: &str) -> Option<&'a str> {
+    request
+        .headers
+        .iter()
+        .find(|(n, _)| n.eq_ignore_ascii_case(name))
+        .map(|(_, v)| v.as_str())
+}
+
+/// Hands out a new token on every request.
+#[derive(Debug, Default)]
+struct Rotating(AtomicUsize);
+
+impl Authenticator for Rotating {
+    fn authorization<'a>(&'a self, _: &'a Client) -> BoxFuture<'a, Result<String, ClientError>> {
+        let n = self.0.fetch_add(1, Ordering::SeqCst);
+        Box::pin(async move { Ok(format!("token {}", n)) })
+    }
+}
+
+#[test]
+fn server_errors_back_off() {
+    let script = Script::new(vec![response(502, &[])]);
+    let client = Client::with_backend(script.clone());
+    match block_on(client.events().poll()) {
+        Err(ClientError::Status(502)) => {}
+        other => panic!("{:?}", other),
+    }
+    assert_eq!(script.requests().len(), 6);
+    let sleeps = script.sleeps();
+    assert_eq!(sleeps.len(), 5);
+    for (retries, sleep) in sleeps.into_iter().enumerate() {
+        let max = Duration::from_secs(1 << retries);
+        assert!(max / 2 <= sleep && sleep <= max, "{:?}", sleep);
+    }
+}
+
+#[test]
+fn retries_are_authorized_again() {
+    let script = Script::new(vec![response(500, &[]), exhausted(SystemTime::now()), ok()]);
+    let client = Client::with_backend(script.clone()).with_auth(Rotating::default());
+    block_on(client.events().poll()).unwrap();
+    let authorizations: Vec<_> = script
+        .requests()
+        .iter()
+        .map(|request| header(request, "Authorization").unwrap().to_owned())
+        .collect();
+    assert_eq!(authorizations, ["token 0", "token 1", "token 2"]);
+}
+
+#[test]
+fn primary_rate_limit() {
+    let reset_at = SystemTime::now() + Duration::from_secs(600);
+    let script = Script::new(vec![exhausted(reset_at), ok()]);
+    let client = Client::with_backend(script.clone());
+    block_on(client.events().poll()).unwrap();
+    assert_eq!(script.requests().len(), 2);
+    let sleeps = script.sleeps();
+    assert!(sleeps[0] > Duration::from_secs(590), "{:?}", sleeps);
+
+    let script = Script::new(vec![exhausted(reset_at)]);
+    let client = Client::with_backend(script.clone()).wait_on_rate_limit(false);
+    match block_on(client.events().poll()) {
+        Err(ClientError::RateLimited { reset_at: at }) => {
+            assert_eq!(unix(at), unix(reset_at));
+        }
+        other => panic!("{:?}", other),
+    }
+    assert_eq!(script.requests().len(), 1);
+    assert!(script.sleeps().is_empty());
+}
+
+#[test]
+fn reset_in_the_past() {
+    // The clock is behind GitHub's, so the reset it reports has already passed here.
+    let reset_at = SystemTime::now() - Duration::from_secs(60);
+    let script = Script::new(vec![exhausted(reset_at)]);
+    let client = Client::with_backend(script.clone());
+    match block_on(client.events().poll()) {
+        Err(ClientError::RateLimited { .. }) => {}
+        other => panic!("{:?}", other),
+    }
+    assert_eq!(script.requests().len(), 6);
+    let sleeps = script.sleeps();
+    assert_eq!(sleeps.len(), 5);
+    assert!(sleeps.iter().all(|sleep| !sleep.is_zero()), "{:?}", sleeps);
+}
+
+#[test]
+fn secondary_rate_limit() {
+    let script = Script::new(vec![
+        response(403, &[("Retry-After", "5".to_owned())]),
+        response(429, &[]),
+        ok(),
+    ]);
+    let client = Client::with_backend(script.clone());
+    block_on(client.events().poll()).unwrap();
+    let sleeps = script.sleeps();
+    assert_eq!(sleeps.len(), 2);
+    assert!(Duration::from_secs(4) < sleeps[0] && sleeps[0] <= Duration::from_secs(5));
+    assert!(Duration::from_secs(59) < sleeps[1] && sleeps[1] <= Duration::from_secs(60));
+
+    let script = Script::new(vec![response(429, &[])]);
+    let client = Client::with_backend(script.clone());
+    match block_on(client.events().poll()) {
+        Err(ClientError::RateLimited { .. }) => {}
+        other => panic!("{:?}", other),
+    }
+    assert_eq!(script.requests().len(), 6);
+}
+
+#[test]
+fn forbidden() {
+    let script = Script::new(vec![response(403, &[])]);
+    let client = Client::with_backend(script.clone());
+    match block_on(client.events().poll()) {
+        Err(ClientError::Status(403)) => {}
+        other => panic!("{:?}", other),
+    }
+    assert_eq!(script.requests().len(), 1);
+}
+
+#[test]
+fn backfill() {
+    let link = |rels: &[(&str, &str)]| {
+        let links: Vec<_> = rels
+            .iter()
+            .map(|(page, rel)| {
+                format!(
+                    "<https://api.github.com/events?page={}>; rel=\"{}\"",
+                    page, rel
+                )
+            })
+            .collect();
+        ("Link", links.join(", "))
+    };
+    let script = Script::new(vec![
+        page(&["3", "2"], &[link(&[("2", "next"), ("3", "last")])]),
+        // Malformed links and other relation types are skipped, and a link can have several.
+        page(
+            &["1"],
+            &[(
+                "Link",
+                format!(
+                    "bogus, {}, {}",
+                    link(&[("9", "nextish")]).1,
+                    link(&[("3", "next last")]).1
+                ),
+            )],
+        ),
+        page(&["0"], &[link(&[("2", "prev")])]),
+        page(&["4"], &[link(&[("2", "next")])]),
+    ]);
+    let client = Client::with_backend(script.clone());
+    let mut poller = client.events().backfill(10);
+    assert_eq!(ids(&block_on(poller.poll()).unwrap()), ["3", "2", "1", "0"]);
+    assert_eq!(
+        urls(&script),
+        [
+            "https://api.github.com/events",
+            "https://api.github.com/events?page=2",
+            "https://api.github.com/events?page=3",
+        ]
+    );
+    // Only the first poll walks back.
+    assert_eq!(ids(&block_on(poller.poll()).unwrap()), ["4"]);
+    assert_eq!(urls(&script).len(), 4);
+
+    let script = Script::new(vec![
+        page(&["2"], &[link(&[("2", "next")])]),
+        page(&["1"], &[link(&[("3", "next")])]),
+    ]);
+    let client = Client::with_backend(script.clone());
+    let events = block_on(client.events().backfill(2).poll()).unwrap();
+    assert_eq!(ids(&events), ["2", "1"]);
+    assert_eq!(urls(&script).len(), 2);
+}
+
+#[test]
+fn conditional_polls() {
+    let script = Script::new(vec![
+        page(
+            &["1"],
+            &[
+                ("ETag", "\"a\"".to_owned()),
+                ("X-Poll-Interval", "30".to_owned()),
+            ],
+        ),
+        response(304, &[("X-Poll-Interval", "90".to_owned())]),
+        page(&["2", "1"], &[("ETag", "\"b\"".to_owned())]),
+    ]);
+    let client = Client::with_backend(script.clone());
+    let mut poller = client.events();
+    assert_eq!(poller.poll_interval(), Duration::from_secs(60));
+
+    assert_eq!(ids(&block_on(poller.poll()).unwrap()), ["1"]);
+    assert_eq!(poller.poll_interval(), Duration::from_secs(30));
+    // Nothing changed.
+    assert!(block_on(poller.poll()).unwrap().is_empty());
+    assert_eq!(poller.poll_interval(), Duration::from_secs(90));
+    assert_eq!(ids(&block_on(poller.poll()).unwrap()), ["2", "1"]);
+    // Without the header, the interval stays as it was.
+    assert_eq!(poller.poll_interval(), Duration::from_secs(90));
+
+    let etags: Vec<_> = script
+        .requests()
+        .iter()
+        .map(|request| header(request, "If-None-Match").map(str::to_owned))
+        .collect();
+    assert_eq!(
+        etags,
+        [None, Some("\"a\"".to_owned()), Some("\"a\"".to_owned())]
+    );
+    // Each poll waits out the interval asked for by the one before.
+    let sleeps = script.sleeps();
+    assert_eq!(sleeps.len(), 2);
+    assert!(Duration::from_secs(29) < sleeps[0] && sleeps[0] <= Duration::from_secs(30));
+    assert!(Duration::from_secs(89) < sleeps[1] && sleeps[1] <= Duration::from_secs(90));
+}
+
+#[test]
+fn streams_each_event_once() {
+    let script = Script::new(vec![
+        page(&["2", "1"], &[]),
+        page(&["3", "2"], &[]),
+        response(304, &[]),
+        page(&["4", "3", "2", "1"], &[]),
+    ]);
+    let client = Client::with_backend(script);
+    let events: Vec<_> = block_on(client.events().into_stream().take(4).collect());
+    let events: Vec<_> = events.into_iter().map(Result::unwrap).collect();
+    assert_eq!(ids(&events), ["1", "2", "3", "4"]);
+}
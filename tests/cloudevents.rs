@@ -0,0 +1,42 @@
+#![cfg(feature = "cloudevents")]
+
+use github_events::cloudevents::CloudEvent;
+use github_events::{Event, EventType};
+
+const DELIVERY: &str = "72d3162e-cc78-11e3-81ab-4c9367dc0958";
+
+#[test]
+fn push_round_trips() {
+    let event = Event::sample(EventType::Push).unwrap();
+    let cloud_event = CloudEvent::wrap(&event, DELIVERY).unwrap();
+    assert_eq!(cloud_event.id, DELIVERY);
+    assert_eq!(cloud_event.type_field, "com.github.push");
+    assert_eq!(cloud_event.source, "https://github.com/octocat/Hello-World");
+    assert_eq!(cloud_event.event_type(), Some(EventType::Push));
+
+    let json = serde_json::to_value(&cloud_event).unwrap();
+    assert_eq!(json["specversion"], "1.0");
+    assert_eq!(json["type"], "com.github.push");
+    assert_eq!(json["datacontenttype"], "application/json");
+
+    let back: CloudEvent = serde_json::from_value(json).unwrap();
+    assert_eq!(back.into_event().unwrap(), event);
+}
+
+#[cfg(feature = "issues")]
+#[test]
+fn type_includes_the_action() {
+    let event = Event::sample(EventType::Issues).unwrap();
+    let cloud_event = CloudEvent::wrap(&event, DELIVERY).unwrap();
+    assert_eq!(cloud_event.type_field, "com.github.issues.opened");
+    assert_eq!(cloud_event.event_type(), Some(EventType::Issues));
+}
+
+#[test]
+fn rejects_foreign_events() {
+    let mut cloud_event =
+        CloudEvent::wrap(&Event::sample(EventType::Push).unwrap(), DELIVERY).unwrap();
+    cloud_event.type_field = "dev.knative.sources.ping".to_owned();
+    assert_eq!(cloud_event.event_type(), None);
+    assert!(cloud_event.into_event().is_err());
+}
@@ -0,0 +1,95 @@
+#![cfg(feature = "issues")]
+use github_events::commands::{CommandParser, SourceKind};
+use github_events::{Event, EventType};
+
+fn command(name: &str, args: &[&str]) -> (String, Vec<String>) {
+    (
+        name.to_owned(),
+        args.iter().map(|a| a.to_string()).collect(),
+    )
+}
+
+#[test]
+fn slash_commands() {
+    let parser = CommandParser::new();
+    assert_eq!(
+        parser.parse("Thanks!\r\n/deploy staging --force\n  /retry\n"),
+        vec![
+            command("deploy", &["staging", "--force"]),
+            command("retry", &[])
+        ]
+    );
+    assert_eq!(
+        parser.parse("/label \"good first issue\" 'a \"b\"' c\\ d \"\""),
+        vec![command(
+            "label",
+            &["good first issue", "a \"b\"", "c d", ""]
+        )]
+    );
+    assert_eq!(
+        parser.parse("see /usr/bin\nhttps://x/y\n/usr/bin\n/"),
+        vec![]
+    );
+    assert_eq!(
+        parser.parse("> /deploy prod\n```\n/deploy prod\n```\n    /deploy prod\n/ok"),
+        vec![command("ok", &[])]
+    );
+    assert_eq!(parser.parse("@mybot deploy"), vec![]);
+}
+
+#[test]
+fn mentions() {
+    let parser = CommandParser::new().with_bot("mybot[bot]");
+    assert_eq!(
+        parser.parse("@mybot deploy prod\n@MyBot /retry\n@someone deploy\n/lgtm"),
+        vec![
+            command("deploy", &["prod"]),
+            command("retry", &[]),
+            command("lgtm", &[])
+        ]
+    );
+}
+
+#[test]
+fn events() {
+    let parser = CommandParser::new().with_bot("mybot");
+    let mut value =
+        ::serde_json::to_value(Event::sample(EventType::IssueComment).unwrap()).unwrap();
+    value["IssueCommentEvent"]["comment"]["body"] = "/assign @octocat".into();
+    let event: Event = ::serde_json::from_value(value.clone()).unwrap();
+    let commands = parser.commands(&event);
+    assert_eq!(commands.len(), 1);
+    assert_eq!(commands[0].name, "assign");
+    assert_eq!(commands[0].args, vec!["@octocat"]);
+    assert_eq!(commands[0].source.kind, SourceKind::IssueComment);
+    assert_eq!(commands[0].source.number, 1347);
+    assert_eq!(commands[0].source.author, "octocat");
+
+    value["IssueCommentEvent"]["action"] = "edited".into();
+    let edited: Event = ::serde_json::from_value(value.clone()).unwrap();
+    assert!(parser.commands(&edited).is_empty());
+
+    value["IssueCommentEvent"]["action"] = "created".into();
+    value["IssueCommentEvent"]["comment"]["user"]["login"] = "mybot[bot]".into();
+    let own: Event = ::serde_json::from_value(value).unwrap();
+    assert!(parser.commands(&own).is_empty());
+
+    let mut value =
+        ::serde_json::to_value(Event::sample(EventType::PullRequestReview).unwrap()).unwrap();
+    value["PullRequestReviewEvent"]["review"]["body"] = "LGTM\n\n/merge squash".into();
+    let review: Event = ::serde_json::from_value(value.clone()).unwrap();
+    let commands = parser.commands(&review);
+    assert_eq!(commands.len(), 1);
+    assert_eq!(commands[0].source.kind, SourceKind::PullRequestReview);
+    assert_eq!(commands[0].source.id, 80);
+
+    value["PullRequestReviewEvent"]["review"]["body"] = ::serde_json::Value::Null;
+    let empty: Event = ::serde_json::from_value(value).unwrap();
+    assert!(parser.commands(&empty).is_empty());
+
+    let comment = Event::sample(EventType::PullRequestReviewComment).unwrap();
+    assert!(parser.commands(&comment).is_empty());
+    assert!(parser
+        .commands(&Event::sample(EventType::Push).unwrap())
+        .is_empty());
+}
@@ -0,0 +1,56 @@
+#![cfg(feature = "conformance")]
+
+use github_events::conformance::{self, Example};
+use github_events::{Event, EventType};
+use serde_json::json;
+
+#[test]
+fn reports_rejected_unsupported_and_unmodeled() {
+    let mut ping =
+        serde_json::to_value(Event::sample(EventType::Ping).unwrap()).unwrap()["PingEvent"].take();
+    ping["extra"] = json!({ "field": 1 });
+    let index = json!([
+        { "name": "ping", "examples": [ping, { "zen": 7 }] },
+        { "name": "not_an_event", "examples": [{}] },
+    ]);
+    let report =
+        conformance::check(&conformance::parse_index(index.to_string().as_bytes()).unwrap());
+
+    assert_eq!(report.accepted, 1);
+    assert_eq!(report.rejected.len(), 1);
+    assert_eq!(report.rejected[0].0, "ping #1");
+    assert_eq!(report.unsupported.get("not_an_event"), Some(&1));
+    let unmodeled: Vec<_> = report.unmodeled.values().flatten().collect();
+    assert_eq!(unmodeled, ["extra", "extra.field"]);
+    assert!(!report.is_conformant());
+}
+
+#[cfg(feature = "test-fixtures")]
+#[test]
+fn fixtures_conform() {
+    let examples: Vec<_> = github_events::fixtures::webhooks()
+        .iter()
+        .map(|fixture| Example {
+            event: fixture.event_type.to_string(),
+            body: serde_json::from_slice(fixture.body).unwrap(),
+        })
+        .collect();
+    let report = conformance::check(&examples);
+    assert_eq!(report.accepted, examples.len(), "{}", report);
+    assert!(report.rejected.is_empty(), "{}", report);
+}
+
+/// Run with `OCTOKIT_WEBHOOKS_EXAMPLES=path/to/index.json cargo test --features conformance
+/// -- --ignored` to check the published examples. The report is written to
+/// `target/tmp/octokit-conformance.txt`.
+#[test]
+#[ignore]
+fn octokit_examples() {
+    let path = std::env::var("OCTOKIT_WEBHOOKS_EXAMPLES")
+        .expect("OCTOKIT_WEBHOOKS_EXAMPLES should name @octokit/webhooks-examples' index.json");
+    let index = std::fs::read(path).unwrap();
+    let report = conformance::check(&conformance::parse_index(&index).unwrap());
+    let out = std::path::Path::new(env!("CARGO_TARGET_TMPDIR")).join("octokit-conformance.txt");
+    std::fs::write(out, report.to_string()).unwrap();
+    assert!(report.is_conformant(), "{}", report);
+}
@@ -0,0 +1,110 @@
+#![cfg(feature = "context")]
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use futures::executor::block_on;
+use futures::future::{self, BoxFuture};
+use github_events::auth::AppAuth;
+use github_events::client::backend::{BackendError, HttpBackend, Request, Response};
+use github_events::client::Client;
+use github_events::context::{with_context, AppContexts, Context, ContextProvider, NotInstalled};
+use github_events::router::{EventRouter, HandlerError, Outcome, WebhookDelivery};
+use github_events::{Event, EventType, InstallationId};
+
+#[derive(Debug)]
+struct Offline;
+
+impl HttpBackend for Offline {
+    fn send(&self, _: Request) -> BoxFuture<'_, Result<Response, BackendError>> {
+        unreachable!("contexts shouldn't make requests until asked to")
+    }
+
+    fn sleep(&self, _: Duration) -> BoxFuture<'_, ()> {
+        Box::pin(async {})
+    }
+}
+
+/// Hands out unauthenticated contexts, refusing deliveries without an installation.
+struct Installed;
+
+impl ContextProvider for Installed {
+    fn context<'a>(
+        &'a self,
+        delivery: &'a WebhookDelivery,
+    ) -> BoxFuture<'a, Result<Context, HandlerError>> {
+        let context = match delivery.event.installation_id() {
+            Some(_) => Ok(Context::new(Client::with_backend(Offline))),
+            None => Err("the app isn't installed".into()),
+        };
+        Box::pin(future::ready(context))
+    }
+}
+
+/// A push delivered to the app installation `7`.
+fn installed_push() -> Event {
+    let push = Event::sample(EventType::Push).unwrap();
+    let mut payload = serde_json::to_value(&push).unwrap()["PushEvent"].take();
+    payload["installation"] = serde_json::json!({ "id": 7, "node_id": "MDIzOkludGVncmF0aW9u" });
+    Event::from_value(EventType::Push, payload).unwrap()
+}
+
+#[test]
+fn contexts() {
+    let installation = Event::sample(EventType::Installation).unwrap();
+    assert!(installation.installation_id().is_some());
+    assert_eq!(
+        Event::sample(EventType::Push).unwrap().installation_id(),
+        None
+    );
+    assert_eq!(
+        installed_push().installation_id(),
+        Some(InstallationId::new(7))
+    );
+
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let router = EventRouter::new().on(
+        EventType::Push,
+        with_context(Arc::new(Installed), {
+            let seen = seen.clone();
+            move |delivery: Arc<WebhookDelivery>, ctx: Context| {
+                let seen = seen.clone();
+                async move {
+                    assert_eq!(ctx.token().await?, None);
+                    seen.lock().unwrap().push(delivery.id.clone());
+                    Ok::<_, HandlerError>(())
+                }
+            }
+        }),
+    );
+
+    let delivery = WebhookDelivery::new("2", installed_push());
+    assert_eq!(
+        block_on(router.dispatch(delivery)).unwrap(),
+        Outcome::Handled
+    );
+    let push = Event::sample(EventType::Push).unwrap();
+    let error = block_on(router.dispatch(WebhookDelivery::new("3", push))).unwrap_err();
+    assert_eq!(error.to_string(), "the app isn't installed");
+    assert_eq!(*seen.lock().unwrap(), ["2"]);
+}
+
+#[test]
+fn app_contexts() {
+    let app = AppAuth::new(42, include_bytes!("app-key.pem")).unwrap();
+    let contexts = AppContexts::new(app, Client::with_backend(Offline));
+
+    let delivery = WebhookDelivery::new("1", installed_push());
+    let context = block_on(contexts.context(&delivery)).unwrap();
+    assert_eq!(context.installation_id(), Some(InstallationId::new(7)));
+
+    let push = Event::sample(EventType::Push).unwrap();
+    let error = block_on(contexts.context(&WebhookDelivery::new("2", push))).unwrap_err();
+    assert_eq!(
+        error.downcast_ref::<NotInstalled>(),
+        Some(&NotInstalled {
+            delivery_id: "2".to_owned()
+        })
+    );
+    assert_eq!(contexts.app().installation_id(), None);
+}
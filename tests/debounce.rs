@@ -0,0 +1,98 @@
+#![cfg(all(feature = "debounce", feature = "issues"))]
+
+use std::time::Duration;
+
+use futures::executor::block_on;
+use futures::future;
+use futures::stream::{self, StreamExt};
+use github_events::debounce::Debouncer;
+use github_events::{Event, EventType};
+
+/// A `pull_request` event about pull request `number`.
+fn pull_request(number: i64, action: &str) -> Event {
+    let mut event = Event::sample(EventType::PullRequest).unwrap();
+    if let Event::PullRequestEvent(e) = &mut event {
+        e.action = action.to_owned();
+        e.number = number;
+        e.pull_request.id = number;
+        e.pull_request.number = number;
+    }
+    event
+}
+
+/// The pull request number and action of a `pull_request` event, or the type of another.
+fn describe(event: &Event) -> String {
+    match event {
+        Event::PullRequestEvent(e) => format!("#{} {}", e.number, e.action),
+        event => event.event_type().to_string(),
+    }
+}
+
+fn debounce(debouncer: Debouncer, events: Vec<Event>) -> Vec<String> {
+    let events = block_on(debouncer.debounce(stream::iter(events)).collect::<Vec<_>>());
+    events.iter().map(describe).collect()
+}
+
+fn debouncer() -> Debouncer {
+    Debouncer::new(|_| Box::pin(future::pending()))
+}
+
+#[test]
+fn collapses_bursts() {
+    let debouncer = debouncer().with(EventType::PullRequest, Duration::from_secs(30));
+    let mut events = debounce(
+        debouncer,
+        vec![
+            pull_request(1, "synchronize"),
+            pull_request(2, "synchronize"),
+            Event::sample(EventType::Push).unwrap(),
+            pull_request(1, "labeled"),
+            pull_request(1, "synchronize"),
+        ],
+    );
+    // Events no rule matches aren't held back.
+    assert_eq!(events.remove(0), "push");
+    events.sort();
+    assert_eq!(events, ["#1 synchronize", "#2 synchronize"]);
+}
+
+#[test]
+fn filters() {
+    let flapping = "type:pull_request action:labeled,unlabeled"
+        .parse()
+        .unwrap();
+    let debouncer = debouncer().with_filter(flapping, Duration::from_secs(30));
+    let events = debounce(
+        debouncer,
+        vec![
+            pull_request(1, "opened"),
+            pull_request(1, "labeled"),
+            pull_request(1, "unlabeled"),
+            pull_request(1, "synchronize"),
+            pull_request(1, "labeled"),
+        ],
+    );
+    assert_eq!(events, ["#1 opened", "#1 synchronize", "#1 labeled"]);
+}
+
+#[test]
+fn quiet_periods() {
+    let debouncer = debouncer()
+        .with_filter(
+            "action:synchronize".parse().unwrap(),
+            Duration::from_secs(5),
+        )
+        .with(EventType::PullRequest, Duration::from_secs(30));
+    assert_eq!(
+        debouncer.quiet_period(&pull_request(1, "synchronize")),
+        Some(Duration::from_secs(5))
+    );
+    assert_eq!(
+        debouncer.quiet_period(&pull_request(1, "opened")),
+        Some(Duration::from_secs(30))
+    );
+    assert_eq!(
+        debouncer.quiet_period(&Event::sample(EventType::Push).unwrap()),
+        None
+    );
+}
@@ -0,0 +1,22 @@
+use github_events::hooks::HookConfig;
+use github_events::{Event, EventType};
+
+#[test]
+fn secrets_and_emails_are_redacted() {
+    let mut config = HookConfig::new("https://example.com/hook");
+    config.secret = Some("hunter2".to_owned());
+    let push = Event::sample(EventType::Push).unwrap();
+    let debug = format!("{:?} {:?}", config, push);
+
+    assert!(debug.contains("https://example.com/hook"));
+    assert!(debug.contains("octocat"));
+    if cfg!(feature = "debug-full") {
+        assert!(debug.contains("hunter2"));
+        assert!(debug.contains("support@github.com"));
+    } else {
+        assert!(!debug.contains("hunter2"), "{}", debug);
+        assert!(!debug.contains("support@github.com"), "{}", debug);
+        assert!(debug.contains("secret: Some(<redacted>)"));
+        assert!(debug.contains("email: <redacted>"));
+    }
+}
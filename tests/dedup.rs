@@ -0,0 +1,107 @@
+#![cfg(feature = "dedup")]
+
+use std::time::{Duration, UNIX_EPOCH};
+
+use futures::executor::block_on;
+use github_events::dedup::{DeliveryGuard, DeliveryStore, MemoryStore, Verdict};
+
+const GUID: &str = "72d3162e-cc78-11e3-81ab-4c9367dc0958";
+
+#[test]
+fn memory() {
+    let guard = DeliveryGuard::new(MemoryStore::new(100)).with_window(Duration::from_secs(60));
+    block_on(duplicates(&guard));
+}
+
+#[test]
+fn memory_capacity() {
+    let guard = DeliveryGuard::new(MemoryStore::new(2));
+    let at = |secs| UNIX_EPOCH + Duration::from_secs(secs);
+    block_on(async {
+        for (secs, id) in ["a", "b", "c"].iter().enumerate() {
+            assert!(guard.check_at(id, at(secs as u64)).await.unwrap().is_new());
+        }
+        assert_eq!(guard.store().len(), 2);
+        // `a` was forgotten to make room for `c`.
+        assert!(guard.check_at("a", at(3)).await.unwrap().is_new());
+        assert_eq!(
+            guard.check_at("c", at(4)).await.unwrap(),
+            Verdict::Duplicate {
+                first_seen: at(2),
+                received_at: at(4),
+            }
+        );
+    });
+}
+
+/// Run with `REDIS_URL` set to a Redis 7 server.
+#[cfg(feature = "redis")]
+#[tokio::test]
+#[ignore]
+async fn redis() {
+    use github_events::dedup::RedisStore;
+
+    let url = std::env::var("REDIS_URL").expect("REDIS_URL");
+    let connection = redis::Client::open(url)
+        .unwrap()
+        .get_multiplexed_async_connection()
+        .await
+        .unwrap();
+    let prefix = format!(
+        "github-events-test:{}:",
+        UNIX_EPOCH.elapsed().unwrap().as_nanos()
+    );
+    let guard = DeliveryGuard::new(RedisStore::new(connection).with_prefix(prefix))
+        .with_window(Duration::from_secs(60));
+    duplicates(&guard).await;
+}
+
+async fn duplicates<S: DeliveryStore>(guard: &DeliveryGuard<S>) {
+    let first = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+    let verdict = guard.check_at(GUID, first).await.unwrap();
+    assert_eq!(verdict, Verdict::New { received_at: first });
+    assert_eq!(verdict.delay(), Duration::ZERO);
+
+    let again = first + Duration::from_secs(10);
+    let verdict = guard.check_at(GUID, again).await.unwrap();
+    assert_eq!(
+        verdict,
+        Verdict::Duplicate {
+            first_seen: first,
+            received_at: again,
+        }
+    );
+    assert!(!verdict.is_new());
+    assert_eq!(verdict.delay(), Duration::from_secs(10));
+
+    // Other deliveries are unaffected.
+    assert!(guard.check_at("other", again).await.unwrap().is_new());
+}
+
+#[test]
+fn memory_window() {
+    let guard = DeliveryGuard::new(MemoryStore::new(100)).with_window(Duration::from_secs(60));
+    let at = |secs| UNIX_EPOCH + Duration::from_secs(secs);
+    block_on(async {
+        assert!(guard.check_at(GUID, at(0)).await.unwrap().is_new());
+        assert!(!guard.check_at(GUID, at(59)).await.unwrap().is_new());
+        // Once the window has passed, the delivery is new again, and starts a new window.
+        assert!(guard.check_at(GUID, at(60)).await.unwrap().is_new());
+        assert_eq!(
+            guard.check_at(GUID, at(61)).await.unwrap(),
+            Verdict::Duplicate {
+                first_seen: at(60),
+                received_at: at(61),
+            }
+        );
+        assert_eq!(guard.store().len(), 1);
+    });
+
+    // Deliveries received now are remembered from now.
+    let guard = DeliveryGuard::new(MemoryStore::new(100));
+    block_on(async {
+        assert_eq!(guard.first_seen(GUID).await.unwrap(), None);
+        let received_at = guard.check(GUID).await.unwrap().received_at();
+        assert_eq!(guard.first_seen(GUID).await.unwrap(), Some(received_at));
+    });
+}
@@ -0,0 +1,41 @@
+#![cfg(feature = "discord")]
+
+use github_events::{discord, Event, EventType};
+
+#[test]
+fn push_embed() {
+    let embed = discord::embed(&Event::sample(EventType::Push).unwrap());
+    assert_eq!(
+        embed["title"],
+        "Monalisa Octocat pushed 1 commit to master in octocat/Hello-World"
+    );
+    assert_eq!(embed["color"], 0x0366d6);
+    assert_eq!(
+        embed["description"],
+        "[`6dcb09b`](https://github.com/octocat/Hello-World/commit/\
+         6dcb09b5b57875f334f61aebed695e2e4193db5e) Update README"
+    );
+    assert_eq!(embed["author"]["name"], "octocat");
+    assert_eq!(embed["footer"]["text"], "octocat/Hello-World");
+    assert_eq!(
+        discord::message(&Event::sample(EventType::Push).unwrap())["embeds"][0],
+        embed
+    );
+}
+
+#[cfg(feature = "issues")]
+#[test]
+fn merged_pull_request() {
+    let mut value = serde_json::to_value(Event::sample(EventType::PullRequest).unwrap()).unwrap();
+    value["PullRequestEvent"]["action"] = "closed".into();
+    value["PullRequestEvent"]["pull_request"]["merged"] = true.into();
+    value["PullRequestEvent"]["pull_request"]["title"] = "x".repeat(300).into();
+    let embed = discord::embed(&serde_json::from_value(value).unwrap());
+    assert_eq!(embed["color"], 0x6f42c1);
+    assert_eq!(
+        embed["url"],
+        "https://github.com/octocat/Hello-World/pull/1347"
+    );
+    assert_eq!(embed["title"].as_str().unwrap().chars().count(), 256);
+    assert!(embed.get("description").is_none());
+}
@@ -0,0 +1,52 @@
+use github_events::envelope::Envelope;
+use github_events::{Event, EventType};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Routing {
+    tenant: String,
+    #[serde(default)]
+    attempts: u32,
+}
+
+#[test]
+fn metadata_beside_the_event() {
+    let event = Event::sample(EventType::Push).unwrap();
+    let envelope = Envelope::new(
+        event.clone(),
+        Routing {
+            tenant: "acme".to_owned(),
+            attempts: 2,
+        },
+    );
+    let json = serde_json::to_value(&envelope).unwrap();
+    assert_eq!(json["event_type"], "push");
+    assert_eq!(json["tenant"], "acme");
+    assert_eq!(json["payload"]["ref"], "refs/heads/master");
+    let back: Envelope<Routing> = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(back, envelope);
+
+    let plain: Envelope = serde_json::from_value(json).unwrap();
+    assert_eq!(plain.event, event);
+
+    let retried = back.map(|routing| Routing {
+        attempts: routing.attempts + 1,
+        ..routing
+    });
+    assert_eq!(retried.metadata.attempts, 3);
+}
+
+#[test]
+fn bad_payloads() {
+    let json = serde_json::json!({
+        "event_type": "push",
+        "payload": {"ref": 1},
+        "tenant": "acme",
+    });
+    assert!(serde_json::from_value::<Envelope<Routing>>(json).is_err());
+    let json = serde_json::json!({
+        "event_type": "ping",
+        "payload": serde_json::to_value(Event::sample(EventType::Ping).unwrap()).unwrap()["PingEvent"],
+    });
+    assert!(serde_json::from_value::<Envelope<Routing>>(json).is_err());
+}
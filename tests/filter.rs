@@ -0,0 +1,130 @@
+use github_events::filter::EventFilter;
+use github_events::{Event, EventType};
+
+fn push() -> Event {
+    Event::sample(EventType::Push).unwrap()
+}
+
+#[test]
+fn predicates() {
+    let push = push();
+    assert!(EventFilter::All.matches(&push));
+    assert!(EventFilter::Repository("octocat/*".into()).matches(&push));
+    assert!(EventFilter::Repository("OctoCat/hello-world".into()).matches(&push));
+    assert!(!EventFilter::Repository("*".into()).matches(&push));
+    assert!(EventFilter::Repository("**".into()).matches(&push));
+    assert!(EventFilter::Org("octocat".into()).matches(&push));
+    assert!(EventFilter::EventType(EventType::Push).matches(&push));
+    assert!(EventFilter::Sender("octocat".into()).matches(&push));
+    assert!(EventFilter::Ref("master".into()).matches(&push));
+    assert!(EventFilter::Ref("refs/heads/*".into()).matches(&push));
+    assert!(!EventFilter::Ref("main".into()).matches(&push));
+    assert!(!EventFilter::Action("opened".into()).matches(&push));
+}
+
+#[cfg(feature = "issues")]
+#[test]
+fn actions_labels_and_branches() {
+    let issue = Event::sample(EventType::Issues).unwrap();
+    let pull_request = Event::sample(EventType::PullRequest).unwrap();
+    let label = Event::sample(EventType::Label).unwrap();
+
+    assert!(EventFilter::actions(["opened", "reopened"]).matches(&issue));
+    assert!(EventFilter::Label("Bug".into()).matches(&issue));
+    assert!(EventFilter::Label("bug".into()).matches(&pull_request));
+    assert!(EventFilter::Label("bug".into()).matches(&label));
+    assert!(!EventFilter::Label("docs".into()).matches(&issue));
+    // Pull requests match on their base branch.
+    assert!(EventFilter::Ref("master".into()).matches(&pull_request));
+    assert!(!EventFilter::Ref("master".into()).matches(&issue));
+}
+
+#[test]
+fn combinators() {
+    let push = push();
+    let pushes = EventFilter::EventType(EventType::Push);
+    let creates = EventFilter::EventType(EventType::Create);
+
+    assert!((pushes.clone() | creates.clone()).matches(&push));
+    assert!(!(pushes.clone() & creates.clone()).matches(&push));
+    assert!((!creates).matches(&push));
+    assert!(!(!pushes.clone()).matches(&push));
+    assert_eq!(!!pushes.clone(), pushes);
+    assert!(EventFilter::event_types([EventType::Create, EventType::Push]).matches(&push));
+}
+
+#[test]
+fn parse() {
+    let filter: EventFilter = "repo:octocat/* type:push,create -sender:dependabot[bot]"
+        .parse()
+        .unwrap();
+    assert_eq!(
+        filter,
+        EventFilter::And(vec![
+            EventFilter::Repository("octocat/*".into()),
+            EventFilter::Or(vec![
+                EventFilter::EventType(EventType::Push),
+                EventFilter::EventType(EventType::Create),
+            ]),
+            !EventFilter::Sender("dependabot[bot]".into()),
+        ])
+    );
+    assert!(filter.matches(&push()));
+
+    assert_eq!(
+        "label:\"good first issue\"".parse::<EventFilter>().unwrap(),
+        EventFilter::Label("good first issue".into())
+    );
+    assert_eq!("".parse::<EventFilter>().unwrap(), EventFilter::All);
+    assert!("type:pushed".parse::<EventFilter>().is_err());
+    assert!("colour:red".parse::<EventFilter>().is_err());
+    assert!("repo".parse::<EventFilter>().is_err());
+    assert!("label:\"bug".parse::<EventFilter>().is_err());
+}
+
+#[cfg(feature = "test-fixtures")]
+#[test]
+fn timeline_events() {
+    use github_events::fixtures;
+    use github_events::timeline::TimelineEvent;
+
+    let fixture = fixtures::timeline()
+        .iter()
+        .find(|f| f.name == "push")
+        .unwrap();
+    let event: TimelineEvent = serde_json::from_slice(fixture.body).unwrap();
+    let filter: EventFilter = "type:push ref:master org:orhanarifoglu".parse().unwrap();
+    assert!(filter.matches_timeline(&event));
+    assert!(!EventFilter::EventType(EventType::Create).matches_timeline(&event));
+}
+
+#[test]
+fn lazy_events() {
+    use github_events::lazy::LazyEvent;
+
+    let body = serde_json::to_vec(&serde_json::to_value(push()).unwrap()["PushEvent"]).unwrap();
+    let event = LazyEvent::from_slice(EventType::Push, &body).unwrap();
+    assert!("repo:octocat/hello-world ref:master"
+        .parse::<EventFilter>()
+        .unwrap()
+        .matches_lazy(&event));
+}
+
+#[test]
+fn own_events() {
+    let mut push = push();
+    assert!(!push.is_from_app("octocat"));
+    if let Event::PushEvent(event) = &mut push {
+        event.sender.login = "MyBot[bot]".into();
+        event.sender.type_field = "Bot".into();
+    }
+    assert!(push.is_from_app("mybot"));
+    assert!(push.is_from_app("mybot[bot]"));
+    assert!(!push.is_from_app("otherbot"));
+    assert!(EventFilter::App("mybot".into()).matches(&push));
+
+    let filter: EventFilter = "-app:mybot".parse().unwrap();
+    assert_eq!(filter, !EventFilter::App("mybot".into()));
+    assert!(!filter.matches(&push));
+    assert!(filter.matches(&self::push()));
+}
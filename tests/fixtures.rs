@@ -0,0 +1,64 @@
+#![cfg(feature = "test-fixtures")]
+
+use github_events::fixtures::{self, Fixture};
+use github_events::timeline::{self, TimelineEvent};
+use github_events::Event;
+
+fn parse(fixture: &Fixture) -> Result<Event, String> {
+    Event::from_slice(fixture.event_type, fixture.body)
+        .map_err(|e| format!("{}: {}", fixture.name, e))
+}
+
+/// The JSON body an event was parsed from, without the variant tag.
+fn payload(event: &Event) -> Vec<u8> {
+    let tagged = serde_json::to_value(event).unwrap();
+    serde_json::to_vec(tagged.as_object().unwrap().values().next().unwrap()).unwrap()
+}
+
+#[test]
+fn webhooks_parse() {
+    let errors: Vec<_> = fixtures::webhooks()
+        .iter()
+        .filter_map(|fixture| parse(fixture).err())
+        .collect();
+    assert!(errors.is_empty(), "{:#?}", errors);
+}
+
+#[test]
+fn webhooks_round_trip() {
+    for fixture in fixtures::webhooks() {
+        let event = parse(fixture).unwrap();
+        assert_eq!(event.event_type(), fixture.event_type, "{}", fixture.name);
+        let reparsed = Event::from_slice(fixture.event_type, &payload(&event)).unwrap();
+        assert_eq!(reparsed, event, "{}", fixture.name);
+    }
+}
+
+#[test]
+fn timeline_parses() {
+    for fixture in fixtures::timeline() {
+        let event: TimelineEvent = serde_json::from_slice(fixture.body)
+            .unwrap_or_else(|e| panic!("{}: {}", fixture.name, e));
+        let reparsed: TimelineEvent =
+            serde_json::from_slice(&serde_json::to_vec(&event).unwrap()).unwrap();
+        assert_eq!(reparsed, event, "{}", fixture.name);
+    }
+    let page = fixtures::timeline_page();
+    let events = timeline::iter_page(&page)
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(events.len(), fixtures::timeline().len());
+}
+
+/// `push` webhooks send a repository's timestamps as seconds since the epoch.
+#[test]
+fn push_timestamps_are_normalised() {
+    let push = fixtures::webhooks()
+        .iter()
+        .find(|fixture| fixture.name == "push")
+        .unwrap();
+    match parse(push).unwrap() {
+        Event::PushEvent(push) => assert_eq!(push.repository.created_at, "2023-08-18T12:53:34Z"),
+        _ => unreachable!(),
+    }
+}
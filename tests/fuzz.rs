@@ -0,0 +1,45 @@
+#![cfg(feature = "fuzz")]
+
+use arbitrary::{Arbitrary, Unstructured};
+use github_events::Event;
+
+/// Deterministic noise for `Unstructured`, so failures reproduce.
+fn noise(seed: u64, len: usize) -> Vec<u8> {
+    let mut state = seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1;
+    (0..len)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state as u8
+        })
+        .collect()
+}
+
+fn arbitrary_events() -> impl Iterator<Item = Event> {
+    (0..500).filter_map(|seed| {
+        let bytes = noise(seed, 16 * 1024);
+        Event::arbitrary(&mut Unstructured::new(&bytes)).ok()
+    })
+}
+
+#[test]
+fn tagged_round_trip() {
+    for event in arbitrary_events() {
+        let json = serde_json::to_string(&event).unwrap();
+        let parsed: Event = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, event, "{}", json);
+    }
+}
+
+/// Events parsed from a webhook body are the events that were serialized into it.
+#[test]
+fn payload_round_trip() {
+    for event in arbitrary_events() {
+        let tagged = serde_json::to_value(&event).unwrap();
+        let payload = tagged.as_object().unwrap().values().next().unwrap();
+        let body = serde_json::to_vec(payload).unwrap();
+        let parsed = Event::from_slice(event.event_type(), &body).unwrap();
+        assert_eq!(parsed, event);
+    }
+}
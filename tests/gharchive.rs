@@ -0,0 +1,87 @@
+#![cfg(all(feature = "gharchive", feature = "test-fixtures"))]
+
+use std::io::Write;
+
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use github_events::fixtures;
+use github_events::gharchive::{self, Archive};
+use github_events::timeline::TimelineEvent;
+
+fn gzip(lines: &[&[u8]]) -> Vec<u8> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::fast());
+    for line in lines {
+        encoder.write_all(line).unwrap();
+        encoder.write_all(b"\n").unwrap();
+    }
+    encoder.finish().unwrap()
+}
+
+/// The timeline fixtures, minified to a line each.
+fn lines() -> Vec<Vec<u8>> {
+    fixtures::timeline()
+        .iter()
+        .map(|fixture| {
+            let event: serde_json::Value = serde_json::from_slice(fixture.body).unwrap();
+            serde_json::to_vec(&event).unwrap()
+        })
+        .collect()
+}
+
+#[test]
+fn reads_events_past_bad_lines() {
+    let lines = lines();
+    // Dumps can be several gzip members end to end.
+    let mut dump = gzip(&[&lines[0], b"{\"type\": \"PushEvent\"}", b""]);
+    dump.extend(gzip(&[b"not json", &lines[1]]));
+
+    let mut archive = Archive::new(&dump[..]);
+    let first = archive.next().unwrap().unwrap();
+    assert_eq!(
+        first,
+        serde_json::from_slice::<TimelineEvent>(&lines[0]).unwrap()
+    );
+    let err = archive.next().unwrap().unwrap_err();
+    assert!(err.to_string().starts_with("line 2: "), "{}", err);
+    let err = archive.next().unwrap().unwrap_err();
+    assert!(err.to_string().starts_with("line 4: "), "{}", err);
+    assert!(archive.next().unwrap().is_ok());
+    assert!(archive.next().is_none());
+    assert_eq!(archive.lines_read(), 5);
+}
+
+#[test]
+fn corrupt_dump_ends_reading() {
+    let lines = lines();
+    let dump = gzip(&[&lines[0], &lines[1]]);
+    let mut archive = Archive::new(&dump[..dump.len() / 2]);
+    assert!(archive.by_ref().any(|event| event.is_err()));
+    assert!(archive.next().is_none());
+}
+
+#[test]
+fn local_files() {
+    let lines = lines();
+    let refs: Vec<&[u8]> = lines.iter().map(Vec::as_slice).collect();
+    let path = std::env::temp_dir().join(format!(
+        "github-events-gharchive-{}.json.gz",
+        std::process::id()
+    ));
+    std::fs::write(&path, gzip(&refs)).unwrap();
+
+    let events = gharchive::open_or_fetch(path.to_str().unwrap())
+        .unwrap()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(events.len(), fixtures::timeline().len());
+    assert_eq!(gharchive::open(&path).unwrap().count(), events.len());
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn hour_urls() {
+    assert_eq!(
+        gharchive::hour_url(2015, 1, 1, 5),
+        "https://data.gharchive.org/2015-01-01-5.json.gz"
+    );
+}
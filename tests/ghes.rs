@@ -0,0 +1,24 @@
+#![cfg(feature = "ghes")]
+
+use github_events::ghes::{self, Version};
+use github_events::EventType;
+
+#[test]
+fn versions() {
+    assert_eq!("3.8".parse(), Ok(Version::V3_8));
+    assert_eq!("3.13.2".parse(), Ok(Version::V3_13));
+    assert!("3.7".parse::<Version>().is_err());
+    assert!("2.22".parse::<Version>().is_err());
+    for &version in Version::ALL {
+        assert_eq!(version.to_string().parse(), Ok(version));
+    }
+}
+
+#[test]
+fn gaps() {
+    for &version in Version::ALL {
+        assert!(ghes::sends(version, EventType::Push));
+        assert!(!ghes::sends(version, EventType::RepositoryImport));
+        assert!(ghes::missing_fields(version, EventType::Push).is_empty());
+    }
+}
@@ -0,0 +1,63 @@
+use github_events::{Event, EventType, GitRef};
+
+#[test]
+fn branches_and_tags() {
+    let branch = GitRef::new("refs/heads/release/1.0");
+    assert!(branch.is_branch());
+    assert!(!branch.is_tag());
+    assert_eq!(branch.branch_name(), Some("release/1.0"));
+    assert_eq!(branch.tag_name(), None);
+    assert_eq!(branch, GitRef::branch("release/1.0"));
+
+    let tag = GitRef::tag("v1.0.0");
+    assert_eq!(tag, "refs/tags/v1.0.0");
+    assert!(tag.is_tag());
+    assert_eq!(tag.short_name(), "v1.0.0");
+
+    let note = GitRef::new("refs/notes/commits");
+    assert!(!note.is_branch() && !note.is_tag());
+    assert_eq!(note.short_name(), "refs/notes/commits");
+}
+
+#[test]
+fn pushes_creations_and_deletions() {
+    match Event::sample(EventType::Push) {
+        Some(Event::PushEvent(push)) => assert_eq!(push.ref_field.branch_name(), Some("master")),
+        _ => panic!("not a push"),
+    }
+    match Event::sample(EventType::Create) {
+        Some(Event::CreateEvent(create)) => {
+            assert_eq!(create.git_ref(), Some(GitRef::new("refs/tags/simple-tag")))
+        }
+        _ => panic!("not a creation"),
+    }
+    match Event::sample(EventType::Delete) {
+        Some(Event::DeleteEvent(delete)) => {
+            assert_eq!(delete.git_ref(), Some(GitRef::new("refs/tags/simple-tag")))
+        }
+        _ => panic!("not a deletion"),
+    }
+}
+
+#[cfg(feature = "checks")]
+#[test]
+fn check_suites() {
+    match Event::sample(EventType::CheckSuite) {
+        Some(Event::CheckSuiteEvent(e)) => {
+            assert_eq!(e.check_suite.head_ref(), Some(GitRef::branch("master")));
+            let mut suite = e.check_suite;
+            suite.head_branch.clear();
+            assert_eq!(suite.head_ref(), None);
+        }
+        _ => panic!("not a check suite"),
+    }
+}
+
+#[test]
+fn serializes_as_a_string() {
+    let git_ref: GitRef = serde_json::from_str("\"refs/heads/main\"").unwrap();
+    assert_eq!(
+        serde_json::to_string(&git_ref).unwrap(),
+        "\"refs/heads/main\""
+    );
+}
@@ -0,0 +1,106 @@
+use github_events::hooks::{ContentType, EditHook};
+use github_events::{Event, EventType};
+
+#[test]
+fn content_types() {
+    assert_eq!(
+        ContentType::from_header("application/json"),
+        Some(ContentType::Json)
+    );
+    assert_eq!(
+        ContentType::from_header("Application/X-WWW-Form-Urlencoded; charset=utf-8"),
+        Some(ContentType::Form)
+    );
+    assert_eq!(ContentType::from_header("text/plain"), None);
+}
+
+#[test]
+fn form_payloads() {
+    let event = Event::sample(EventType::Watch).unwrap();
+    let json = match &event {
+        Event::WatchEvent(watch) => serde_json::to_string(watch).unwrap(),
+        _ => unreachable!(),
+    };
+    let mut form = String::from("payload=");
+    for b in json.bytes() {
+        match b {
+            b' ' => form.push('+'),
+            b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'-' | b'_' | b'.' => form.push(b as char),
+            b => form.push_str(&format!("%{:02X}", b)),
+        }
+    }
+
+    assert_eq!(
+        ContentType::Form.payload(form.as_bytes()).unwrap(),
+        json.as_bytes()
+    );
+    let form_type = Some("application/x-www-form-urlencoded");
+    assert_eq!(
+        Event::from_body(EventType::Watch, form_type, form.as_bytes()).unwrap(),
+        event
+    );
+    assert_eq!(
+        Event::from_body(EventType::Watch, None, json.as_bytes()).unwrap(),
+        event
+    );
+
+    assert!(Event::from_body(EventType::Watch, form_type, b"other=1").is_err());
+    assert!(Event::from_body(EventType::Watch, form_type, b"payload=%7").is_err());
+}
+
+#[cfg(feature = "compression")]
+#[test]
+fn compressed_bodies() {
+    use std::io::Write;
+
+    use flate2::write::{GzEncoder, ZlibEncoder};
+    use flate2::Compression;
+    use github_events::hooks::decompress;
+
+    let body = br#"{"zen":"Keep it logically awesome."}"#;
+    let mut gzip = GzEncoder::new(Vec::new(), Compression::default());
+    gzip.write_all(body).unwrap();
+    let gzip = gzip.finish().unwrap();
+    let mut zlib = ZlibEncoder::new(Vec::new(), Compression::default());
+    zlib.write_all(body).unwrap();
+    let zlib = zlib.finish().unwrap();
+
+    assert_eq!(decompress(None, body).unwrap(), &body[..]);
+    assert_eq!(decompress(Some("identity"), body).unwrap(), &body[..]);
+    assert_eq!(decompress(Some("gzip"), &gzip).unwrap(), &body[..]);
+    assert_eq!(decompress(Some("Deflate"), &zlib).unwrap(), &body[..]);
+    assert!(decompress(Some("br"), body).is_err());
+    assert!(decompress(Some("gzip"), body).is_err());
+
+    // Signatures are of the body GitHub sent, before compression.
+    #[cfg(feature = "signature")]
+    {
+        use github_events::signature;
+        let sig = signature::sign("secret", body);
+        assert!(signature::verify(
+            "secret",
+            &decompress(Some("gzip"), &gzip).unwrap(),
+            &sig
+        ));
+    }
+}
+
+#[test]
+fn pings_name_their_hook() {
+    let body = include_bytes!("../fixtures/webhooks/ping.json");
+    match Event::from_slice(EventType::Ping, body).unwrap() {
+        Event::PingEvent(ping) => assert_eq!(ping.hook_id, ping.hook.id),
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn edits_leave_unset_fields_unchanged() {
+    let mut edit = EditHook::new();
+    assert_eq!(serde_json::to_value(&edit).unwrap(), serde_json::json!({}));
+    edit.active = Some(false);
+    assert_eq!(
+        serde_json::to_value(&edit).unwrap(),
+        serde_json::json!({ "active": false })
+    );
+}
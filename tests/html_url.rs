@@ -0,0 +1,45 @@
+use github_events::{Event, EventType};
+
+fn html_url(event_type: EventType) -> Option<String> {
+    Event::sample(event_type)?.html_url().map(String::from)
+}
+
+#[test]
+fn links() {
+    let push = html_url(EventType::Push).unwrap();
+    assert!(push.starts_with("https://github.com/octocat/Hello-World/compare/"));
+    assert_eq!(
+        html_url(EventType::Watch).as_deref(),
+        Some("https://github.com/octocat/Hello-World")
+    );
+    assert_eq!(html_url(EventType::Delete), None);
+}
+
+#[cfg(feature = "issues")]
+#[test]
+fn comments_link_to_themselves() {
+    assert_eq!(
+        html_url(EventType::IssueComment).as_deref(),
+        Some("https://github.com/octocat/Hello-World/issues/1347#issuecomment-1")
+    );
+    assert_eq!(
+        html_url(EventType::PullRequestReview).as_deref(),
+        Some("https://github.com/octocat/Hello-World/pull/1347#pullrequestreview-80")
+    );
+    let pull_request = html_url(EventType::PullRequest).unwrap();
+    assert!(pull_request.ends_with("/pull/1347"), "{}", pull_request);
+}
+
+/// Every sample with a repository links somewhere.
+#[test]
+fn samples_have_links() {
+    for &event_type in EventType::ALL {
+        if let Some(event) = Event::sample(event_type) {
+            let payload = serde_json::to_value(&event).unwrap();
+            let payload = payload.as_object().unwrap().values().next().unwrap();
+            if payload["repository"].is_object() {
+                assert!(event.html_url().is_some(), "{}", event_type);
+            }
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use std::collections::HashSet;
+
+use github_events::jsonl::Entry;
+use github_events::{Event, EventIdentity, EventType};
+
+#[test]
+fn events_are_hashable() {
+    let push = Event::sample(EventType::Push).unwrap();
+    let watch = Event::sample(EventType::Watch).unwrap();
+    let events: HashSet<Event> = vec![push.clone(), watch, push].into_iter().collect();
+    assert_eq!(events.len(), 2);
+}
+
+#[test]
+fn identity_ignores_what_changes_between_deliveries() {
+    let push = Event::sample(EventType::Push).unwrap();
+    let mut redelivered = push.clone();
+    if let Event::PushEvent(e) = &mut redelivered {
+        e.sender.site_admin = true;
+        e.repository.stargazers_count += 1;
+    }
+    assert_ne!(push, redelivered);
+    assert_eq!(push.identity(), redelivered.identity());
+    match push.identity() {
+        EventIdentity::Payload {
+            event_type,
+            action,
+            subject,
+            at,
+        } => {
+            assert_eq!(event_type, EventType::Push);
+            assert_eq!(action, None);
+            assert_eq!(
+                subject,
+                "octocat/Hello-World:refs/heads/master@6dcb09b5b57875f334f61aebed695e2e4193db5e"
+            );
+            assert_eq!(at.as_deref(), Some("2011-01-26T19:01:12Z"));
+        }
+        identity => panic!("{:?}", identity),
+    }
+
+    let entry = Entry::new("72d3162e-cc78-11e3-81ab-4c9367dc0958", push);
+    assert_eq!(
+        entry.identity(),
+        EventIdentity::Delivery("72d3162e-cc78-11e3-81ab-4c9367dc0958".into())
+    );
+}
+
+/// Every sample is about something.
+#[test]
+fn samples_have_subjects() {
+    for &event_type in EventType::ALL {
+        if let Some(event) = Event::sample(event_type) {
+            match event.identity() {
+                EventIdentity::Payload { subject, .. } => {
+                    assert!(!subject.is_empty(), "{}", event_type)
+                }
+                identity => panic!("{}: {:?}", event_type, identity),
+            }
+        }
+    }
+}
+
+#[cfg(feature = "issues")]
+#[test]
+fn comments_are_their_own_subjects() {
+    let comment = Event::sample(EventType::IssueComment).unwrap();
+    match comment.identity() {
+        EventIdentity::Payload {
+            subject, action, ..
+        } => {
+            assert_eq!(subject, "comment:1");
+            assert_eq!(action.as_deref(), Some("created"));
+        }
+        identity => panic!("{:?}", identity),
+    }
+}
+
+#[cfg(all(feature = "issues", feature = "chrono"))]
+#[test]
+fn edits_of_a_comment_are_told_apart() {
+    let comment = Event::sample(EventType::IssueComment).unwrap();
+    let mut edited = comment.clone();
+    if let Event::IssueCommentEvent(e) = &mut edited {
+        e.comment.updated_at = "2011-01-27T00:00:00Z".into();
+    }
+    assert_ne!(comment.identity(), edited.identity());
+    assert_eq!(comment.occurred_at(), edited.occurred_at());
+}
@@ -0,0 +1,22 @@
+use github_events::{Repository, RepositoryId, User, UserId};
+
+#[test]
+fn conversions() {
+    let id = RepositoryId::from(1296269);
+    assert_eq!(id.get(), 1296269);
+    assert_eq!(u64::from(id), 1296269);
+    assert_eq!(id.to_string(), "1296269");
+    assert_eq!(serde_json::to_string(&id).unwrap(), "1296269");
+}
+
+#[test]
+fn ids_beyond_i32() {
+    let mut user = serde_json::to_value(User::sample()).unwrap();
+    user["id"] = serde_json::json!(5_000_000_000u64);
+    let user: User = serde_json::from_value(user).unwrap();
+    assert_eq!(user.id, UserId::new(5_000_000_000));
+
+    let repository = Repository::sample();
+    assert_eq!(repository.owner.id, UserId::new(1));
+    assert_eq!(repository.id, RepositoryId::new(1296269));
+}
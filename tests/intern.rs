@@ -0,0 +1,58 @@
+#![cfg(feature = "intern")]
+
+use std::collections::HashSet;
+
+use github_events::intern::Interned;
+use github_events::{Event, EventType};
+
+#[test]
+fn shared() {
+    let a = Interned::new("octocat");
+    let b = Interned::from(String::from("octocat"));
+    assert!(std::ptr::eq(a.as_str(), b.as_str()));
+    assert!(!std::ptr::eq(a.as_str(), Interned::new("hubot").as_str()));
+
+    assert_eq!(a, "octocat");
+    assert_eq!(&*a, "octocat");
+    assert_eq!(a.to_string(), "octocat");
+    assert_eq!(format!("{:?}", a), "\"octocat\"");
+    let set: HashSet<Interned> = [a.clone()].into_iter().collect();
+    assert!(set.contains("octocat"));
+    assert_eq!(&*a.into_arc(), "octocat");
+}
+
+#[test]
+fn deserialized() {
+    let strings: Vec<Interned> = serde_json::from_str(r#"["Codertocat", "Codertocat"]"#).unwrap();
+    assert!(std::ptr::eq(strings[0].as_str(), strings[1].as_str()));
+    assert_eq!(
+        serde_json::to_string(&strings).unwrap(),
+        r#"["Codertocat","Codertocat"]"#
+    );
+
+    // Every event from the same sender shares its login.
+    let push = Event::sample(EventType::Push).unwrap();
+    let watch = Event::sample(EventType::Watch).unwrap();
+    match (&push, &watch) {
+        (Event::PushEvent(push), Event::WatchEvent(watch)) => {
+            assert_eq!(push.sender.login, watch.sender.login);
+            assert!(std::ptr::eq(
+                push.sender.login.as_str(),
+                watch.sender.login.as_str()
+            ));
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn pruning_keeps_strings_in_use() {
+    let kept = Interned::new("kept across pruning");
+    for i in 0..10_000 {
+        Interned::new(&format!("dropped {}", i));
+    }
+    assert!(std::ptr::eq(
+        kept.as_str(),
+        Interned::new("kept across pruning").as_str()
+    ));
+}
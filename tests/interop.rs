@@ -0,0 +1,65 @@
+#![cfg(all(feature = "octocrab-interop", not(feature = "slim")))]
+
+use std::convert::TryFrom;
+
+#[cfg(feature = "issues")]
+use github_events::{Event, EventType};
+use github_events::{Repository, User};
+use octocrab::models;
+
+#[test]
+fn repository_and_user() {
+    let repository = models::Repository::try_from(Repository::sample()).unwrap();
+    assert_eq!(repository.full_name.as_deref(), Some("octocat/Hello-World"));
+
+    let author = models::Author::try_from(User::sample()).unwrap();
+    assert_eq!(author.login, "octocat");
+    let back = User::try_from(author).unwrap();
+    assert_eq!((back.login, back.id), ("octocat".into(), 1));
+}
+
+#[cfg(feature = "issues")]
+#[test]
+fn pull_request_and_issue_samples() {
+    let pull_request = match Event::sample(EventType::PullRequest) {
+        Some(Event::PullRequestEvent(event)) => event.pull_request,
+        _ => panic!("not a pull request event"),
+    };
+    let theirs = models::pulls::PullRequest::try_from(pull_request.clone()).unwrap();
+    assert_eq!(theirs.number, pull_request.number as u64);
+
+    let issue = match Event::sample(EventType::Issues) {
+        Some(Event::IssueEvent(event)) => event.issue,
+        _ => panic!("not an issues event"),
+    };
+    let theirs = models::issues::Issue::try_from(issue.clone()).unwrap();
+    assert_eq!(theirs.title, issue.title);
+    assert_eq!(
+        github_events::Issue::try_from(theirs).unwrap().number,
+        issue.number
+    );
+}
+
+#[cfg(feature = "test-fixtures")]
+#[test]
+fn fixtures_convert() {
+    for fixture in github_events::fixtures::webhooks() {
+        match Event::from_slice(fixture.event_type, fixture.body).unwrap() {
+            Event::PullRequestEvent(event) => {
+                models::pulls::PullRequest::try_from(event.pull_request).unwrap();
+                models::Repository::try_from(event.repository).unwrap();
+            }
+            Event::IssueEvent(event) => {
+                models::issues::Issue::try_from(event.issue).unwrap();
+            }
+            Event::IssueCommentEvent(event) => {
+                models::issues::Comment::try_from(event.comment).unwrap();
+            }
+            Event::PushEvent(event) => {
+                models::Repository::try_from(event.repository).unwrap();
+                models::Author::try_from(event.sender).unwrap();
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,267 @@
+#![cfg(feature = "issues")]
+
+use github_events::{Event, EventType, MergeMethod, MergeableState, Repository};
+use serde_json::{json, Value};
+
+/// The sample event of `event_type`, with `fields` set on its payload.
+fn with(event_type: EventType, fields: Value) -> Event {
+    let mut value = serde_json::to_value(Event::sample(event_type).unwrap()).unwrap();
+    let payload = value.as_object_mut().unwrap().values_mut().next().unwrap();
+    for (field, v) in fields.as_object().unwrap() {
+        payload[field] = v.clone();
+    }
+    serde_json::from_value(value).unwrap()
+}
+
+fn user(login: &str) -> Value {
+    let mut user = serde_json::to_value(github_events::User::sample()).unwrap();
+    user["login"] = login.into();
+    user
+}
+
+fn label() -> Value {
+    json!({
+        "id": 208045946,
+        "node_id": "MDU6TGFiZWwyMDgwNDU5NDY=",
+        "url": "https://api.github.com/repos/octocat/Hello-World/labels/bug",
+        "name": "bug",
+        "color": "f29513",
+        "default": true
+    })
+}
+
+#[test]
+fn issue_labels_and_assignees() {
+    match with(
+        EventType::Issues,
+        json!({"action": "labeled", "label": label()}),
+    ) {
+        Event::IssueEvent(e) => {
+            assert_eq!(e.label.unwrap().name, "bug");
+            assert_eq!(e.assignee, None);
+        }
+        _ => unreachable!(),
+    }
+    match with(
+        EventType::Issues,
+        json!({"action": "unassigned", "assignee": user("hubot")}),
+    ) {
+        Event::IssueEvent(e) => assert_eq!(&*e.assignee.unwrap().login, "hubot"),
+        _ => unreachable!(),
+    }
+    match Event::sample(EventType::Issues).unwrap() {
+        Event::IssueEvent(e) => assert_eq!((e.label, e.assignee), (None, None)),
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn issue_milestones() {
+    let milestone = match Event::sample(EventType::Milestone).unwrap() {
+        Event::MilestoneEvent(e) => serde_json::to_value(&e.milestone).unwrap(),
+        _ => unreachable!(),
+    };
+    match with(
+        EventType::Issues,
+        json!({"action": "demilestoned", "milestone": milestone}),
+    ) {
+        Event::IssueEvent(e) => assert_eq!(e.milestone.unwrap().title, "v1.0"),
+        _ => unreachable!(),
+    }
+    match Event::sample(EventType::Issues).unwrap() {
+        Event::IssueEvent(e) => assert_eq!(e.milestone, None),
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn pull_request_review_requests() {
+    match with(
+        EventType::PullRequest,
+        json!({"action": "review_requested", "requested_reviewer": user("hubot")}),
+    ) {
+        Event::PullRequestEvent(e) => {
+            assert_eq!(&*e.requested_reviewer.unwrap().login, "hubot");
+            assert_eq!(e.requested_team, None);
+        }
+        _ => unreachable!(),
+    }
+    let team = json!({
+        "name": "Justice League",
+        "id": 1,
+        "node_id": "MDQ6VGVhbTE=",
+        "slug": "justice-league",
+        "description": null,
+        "privacy": "closed",
+        "url": "https://api.github.com/teams/1",
+        "html_url": "https://github.com/orgs/github/teams/justice-league",
+        "members_url": "https://api.github.com/teams/1/members{/member}",
+        "repositories_url": "https://api.github.com/teams/1/repos",
+        "permission": "admin"
+    });
+    match with(
+        EventType::PullRequest,
+        json!({"action": "review_request_removed", "requested_team": team}),
+    ) {
+        Event::PullRequestEvent(e) => {
+            let team = e.requested_team.unwrap();
+            assert_eq!(team.slug, "justice-league");
+            assert_eq!(team.description, None);
+        }
+        _ => unreachable!(),
+    }
+    match with(
+        EventType::PullRequest,
+        json!({"action": "unlabeled", "label": label(), "assignee": user("octocat")}),
+    ) {
+        Event::PullRequestEvent(e) => {
+            assert_eq!(e.label.unwrap().color, "f29513");
+            assert_eq!(&*e.assignee.unwrap().login, "octocat");
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn pull_request_auto_merge() {
+    let mut pull_request = match Event::sample(EventType::PullRequest).unwrap() {
+        Event::PullRequestEvent(e) => serde_json::to_value(&e.pull_request).unwrap(),
+        _ => unreachable!(),
+    };
+    pull_request["auto_merge"] = json!({
+        "enabled_by": user("octocat"),
+        "merge_method": "squash",
+        "commit_title": "Update the README (#1347)",
+        "commit_message": null
+    });
+    match with(
+        EventType::PullRequest,
+        json!({"action": "auto_merge_enabled", "pull_request": pull_request}),
+    ) {
+        Event::PullRequestEvent(e) => {
+            let auto_merge = e.pull_request.auto_merge.unwrap();
+            assert_eq!(&*auto_merge.enabled_by.login, "octocat");
+            assert_eq!(auto_merge.merge_method, MergeMethod::Squash);
+            assert_eq!(auto_merge.commit_message, None);
+        }
+        _ => unreachable!(),
+    }
+    match with(
+        EventType::PullRequest,
+        json!({"action": "auto_merge_disabled", "reason": "Base branch was modified"}),
+    ) {
+        Event::PullRequestEvent(e) => {
+            assert_eq!(e.reason.as_deref(), Some("Base branch was modified"));
+            assert_eq!(e.pull_request.auto_merge, None);
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn mergeable_states() {
+    let state = |state: &str| {
+        let mut pull_request = match Event::sample(EventType::PullRequest).unwrap() {
+            Event::PullRequestEvent(e) => serde_json::to_value(&e.pull_request).unwrap(),
+            _ => unreachable!(),
+        };
+        pull_request["mergeable_state"] = state.into();
+        match with(
+            EventType::PullRequest,
+            json!({"pull_request": pull_request}),
+        ) {
+            Event::PullRequestEvent(e) => e.pull_request.mergeable_state,
+            _ => unreachable!(),
+        }
+    };
+    assert_eq!(state("clean"), MergeableState::Clean);
+    assert_eq!(state("dirty"), MergeableState::Dirty);
+    assert_eq!(state("has_hooks"), MergeableState::HasHooks);
+    assert_eq!(state("unknown"), MergeableState::Unknown);
+    assert_eq!(state("some_new_state"), MergeableState::Unknown);
+    assert_eq!(
+        serde_json::to_value(MergeableState::Behind).unwrap(),
+        "behind"
+    );
+}
+
+#[test]
+fn repository_merge_methods() {
+    let mut repository = serde_json::to_value(Repository::sample()).unwrap();
+    assert!(Repository::sample().merge_methods().is_empty());
+    repository["allow_merge_commit"] = false.into();
+    repository["allow_squash_merge"] = true.into();
+    repository["allow_rebase_merge"] = true.into();
+    let repository: Repository = serde_json::from_value(repository).unwrap();
+    assert_eq!(
+        repository.merge_methods(),
+        [MergeMethod::Squash, MergeMethod::Rebase]
+    );
+}
+
+#[test]
+fn pull_request_outcomes() {
+    use github_events::builders::PullRequestEventBuilder;
+
+    let merged = PullRequestEventBuilder::new("closed")
+        .closed(true)
+        .payload();
+    assert!(merged.was_merged());
+    assert!(!merged.was_closed_unmerged());
+
+    let closed = PullRequestEventBuilder::new("closed")
+        .closed(false)
+        .payload();
+    assert!(!closed.was_merged());
+    assert!(closed.was_closed_unmerged());
+
+    // Merging only shows in the closing event; later events of a merged pull request aren't merges.
+    let labeled = PullRequestEventBuilder::new("labeled")
+        .closed(true)
+        .payload();
+    assert!(!labeled.was_merged());
+
+    let opened = PullRequestEventBuilder::new("opened")
+        .head("feature", "6dcb09b5b57875f334f61aebed695e2e4193db5e")
+        .base("develop")
+        .payload();
+    assert!(opened.is_ready_for_review());
+    assert_eq!(opened.target_branch(), "develop");
+    assert_eq!(opened.source_branch(), "feature");
+
+    let draft = PullRequestEventBuilder::new("opened").draft(true).payload();
+    assert!(!draft.is_ready_for_review());
+    let ready = PullRequestEventBuilder::new("ready_for_review").payload();
+    assert!(ready.is_ready_for_review());
+    assert!(PullRequestEventBuilder::new("synchronize")
+        .payload()
+        .was_synchronized());
+}
+
+#[test]
+fn reactions_and_apps() {
+    match Event::sample(EventType::IssueComment).unwrap() {
+        Event::IssueCommentEvent(e) => {
+            let reactions = e.issue.reactions.unwrap();
+            assert_eq!((reactions.total_count, reactions.plus_one), (3, 2));
+            assert_eq!(e.comment.reactions.unwrap().hooray, 1);
+            assert_eq!(e.comment.performed_via_github_app, None);
+        }
+        _ => unreachable!(),
+    }
+
+    let mut app = serde_json::to_value(github_events::App::sample()).unwrap();
+    app["name"] = "Dependabot".into();
+    let mut issue = serde_json::to_value(Event::sample(EventType::Issues).unwrap()).unwrap()
+        ["IssueEvent"]["issue"]
+        .take();
+    issue["performed_via_github_app"] = app;
+    issue.as_object_mut().unwrap().remove("reactions");
+    match with(EventType::Issues, json!({ "issue": issue })) {
+        Event::IssueEvent(e) => {
+            assert_eq!(e.issue.performed_via_github_app.unwrap().name, "Dependabot");
+            assert_eq!(e.issue.reactions, None);
+        }
+        _ => unreachable!(),
+    }
+}
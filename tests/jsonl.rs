@@ -0,0 +1,146 @@
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+use std::time::{Duration, UNIX_EPOCH};
+
+use github_events::jsonl::{Entry, JsonlEventLog};
+use github_events::provenance::{Provenance, Source};
+use github_events::{Event, EventType, HookId};
+
+/// An empty directory for the log of `test`.
+fn log_dir(test: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "github-events-jsonl-{}-{}",
+        test,
+        std::process::id()
+    ));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+fn entry(delivery_id: &str, event_type: EventType, secs: u64) -> Entry {
+    let mut entry = Entry::new(delivery_id, Event::sample(event_type).unwrap());
+    entry.received_at = UNIX_EPOCH + Duration::from_secs(secs);
+    entry
+}
+
+fn segment_names(dir: &PathBuf) -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(dir)
+        .unwrap()
+        .map(|e| e.unwrap().file_name().into_string().unwrap())
+        .collect();
+    names.sort();
+    names
+}
+
+// 2019-05-15T15:20:33Z
+const MAY_15: u64 = 1_557_933_633;
+const DAY: u64 = 86_400;
+
+#[test]
+fn append_and_replay() {
+    let dir = log_dir("replay");
+    let replayed_provenance = Provenance::new(Source::Replay, "2")
+        .with_received_at(UNIX_EPOCH + Duration::from_secs(MAY_15 + 1))
+        .with_hook_id(HookId::new(42));
+    let entries = vec![
+        entry("1", EventType::Push, MAY_15),
+        Entry::from_provenance(
+            replayed_provenance.clone(),
+            Event::sample(EventType::Create).unwrap(),
+        ),
+    ];
+    let mut log = JsonlEventLog::open(&dir).unwrap();
+    for entry in &entries {
+        log.append(entry).unwrap();
+    }
+
+    let replayed: Vec<Entry> = JsonlEventLog::replay(&dir)
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(replayed, entries);
+    assert_eq!(replayed[1].provenance(), replayed_provenance);
+
+    // The lines hold the payloads as GitHub sends them.
+    let segment = fs::read_to_string(dir.join(&segment_names(&dir)[0])).unwrap();
+    let line: serde_json::Value = serde_json::from_str(segment.lines().next().unwrap()).unwrap();
+    assert_eq!(line["received_at"], "2019-05-15T15:20:33Z");
+    assert_eq!(line["delivery_id"], "1");
+    assert_eq!(line["source"], "webhook");
+    assert!(line.get("hook_id").is_none());
+    assert_eq!(line["event_type"], "push");
+    assert!(line["payload"]["ref"].is_string());
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn rotation() {
+    let dir = log_dir("rotation");
+    let mut log = JsonlEventLog::open(&dir).unwrap().rotate_daily();
+    log.append(&entry("1", EventType::Push, MAY_15)).unwrap();
+    log.append(&entry("2", EventType::Push, MAY_15 + 60))
+        .unwrap();
+    log.append(&entry("3", EventType::Push, MAY_15 + DAY))
+        .unwrap();
+    assert_eq!(
+        segment_names(&dir),
+        [
+            "events-2019-05-15-000001.jsonl",
+            "events-2019-05-16-000001.jsonl"
+        ]
+    );
+
+    // A reopened log continues the last segment, and rotates once it's full.
+    let size = fs::metadata(dir.join("events-2019-05-16-000001.jsonl"))
+        .unwrap()
+        .len();
+    let mut log = JsonlEventLog::open(&dir)
+        .unwrap()
+        .rotate_daily()
+        .rotate_at_size(size * 2);
+    log.append(&entry("4", EventType::Push, MAY_15 + DAY))
+        .unwrap();
+    log.append(&entry("5", EventType::Push, MAY_15 + DAY))
+        .unwrap();
+    assert_eq!(
+        segment_names(&dir),
+        [
+            "events-2019-05-15-000001.jsonl",
+            "events-2019-05-16-000001.jsonl",
+            "events-2019-05-16-000002.jsonl"
+        ]
+    );
+
+    let deliveries: Vec<String> = JsonlEventLog::replay(&dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().delivery_id)
+        .collect();
+    assert_eq!(deliveries, ["1", "2", "3", "4", "5"]);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn truncated_lines() {
+    let dir = log_dir("truncated");
+    let mut log = JsonlEventLog::open(&dir).unwrap();
+    log.append(&entry("1", EventType::Push, MAY_15)).unwrap();
+    let segment = dir.join(&segment_names(&dir)[0]);
+    fs::OpenOptions::new()
+        .append(true)
+        .open(&segment)
+        .unwrap()
+        .write_all(b"{\"received_at\":\"2019-05-15T15:2\n")
+        .unwrap();
+    log.append(&entry("2", EventType::Push, MAY_15)).unwrap();
+
+    let replayed: Vec<_> = JsonlEventLog::replay(&dir).unwrap().collect();
+    assert_eq!(replayed.len(), 3);
+    assert_eq!(replayed[0].as_ref().unwrap().delivery_id, "1");
+    assert!(replayed[1].is_err());
+    assert_eq!(replayed[2].as_ref().unwrap().delivery_id, "2");
+
+    fs::remove_dir_all(&dir).unwrap();
+}
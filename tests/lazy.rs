@@ -0,0 +1,59 @@
+#![cfg(feature = "std")]
+
+use github_events::lazy::LazyEvent;
+use github_events::{Event, EventType, InstallationId};
+
+const ISSUES_OPENED: &[u8] = include_bytes!("../fixtures/webhooks/issues.opened.json");
+
+#[test]
+fn routing_keys() {
+    let lazy = LazyEvent::from_slice(EventType::Issues, ISSUES_OPENED).unwrap();
+    assert_eq!(lazy.event_type(), EventType::Issues);
+    assert_eq!(lazy.action(), Some("opened"));
+    assert!(lazy.organization().is_none());
+
+    let repository = lazy.repository().unwrap();
+    assert!(repository.raw().get().contains("\"gagbo/circadian.nvim\""));
+    assert_eq!(repository.get().unwrap().full_name, "gagbo/circadian.nvim");
+    // Parsed once, then borrowed.
+    assert!(std::ptr::eq(
+        repository.get().unwrap(),
+        repository.get().unwrap()
+    ));
+    assert_eq!(lazy.sender().unwrap().get().unwrap().login, "gagbo");
+    assert_eq!(
+        lazy.installation().unwrap().get().unwrap().id,
+        InstallationId::new(7777777)
+    );
+
+    let event = Event::from_slice(EventType::Issues, ISSUES_OPENED).unwrap();
+    assert_eq!(lazy.parse().unwrap(), event);
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(lazy.payload().get()).unwrap(),
+        serde_json::from_slice::<serde_json::Value>(ISSUES_OPENED).unwrap()
+    );
+}
+
+#[test]
+fn installation_events() {
+    let body = include_bytes!("../fixtures/webhooks/installation.created.json");
+    let lazy = LazyEvent::from_slice(EventType::Installation, body).unwrap();
+    let event = lazy.parse().unwrap();
+    assert_eq!(
+        Some(lazy.installation().unwrap().get().unwrap().id),
+        event.installation_id()
+    );
+}
+
+#[test]
+fn sub_objects_fail_on_access() {
+    let body = br#"{"action": "started", "repository": {"full_name": 5}, "sender": null}"#;
+    let lazy = LazyEvent::from_slice(EventType::Watch, body).unwrap();
+    assert_eq!(lazy.action(), Some("started"));
+    assert!(lazy.sender().is_none());
+    assert!(lazy.repository().unwrap().get().is_err());
+    assert!(lazy.parse().is_err());
+
+    assert!(LazyEvent::from_slice(EventType::Watch, b"{\"action\": ").is_err());
+    assert!(LazyEvent::from_slice(EventType::Watch, b"[]").is_err());
+}
@@ -0,0 +1,75 @@
+#![cfg(all(feature = "client", feature = "test-fixtures"))]
+
+use std::time::Duration;
+
+use futures::executor::block_on;
+use futures::stream::{self, StreamExt};
+use github_events::client::backend::{BackendError, BoxFuture, HttpBackend, Request, Response};
+use github_events::client::{Client, ClientError};
+use github_events::fixtures;
+use github_events::timeline::TimelineEvent;
+
+/// Blocks the thread while sleeping, and sends nothing.
+#[derive(Debug)]
+struct Sleeper;
+
+impl HttpBackend for Sleeper {
+    fn send(&self, _: Request) -> BoxFuture<'_, Result<Response, BackendError>> {
+        Box::pin(async { Err("offline".into()) })
+    }
+
+    fn sleep(&self, duration: Duration) -> BoxFuture<'_, ()> {
+        Box::pin(async move { std::thread::sleep(duration) })
+    }
+}
+
+fn event(id: &str, created_at: &str) -> Result<TimelineEvent, ClientError> {
+    let watch = fixtures::timeline()
+        .iter()
+        .find(|f| f.name == "watch")
+        .unwrap();
+    let mut event: TimelineEvent = serde_json::from_slice(watch.body).unwrap();
+    event.id = id.to_owned();
+    event.created_at = created_at.to_owned();
+    Ok(event)
+}
+
+fn ids(events: Vec<Result<TimelineEvent, ClientError>>) -> Vec<String> {
+    events.into_iter().map(|e| e.unwrap().id).collect()
+}
+
+#[test]
+fn ordered_by_created_at() {
+    let client = Client::with_backend(Sleeper);
+    let a = stream::iter(vec![
+        event("a1", "2019-06-03T00:00:01Z"),
+        event("a3", "2019-06-03T00:00:03Z"),
+        event("a5", "2019-06-03T00:00:05Z"),
+    ])
+    .boxed();
+    let b = stream::iter(vec![
+        event("b2", "2019-06-03T00:00:02Z"),
+        event("b4", "2019-06-03T00:00:04Z"),
+        event("b5", "2019-06-03T00:00:05Z"),
+    ])
+    .boxed();
+    let merged = client.merge(vec![a, b], Duration::from_secs(60));
+    assert_eq!(
+        ids(block_on(merged.collect())),
+        ["a1", "b2", "a3", "b4", "a5", "b5"]
+    );
+}
+
+#[test]
+fn held_for_the_window() {
+    let client = Client::with_backend(Sleeper);
+    // The other stream never ends, so events are only released once the window has passed.
+    let a = stream::iter(vec![
+        event("late", "2019-06-03T00:00:02Z"),
+        event("early", "2019-06-03T00:00:01Z"),
+    ])
+    .chain(stream::pending())
+    .boxed();
+    let merged = client.merge(vec![a], Duration::from_millis(20));
+    assert_eq!(ids(block_on(merged.take(2).collect())), ["early", "late"]);
+}
@@ -0,0 +1,84 @@
+#![cfg(feature = "metrics")]
+
+use github_events::metrics::{EVENTS_RECEIVED, PARSE_FAILURES};
+use github_events::{Event, EventType};
+use metrics_util::debugging::{DebugValue, DebuggingRecorder};
+use metrics_util::MetricKind;
+
+/// Counters' names, labels, and values.
+type Counters = Vec<(String, Vec<(String, String)>, u64)>;
+
+/// The counters recorded while running `f`, sorted.
+fn counters(f: impl FnOnce()) -> Counters {
+    let recorder = DebuggingRecorder::new();
+    let snapshotter = recorder.snapshotter();
+    metrics::with_local_recorder(&recorder, f);
+    let mut counters: Vec<_> = snapshotter
+        .snapshot()
+        .into_vec()
+        .into_iter()
+        .filter_map(|(key, _, _, value)| match (key.kind(), value) {
+            (MetricKind::Counter, DebugValue::Counter(n)) => {
+                let key = key.key();
+                let labels = key
+                    .labels()
+                    .map(|l| (l.key().to_owned(), l.value().to_owned()))
+                    .collect();
+                Some((key.name().to_owned(), labels, n))
+            }
+            _ => None,
+        })
+        .collect();
+    counters.sort();
+    counters
+}
+
+fn labels(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+    pairs
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+#[test]
+fn parsing() {
+    let push = Event::sample(EventType::Push).unwrap();
+    let body = serde_json::to_value(&push).unwrap()["PushEvent"].to_string();
+    let counted = counters(|| {
+        Event::from_slice(EventType::Push, body.as_bytes()).unwrap();
+        Event::from_slice(EventType::Push, body.as_bytes()).unwrap();
+        Event::from_value(
+            EventType::Ping,
+            serde_json::from_str(r#"{"zen": "Keep it logically awesome.", "action": "x"}"#)
+                .unwrap(),
+        )
+        .unwrap_err();
+        Event::from_slice(EventType::Push, b"{").unwrap_err();
+    });
+    assert_eq!(
+        counted,
+        [
+            (PARSE_FAILURES.to_owned(), labels(&[("event", "ping")]), 1),
+            (PARSE_FAILURES.to_owned(), labels(&[("event", "push")]), 1),
+            (
+                EVENTS_RECEIVED.to_owned(),
+                labels(&[("event", "push"), ("action", "")]),
+                2
+            ),
+        ]
+    );
+}
+
+#[cfg(feature = "signature")]
+#[test]
+fn signature_failures() {
+    use github_events::metrics::SIGNATURE_FAILURES;
+    use github_events::signature::{sign, verify};
+
+    let counted = counters(|| {
+        assert!(verify("secret", b"{}", &sign("secret", b"{}")));
+        assert!(!verify("secret", b"{}", &sign("other", b"{}")));
+        assert!(!verify("secret", b"{}", "sha1=0"));
+    });
+    assert_eq!(counted, [(SIGNATURE_FAILURES.to_owned(), Vec::new(), 2)]);
+}
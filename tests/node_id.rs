@@ -0,0 +1,39 @@
+use github_events::{node_id, Repository, User};
+
+#[test]
+fn legacy_ids() {
+    let repository = Repository::sample();
+    assert_eq!(
+        node_id::decode(&repository.node_id),
+        Some(("Repository".to_owned(), repository.id.get() as i64))
+    );
+    assert_eq!(
+        node_id::decode("MDEwOlJlcG9zaXRvcnkxMjk2MjY5"),
+        Some(("Repository".to_owned(), 1296269))
+    );
+    let user = User::sample();
+    assert_eq!(
+        node_id::legacy("User", user.id.get() as i64),
+        user.node_id.to_string()
+    );
+}
+
+#[test]
+fn new_ids() {
+    assert_eq!(
+        node_id::decode("U_kgDOB1vNFQ"),
+        Some(("User".to_owned(), 123_456_789))
+    );
+    // Issues' ids also hold their repository's.
+    assert_eq!(
+        node_id::decode("I_kwDOABPGjc53NZQA"),
+        Some(("Issue".to_owned(), 2_000_000_000))
+    );
+}
+
+#[test]
+fn unknown_ids() {
+    assert_eq!(node_id::decode("XYZ_kgDOB1vNFQ"), None);
+    assert_eq!(node_id::decode("not a node id"), None);
+    assert_eq!(node_id::decode(""), None);
+}
@@ -0,0 +1,90 @@
+use github_events::normalized::{Activity, ObjectKind, Source};
+use github_events::{Event, EventType};
+
+#[test]
+fn pushes() {
+    let activity = Activity::from_event(Event::sample(EventType::Push).unwrap());
+    assert_eq!(activity.actor.as_deref(), Some("octocat"));
+    assert_eq!(activity.verb, "pushed");
+    assert_eq!(activity.object.kind, ObjectKind::Ref);
+    assert_eq!(activity.object.id.as_deref(), Some("refs/heads/master"));
+    assert_eq!(activity.object.name.as_deref(), Some("master"));
+    assert_eq!(activity.target, None);
+    assert_eq!(activity.repo.as_deref(), Some("octocat/Hello-World"));
+    assert_eq!(activity.timestamp.as_deref(), Some("2011-01-26T19:01:12Z"));
+    assert_eq!(activity.event_type, Some(EventType::Push));
+    assert_eq!(
+        activity.source,
+        Source::Webhook(Box::new(Event::sample(EventType::Push).unwrap()))
+    );
+}
+
+#[cfg(feature = "issues")]
+#[test]
+fn comments_and_merges() {
+    let activity: Activity = Event::sample(EventType::PullRequestReviewComment)
+        .unwrap()
+        .into();
+    assert_eq!(activity.verb, "created");
+    assert_eq!(activity.object.kind, ObjectKind::Comment);
+    assert_eq!(activity.object.id.as_deref(), Some("1"));
+    let target = activity.target.unwrap();
+    assert_eq!(target.kind, ObjectKind::PullRequest);
+    assert_eq!(target.id.as_deref(), Some("1347"));
+
+    let mut value = serde_json::to_value(Event::sample(EventType::PullRequest).unwrap()).unwrap();
+    value["PullRequestEvent"]["action"] = "closed".into();
+    value["PullRequestEvent"]["pull_request"]["merged"] = true.into();
+    let activity = Activity::from_event(serde_json::from_value(value).unwrap());
+    assert_eq!(activity.verb, "merged");
+    assert_eq!(activity.object.kind, ObjectKind::PullRequest);
+}
+
+#[test]
+fn every_sample_round_trips() {
+    for &event_type in EventType::ALL.iter() {
+        if let Some(event) = Event::sample(event_type) {
+            let activity = Activity::from_event(event);
+            assert!(!activity.verb.is_empty(), "{}", event_type);
+            let json = serde_json::to_string(&activity).unwrap();
+            assert_eq!(
+                serde_json::from_str::<Activity>(&json).unwrap(),
+                activity,
+                "{}",
+                event_type
+            );
+        }
+    }
+}
+
+#[cfg(feature = "test-fixtures")]
+#[test]
+fn timeline_events() {
+    use github_events::fixtures;
+    use github_events::timeline::TimelineEvent;
+
+    for fixture in fixtures::timeline() {
+        let event: TimelineEvent = serde_json::from_slice(fixture.body).unwrap();
+        assert_eq!(
+            event.event_type(),
+            Some(fixture.event_type),
+            "{}",
+            fixture.name
+        );
+        let activity = Activity::from_timeline(event.clone());
+        assert_eq!(activity.actor.as_deref(), Some(&*event.actor.login));
+        assert_eq!(activity.repo.as_deref(), Some(&*event.repo.name));
+        assert_eq!(activity.timestamp.as_deref(), Some(&*event.created_at));
+        assert_eq!(activity.source, Source::Timeline(Box::new(event)));
+    }
+
+    let push = fixtures::timeline()
+        .iter()
+        .find(|f| f.name == "push")
+        .unwrap();
+    let activity: Activity = serde_json::from_slice::<TimelineEvent>(push.body)
+        .unwrap()
+        .into();
+    assert_eq!(activity.verb, "pushed");
+    assert_eq!(activity.object.name.as_deref(), Some("master"));
+}
@@ -0,0 +1,147 @@
+use github_events::notifications::{Reason, Thread};
+use serde_json::json;
+
+/// A thread as in GitHub's documentation of the notifications API, abridged.
+fn thread(reason: &str) -> serde_json::Value {
+    json!({
+      "id": "1",
+      "repository": {
+        "id": 1296269,
+        "node_id": "MDEwOlJlcG9zaXRvcnkxMjk2MjY5",
+        "name": "Hello-World",
+        "full_name": "octocat/Hello-World",
+        "owner": {
+          "login": "octocat",
+          "id": 1,
+          "node_id": "MDQ6VXNlcjE=",
+          "avatar_url": "https://github.com/images/error/octocat_happy.gif",
+          "gravatar_id": "",
+          "url": "https://api.github.com/users/octocat",
+          "html_url": "https://github.com/octocat",
+          "followers_url": "https://api.github.com/users/octocat/followers",
+          "following_url": "https://api.github.com/users/octocat/following{/other_user}",
+          "gists_url": "https://api.github.com/users/octocat/gists{/gist_id}",
+          "starred_url": "https://api.github.com/users/octocat/starred{/owner}{/repo}",
+          "subscriptions_url": "https://api.github.com/users/octocat/subscriptions",
+          "organizations_url": "https://api.github.com/users/octocat/orgs",
+          "repos_url": "https://api.github.com/users/octocat/repos",
+          "events_url": "https://api.github.com/users/octocat/events{/privacy}",
+          "received_events_url": "https://api.github.com/users/octocat/received_events",
+          "type": "User",
+          "site_admin": false
+        },
+        "private": false,
+        "html_url": "https://github.com/octocat/Hello-World",
+        "description": "This your first repo!",
+        "fork": false,
+        "url": "https://api.github.com/repos/octocat/Hello-World",
+        "archive_url": "https://api.github.com/repos/octocat/Hello-World/{archive_format}/{/ref}"
+      },
+      "subject": {
+        "title": "Greetings",
+        "url": "https://api.github.com/repos/octokit/octokit.rb/issues/123",
+        "latest_comment_url": "https://api.github.com/repos/octokit/octokit.rb/issues/comments/123",
+        "type": "Issue"
+      },
+      "reason": reason,
+      "unread": true,
+      "updated_at": "2014-11-07T22:01:45Z",
+      "last_read_at": "2014-11-07T22:01:45Z",
+      "url": "https://api.github.com/notifications/threads/1",
+      "subscription_url": "https://api.github.com/notifications/threads/1/subscription"
+    })
+}
+
+#[test]
+fn threads() {
+    let subscribed: Thread = serde_json::from_value(thread("subscribed")).unwrap();
+    assert_eq!(subscribed.reason, Reason::Subscribed);
+    assert_eq!(subscribed.repository.full_name, "octocat/Hello-World");
+    assert_eq!(&*subscribed.repository.owner.login, "octocat");
+    assert_eq!(subscribed.subject.type_field, "Issue");
+    assert!(subscribed.unread);
+    let round_tripped: Thread =
+        serde_json::from_value(serde_json::to_value(&subscribed).unwrap()).unwrap();
+    assert_eq!(round_tripped, subscribed);
+
+    let reason = |reason| {
+        serde_json::from_value::<Thread>(thread(reason))
+            .unwrap()
+            .reason
+    };
+    assert_eq!(reason("review_requested"), Reason::ReviewRequested);
+    assert_eq!(reason("ci_activity"), Reason::CiActivity);
+    assert_eq!(reason("some_new_reason"), Reason::Other);
+}
+
+#[cfg(feature = "client")]
+#[test]
+fn polling() {
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    use futures::executor::block_on;
+    use github_events::client::backend::{BackendError, BoxFuture, HttpBackend, Request, Response};
+    use github_events::client::Client;
+
+    const LAST_MODIFIED: &str = "Thu, 25 Oct 2012 15:16:27 GMT";
+
+    /// Has one thread until it's polled with `If-Modified-Since`.
+    #[derive(Debug, Default)]
+    struct Notifications {
+        requests: Arc<Mutex<Vec<Request>>>,
+    }
+
+    impl HttpBackend for Notifications {
+        fn send(&self, request: Request) -> BoxFuture<'_, Result<Response, BackendError>> {
+            let modified_since = request
+                .headers
+                .iter()
+                .any(|(name, value)| name == "If-Modified-Since" && value == LAST_MODIFIED);
+            self.requests.lock().unwrap().push(request);
+            let response = if modified_since {
+                Response {
+                    status: 304,
+                    headers: vec![("X-Poll-Interval".to_owned(), "60".to_owned())],
+                    body: Vec::new(),
+                }
+            } else {
+                Response {
+                    status: 200,
+                    headers: vec![
+                        ("Last-Modified".to_owned(), LAST_MODIFIED.to_owned()),
+                        ("X-Poll-Interval".to_owned(), "120".to_owned()),
+                    ],
+                    body: serde_json::to_vec(&[thread("mention")]).unwrap(),
+                }
+            };
+            Box::pin(async { Ok(response) })
+        }
+
+        fn sleep(&self, _: Duration) -> BoxFuture<'_, ()> {
+            Box::pin(async {})
+        }
+    }
+
+    let backend = Notifications::default();
+    let requests = backend.requests.clone();
+    let client = Client::with_backend(backend);
+    let mut poller = client.notifications().participating(true);
+
+    let threads = block_on(poller.poll()).unwrap();
+    assert_eq!(threads.len(), 1);
+    assert_eq!(threads[0].reason, Reason::Mention);
+    assert_eq!(poller.last_modified(), Some(LAST_MODIFIED));
+    assert_eq!(poller.poll_interval(), Duration::from_secs(120));
+
+    assert!(block_on(poller.poll()).unwrap().is_empty());
+    assert_eq!(poller.last_modified(), Some(LAST_MODIFIED));
+    assert_eq!(poller.poll_interval(), Duration::from_secs(60));
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(
+        requests[0].url,
+        "https://api.github.com/notifications?all=false&participating=true&per_page=50"
+    );
+    assert_eq!(requests.len(), 2);
+}
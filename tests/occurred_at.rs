@@ -0,0 +1,66 @@
+#![cfg(feature = "chrono")]
+
+use github_events::{Event, EventType};
+
+/// `event.occurred_at()` as seconds since the Unix epoch.
+fn secs(event: &Event) -> Option<i64> {
+    event
+        .occurred_at()
+        .map(|occurred_at| occurred_at.timestamp())
+}
+
+#[test]
+fn pushes_happen_when_their_head_commit_was_made() {
+    let mut push = Event::sample(EventType::Push).unwrap();
+    assert_eq!(secs(&push), Some(1_296_068_472));
+    assert_eq!(
+        push.occurred_at().unwrap().to_rfc3339(),
+        "2011-01-26T19:01:12+00:00"
+    );
+    if let Event::PushEvent(e) = &mut push {
+        e.head_commit["timestamp"] = "2011-01-26T11:01:12-08:00".into();
+    }
+    assert_eq!(secs(&push), Some(1_296_068_472));
+    if let Event::PushEvent(e) = &mut push {
+        e.head_commit = serde_json::Value::Null;
+    }
+    assert_eq!(push.occurred_at(), None);
+}
+
+#[cfg(feature = "issues")]
+#[test]
+fn comments_happen_when_created() {
+    // Editing a comment doesn't move it.
+    let comment = Event::sample(EventType::IssueComment).unwrap();
+    assert_eq!(secs(&comment), Some(1_296_068_472));
+    let comment = Event::sample(EventType::PullRequestReviewComment).unwrap();
+    assert_eq!(secs(&comment), Some(1_296_068_472));
+    let comment = Event::sample(EventType::CommitComment).unwrap();
+    assert_eq!(secs(&comment), Some(1_296_068_472));
+}
+
+#[test]
+fn statuses_happen_when_created() {
+    let status = Event::sample(EventType::Status).unwrap();
+    assert_eq!(secs(&status), Some(1_296_068_472));
+}
+
+#[cfg(feature = "checks")]
+#[test]
+fn check_runs_happen_when_started() {
+    let mut check_run = Event::sample(EventType::CheckRun).unwrap();
+    assert_eq!(secs(&check_run), Some(1_296_068_472));
+    if let Event::CheckRunEvent(e) = &mut check_run {
+        e.check_run.started_at.clear();
+    }
+    assert_eq!(check_run.occurred_at(), None);
+}
+
+#[test]
+fn events_without_timestamps() {
+    assert_eq!(
+        Event::sample(EventType::Create).unwrap().occurred_at(),
+        None
+    );
+    assert_eq!(Event::sample(EventType::Watch).unwrap().occurred_at(), None);
+}
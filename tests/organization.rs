@@ -0,0 +1,127 @@
+use github_events::{Event, EventType, Organization};
+
+/// The sample of `event_type` as its payload, owned by a user or by the `github` organization.
+fn payload(event_type: EventType, org_owned: bool) -> Option<serde_json::Value> {
+    let event = serde_json::to_value(Event::sample(event_type)?).unwrap();
+    let mut payload = event.as_object().unwrap().values().next().unwrap().clone();
+    let object = payload.as_object_mut().unwrap();
+    if org_owned {
+        object.insert(
+            "organization".to_owned(),
+            serde_json::to_value(Organization::sample()).unwrap(),
+        );
+    } else {
+        object.remove("organization");
+    }
+    Some(payload)
+}
+
+#[test]
+fn every_event_has_an_optional_organization() {
+    for &event_type in EventType::ALL {
+        let user_owned = match payload(event_type, false) {
+            Some(payload) => Event::from_value(event_type, payload)
+                .unwrap_or_else(|e| panic!("{}: {}", event_type, e)),
+            None => continue,
+        };
+        assert_eq!(user_owned.organization(), None, "{}", event_type);
+
+        let org_owned = Event::from_value(event_type, payload(event_type, true).unwrap())
+            .unwrap_or_else(|e| panic!("{}: {}", event_type, e));
+        assert_eq!(
+            org_owned.organization(),
+            Some(&Organization::sample()),
+            "{}",
+            event_type
+        );
+    }
+}
+
+#[test]
+fn null_descriptions() {
+    let mut organization = serde_json::to_value(Organization::sample()).unwrap();
+    organization["description"] = serde_json::Value::Null;
+    let organization: Organization = serde_json::from_value(organization).unwrap();
+    assert_eq!(organization.description, None);
+}
+
+#[cfg(feature = "test-fixtures")]
+#[test]
+fn fixtures_are_user_owned() {
+    for fixture in github_events::fixtures::webhooks() {
+        let event = Event::from_slice(fixture.event_type, fixture.body).unwrap();
+        assert_eq!(event.organization(), None, "{}", fixture.name);
+    }
+}
+
+#[cfg(feature = "orgs")]
+#[test]
+fn renames_and_invitations() {
+    use github_events::{peek, User};
+    use serde_json::json;
+
+    let body = json!({
+        "action": "renamed",
+        "changes": { "login": { "from": "old-github" } },
+        "organization": Organization::sample(),
+        "sender": User::sample(),
+    });
+    let bytes = serde_json::to_vec(&body).unwrap();
+    assert_eq!(peek::event_type(&bytes), Some(EventType::Organization));
+    let event = Event::from_value(EventType::Organization, body).unwrap();
+    let renamed = match &event {
+        Event::OrganizationEvent(e) => e,
+        _ => unreachable!(),
+    };
+    assert_eq!(renamed.membership, None);
+    let login = renamed.changes.as_ref().and_then(|c| c.login.as_ref());
+    assert_eq!(login.map(|l| l.from.as_str()), Some("old-github"));
+    assert_eq!(event.summary(), "octocat renamed old-github to github");
+
+    let invited = json!({
+        "action": "member_invited",
+        "invitation": { "login": "hubot", "role": "direct_member" },
+        "organization": Organization::sample(),
+        "sender": User::sample(),
+    });
+    match Event::from_value(EventType::Organization, invited).unwrap() {
+        Event::OrganizationEvent(e) => {
+            assert_eq!(e.membership, None);
+            assert_eq!(e.changes, None);
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[cfg(feature = "orgs")]
+#[test]
+fn membership_scopes() {
+    use github_events::{MembershipAction, MembershipScope};
+
+    let mut payload = payload(EventType::Membership, true).unwrap();
+    payload["team"]["parent"] = payload["team"].clone();
+    payload["team"]["parent"]["slug"] = "heroes".into();
+    match Event::from_value(EventType::Membership, payload.clone()).unwrap() {
+        Event::MembershipEvent(e) => {
+            assert_eq!(e.action, MembershipAction::Added);
+            assert_eq!(e.scope, MembershipScope::Team);
+            let parent = e.team.unwrap().parent.unwrap();
+            assert_eq!(parent.slug, "heroes");
+        }
+        _ => unreachable!(),
+    }
+
+    payload["action"] = "removed".into();
+    payload["scope"] = "organization".into();
+    payload.as_object_mut().unwrap().remove("team");
+    let event = Event::from_value(EventType::Membership, payload).unwrap();
+    assert_eq!(event.summary(), "octocat removed hubot from github");
+    match event {
+        Event::MembershipEvent(e) => {
+            assert_eq!(e.action, MembershipAction::Removed);
+            assert_eq!(e.scope, MembershipScope::Organization);
+            assert_eq!(e.team, None);
+        }
+        _ => unreachable!(),
+    }
+}
@@ -0,0 +1,69 @@
+use std::fs;
+
+use github_events::{peek, Event, EventType, InstallationId};
+use serde_json::Value;
+
+/// The payload of `event`, without the tag naming its type.
+fn payload(event: &Event) -> Vec<u8> {
+    match serde_json::to_value(event).unwrap() {
+        Value::Object(tagged) => serde_json::to_vec(tagged.values().next().unwrap()).unwrap(),
+        _ => unreachable!(),
+    }
+}
+
+fn check(event_type: EventType, body: &[u8]) {
+    let value: Value = serde_json::from_slice(body).unwrap();
+    assert_eq!(peek::event_type(body), Some(event_type));
+    assert_eq!(
+        peek::action(body),
+        value["action"].as_str(),
+        "{}",
+        event_type
+    );
+    assert_eq!(
+        peek::repository(body),
+        value["repository"]["full_name"].as_str(),
+        "{}",
+        event_type
+    );
+    assert_eq!(
+        peek::installation_id(body),
+        value["installation"]["id"]
+            .as_u64()
+            .map(InstallationId::new),
+        "{}",
+        event_type
+    );
+}
+
+#[test]
+fn fixtures() {
+    for entry in fs::read_dir("fixtures/webhooks").unwrap() {
+        let path = entry.unwrap().path();
+        let name = path.file_name().unwrap().to_str().unwrap();
+        let event_type: EventType = name.split('.').next().unwrap().parse().unwrap();
+        check(event_type, &fs::read(&path).unwrap());
+    }
+}
+
+#[test]
+fn samples() {
+    for &event_type in EventType::ALL {
+        if let Some(event) = Event::sample(event_type) {
+            check(event_type, &payload(&event));
+        }
+    }
+}
+
+#[test]
+fn not_payloads() {
+    for body in [&b"[]"[..], b"\"push\"", b"{\"action\": ", b""] {
+        assert_eq!(peek::event_type(body), None);
+        assert_eq!(peek::action(body), None);
+        assert_eq!(peek::repository(body), None);
+        assert_eq!(peek::installation_id(body), None);
+    }
+    assert_eq!(peek::event_type(b"{}"), None);
+    assert_eq!(peek::event_type(br#"{"action": 5}"#), None);
+    assert_eq!(peek::action(br#"{"action": null}"#), None);
+}
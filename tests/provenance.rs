@@ -0,0 +1,116 @@
+use std::time::{Duration, UNIX_EPOCH};
+
+use github_events::envelope::Envelope;
+use github_events::provenance::{Provenance, Source};
+use github_events::{Event, EventType, HookId};
+
+const DELIVERY: &str = "72d3162e-cc78-11e3-81ab-4c9367dc0958";
+
+/// Received at 2019-05-15T15:20:33Z.
+fn provenance(source: Source) -> Provenance {
+    Provenance::new(source, DELIVERY)
+        .with_received_at(UNIX_EPOCH + Duration::from_secs(1_557_933_633))
+        .with_hook_id(HookId::new(292_430_182))
+}
+
+#[test]
+fn headers() {
+    let archived = provenance(Source::Archive);
+    let headers = archived.headers();
+    assert_eq!(
+        headers,
+        [
+            ("X-GitHub-Delivery".to_owned(), DELIVERY.to_owned()),
+            ("X-GitHub-Hook-ID".to_owned(), "292430182".to_owned()),
+            ("X-Event-Source".to_owned(), "archive".to_owned()),
+            (
+                "X-Received-At".to_owned(),
+                "2019-05-15T15:20:33Z".to_owned()
+            ),
+        ]
+    );
+    assert_eq!(Provenance::from_headers(headers), archived);
+
+    // A webhook request has GitHub's headers alone.
+    let delivered = Provenance::from_headers([
+        ("x-github-delivery", DELIVERY),
+        ("x-github-hook-id", "not a number"),
+    ]);
+    assert_eq!(delivered.source, Source::Webhook);
+    assert_eq!(delivered.delivery_id, DELIVERY);
+    assert_eq!(delivered.hook_id, None);
+}
+
+#[test]
+fn serialization() {
+    let polled = provenance(Source::Poll);
+    let json = serde_json::to_value(&polled).unwrap();
+    assert_eq!(json["received_at"], "2019-05-15T15:20:33Z");
+    assert_eq!(json["source"], "poll");
+    assert_eq!(json["hook_id"], 292_430_182);
+    assert_eq!(serde_json::from_value::<Provenance>(json).unwrap(), polled);
+
+    // Beside an event.
+    let envelope = Envelope::new(Event::sample(EventType::Push).unwrap(), polled.clone());
+    let json = serde_json::to_value(&envelope).unwrap();
+    assert_eq!(json["delivery_id"], DELIVERY);
+    assert_eq!(
+        serde_json::from_value::<Envelope<Provenance>>(json).unwrap(),
+        envelope
+    );
+
+    let replayed = polled.replayed();
+    assert_eq!(replayed.source, Source::Replay);
+    assert_eq!(replayed.hook_id, polled.hook_id);
+    assert!(replayed.received_at > polled.received_at);
+}
+
+#[cfg(feature = "sink")]
+#[test]
+fn messages() {
+    use github_events::sink::{EventSink, Message, Publisher, SinkError};
+
+    #[derive(Debug)]
+    struct Unused;
+
+    impl EventSink for Unused {
+        fn send(&self, _: Message) -> futures::future::BoxFuture<'_, Result<(), SinkError>> {
+            unreachable!()
+        }
+    }
+
+    let replayed = provenance(Source::Replay);
+    let message = Publisher::new(Unused)
+        .message_with_provenance(&Event::sample(EventType::Push).unwrap(), &replayed)
+        .unwrap();
+    assert_eq!(message.header("X-GitHub-Event"), Some("push"));
+    assert_eq!(message.header("X-Event-Source"), Some("replay"));
+    assert_eq!(message.provenance(), replayed);
+    assert_eq!(
+        message
+            .headers
+            .iter()
+            .filter(|(name, _)| name == "X-GitHub-Delivery")
+            .count(),
+        1
+    );
+}
+
+#[cfg(feature = "router")]
+#[test]
+fn deliveries() {
+    use github_events::router::WebhookDelivery;
+
+    let delivery = WebhookDelivery::from_provenance(
+        provenance(Source::Replay),
+        Event::sample(EventType::Push).unwrap(),
+    );
+    assert_eq!(delivery.id, DELIVERY);
+    assert_eq!(delivery.provenance.source, Source::Replay);
+    assert_eq!(
+        WebhookDelivery::new(DELIVERY, Event::sample(EventType::Push).unwrap())
+            .provenance
+            .source,
+        Source::Webhook
+    );
+}
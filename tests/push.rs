@@ -0,0 +1,58 @@
+use github_events::builders::PushEventBuilder;
+
+const SHA: &str = "6dcb09b5b57875f334f61aebed695e2e4193db5e";
+
+#[test]
+fn classification() {
+    let push = PushEventBuilder::new().branch("main").payload();
+    assert_eq!(push.branch(), Some("main"));
+    assert!(!push.is_tag_push());
+    assert!(!push.is_branch_deletion());
+    assert!(!push.is_force_push());
+
+    let deletion = PushEventBuilder::new()
+        .branch("main")
+        .deleted(true)
+        .payload();
+    assert!(deletion.is_branch_deletion());
+
+    let tag = PushEventBuilder::new()
+        .tag("v1.0.0")
+        .deleted(true)
+        .payload();
+    assert!(tag.is_tag_push());
+    assert_eq!(tag.branch(), None);
+    assert!(!tag.is_branch_deletion());
+
+    assert!(PushEventBuilder::new()
+        .forced(true)
+        .payload()
+        .is_force_push());
+}
+
+#[test]
+fn changed_files() {
+    let mut push = PushEventBuilder::new()
+        .commit(SHA, "Add the docs")
+        .commit("9049f1265b7d61be4a8904a9a27120d2064dab3b", "Fix the docs")
+        .payload();
+    push.commits[0].added = vec!["docs/index.md".to_owned(), "README.md".to_owned()];
+    push.commits[1].modified = vec!["docs/index.md".to_owned()];
+    push.commits[1].removed = vec!["docs/old.md".to_owned()];
+    assert_eq!(
+        push.changed_files(),
+        ["README.md", "docs/index.md", "docs/old.md"]
+    );
+}
+
+#[test]
+fn webhook_commits() {
+    // Webhooks name a commit's SHA `id`, where the Events API has `sha`.
+    let mut body =
+        serde_json::to_value(PushEventBuilder::new().commit(SHA, "Fix").payload()).unwrap();
+    let commit = body["commits"][0].as_object_mut().unwrap();
+    let sha = commit.remove("sha").unwrap();
+    commit.insert("id".to_owned(), sha);
+    let push: github_events::PushEvent = serde_json::from_value(body).unwrap();
+    assert_eq!(push.commits[0].sha, SHA);
+}
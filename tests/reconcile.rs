@@ -0,0 +1,176 @@
+#![cfg(all(feature = "client", feature = "test-fixtures"))]
+
+use std::time::Duration;
+
+use futures::executor::block_on;
+use github_events::client::backend::{BackendError, BoxFuture, HttpBackend, Request, Response};
+use github_events::client::{Checkpoint, Client, HookRef, Missed, Reconciler};
+use github_events::{fixtures, HookId};
+use serde_json::{json, Value};
+
+/// A hook's delivery log, newest first, and a repository's Events API page.
+#[derive(Debug)]
+struct Log {
+    deliveries: Vec<(i64, &'static str, &'static str)>,
+    events: Vec<(&'static str, &'static str)>,
+}
+
+impl Log {
+    fn delivery(&self, id: i64, guid: &str, delivered_at: &str) -> Value {
+        json!({
+            "id": id,
+            "guid": guid,
+            "delivered_at": delivered_at,
+            "redelivery": false,
+            "duration": 0.1,
+            "status": "OK",
+            "status_code": 200,
+            "event": "watch",
+            "action": "started",
+            "installation_id": null,
+            "repository_id": null,
+        })
+    }
+
+    fn body(&self, url: &str) -> Option<Value> {
+        let path = url.strip_prefix("https://api.github.com")?;
+        if path == "/repos/o/r/hooks/1/deliveries?per_page=100" {
+            let page = self.deliveries.iter();
+            return Some(page.map(|&(id, g, at)| self.delivery(id, g, at)).collect());
+        }
+        if let Some(id) = path.strip_prefix("/repos/o/r/hooks/1/deliveries/") {
+            let &(id, guid, at) = self.deliveries.iter().find(|d| d.0.to_string() == id)?;
+            let mut detail = self.delivery(id, guid, at);
+            detail["url"] = json!("https://example.com/webhook");
+            detail["request"] = json!({ "headers": {}, "payload": {} });
+            detail["response"] = json!({ "headers": {}, "payload": "ok" });
+            return Some(detail);
+        }
+        if path.starts_with("/repos/o/r/events") {
+            let watch = fixtures::timeline()
+                .iter()
+                .find(|f| f.name == "watch")
+                .unwrap();
+            let events = self.events.iter().map(|&(id, created_at)| {
+                let mut event: Value = serde_json::from_slice(watch.body).unwrap();
+                event["id"] = json!(id);
+                event["created_at"] = json!(created_at);
+                event
+            });
+            return Some(events.collect());
+        }
+        None
+    }
+}
+
+impl HttpBackend for Log {
+    fn send(&self, req: Request) -> BoxFuture<'_, Result<Response, BackendError>> {
+        let res = match self.body(&req.url) {
+            Some(body) => Response {
+                status: 200,
+                headers: Vec::new(),
+                body: serde_json::to_vec(&body).unwrap(),
+            },
+            None => Response {
+                status: 404,
+                headers: Vec::new(),
+                body: Vec::new(),
+            },
+        };
+        Box::pin(async move { Ok(res) })
+    }
+
+    fn sleep(&self, _: Duration) -> BoxFuture<'_, ()> {
+        Box::pin(async {})
+    }
+}
+
+const HOOK: HookRef<'static> = HookRef::Repo {
+    owner: "o",
+    repo: "r",
+    id: HookId::new(1),
+};
+
+fn log() -> Log {
+    Log {
+        deliveries: vec![
+            (4, "d", "2019-06-03T00:00:04Z"),
+            (3, "c", "2019-06-03T00:00:03Z"),
+            (2, "b", "2019-06-03T00:00:02Z"),
+        ],
+        events: vec![
+            ("e3", "2019-06-03T00:00:03Z"),
+            ("e2", "2019-06-03T00:00:01Z"),
+            ("e1", "2019-06-02T23:59:59Z"),
+        ],
+    }
+}
+
+fn missed(missed: &[Missed]) -> Vec<String> {
+    missed
+        .iter()
+        .map(|missed| match missed {
+            Missed::Delivery(detail) => detail.delivery.guid.clone(),
+            Missed::Timeline(event) => event.id.clone(),
+        })
+        .collect()
+}
+
+#[test]
+fn since_compares_times() {
+    let client = Client::with_backend(log());
+    let reconciler = Reconciler::new(&client, HOOK).fallback(client.repo_events("o", "r"));
+    // 00:00:02Z, so "2019-06-03T00:00:03Z" sorts before it as a string but comes after it.
+    for since in ["2019-06-03T02:00:02+02:00", "2019-06-03T00:00:02.5Z"] {
+        let checkpoint = Checkpoint::Since(since.to_owned());
+        let reconciliation = block_on(reconciler.missed_since(&checkpoint)).unwrap();
+        assert!(reconciliation.complete, "{}", since);
+        assert_eq!(missed(&reconciliation.missed), ["c", "d"], "{}", since);
+    }
+}
+
+#[test]
+fn since_before_the_log() {
+    let client = Client::with_backend(log());
+    let reconciler = Reconciler::new(&client, HOOK).fallback(client.repo_events("o", "r"));
+    let checkpoint = Checkpoint::Since("2019-06-03T00:00:00Z".to_owned());
+    let reconciliation = block_on(reconciler.missed_since(&checkpoint)).unwrap();
+    assert!(!reconciliation.complete);
+    // e1 happened before the checkpoint and e3 after the oldest logged delivery.
+    assert_eq!(missed(&reconciliation.missed), ["e2", "b", "c", "d"]);
+}
+
+#[test]
+fn unknown_guid() {
+    let client = Client::with_backend(log());
+    let reconciler = Reconciler::new(&client, HOOK).fallback(client.repo_events("o", "r"));
+    let checkpoint = Checkpoint::Guid("a".to_owned());
+    let reconciliation = block_on(reconciler.missed_since(&checkpoint)).unwrap();
+    assert!(!reconciliation.complete);
+    // Which events came after "a" isn't known, so none are guessed at.
+    assert_eq!(missed(&reconciliation.missed), ["b", "c", "d"]);
+
+    let checkpoint = Checkpoint::Guid("c".to_owned());
+    let reconciliation = block_on(reconciler.missed_since(&checkpoint)).unwrap();
+    assert!(reconciliation.complete);
+    assert_eq!(missed(&reconciliation.missed), ["d"]);
+}
+
+#[test]
+fn redeliveries_once() {
+    let mut log = log();
+    log.deliveries.insert(0, (5, "c", "2019-06-03T00:00:05Z"));
+    let client = Client::with_backend(log);
+    let reconciler = Reconciler::new(&client, HOOK);
+    let checkpoint = Checkpoint::Guid("b".to_owned());
+    let reconciliation = block_on(reconciler.missed_since(&checkpoint)).unwrap();
+    let ids: Vec<i64> = reconciliation
+        .missed
+        .iter()
+        .map(|missed| match missed {
+            Missed::Delivery(detail) => detail.delivery.id,
+            Missed::Timeline(_) => unreachable!(),
+        })
+        .collect();
+    assert_eq!(ids, [3, 4]);
+}
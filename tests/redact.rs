@@ -0,0 +1,120 @@
+use github_events::redact::{redact, redact_salted};
+use github_events::{Event, EventType};
+
+fn json(event: &Event) -> String {
+    serde_json::to_string(event).unwrap()
+}
+
+#[test]
+fn push_is_scrubbed() {
+    let event = Event::sample(EventType::Push).unwrap();
+    let redacted = json(&redact(&event).unwrap());
+    for personal in &[
+        "octocat",
+        "support@github.com",
+        "Monalisa Octocat",
+        "u/1?v=4",
+    ] {
+        assert!(!redacted.contains(personal), "{} in {}", personal, redacted);
+    }
+    // Everything else is kept.
+    assert!(redacted.contains("Hello-World"));
+    assert!(redacted.contains("MIT License"));
+}
+
+#[test]
+fn pseudonyms_are_stable() {
+    let event = Event::sample(EventType::Push).unwrap();
+    let (first, second) = (redact(&event).unwrap(), redact(&event).unwrap());
+    assert_eq!(first, second);
+
+    let push = match &first {
+        Event::PushEvent(push) => push,
+        _ => panic!("not a push event"),
+    };
+    assert!(push.sender.login.starts_with("user-"));
+    // 64 bits, so that pseudonyms of different people don't collide.
+    assert_eq!(push.sender.login.len(), "user-".len() + 16);
+    assert_eq!(push.sender.login, push.repository.owner.login);
+    assert_eq!(push.sender.id, push.repository.owner.id);
+    assert_eq!(
+        &*push.repository.full_name,
+        format!("{}/Hello-World", push.sender.login)
+    );
+
+    assert_ne!(
+        json(&redact_salted(&event, "secret").unwrap()),
+        json(&first)
+    );
+}
+
+#[test]
+fn logins_equal_to_enum_values() {
+    let event = Event::sample(EventType::PullRequest).unwrap();
+    let mut payload = serde_json::to_value(&event).unwrap()["PullRequestEvent"].take();
+    payload["sender"]["login"] = "squash".into();
+    payload["pull_request"]["auto_merge"] = serde_json::json!({
+        "enabled_by": payload["sender"],
+        "merge_method": "squash",
+        "commit_title": null,
+        "commit_message": null,
+    });
+    let event = Event::from_value(EventType::PullRequest, payload).unwrap();
+
+    let redacted = serde_json::to_value(redact(&event).unwrap()).unwrap();
+    let pull_request = &redacted["PullRequestEvent"]["pull_request"];
+    assert_eq!(pull_request["auto_merge"]["merge_method"], "squash");
+    assert_ne!(pull_request["auto_merge"]["enabled_by"]["login"], "squash");
+}
+
+/// The values of every field named `key`, or of every url if `key` is `None`, in `value`.
+#[cfg(feature = "test-fixtures")]
+fn strings(value: &serde_json::Value, key: Option<&str>, out: &mut Vec<String>) {
+    match value {
+        serde_json::Value::Object(fields) => {
+            for (name, value) in fields {
+                match value.as_str() {
+                    Some(s) if Some(name.as_str()) == key => out.push(s.to_owned()),
+                    Some(s) if key.is_none() && (name == "full_name" || s.contains("://")) => {
+                        out.push(s.to_owned())
+                    }
+                    _ => strings(value, key, out),
+                }
+            }
+        }
+        serde_json::Value::Array(elements) => elements.iter().for_each(|e| strings(e, key, out)),
+        _ => {}
+    }
+}
+
+#[cfg(feature = "test-fixtures")]
+#[test]
+fn fixtures_are_scrubbed() {
+    for fixture in github_events::fixtures::webhooks() {
+        let event = Event::from_slice(fixture.event_type, fixture.body).unwrap();
+        let mut logins = Vec::new();
+        strings(
+            &serde_json::to_value(&event).unwrap(),
+            Some("login"),
+            &mut logins,
+        );
+
+        let redacted = serde_json::to_value(redact(&event).unwrap()).unwrap();
+        let (mut redacted_logins, mut urls) = (Vec::new(), Vec::new());
+        strings(&redacted, Some("login"), &mut redacted_logins);
+        strings(&redacted, None, &mut urls);
+        for login in logins {
+            let segment = format!("/{}/", login);
+            let full_name = format!("{}/", login);
+            assert!(
+                !redacted_logins.contains(&login)
+                    && !urls
+                        .iter()
+                        .any(|url| url.contains(&segment) || url.starts_with(&full_name)),
+                "{} in {}",
+                login,
+                fixture.name
+            );
+        }
+    }
+}
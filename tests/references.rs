@@ -0,0 +1,74 @@
+use github_events::references::{
+    closing_references, issue_references, mentions, IssueReference, Mention,
+};
+
+fn local(number: u64) -> IssueReference {
+    IssueReference {
+        repository: None,
+        number,
+    }
+}
+
+fn remote(repository: &str, number: u64) -> IssueReference {
+    IssueReference {
+        repository: Some(repository.to_owned()),
+        number,
+    }
+}
+
+#[test]
+fn finds_mentions() {
+    let body = "cc @octocat, @github/justice-league (and @octocat's bot @dependabot[bot]).\n\
+                Mail me at monalisa@github.com, or see `@not-this` and\n\
+                ```\n@nor-this\n```\n@-invalid";
+    assert_eq!(
+        mentions(body),
+        vec![
+            Mention::User("octocat".into()),
+            Mention::Team {
+                org: "github".into(),
+                team: "justice-league".into()
+            },
+            Mention::User("dependabot".into()),
+        ]
+    );
+}
+
+#[test]
+fn finds_references() {
+    let body = "Related to #12, GH-13 and octocat/Hello-World#14.\n\
+                See https://github.com/rust-lang/rust/pull/15 (and #12 again).\n\
+                Not issue#16, #0, #17a, or `#18`.";
+    assert_eq!(
+        issue_references(body),
+        vec![
+            local(12),
+            local(13),
+            remote("octocat/Hello-World", 14),
+            remote("rust-lang/rust", 15),
+        ]
+    );
+    assert_eq!(
+        remote("octocat/Hello-World", 14).to_string(),
+        "octocat/Hello-World#14"
+    );
+    assert_eq!(local(12).to_string(), "#12");
+    assert_eq!(
+        local(12).repository_or("octocat/Hello-World"),
+        "octocat/Hello-World"
+    );
+}
+
+#[test]
+fn finds_closing_keywords() {
+    let body = "Fixes #1, #2\nThis closes: octocat/Hello-World#3 and RESOLVED \
+                https://github.com/octocat/Hello-World/issues/4.\nRelated to #5; fixing #6";
+    assert_eq!(
+        closing_references(body),
+        vec![
+            local(1),
+            remote("octocat/Hello-World", 3),
+            remote("octocat/Hello-World", 4),
+        ]
+    );
+}
@@ -0,0 +1,70 @@
+use github_events::{RepoId, Repository, Visibility};
+use serde_json::json;
+
+#[test]
+fn governance_fields() {
+    let mut template = serde_json::to_value(Repository::sample()).unwrap();
+    template["name"] = "template".into();
+    template["full_name"] = "octocat/template".into();
+    template["is_template"] = true.into();
+
+    let mut repository = serde_json::to_value(Repository::sample()).unwrap();
+    repository["topics"] = json!(["octocat", "api"]);
+    repository["visibility"] = "internal".into();
+    repository["template_repository"] = template;
+    repository["delete_branch_on_merge"] = true.into();
+    let repository: Repository = serde_json::from_value(repository).unwrap();
+    assert_eq!(repository.topics, ["octocat", "api"]);
+    assert_eq!(repository.visibility, Visibility::Internal);
+    assert!(!repository.is_template);
+    let template = repository.template_repository.as_deref().unwrap();
+    assert!(template.is_template);
+    assert_eq!(template.full_name, "octocat/template");
+    assert_eq!(repository.delete_branch_on_merge, Some(true));
+
+    let reparsed: Repository =
+        serde_json::from_value(serde_json::to_value(&repository).unwrap()).unwrap();
+    assert_eq!(reparsed, repository);
+}
+
+/// Payloads from before the fields were added still parse.
+#[test]
+fn older_payloads() {
+    let mut repository = serde_json::to_value(Repository::sample()).unwrap();
+    let object = repository.as_object_mut().unwrap();
+    for field in &[
+        "topics",
+        "visibility",
+        "is_template",
+        "template_repository",
+        "delete_branch_on_merge",
+    ] {
+        object.remove(*field);
+    }
+    let repository: Repository = serde_json::from_value(repository).unwrap();
+    assert!(repository.topics.is_empty());
+    assert_eq!(repository.visibility, Visibility::Public);
+    assert_eq!(repository.template_repository, None);
+    assert_eq!(repository.delete_branch_on_merge, None);
+}
+
+#[test]
+fn repo_ids() {
+    let id: RepoId = "octocat/Hello-World".parse().unwrap();
+    assert_eq!(id.owner, "octocat");
+    assert_eq!(id.name, "Hello-World");
+    assert_eq!(id.to_string(), "octocat/Hello-World");
+    assert_eq!(Repository::sample().repo_id(), id);
+    assert_eq!(id, "OctoCat/hello-world".parse().unwrap());
+
+    let mut routes = std::collections::HashMap::new();
+    routes.insert(id, "hello");
+    assert_eq!(
+        routes.get(&"octocat/HELLO-WORLD".parse().unwrap()),
+        Some(&"hello")
+    );
+
+    for invalid in ["octocat", "/Hello-World", "octocat/", "a/b/c"] {
+        assert!(invalid.parse::<RepoId>().is_err(), "{}", invalid);
+    }
+}
@@ -0,0 +1,487 @@
+#![cfg(feature = "router")]
+
+use std::sync::{Arc, Mutex};
+
+use futures::channel::oneshot;
+use futures::executor::block_on;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::Duration;
+
+use futures::future::{self, BoxFuture, FutureExt};
+use futures::stream::{self, StreamExt};
+use github_events::router::{
+    Control, DeadLetter, Dispatcher, EventRouter, HandlerError, Middleware, Outcome, RetryPolicy,
+    ShuttingDown, WebhookDelivery,
+};
+use github_events::{Event, EventType};
+
+fn delivery(event_type: EventType) -> WebhookDelivery {
+    WebhookDelivery::new(
+        format!("{}-delivery", event_type),
+        Event::sample(event_type).unwrap(),
+    )
+}
+
+type Log = Arc<Mutex<Vec<String>>>;
+
+/// Records its hooks, and skips or fails deliveries of `ping`s.
+struct Recorder {
+    name: &'static str,
+    log: Log,
+    on_ping: Option<Control>,
+}
+
+impl Middleware for Recorder {
+    fn before<'a>(
+        &'a self,
+        delivery: &'a WebhookDelivery,
+    ) -> BoxFuture<'a, Result<Control, HandlerError>> {
+        self.log
+            .lock()
+            .unwrap()
+            .push(format!("{} before", self.name));
+        let result = match (delivery.event.event_type(), self.on_ping) {
+            (EventType::Ping, Some(control)) => Ok(control),
+            (EventType::Ping, None) => Err("no pings".into()),
+            _ => Ok(Control::Continue),
+        };
+        Box::pin(async move { result })
+    }
+
+    fn after<'a>(
+        &'a self,
+        _: &'a WebhookDelivery,
+        result: &'a Result<Outcome, HandlerError>,
+    ) -> BoxFuture<'a, ()> {
+        let result = match result {
+            Ok(outcome) => format!("{:?}", outcome),
+            Err(e) => e.to_string(),
+        };
+        self.log
+            .lock()
+            .unwrap()
+            .push(format!("{} after {}", self.name, result));
+        Box::pin(async {})
+    }
+}
+
+#[test]
+fn routing() {
+    let log = Log::default();
+    let handler = |name: &'static str| {
+        let log = log.clone();
+        move |delivery: Arc<WebhookDelivery>| {
+            let log = log.clone();
+            async move {
+                log.lock()
+                    .unwrap()
+                    .push(format!("{} {}", name, delivery.id));
+                Ok::<_, HandlerError>(())
+            }
+        }
+    };
+    let router = EventRouter::new()
+        .on(EventType::Push, handler("push"))
+        .on_filter("type:watch".parse().unwrap(), handler("watch"))
+        .on_any(handler("any"));
+
+    assert_eq!(
+        block_on(router.dispatch(delivery(EventType::Push))).unwrap(),
+        Outcome::Handled
+    );
+    assert_eq!(
+        block_on(router.dispatch(delivery(EventType::Watch))).unwrap(),
+        Outcome::Handled
+    );
+    assert_eq!(
+        *log.lock().unwrap(),
+        [
+            "push push-delivery",
+            "any push-delivery",
+            "watch watch-delivery",
+            "any watch-delivery"
+        ]
+    );
+
+    let router = EventRouter::new().on(EventType::Push, handler("push"));
+    assert_eq!(
+        block_on(router.dispatch(delivery(EventType::Watch))).unwrap(),
+        Outcome::Unrouted
+    );
+}
+
+#[test]
+fn middleware() {
+    let log = Log::default();
+    let handled = log.clone();
+    let router = EventRouter::new()
+        .with_middleware(Recorder {
+            name: "outer",
+            log: log.clone(),
+            on_ping: Some(Control::Continue),
+        })
+        .with_middleware(Recorder {
+            name: "inner",
+            log: log.clone(),
+            on_ping: None,
+        })
+        .on_any(move |_| {
+            let handled = handled.clone();
+            async move {
+                handled.lock().unwrap().push("handler".to_owned());
+                Err::<(), HandlerError>("failed".into())
+            }
+        });
+
+    assert!(block_on(router.dispatch(delivery(EventType::Push))).is_err());
+    assert_eq!(
+        std::mem::take(&mut *log.lock().unwrap()),
+        [
+            "outer before",
+            "inner before",
+            "handler",
+            "inner after failed",
+            "outer after failed"
+        ]
+    );
+
+    // The inner middleware fails pings before they reach the handler.
+    assert!(block_on(router.dispatch(delivery(EventType::Ping))).is_err());
+    assert_eq!(
+        std::mem::take(&mut *log.lock().unwrap()),
+        [
+            "outer before",
+            "inner before",
+            "inner after no pings",
+            "outer after no pings"
+        ]
+    );
+
+    let router = EventRouter::new()
+        .with_middleware(Recorder {
+            name: "skipper",
+            log: log.clone(),
+            on_ping: Some(Control::Skip),
+        })
+        .on_any(|_| async { Err::<(), HandlerError>("unreachable".into()) });
+    assert_eq!(
+        block_on(router.dispatch(delivery(EventType::Ping))).unwrap(),
+        Outcome::Skipped
+    );
+    assert_eq!(
+        *log.lock().unwrap(),
+        ["skipper before", "skipper after Skipped"]
+    );
+}
+
+/// Records the deliveries it's given.
+struct DeadLetters(Log);
+
+impl DeadLetter for DeadLetters {
+    fn dead_letter<'a>(
+        &'a self,
+        delivery: &'a WebhookDelivery,
+        error: &'a HandlerError,
+    ) -> BoxFuture<'a, ()> {
+        self.0
+            .lock()
+            .unwrap()
+            .push(format!("dead letter {} {}", delivery.id, error));
+        Box::pin(future::ready(()))
+    }
+}
+
+#[test]
+fn retries() {
+    let router = |max_attempts| {
+        let attempts = Arc::new(AtomicU32::new(0));
+        let sleeps = Arc::new(Mutex::new(Vec::new()));
+        let slept = sleeps.clone();
+        let policy = RetryPolicy::new(max_attempts, move |duration| {
+            slept.lock().unwrap().push(duration);
+            Box::pin(future::ready(()))
+        })
+        .with_backoff(Duration::from_millis(10), Duration::from_millis(15));
+        let dead_letters = Log::default();
+        let router = EventRouter::new()
+            .with_retry(policy)
+            .with_dead_letter(DeadLetters(dead_letters.clone()))
+            .on_any({
+                let attempts = attempts.clone();
+                move |_| {
+                    let attempt = attempts.fetch_add(1, Ordering::SeqCst) + 1;
+                    async move {
+                        if attempt < 3 {
+                            Err::<(), HandlerError>(format!("attempt {} failed", attempt).into())
+                        } else {
+                            Ok(())
+                        }
+                    }
+                }
+            });
+        (router, sleeps, dead_letters)
+    };
+
+    let (third_time_lucky, sleeps, dead_letters) = router(3);
+    assert_eq!(
+        block_on(third_time_lucky.dispatch(delivery(EventType::Push))).unwrap(),
+        Outcome::Handled
+    );
+    let sleeps = sleeps.lock().unwrap();
+    assert_eq!(sleeps.len(), 2);
+    assert!((Duration::from_millis(5)..=Duration::from_millis(10)).contains(&sleeps[0]));
+    assert!((Duration::from_micros(7500)..=Duration::from_millis(15)).contains(&sleeps[1]));
+    assert!(dead_letters.lock().unwrap().is_empty());
+
+    let (exhausted, _, dead_letters) = router(2);
+    let error = block_on(exhausted.dispatch(delivery(EventType::Push))).unwrap_err();
+    assert_eq!(error.to_string(), "attempt 2 failed");
+    assert_eq!(
+        *dead_letters.lock().unwrap(),
+        ["dead letter push-delivery attempt 2 failed"]
+    );
+}
+
+/// A push to `repository`, delivered as `id`.
+fn push_to(repository: &str, id: &str) -> WebhookDelivery {
+    let mut event = Event::sample(EventType::Push).unwrap();
+    if let Event::PushEvent(push) = &mut event {
+        push.repository.full_name = repository.into();
+    }
+    WebhookDelivery::new(id, event)
+}
+
+#[test]
+fn per_repository_order() {
+    let log = Log::default();
+    let (open, gate) = oneshot::channel::<()>();
+    let gate = gate.shared();
+    let handled = log.clone();
+    let dispatcher = Dispatcher::new(EventRouter::new().on_any(
+        move |delivery: Arc<WebhookDelivery>| {
+            let log = handled.clone();
+            let gate = gate.clone();
+            async move {
+                log.lock().unwrap().push(delivery.id.clone());
+                if delivery.id == "first" {
+                    gate.await.unwrap();
+                }
+                log.lock().unwrap().push(format!("{} done", delivery.id));
+                Ok::<_, HandlerError>(())
+            }
+        },
+    ));
+
+    let first = dispatcher.dispatch(push_to("octocat/Hello-World", "first"));
+    // Giving up its place doesn't let the next delivery overtake the one ahead of it.
+    drop(dispatcher.dispatch(push_to("Octocat/hello-world", "cancelled")));
+    let second = dispatcher.dispatch(push_to("octocat/hello-world", "second"));
+    let other = dispatcher.dispatch(push_to("octocat/Spoon-Knife", "other"));
+    let results = block_on(async {
+        let (first, second, other, ()) = futures::join!(first, second, other, async {
+            open.send(()).unwrap();
+        });
+        [first, second, other]
+    });
+    assert!(results
+        .iter()
+        .all(|r| *r.as_ref().unwrap() == Outcome::Handled));
+    assert_eq!(
+        *log.lock().unwrap(),
+        [
+            "first",
+            "other",
+            "other done",
+            "first done",
+            "second",
+            "second done"
+        ]
+    );
+}
+
+#[test]
+fn long_lines() {
+    let (open, gate) = oneshot::channel::<()>();
+    let gate = gate.shared();
+    let dispatcher = Dispatcher::new(EventRouter::new().on_any(
+        move |delivery: Arc<WebhookDelivery>| {
+            let gate = gate.clone();
+            async move {
+                if delivery.id == "0" {
+                    let _ = gate.await;
+                }
+                Ok::<_, HandlerError>(())
+            }
+        },
+    ));
+    let push = push_to("octocat/hello-world", "push");
+    let mut dispatched: Vec<_> = (0..20_000)
+        .map(|i| dispatcher.dispatch(WebhookDelivery::new(i.to_string(), push.event.clone())))
+        .collect();
+    // Deliveries that give up their places, wherever they are in line, leave no trace.
+    drop(dispatched.remove(10_000));
+    drop(dispatched.pop());
+    drop(dispatcher.dispatch(push_to("octocat/spoon-knife", "abandoned")));
+    let (results, ()) = block_on(async {
+        futures::join!(future::join_all(dispatched), async {
+            open.send(()).unwrap();
+        })
+    });
+    assert!(results
+        .iter()
+        .all(|r| *r.as_ref().unwrap() == Outcome::Handled));
+    assert!(format!("{:?}", dispatcher).contains("queues: 0"));
+
+    let run = dispatcher.run(stream::iter([push]), 1);
+    assert_eq!(block_on(run.count()), 1);
+    assert!(format!("{:?}", dispatcher).contains("runs: 0"));
+}
+
+#[test]
+fn shutdown() {
+    let (open, gate) = oneshot::channel::<()>();
+    let gate = gate.shared();
+    let dispatcher = Dispatcher::new(EventRouter::new().on_any(
+        move |delivery: Arc<WebhookDelivery>| {
+            let gate = gate.clone();
+            async move {
+                if delivery.id != "quick" {
+                    let _ = gate.await;
+                }
+                Ok::<_, HandlerError>(())
+            }
+        },
+    ));
+
+    // The deliveries in flight are waited for.
+    let slow = dispatcher.dispatch(push_to("octocat/hello-world", "slow"));
+    let quick = dispatcher.dispatch(push_to("octocat/spoon-knife", "quick"));
+    let (slow, quick, shutdown, ()) = block_on(async {
+        futures::join!(slow, quick, dispatcher.shutdown(future::pending()), async {
+            open.send(()).unwrap();
+        })
+    });
+    assert_eq!(slow.unwrap(), Outcome::Handled);
+    assert_eq!(quick.unwrap(), Outcome::Handled);
+    assert!(shutdown.is_clean());
+
+    // New ones are refused, and runs end.
+    let error = block_on(dispatcher.dispatch(push_to("octocat/hello-world", "late"))).unwrap_err();
+    assert!(error.is::<ShuttingDown>());
+    let run = dispatcher.run(stream::iter([push_to("octocat/hello-world", "late")]), 1);
+    assert_eq!(block_on(run.count()), 0);
+}
+
+#[test]
+fn shutdown_timeout() {
+    let dispatcher = Dispatcher::new(
+        EventRouter::new().on_any(|_| future::pending::<Result<(), HandlerError>>()),
+    );
+    let mut run = dispatcher.run(
+        stream::iter([
+            push_to("octocat/hello-world", "first"),
+            push_to("octocat/hello-world", "second"),
+        ])
+        .chain(stream::pending()),
+        10,
+    );
+    let stuck = dispatcher.dispatch(push_to("octocat/spoon-knife", "stuck"));
+    let shutdown = block_on(async {
+        // Let the run take both deliveries.
+        assert!(futures::poll!(run.next()).is_pending());
+        dispatcher.shutdown(future::ready(())).await
+    });
+    assert_eq!(shutdown.unprocessed, ["stuck", "first", "second"]);
+
+    // Dropping the deliveries left cancels them.
+    drop(stuck);
+    drop(run);
+    assert!(block_on(dispatcher.shutdown(future::pending())).is_clean());
+}
+
+#[cfg(feature = "dedup")]
+#[test]
+fn deduplication() {
+    use github_events::dedup::{DeliveryGuard, MemoryStore};
+
+    let router = EventRouter::new()
+        .with_middleware(DeliveryGuard::new(MemoryStore::new(10)))
+        .on_any(|_| async { Ok::<_, HandlerError>(()) });
+    assert_eq!(
+        block_on(router.dispatch(delivery(EventType::Push))).unwrap(),
+        Outcome::Handled
+    );
+    assert_eq!(
+        block_on(router.dispatch(delivery(EventType::Push))).unwrap(),
+        Outcome::Skipped
+    );
+}
+
+#[cfg(feature = "signature")]
+#[test]
+fn verified_requests() {
+    use github_events::signature::{self, ResolveError, SecretKey, VerifyError};
+    use github_events::HookId;
+
+    let event = Event::sample(EventType::Push).unwrap();
+    let body = match serde_json::to_value(&event).unwrap() {
+        serde_json::Value::Object(tagged) => {
+            serde_json::to_vec(tagged.values().next().unwrap()).unwrap()
+        }
+        _ => unreachable!(),
+    };
+    let resolver = |key: SecretKey| {
+        let secret = (key.hook_id == Some(HookId::new(7))).then(|| b"secret".to_vec());
+        future::ready(Ok::<_, ResolveError>(secret))
+    };
+    let headers = [
+        ("X-GitHub-Event", "push".to_owned()),
+        ("X-GitHub-Delivery", "abc".to_owned()),
+        ("X-GitHub-Hook-ID", "7".to_owned()),
+        ("Content-Type", "application/json".to_owned()),
+        ("X-Hub-Signature-256", signature::sign("secret", &body)),
+    ];
+
+    let delivery = block_on(WebhookDelivery::from_request(&resolver, &headers, &body)).unwrap();
+    assert_eq!(delivery.event, event);
+    assert_eq!(delivery.provenance.delivery_id, "abc");
+    assert_eq!(delivery.provenance.hook_id, Some(HookId::new(7)));
+
+    let error = block_on(WebhookDelivery::from_request(&resolver, &headers, b"{}")).unwrap_err();
+    assert!(matches!(
+        error.downcast_ref::<VerifyError>(),
+        Some(VerifyError::Mismatch)
+    ));
+}
+
+#[cfg(all(feature = "signature", feature = "compression"))]
+#[test]
+fn compressed_requests() {
+    use std::io::Write;
+
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+    use github_events::signature::{self, ResolveError, SecretKey};
+
+    let event = Event::sample(EventType::Ping).unwrap();
+    let body = match serde_json::to_value(&event).unwrap() {
+        serde_json::Value::Object(tagged) => {
+            serde_json::to_vec(tagged.values().next().unwrap()).unwrap()
+        }
+        _ => unreachable!(),
+    };
+    let mut gzip = GzEncoder::new(Vec::new(), Compression::default());
+    gzip.write_all(&body).unwrap();
+    let gzip = gzip.finish().unwrap();
+    let resolver = |_: SecretKey| future::ready(Ok::<_, ResolveError>(Some(b"secret".to_vec())));
+    // Signed before a proxy compressed it.
+    let headers = [
+        ("X-GitHub-Event", "ping".to_owned()),
+        ("X-GitHub-Delivery", "abc".to_owned()),
+        ("Content-Encoding", "gzip".to_owned()),
+        ("X-Hub-Signature-256", signature::sign("secret", &body)),
+    ];
+
+    let delivery = block_on(WebhookDelivery::from_request(&resolver, &headers, &gzip)).unwrap();
+    assert_eq!(delivery.event, event);
+}
@@ -0,0 +1,82 @@
+use github_events::deliveries::{HookDelivery, HookDeliveryDetail};
+use github_events::{Event, EventType, Repository};
+
+/// Every event has a sample when every family is on.
+#[cfg(feature = "full")]
+#[test]
+fn every_event_has_a_sample() {
+    for &event_type in EventType::ALL {
+        let event =
+            Event::sample(event_type).unwrap_or_else(|| panic!("no sample for {}", event_type));
+        assert_eq!(event.event_type(), event_type);
+    }
+}
+
+#[test]
+fn samples_are_consistent() {
+    let repository = Repository::sample();
+    assert_eq!(repository.full_name, "octocat/Hello-World");
+    assert_eq!(repository.owner.login, "octocat");
+    match Event::sample(EventType::Push) {
+        Some(Event::PushEvent(push)) => {
+            assert_eq!(push.repository, repository);
+            assert_eq!(push.head.as_deref(), Some(push.after.as_str()));
+        }
+        _ => panic!("not a push"),
+    }
+}
+
+/// The samples of the parts of events are the parts of the sample events.
+#[cfg(feature = "issues")]
+#[test]
+fn parts_agree_with_events() {
+    use github_events::{Comment, Issue, PullRequest, PullRequestRef};
+
+    match Event::sample(EventType::IssueComment) {
+        Some(Event::IssueCommentEvent(e)) => {
+            assert_eq!(e.issue, Issue::sample());
+            assert_eq!(e.comment, Comment::sample());
+        }
+        _ => panic!("not an issue comment"),
+    }
+    match Event::sample(EventType::PullRequest) {
+        Some(Event::PullRequestEvent(e)) => {
+            assert_eq!(e.pull_request, PullRequest::sample());
+            assert_eq!(e.pull_request.number, PullRequestRef::sample().number);
+        }
+        _ => panic!("not a pull request"),
+    }
+}
+
+#[test]
+fn samples_parse_as_webhooks() {
+    for &event_type in EventType::ALL {
+        let event = match Event::sample(event_type) {
+            Some(event) => event,
+            None => continue,
+        };
+        let json = serde_json::to_value(&event).unwrap();
+        let body = json
+            .as_object()
+            .unwrap()
+            .values()
+            .next()
+            .unwrap()
+            .to_string();
+        let parsed = github_events::parse_webhook(event_type.as_str(), &body).unwrap();
+        assert_eq!(parsed, event, "{}", event_type);
+    }
+    let error = github_events::parse_webhook("pushed", "{}").unwrap_err();
+    assert!(error.to_string().contains("pushed"), "{}", error);
+}
+
+#[test]
+fn deliveries() {
+    let detail = HookDeliveryDetail::sample();
+    assert_eq!(detail.delivery, HookDelivery::sample());
+    assert!(detail.delivery.is_success());
+    assert_eq!(
+        detail.parse_event().unwrap(),
+        Event::sample(EventType::Push).unwrap()
+    );
+}
@@ -0,0 +1,43 @@
+#![cfg(feature = "schemars")]
+
+use github_events::{Event, EventType, PushEvent};
+use schemars::schema_for;
+use serde_json::Value;
+
+#[test]
+fn push_schema() {
+    let schema = schema_for!(PushEvent);
+    let schema = schema.as_value();
+    let required = schema["required"].as_array().unwrap();
+    assert!(required.contains(&Value::from("ref")));
+    assert!(required.contains(&Value::from("commits")));
+
+    // Repository timestamps are numbers in push events.
+    let repository = &schema["$defs"]["Repository"]["properties"];
+    assert_eq!(
+        repository["created_at"]["type"],
+        serde_json::json!(["string", "integer"])
+    );
+}
+
+#[test]
+fn schema_covers_samples() {
+    let schema = schema_for!(Event);
+    let schema = schema.as_value();
+    let event = serde_json::to_value(Event::sample(EventType::Push).unwrap()).unwrap();
+    let variants = schema["oneOf"].as_array().unwrap();
+    let push = variants
+        .iter()
+        .find(|variant| variant["required"] == serde_json::json!(["PushEvent"]))
+        .expect("a PushEvent variant");
+    assert!(push["properties"]["PushEvent"].is_object());
+    for key in event["PushEvent"].as_object().unwrap().keys() {
+        assert!(
+            schema["$defs"]["PushEvent"]["properties"]
+                .get(key)
+                .is_some(),
+            "{} is not in the schema",
+            key
+        );
+    }
+}
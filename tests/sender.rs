@@ -0,0 +1,29 @@
+use github_events::{Event, EventType, Sender, User};
+
+#[test]
+fn senders_are_users() {
+    let sender: Sender = User::sample();
+    let push = match Event::sample(EventType::Push) {
+        Some(Event::PushEvent(event)) => event,
+        _ => panic!("not a push event"),
+    };
+    assert_eq!(push.sender, sender);
+    assert!(!sender.is_bot());
+    assert!(!sender.is_site_admin());
+}
+
+#[test]
+fn bots() {
+    let mut app = User::sample();
+    app.login = "dependabot[bot]".into();
+    assert!(app.is_bot());
+
+    let mut typed = User::sample();
+    typed.type_field = "Bot".into();
+    assert!(typed.is_bot());
+
+    let mut admin = User::sample();
+    admin.site_admin = true;
+    assert!(admin.is_site_admin());
+    assert!(!admin.is_bot());
+}
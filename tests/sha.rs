@@ -0,0 +1,51 @@
+use std::convert::TryFrom;
+
+use github_events::{Event, EventType, Sha};
+
+const SHA: &str = "6dcb09b5b57875f334f61aebed695e2e4193db5e";
+
+#[test]
+fn parsing() {
+    let sha: Sha = SHA.to_uppercase().parse().unwrap();
+    assert_eq!(sha, SHA);
+    assert_eq!(sha.short(), "6dcb09b");
+    assert!(!sha.is_null());
+    assert!(Sha::new(&"0".repeat(40)).unwrap().is_null());
+    assert!(Sha::new(&"a".repeat(64)).is_some());
+    assert!("6dcb09b".parse::<Sha>().is_err());
+    assert!(SHA.replace('6', "g").parse::<Sha>().is_err());
+
+    assert_eq!(Sha::try_from(SHA.to_owned()).unwrap(), SHA);
+    assert!(Sha::try_from("6dcb09b").is_err());
+    assert_eq!(Sha::new_unchecked("6dcb09b".to_owned()), "6dcb09b");
+}
+
+#[test]
+fn deserializing_checks() {
+    let sha: Sha = serde_json::from_str(&format!("\"{}\"", SHA.to_uppercase())).unwrap();
+    assert_eq!(sha, SHA);
+    assert_eq!(serde_json::to_string(&sha).unwrap(), format!("\"{}\"", SHA));
+    assert!(serde_json::from_str::<Sha>("\"6dcb09b\"").is_err());
+    assert!(serde_json::from_str::<Sha>("\"\"").is_err());
+
+    let mut payload = serde_json::to_value(Event::sample(EventType::Push).unwrap()).unwrap();
+    payload["PushEvent"]["after"] = "main".into();
+    assert!(serde_json::from_value::<Event>(payload).is_err());
+}
+
+#[test]
+fn pushes_and_pull_requests_share_commits() {
+    let push = match Event::sample(EventType::Push) {
+        Some(Event::PushEvent(push)) => push,
+        _ => panic!("not a push"),
+    };
+    assert_eq!(push.after, SHA);
+    assert_eq!(push.commits[0].sha, push.after);
+    assert_eq!(Sha::new(&push.after).as_ref(), Some(&push.after));
+
+    #[cfg(feature = "issues")]
+    match Event::sample(EventType::PullRequest) {
+        Some(Event::PullRequestEvent(e)) => assert_eq!(e.pull_request.head.sha, push.after),
+        _ => panic!("not a pull request"),
+    }
+}
@@ -0,0 +1,153 @@
+#![cfg(feature = "signature")]
+
+use github_events::signature::{sign, verify};
+
+// The example in GitHub's documentation on validating deliveries.
+const SECRET: &str = "It's a Secret to Everybody";
+const BODY: &[u8] = b"Hello, World!";
+const SIGNATURE: &str = "sha256=757107ea0eb2509fc211221cce984b8a37570b6d7586c22c46f4379c8b043e17";
+
+#[test]
+fn sign_and_verify() {
+    assert_eq!(sign(SECRET, BODY), SIGNATURE);
+    assert!(verify(SECRET, BODY, SIGNATURE));
+    assert!(verify(
+        SECRET.as_bytes(),
+        BODY,
+        &SIGNATURE.to_uppercase().replace("SHA", "sha")
+    ));
+
+    assert!(!verify("wrong", BODY, SIGNATURE));
+    assert!(!verify(SECRET, b"Hello, World?", SIGNATURE));
+    assert!(!verify(SECRET, BODY, &SIGNATURE["sha256=".len()..]));
+    assert!(!verify(SECRET, BODY, &SIGNATURE[..SIGNATURE.len() - 2]));
+    assert!(!verify(SECRET, BODY, &SIGNATURE.replace('e', "g")));
+    assert!(!verify(SECRET, BODY, ""));
+}
+
+#[cfg(feature = "client")]
+#[test]
+fn test_delivery() {
+    use std::sync::Mutex;
+
+    use futures::executor::block_on;
+    use github_events::client::backend::{BackendError, BoxFuture, HttpBackend, Request, Response};
+    use github_events::signature::send_test_delivery;
+    use github_events::{Event, EventType};
+
+    #[derive(Debug, Default)]
+    struct Receiver(Mutex<Vec<Request>>);
+
+    impl HttpBackend for Receiver {
+        fn send(&self, request: Request) -> BoxFuture<'_, Result<Response, BackendError>> {
+            self.0.lock().unwrap().push(request);
+            Box::pin(async {
+                Ok(Response {
+                    status: 204,
+                    headers: Vec::new(),
+                    body: Vec::new(),
+                })
+            })
+        }
+
+        fn sleep(&self, _: std::time::Duration) -> BoxFuture<'_, ()> {
+            Box::pin(async {})
+        }
+    }
+
+    let receiver = Receiver::default();
+    let event = Event::sample(EventType::Push).unwrap();
+    for _ in 0..2 {
+        let response = block_on(send_test_delivery(
+            &receiver,
+            "http://localhost/hook",
+            SECRET,
+            &event,
+        ))
+        .unwrap();
+        assert_eq!(response.status, 204);
+    }
+
+    let requests = receiver.0.into_inner().unwrap();
+    let header = |request: &Request, name: &str| {
+        request
+            .headers
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, v)| v.clone())
+            .unwrap()
+    };
+    let request = &requests[0];
+    assert_eq!(request.url, "http://localhost/hook");
+    assert_eq!(header(request, "X-GitHub-Event"), "push");
+    let body = request.body.as_ref().unwrap();
+    assert!(verify(
+        SECRET,
+        body,
+        &header(request, "X-Hub-Signature-256")
+    ));
+    assert_eq!(Event::from_slice(EventType::Push, body).unwrap(), event);
+
+    let guid = header(request, "X-GitHub-Delivery");
+    assert_eq!(guid.len(), 36);
+    assert_eq!(&guid[14..15], "4");
+    assert_ne!(guid, header(&requests[1], "X-GitHub-Delivery"));
+}
+
+#[test]
+fn resolve_secrets() {
+    use futures::executor::block_on;
+    use futures::future;
+    use github_events::signature::{verify_request, ResolveError, SecretKey, VerifyError};
+    use github_events::{HookId, InstallationId};
+
+    let body = br#"{"action":"opened","installation":{"id":42},"repository":{"full_name":"octocat/Hello-World"}}"#;
+    let resolver = |key: SecretKey| {
+        let secret = match key.repository.as_deref() {
+            Some("octocat/Hello-World") => Some(b"customer secret".to_vec()),
+            _ => None,
+        };
+        future::ready(Ok::<_, ResolveError>(secret))
+    };
+    let request = |signature: &str| {
+        vec![
+            ("x-github-hook-id", "7".to_owned()),
+            ("X-Hub-Signature-256", signature.to_owned()),
+        ]
+    };
+
+    let key = SecretKey::from_request(&request(""), body);
+    assert_eq!(key.hook_id, Some(HookId::new(7)));
+    assert_eq!(key.installation_id, Some(InstallationId::new(42)));
+    assert_eq!(key.repository.as_deref(), Some("octocat/Hello-World"));
+
+    let signed = request(&sign("customer secret", body));
+    assert!(block_on(verify_request(&resolver, &signed, body)).is_ok());
+    assert!(matches!(
+        block_on(verify_request(
+            &resolver,
+            &request(&sign("other secret", body)),
+            body
+        )),
+        Err(VerifyError::Mismatch)
+    ));
+    assert!(matches!(
+        block_on(verify_request(
+            &resolver,
+            &[("X-GitHub-Hook-ID", "7")],
+            body
+        )),
+        Err(VerifyError::Unsigned)
+    ));
+    let other = br#"{"repository":{"full_name":"octocat/Spoon-Knife"}}"#;
+    assert!(matches!(
+        block_on(verify_request(&resolver, &request(&sign("customer secret", other)), other)),
+        Err(VerifyError::UnknownSecret(key)) if key.repository.as_deref() == Some("octocat/Spoon-Knife")
+    ));
+
+    let failing = |_| future::ready(Err::<Option<Vec<u8>>, ResolveError>("database down".into()));
+    assert!(matches!(
+        block_on(verify_request(&failing, &signed, body)),
+        Err(VerifyError::Resolve(_))
+    ));
+}
@@ -0,0 +1,87 @@
+#![cfg(feature = "sink")]
+
+use std::sync::Mutex;
+
+use futures::executor::block_on;
+use futures::future::{self, BoxFuture};
+use github_events::sink::{EventSink, Message, Publisher, SinkError};
+use github_events::{Event, EventType};
+
+#[derive(Debug, Default)]
+struct Recorder {
+    messages: Mutex<Vec<Message>>,
+}
+
+impl EventSink for Recorder {
+    fn send(&self, message: Message) -> BoxFuture<'_, Result<(), SinkError>> {
+        self.messages.lock().unwrap().push(message);
+        Box::pin(future::ready(Ok(())))
+    }
+}
+
+const DELIVERY: &str = "72d3162e-cc78-11e3-81ab-4c9367dc0958";
+
+#[test]
+fn publishes_payloads() {
+    let publisher = Publisher::new(Recorder::default());
+    let push = Event::sample(EventType::Push).unwrap();
+    block_on(publisher.publish(&push, DELIVERY)).unwrap();
+
+    let messages = publisher.sink().messages.lock().unwrap();
+    let message = &messages[0];
+    assert_eq!(message.subject, "github.octocat.Hello-World.push");
+    assert_eq!(message.key.as_deref(), Some("octocat/Hello-World"));
+    assert_eq!(message.header("x-github-delivery"), Some(DELIVERY));
+    assert_eq!(message.header("X-GitHub-Event"), Some("push"));
+    assert_eq!(message.header("Content-Type"), Some("application/json"));
+    assert_eq!(
+        Event::from_slice(EventType::Push, &message.payload).unwrap(),
+        push
+    );
+}
+
+#[cfg(feature = "orgs")]
+#[test]
+fn subject_templates() {
+    let publisher =
+        Publisher::new(Recorder::default()).with_subject("gh.{owner}.{repo}.{event}.{action}");
+    let organization = Event::sample(EventType::Organization).unwrap();
+    let message = publisher.message(&organization, DELIVERY).unwrap();
+    assert_eq!(message.subject, "gh.github._.organization.member_added");
+    assert_eq!(message.key, None);
+}
+
+#[test]
+fn publishes_streams_in_order() {
+    let publisher = Publisher::new(Recorder::default());
+    let events = vec![
+        (Event::sample(EventType::Push).unwrap(), "1".to_owned()),
+        (Event::sample(EventType::Create).unwrap(), "2".to_owned()),
+    ];
+    block_on(publisher.publish_all(futures::stream::iter(events))).unwrap();
+
+    let messages = publisher.sink().messages.lock().unwrap();
+    let deliveries: Vec<_> = messages
+        .iter()
+        .map(|m| m.header("X-GitHub-Delivery").unwrap())
+        .collect();
+    assert_eq!(deliveries, ["1", "2"]);
+}
+
+#[cfg(feature = "cloudevents")]
+#[test]
+fn publishes_cloudevents() {
+    use github_events::cloudevents::CloudEvent;
+    use github_events::sink::Format;
+
+    let publisher = Publisher::new(Recorder::default()).with_format(Format::CloudEvents);
+    let push = Event::sample(EventType::Push).unwrap();
+    let message = publisher.message(&push, DELIVERY).unwrap();
+    assert_eq!(
+        message.header("Content-Type"),
+        Some("application/cloudevents+json")
+    );
+    let cloud_event: CloudEvent = serde_json::from_slice(&message.payload).unwrap();
+    assert_eq!(cloud_event.id, DELIVERY);
+    assert_eq!(cloud_event.into_event().unwrap(), push);
+}
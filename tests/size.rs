@@ -0,0 +1,21 @@
+use std::mem::size_of;
+
+use github_events::{Event, EventType};
+
+/// Every payload is boxed, so an `Event` is a discriminant and a pointer.
+#[test]
+fn event_is_two_words() {
+    assert_eq!(size_of::<Event>(), 2 * size_of::<usize>());
+}
+
+/// Boxing the payloads doesn't change how events are (de)serialized.
+#[test]
+fn boxed_payloads_keep_their_json() {
+    let event = Event::sample(EventType::Watch).unwrap();
+    assert!(matches!(event, Event::WatchEvent(_)));
+    let json = serde_json::to_value(&event).unwrap();
+    assert_eq!(json["WatchEvent"]["action"], "started");
+
+    let payload = json["WatchEvent"].clone();
+    assert_eq!(Event::from_value(EventType::Watch, payload).unwrap(), event);
+}
@@ -0,0 +1,47 @@
+#![cfg(feature = "slack")]
+
+use github_events::{slack, Event, EventType};
+
+#[test]
+fn push_message() {
+    let message = slack::message(&Event::sample(EventType::Push).unwrap());
+    assert_eq!(
+        message["text"],
+        "Monalisa Octocat pushed 1 commit to master in octocat/Hello-World"
+    );
+    let attachment = &message["attachments"][0];
+    assert_eq!(attachment["color"], "#0366d6");
+    let blocks = attachment["blocks"].as_array().unwrap();
+    assert_eq!(blocks.len(), 3);
+    assert!(blocks[0]["text"]["text"]
+        .as_str()
+        .unwrap()
+        .starts_with("*<https://github.com/octocat/Hello-World/compare/"));
+    assert_eq!(
+        blocks[1]["text"]["text"],
+        "<https://github.com/octocat/Hello-World/commit/6dcb09b5b57875f334f61aebed695e2e4193db5e\
+         |`6dcb09b`> Update README"
+    );
+    assert_eq!(blocks[2]["type"], "context");
+    assert_eq!(blocks[2]["elements"][0]["alt_text"], "octocat");
+    assert_eq!(
+        slack::blocks(&Event::sample(EventType::Push).unwrap()),
+        attachment["blocks"]
+    );
+}
+
+#[cfg(feature = "issues")]
+#[test]
+fn escapes_and_quotes_bodies() {
+    let mut value = serde_json::to_value(Event::sample(EventType::Issues).unwrap()).unwrap();
+    value["IssueEvent"]["issue"]["title"] = "a <b> & c".into();
+    value["IssueEvent"]["issue"]["body"] = "line one\nline <two>".into();
+    let message = slack::message(&serde_json::from_value(value).unwrap());
+    assert_eq!(
+        message["text"],
+        "octocat opened issue #1347 in octocat/Hello-World: a &lt;b&gt; &amp; c"
+    );
+    let blocks = &message["attachments"][0]["blocks"];
+    assert_eq!(message["attachments"][0]["color"], "#28a745");
+    assert_eq!(blocks[1]["text"]["text"], ">line one\n>line &lt;two&gt;");
+}
@@ -0,0 +1,71 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use github_events::stale::{Freshness, StalePolicy};
+use github_events::{Event, EventType};
+
+const MINUTE: Duration = Duration::from_secs(60);
+
+/// When the sample push's head commit was made.
+fn pushed_at() -> SystemTime {
+    UNIX_EPOCH + Duration::from_secs(1_296_068_472)
+}
+
+#[test]
+fn thresholds() {
+    let push = Event::sample(EventType::Push).unwrap();
+    let policy = StalePolicy::new(60 * MINUTE);
+    assert_eq!(
+        policy.check(&push, pushed_at() + 5 * MINUTE),
+        Freshness::Fresh { age: 5 * MINUTE }
+    );
+    assert_eq!(
+        policy.check(&push, pushed_at() + 60 * MINUTE),
+        Freshness::Stale {
+            age: 60 * MINUTE,
+            threshold: 60 * MINUTE
+        }
+    );
+    // Clock skew.
+    assert_eq!(
+        policy.check(&push, pushed_at() - MINUTE),
+        Freshness::Fresh {
+            age: Duration::ZERO
+        }
+    );
+
+    let policy = policy.with(EventType::Push, MINUTE);
+    assert!(policy.check(&push, pushed_at() + 5 * MINUTE).is_stale());
+    assert_eq!(policy.threshold(EventType::Watch), Some(60 * MINUTE));
+
+    let policy = policy.without(EventType::Push);
+    assert_eq!(policy.threshold(EventType::Push), None);
+    let freshness = policy.check(&push, pushed_at() + 1000 * MINUTE);
+    assert!(!freshness.is_stale());
+    assert_eq!(freshness.age(), Some(1000 * MINUTE));
+}
+
+#[test]
+fn events_without_timestamps() {
+    let create = Event::sample(EventType::Create).unwrap();
+    assert_eq!(
+        StalePolicy::new(MINUTE).check(&create, SystemTime::now()),
+        Freshness::Unknown
+    );
+}
+
+#[cfg(feature = "router")]
+#[test]
+fn router_middleware() {
+    use futures::executor::block_on;
+    use github_events::router::{EventRouter, HandlerError, Outcome, WebhookDelivery};
+
+    let router = EventRouter::new()
+        .with_middleware(StalePolicy::new(60 * MINUTE))
+        .on_any(|_| async { Ok::<_, HandlerError>(()) });
+    let dispatch = |event_type| {
+        let delivery = WebhookDelivery::new("delivery", Event::sample(event_type).unwrap());
+        block_on(router.dispatch(delivery)).unwrap()
+    };
+    assert_eq!(dispatch(EventType::Push), Outcome::Skipped);
+    assert_eq!(dispatch(EventType::Create), Outcome::Handled);
+}
@@ -0,0 +1,83 @@
+use github_events::{Event, EventType, VerificationReason};
+
+fn status(verification: serde_json::Value) -> github_events::StatusEvent {
+    let mut payload = serde_json::to_value(Event::sample(EventType::Status).unwrap()).unwrap()
+        ["StatusEvent"]
+        .take();
+    payload["commit"]["commit"]["verification"] = verification;
+    match Event::from_value(EventType::Status, payload).unwrap() {
+        Event::StatusEvent(e) => *e,
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn unsigned_and_signed_commits() {
+    let unsigned = status(serde_json::json!({
+        "verified": false,
+        "reason": "unsigned",
+        "signature": null,
+        "payload": null,
+    }));
+    let verification = unsigned.commit.commit.verification;
+    assert_eq!(verification.reason, VerificationReason::Unsigned);
+    assert_eq!(verification.signature, None);
+    assert_eq!(verification.verified_at, None);
+
+    let signed = status(serde_json::json!({
+        "verified": true,
+        "reason": "valid",
+        "signature": "-----BEGIN PGP SIGNATURE-----\n...",
+        "payload": "tree 9049f1265b7d61be4a8904a9a27120d2064dab3b\n...",
+        "verified_at": "2011-01-26T19:01:12Z",
+    }));
+    let verification = signed.commit.commit.verification;
+    assert!(verification.verified);
+    assert_eq!(verification.reason, VerificationReason::Valid);
+    assert!(verification.signature.is_some());
+
+    let unknown = status(serde_json::json!({
+        "verified": false,
+        "reason": "some_new_reason",
+        "signature": null,
+        "payload": null,
+    }));
+    assert_eq!(
+        unknown.commit.commit.verification.reason,
+        VerificationReason::Unknown
+    );
+}
+
+#[test]
+fn commits_without_github_users() {
+    let mut payload = serde_json::to_value(Event::sample(EventType::Status).unwrap()).unwrap()
+        ["StatusEvent"]
+        .take();
+    payload["commit"]["author"] = serde_json::Value::Null;
+    payload["commit"]["committer"] = serde_json::Value::Null;
+    match Event::from_value(EventType::Status, payload).unwrap() {
+        Event::StatusEvent(e) => {
+            assert_eq!(e.commit.author, None);
+            assert_eq!(e.commit.commit.author.name, "Monalisa Octocat");
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parents_and_branches() {
+    let status = match Event::sample(EventType::Status).unwrap() {
+        Event::StatusEvent(e) => *e,
+        _ => unreachable!(),
+    };
+    let parent = &status.commit.parents[0];
+    assert_eq!(parent.sha, "9049f1265b7d61be4a8904a9a27120d2064dab3b");
+    assert_eq!(
+        parent.html_url.as_deref(),
+        Some("https://github.com/octocat/Hello-World/commit/9049f1265b7d61be4a8904a9a27120d2064dab3b")
+    );
+    let branch = &status.branches[0];
+    assert_eq!(branch.name, "master");
+    assert_eq!(branch.commit.sha, status.sha);
+    assert_eq!(branch.commit.html_url, None);
+}
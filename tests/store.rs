@@ -0,0 +1,99 @@
+#![cfg(any(feature = "sqlite", feature = "postgres"))]
+
+use std::time::{Duration, UNIX_EPOCH};
+
+use github_events::store::{EventStore, Query, StoredEvent};
+use github_events::{Event, EventType};
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn sqlite() {
+    use github_events::store::SqliteStore;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    // Every connection to `:memory:` is a database of its own.
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+    let store = SqliteStore::new(pool);
+    store.migrate().await.unwrap();
+    // Migrating twice is harmless.
+    store.migrate().await.unwrap();
+    append_and_get(&store).await;
+    queries(&store).await;
+}
+
+/// Run with `DATABASE_URL` set to an empty Postgres database.
+#[cfg(feature = "postgres")]
+#[tokio::test]
+#[ignore]
+async fn postgres() {
+    use github_events::store::PostgresStore;
+
+    let url = std::env::var("DATABASE_URL").expect("DATABASE_URL");
+    let store = PostgresStore::new(sqlx::PgPool::connect(&url).await.unwrap());
+    store.migrate().await.unwrap();
+    append_and_get(&store).await;
+    queries(&store).await;
+}
+
+fn stored(delivery_id: &str, event_type: EventType, secs: u64) -> StoredEvent {
+    let mut stored = StoredEvent::new(delivery_id, Event::sample(event_type).unwrap());
+    stored.received_at = UNIX_EPOCH + Duration::from_millis(secs * 1000 + 250);
+    stored
+}
+
+async fn append_and_get(store: &impl EventStore) {
+    let push = stored("1", EventType::Push, 10);
+    assert!(store.append(&push).await.unwrap());
+    // Redeliveries are only stored once.
+    assert!(!store.append(&push).await.unwrap());
+
+    assert_eq!(store.get_by_delivery_id("1").await.unwrap(), Some(push));
+    assert_eq!(store.get_by_delivery_id("2").await.unwrap(), None);
+}
+
+async fn queries(store: &impl EventStore) {
+    let events = [
+        stored("a", EventType::Create, 30),
+        stored("b", EventType::Push, 10),
+        stored("c", EventType::Push, 20),
+        stored("d", EventType::InstallationRepositories, 40),
+    ];
+    for event in &events {
+        store.append(event).await.unwrap();
+    }
+    let deliveries = |stored: Vec<StoredEvent>| -> Vec<String> {
+        stored.into_iter().map(|s| s.delivery_id).collect()
+    };
+
+    let all = store.query(&Query::new()).await.unwrap();
+    assert_eq!(deliveries(all), ["1", "b", "c", "a", "d"]);
+
+    let pushes = Query::new().event_type(EventType::Push);
+    assert_eq!(
+        deliveries(store.query(&pushes).await.unwrap()),
+        ["1", "b", "c"]
+    );
+
+    let window = Query::new()
+        .since(UNIX_EPOCH + Duration::from_secs(20))
+        .until(UNIX_EPOCH + Duration::from_secs(40));
+    assert_eq!(deliveries(store.query(&window).await.unwrap()), ["c", "a"]);
+
+    let repository = Query::new()
+        .repository("octocat/Hello-World")
+        .event_type(EventType::Push)
+        .event_type(EventType::Create)
+        .since(UNIX_EPOCH + Duration::from_secs(20))
+        .limit(2);
+    assert_eq!(
+        deliveries(store.query(&repository).await.unwrap()),
+        ["c", "a"]
+    );
+
+    let elsewhere = Query::new().repository("octocat/Spoon-Knife");
+    assert!(store.query(&elsewhere).await.unwrap().is_empty());
+}
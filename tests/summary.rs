@@ -0,0 +1,69 @@
+use github_events::{Event, EventType};
+
+fn summary(event_type: EventType, edit: impl FnOnce(&mut serde_json::Value)) -> String {
+    let mut value = serde_json::to_value(Event::sample(event_type).unwrap()).unwrap();
+    edit(value.as_object_mut().unwrap().values_mut().next().unwrap());
+    serde_json::from_value::<Event>(value).unwrap().summary()
+}
+
+#[test]
+fn every_sample_has_a_summary() {
+    for &event_type in EventType::ALL.iter() {
+        if let Some(event) = Event::sample(event_type) {
+            let summary = event.summary();
+            assert!(
+                !summary.is_empty() && !summary.contains('\n'),
+                "{}",
+                summary
+            );
+            assert!(!summary.contains("null"), "{}", summary);
+        }
+    }
+}
+
+#[test]
+fn pushes() {
+    assert_eq!(
+        summary(EventType::Push, |_| ()),
+        "Monalisa Octocat pushed 1 commit to master in octocat/Hello-World"
+    );
+    assert_eq!(
+        summary(EventType::Push, |p| {
+            p["forced"] = true.into();
+            p["commits"] = serde_json::json!([]);
+            p["pusher"]["name"] = "".into();
+        }),
+        "octocat force-pushed 0 commits to master in octocat/Hello-World"
+    );
+}
+
+#[cfg(feature = "issues")]
+#[test]
+fn pull_requests() {
+    assert_eq!(
+        summary(EventType::PullRequest, |p| {
+            p["number"] = 42.into();
+            p["pull_request"]["number"] = 42.into();
+            p["pull_request"]["title"] = "Fix ICE".into();
+        }),
+        "octocat opened pull request #42 in octocat/Hello-World: Fix ICE"
+    );
+    assert_eq!(
+        summary(EventType::PullRequest, |p| {
+            p["action"] = "closed".into();
+            p["pull_request"]["merged"] = true.into();
+        }),
+        "octocat merged pull request #1347 in octocat/Hello-World: Amazing new feature"
+    );
+    assert_eq!(
+        summary(EventType::PullRequestReview, |p| {
+            p["review"]["state"] = "changes_requested".into();
+        }),
+        "octocat requested changes on pull request #1347 in octocat/Hello-World: \
+         Amazing new feature"
+    );
+    assert_eq!(
+        summary(EventType::IssueComment, |_| ()),
+        "octocat commented on issue #1347 in octocat/Hello-World: Found a bug"
+    );
+}
@@ -0,0 +1,246 @@
+use github_events::filter::EventFilter;
+use github_events::normalized::{Activity, ObjectKind};
+use github_events::timeline::{self, EventPayload, TimelineEvent};
+use github_events::{EventType, Partial};
+use serde_json::{json, Value};
+
+fn event(type_name: &str, repo: &str, payload: Value) -> TimelineEvent {
+    serde_json::from_value(json!({
+      "id": "2489651045",
+      "type": type_name,
+      "actor": {
+        "id": 665991,
+        "login": "petroav",
+        "gravatar_id": "",
+        "url": "https://api.github.com/users/petroav",
+        "avatar_url": "https://avatars.githubusercontent.com/u/665991?"
+      },
+      "repo": {
+        "id": 28688495,
+        "name": repo,
+        "url": format!("https://api.github.com/repos/{}", repo)
+      },
+      "payload": payload,
+      "public": true,
+      "created_at": "2015-01-01T15:00:00Z"
+    }))
+    .unwrap_or_else(|e| panic!("{}: {}", type_name, e))
+}
+
+/// Legacy events as GH Archive recorded them, abridged.
+fn legacy() -> Vec<TimelineEvent> {
+    vec![
+        event(
+            "GistEvent",
+            "/",
+            json!({
+              "action": "create",
+              "gist": {
+                "url": "https://api.github.com/gists/aa5a315d61ae9438b18d",
+                "id": "aa5a315d61ae9438b18d",
+                "html_url": "https://gist.github.com/aa5a315d61ae9438b18d",
+                "files": {},
+                "public": true,
+                "created_at": "2015-01-01T15:00:00Z",
+                "updated_at": "2015-01-01T15:00:00Z",
+                "description": "Hello gist",
+                "comments": 0,
+                "user": null
+              }
+            }),
+        ),
+        event(
+            "FollowEvent",
+            "/",
+            json!({
+              "target": {
+                "login": "octocat",
+                "id": 583231,
+                "avatar_url": "https://avatars.githubusercontent.com/u/583231?v=3",
+                "gravatar_id": "",
+                "url": "https://api.github.com/users/octocat",
+                "html_url": "https://github.com/octocat",
+                "type": "User",
+                "site_admin": false,
+                "name": "The Octocat",
+                "public_repos": 5,
+                "followers": 1000,
+                "following": 5,
+                "created_at": "2011-01-25T18:44:36Z"
+              }
+            }),
+        ),
+        event(
+            "DownloadEvent",
+            "octocat/Hello-World",
+            json!({
+              "download": {
+                "url": "https://api.github.com/repos/octocat/Hello-World/downloads/1",
+                "html_url": "https://github.com/downloads/octocat/Hello-World/new_file.jpg",
+                "id": 1,
+                "name": "new_file.jpg",
+                "description": "Description of your download",
+                "size": 1024,
+                "download_count": 40,
+                "content_type": ".jpg"
+              }
+            }),
+        ),
+        event(
+            "ForkApplyEvent",
+            "octocat/Hello-World",
+            json!({
+              "head": "master",
+              "before": "6dcb09b5b57875f334f61aebed695e2e4193db5e",
+              "after": "762941318ee16e59dabbacb1b4049eec22f0d303"
+            }),
+        ),
+    ]
+}
+
+#[test]
+fn legacy_events() {
+    let events = legacy();
+    let names: Vec<_> = events.iter().map(TimelineEvent::type_name).collect();
+    assert_eq!(
+        names,
+        [
+            "GistEvent",
+            "FollowEvent",
+            "DownloadEvent",
+            "ForkApplyEvent"
+        ]
+    );
+    for event in &events {
+        assert_eq!(event.event_type(), None, "{}", event.type_name());
+        let reparsed: TimelineEvent =
+            serde_json::from_value(serde_json::to_value(event).unwrap()).unwrap();
+        assert_eq!(&reparsed, event);
+        assert!(
+            !EventFilter::EventType(EventType::Fork).matches_timeline(event),
+            "{}",
+            event.type_name()
+        );
+    }
+
+    match &events[0].payload {
+        EventPayload::GistEvent(gist) => {
+            assert_eq!(gist.action, "create");
+            assert_eq!(gist.gist.id, "aa5a315d61ae9438b18d");
+        }
+        payload => panic!("{:?}", payload),
+    }
+    match &events[1].payload {
+        EventPayload::FollowEvent(follow) => assert_eq!(&*follow.target.login, "octocat"),
+        payload => panic!("{:?}", payload),
+    }
+}
+
+#[test]
+fn legacy_activities() {
+    let activities: Vec<_> = legacy().into_iter().map(Activity::from).collect();
+    let described: Vec<_> = activities
+        .iter()
+        .map(|a| (a.verb.as_str(), a.object.kind, a.object.id.as_deref()))
+        .collect();
+    assert_eq!(
+        described,
+        [
+            ("created", ObjectKind::Gist, Some("aa5a315d61ae9438b18d")),
+            ("followed", ObjectKind::User, Some("octocat")),
+            ("uploaded", ObjectKind::Download, Some("1")),
+            ("applied", ObjectKind::Ref, Some("master")),
+        ]
+    );
+    assert!(activities.iter().all(|a| a.event_type.is_none()));
+}
+
+#[test]
+fn abbreviated_pull_requests() {
+    let head = json!({
+        "ref": "patch-1",
+        "sha": "6dcb09b5b57875f334f61aebed695e2e4193db5e",
+        "repo": {
+            "id": 1296269,
+            "url": "https://api.github.com/repos/octocat/Hello-World",
+            "name": "Hello-World"
+        }
+    });
+    let payload = json!({
+        "action": "opened",
+        "number": 1347,
+        "pull_request": {
+            "url": "https://api.github.com/repos/octocat/Hello-World/pulls/1347",
+            "id": 1,
+            "number": 1347,
+            "head": head,
+            "base": head
+        }
+    });
+    let event = event("PullRequestEvent", "octocat/Hello-World", payload.clone());
+    match &event.payload {
+        EventPayload::PullRequestEvent(e) => {
+            assert_eq!(e.pull_request.full(), None);
+            assert_eq!((e.pull_request.id(), e.pull_request.number()), (1, 1347));
+            match &e.pull_request {
+                Partial::Ref(pull_request) => assert_eq!(pull_request.head.ref_field, "patch-1"),
+                Partial::Full(_) => unreachable!(),
+            }
+        }
+        payload => panic!("{:?}", payload),
+    }
+    assert_eq!(
+        serde_json::to_value(&event).unwrap()["payload"]["pull_request"],
+        payload["pull_request"]
+    );
+}
+
+#[test]
+fn pages() {
+    let watch = |id: &str| {
+        let mut watch = event(
+            "WatchEvent",
+            "octocat/Hello-World",
+            json!({"action": "started"}),
+        );
+        watch.id = id.to_owned();
+        serde_json::to_string(&watch).unwrap()
+    };
+    let ids = |body: &str| -> Vec<Result<String, ()>> {
+        timeline::iter_page(body.as_bytes())
+            .map(|event| event.map(|event| event.id).map_err(|_| ()))
+            .collect()
+    };
+
+    let page = format!(" [\n{}, {} ]\n", watch("1"), watch("2"));
+    assert_eq!(ids(&page), [Ok("1".to_owned()), Ok("2".to_owned())]);
+    let all: Vec<TimelineEvent> = serde_json::from_str(&page).unwrap();
+    let iterated: Vec<_> = timeline::iter_page(page.as_bytes())
+        .map(Result::unwrap)
+        .collect();
+    assert_eq!(iterated, all);
+    assert_eq!(ids(" [ ] "), []);
+
+    // An event that doesn't fit doesn't take the others down with it.
+    let page = format!("[{}, {{\"id\": 5}}, {}]", watch("1"), watch("3"));
+    assert_eq!(
+        ids(&page),
+        [Ok("1".to_owned()), Err(()), Ok("3".to_owned())]
+    );
+
+    // Malformed JSON ends the page, after the events before it.
+    assert_eq!(ids("{}"), [Err(())]);
+    assert_eq!(ids(""), [Err(())]);
+    assert_eq!(
+        ids(&format!("[{},", watch("1"))),
+        [Ok("1".to_owned()), Err(())]
+    );
+    assert_eq!(
+        ids(&format!("[{} {}]", watch("1"), watch("2"))),
+        [Ok("1".to_owned()), Err(())]
+    );
+    assert_eq!(
+        ids(&format!("[{}, {{", watch("1"))),
+        [Ok("1".to_owned()), Err(())]
+    );
+}
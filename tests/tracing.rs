@@ -0,0 +1,191 @@
+#![cfg(feature = "tracing")]
+
+use std::io;
+use std::sync::{Arc, Mutex};
+
+use github_events::{Event, EventType};
+
+/// What's logged while running `f`, without colors, targets, or timestamps.
+fn logged(f: impl FnOnce()) -> String {
+    #[derive(Clone, Default)]
+    struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+    impl io::Write for Buffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let buffer = Buffer::default();
+    let writer = buffer.clone();
+    let subscriber = tracing_subscriber::fmt()
+        .with_writer(move || writer.clone())
+        .with_max_level(tracing::Level::DEBUG)
+        .with_ansi(false)
+        .with_target(false)
+        .without_time()
+        .finish();
+    tracing::subscriber::with_default(subscriber, f);
+    let logged = buffer.0.lock().unwrap().clone();
+    String::from_utf8(logged).unwrap()
+}
+
+#[cfg(feature = "issues")]
+#[test]
+fn event_spans() {
+    use github_events::tracing::event_span;
+
+    let event = Event::sample(EventType::PullRequest).unwrap();
+    let logged = logged(|| {
+        event_span("72d3162e-cc78-11e3-81ab-4c9367dc0958", &event).in_scope(|| {
+            tracing::info!("handled");
+        });
+    });
+    assert_eq!(
+        logged.trim(),
+        "INFO github_event{delivery_id=\"72d3162e-cc78-11e3-81ab-4c9367dc0958\" \
+         event_type=\"pull_request\" action=\"opened\" repo=\"octocat/Hello-World\" \
+         sender=\"octocat\"}: handled"
+    );
+}
+
+#[test]
+fn parse_failures() {
+    let logged = logged(|| {
+        Event::from_slice(EventType::Push, b"{}").unwrap_err();
+    });
+    assert!(
+        logged.starts_with(
+            "DEBUG webhook payload failed to parse event_type=\"push\" error=missing field"
+        ),
+        "{}",
+        logged
+    );
+}
+
+#[cfg(feature = "test-fixtures")]
+#[test]
+fn timeline_spans() {
+    use github_events::fixtures;
+    use github_events::timeline::TimelineEvent;
+    use github_events::tracing::timeline_span;
+
+    let issues = fixtures::timeline()
+        .iter()
+        .find(|f| f.name == "issues")
+        .unwrap();
+    let event: TimelineEvent = serde_json::from_slice(issues.body).unwrap();
+    let logged = logged(|| {
+        timeline_span(&event).in_scope(|| tracing::info!("handled"));
+    });
+    let action = format!(
+        "action=\"{}\"",
+        serde_json::to_value(&event.payload).unwrap()["payload"]["action"]
+            .as_str()
+            .unwrap()
+    );
+    assert!(
+        logged.contains(&format!("event_id=\"{}\"", event.id)),
+        "{}",
+        logged
+    );
+    assert!(logged.contains("event_type=\"issues\""), "{}", logged);
+    assert!(logged.contains(&action), "{}", logged);
+    assert!(
+        logged.contains(&format!("repo=\"{}\"", &*event.repo.name)),
+        "{}",
+        logged
+    );
+}
+
+#[cfg(feature = "otel")]
+#[test]
+fn otel_spans() {
+    use opentelemetry::trace::{SpanKind, TracerProvider as _};
+    use opentelemetry::Value;
+    use opentelemetry_sdk::propagation::TraceContextPropagator;
+    use opentelemetry_sdk::trace::{InMemorySpanExporter, SdkTracerProvider, SpanData};
+    use tracing_subscriber::layer::SubscriberExt;
+
+    use github_events::tracing::otel;
+
+    opentelemetry::global::set_text_map_propagator(TraceContextPropagator::new());
+    let exporter = InMemorySpanExporter::default();
+    let provider = SdkTracerProvider::builder()
+        .with_simple_exporter(exporter.clone())
+        .build();
+    let subscriber = tracing_subscriber::registry()
+        .with(tracing_opentelemetry::layer().with_tracer(provider.tracer("test")));
+
+    let traceparent = "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01";
+    let headers = [
+        ("X-GitHub-Event", "push"),
+        ("X-GitHub-Delivery", "72d3162e-cc78-11e3-81ab-4c9367dc0958"),
+        ("traceparent", traceparent),
+    ];
+    let event = Event::sample(EventType::Push).unwrap();
+    let sent = tracing::subscriber::with_default(subscriber, || {
+        let receive = otel::receive_span(&headers);
+        let process = otel::process_span(
+            "72d3162e-cc78-11e3-81ab-4c9367dc0958",
+            &event,
+            &otel::context(&receive),
+        );
+        let sent = otel::headers(&receive);
+        drop((process, receive));
+        sent
+    });
+
+    let spans = exporter.get_finished_spans().unwrap();
+    let span = |name: &str| spans.iter().find(|s| s.name == name).unwrap().clone();
+    let attribute = |span: &SpanData, key: &str| {
+        span.attributes
+            .iter()
+            .find(|kv| kv.key.as_str() == key)
+            .map(|kv| kv.value.clone())
+    };
+    let receive = span("receive push");
+    let process = span("process push");
+
+    assert_eq!(receive.span_kind, SpanKind::Server);
+    assert_eq!(
+        receive.span_context.trace_id().to_string(),
+        "4bf92f3577b34da6a3ce929d0e0e4736"
+    );
+    assert_eq!(receive.parent_span_id.to_string(), "00f067aa0ba902b7");
+    assert_eq!(sent[0].0, "traceparent");
+    assert!(sent[0]
+        .1
+        .contains(&receive.span_context.span_id().to_string()));
+
+    assert_eq!(process.span_kind, SpanKind::Consumer);
+    assert_ne!(
+        process.span_context.trace_id(),
+        receive.span_context.trace_id()
+    );
+    assert_eq!(process.links.links.len(), 1);
+    assert_eq!(process.links.links[0].span_context, receive.span_context);
+
+    for span in [&receive, &process] {
+        assert_eq!(
+            attribute(span, "messaging.system"),
+            Some(Value::from("github_webhooks"))
+        );
+        assert_eq!(
+            attribute(span, "messaging.message.id"),
+            Some(Value::from("72d3162e-cc78-11e3-81ab-4c9367dc0958"))
+        );
+        assert_eq!(
+            attribute(span, "messaging.destination.name"),
+            Some(Value::from("push"))
+        );
+    }
+    assert_eq!(
+        attribute(&process, "repo"),
+        Some(Value::from("octocat/Hello-World"))
+    );
+}
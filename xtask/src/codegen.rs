@@ -0,0 +1,387 @@
+//! Payload types from the JSON Schema of `@octokit/webhooks-schemas`.
+//!
+//! Every definition that is an object becomes a struct named after it (`issues$opened` becomes
+//! `IssuesOpened`), as does every object nested in one without a definition of its own
+//! (`IssuesOpenedIssue`). Other schemas map to
+//! - `string` to `String`, listing the values of an `enum` in the field's docs,
+//! - `integer` to `i64`, `number` to `f64`, and `boolean` to `bool`,
+//! - `array` to `Vec`, and an object with only `additionalProperties` to a `BTreeMap`,
+//! - a nullable or not `required` field to an `Option`,
+//! - and anything else, such as a `oneOf` of several types, to `serde_json::Value`.
+//!
+//! The structs follow the conventions of the hand-written ones in `src/lib.rs`: the same
+//! derives, `<keyword>_field` for fields named after keywords, and `crate::fuzz` helpers
+//! for `serde_json::Value` fields.
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde_json::{Map, Value};
+
+const VALUE: &str = "serde_json::Value";
+
+const HEADER: &str = "\
+//! Payload types generated by `cargo xtask codegen` from the JSON Schema of
+//! `@octokit/webhooks-schemas`. Don't edit this file; change the generator in `xtask/` instead.
+";
+
+const IMPORTS: &str = "\
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+";
+
+const KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate",
+    "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "if", "impl", "in",
+    "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+    "return", "self", "static", "struct", "super", "trait", "true", "try", "type", "typeof",
+    "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+/// The source of a module with a type for every definition in `schema`.
+pub fn generate(schema: &Value) -> Result<String, String> {
+    let definitions = schema
+        .get("definitions")
+        .and_then(Value::as_object)
+        .ok_or("the schema has no `definitions`")?;
+    let mut generator = Generator {
+        definitions,
+        items: BTreeMap::new(),
+        defined: BTreeMap::new(),
+        in_progress: BTreeSet::new(),
+        uses_map: false,
+    };
+    for name in definitions.keys() {
+        generator.definition(name)?;
+    }
+
+    let mut out = String::from(HEADER);
+    if generator.uses_map {
+        out.push_str("use alloc::collections::BTreeMap;\n\n");
+    }
+    out.push_str(IMPORTS);
+    for item in generator.items.values() {
+        out.push('\n');
+        out.push_str(item);
+    }
+    Ok(out)
+}
+
+struct Generator<'a> {
+    definitions: &'a Map<String, Value>,
+    /// The source of each struct, by name.
+    items: BTreeMap<String, String>,
+    /// The struct generated for each object definition.
+    defined: BTreeMap<&'a str, String>,
+    /// The definitions being generated, which have to be boxed where they contain themselves.
+    in_progress: BTreeSet<&'a str>,
+    uses_map: bool,
+}
+
+impl<'a> Generator<'a> {
+    /// The type of the definition `name`, generating it if it is an object.
+    fn definition(&mut self, name: &'a str) -> Result<String, String> {
+        let schema = self
+            .definitions
+            .get(name)
+            .ok_or_else(|| format!("no definition `{}`", name))?;
+        if !is_object(schema) {
+            return self.type_of(schema, &type_name(name));
+        }
+        if let Some(ty) = self.defined.get(name) {
+            return Ok(ty.clone());
+        }
+        if self.in_progress.contains(name) {
+            return Ok(format!("Box<{}>", type_name(name)));
+        }
+        self.in_progress.insert(name);
+        let ty = self.object(&type_name(name), schema)?;
+        self.in_progress.remove(name);
+        self.defined.insert(name, ty.clone());
+        Ok(ty)
+    }
+
+    /// The Rust type of `schema`, where `context` names any struct it needs.
+    fn type_of(&mut self, schema: &'a Value, context: &str) -> Result<String, String> {
+        if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+            let name = reference
+                .strip_prefix("#/definitions/")
+                .ok_or_else(|| format!("unsupported $ref `{}`", reference))?;
+            return self.definition(name);
+        }
+        for key in &["anyOf", "oneOf"] {
+            if let Some(variants) = schema.get(*key).and_then(Value::as_array) {
+                let others: Vec<_> = variants.iter().filter(|v| !is_null(v)).collect();
+                return match others[..] {
+                    [only] => {
+                        let ty = self.type_of(only, context)?;
+                        Ok(if others.len() < variants.len() {
+                            option(&ty)
+                        } else {
+                            ty
+                        })
+                    }
+                    _ => Ok(VALUE.to_owned()),
+                };
+            }
+        }
+
+        let (types, nullable) = match schema.get("type") {
+            Some(Value::String(ty)) => (vec![ty.as_str()], false),
+            Some(Value::Array(types)) => {
+                let types: Vec<_> = types.iter().filter_map(Value::as_str).collect();
+                let others: Vec<_> = types.iter().copied().filter(|t| *t != "null").collect();
+                let nullable = others.len() < types.len();
+                (others, nullable)
+            }
+            _ => (vec![], false),
+        };
+        let ty = if is_object(schema) {
+            self.object(context, schema)?
+        } else {
+            match types[..] {
+                ["string"] => "String".to_owned(),
+                ["integer"] => "i64".to_owned(),
+                ["number"] => "f64".to_owned(),
+                ["boolean"] => "bool".to_owned(),
+                ["array"] => match schema.get("items") {
+                    Some(items) => vec(&self.type_of(items, &format!("{}Item", context))?),
+                    None => vec(VALUE),
+                },
+                ["object"] => match schema.get("additionalProperties") {
+                    Some(values) if values.is_object() => {
+                        let ty = map(&self.type_of(values, &format!("{}Value", context))?);
+                        self.uses_map |= ty.starts_with("BTreeMap");
+                        ty
+                    }
+                    _ => VALUE.to_owned(),
+                },
+                _ => VALUE.to_owned(),
+            }
+        };
+        Ok(if nullable { option(&ty) } else { ty })
+    }
+
+    /// Generate a struct for an object schema, returning its name.
+    fn object(&mut self, name: &str, schema: &'a Value) -> Result<String, String> {
+        let base = name;
+        let mut name = base.to_owned();
+        for suffix in 2.. {
+            if !self.items.contains_key(&name) {
+                break;
+            }
+            name = format!("{}{}", base, suffix);
+        }
+        // Claim the name before generating the fields, which may generate other structs.
+        self.items.insert(name.clone(), String::new());
+
+        let mut properties = Vec::new();
+        let mut required = BTreeSet::new();
+        self.properties(schema, &mut properties, &mut required)?;
+
+        let mut fields = String::new();
+        for (key, field) in properties {
+            let mut ty = self.type_of(field, &format!("{}{}", name, type_name(key)))?;
+            let optional = !required.contains(key.as_str());
+            if optional {
+                ty = option(&ty);
+            }
+            docs(field, "    ", &mut fields);
+            let ident = field_name(key);
+            if ident != *key {
+                fields.push_str(&format!("    #[serde(rename = \"{}\")]\n", key));
+            }
+            if optional && ty == VALUE {
+                fields.push_str("    #[serde(default)]\n");
+            }
+            if let Some(with) = fuzz_with(&ty) {
+                fields.push_str(&format!(
+                    "    #[cfg_attr(feature = \"fuzz\", arbitrary(with = crate::fuzz::{}))]\n",
+                    with
+                ));
+            }
+            fields.push_str(&format!("    pub {}: {},\n", ident, ty));
+        }
+
+        let mut item = String::new();
+        docs(schema, "", &mut item);
+        item.push_str("#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]\n");
+        item.push_str("#[cfg_attr(feature = \"fuzz\", derive(arbitrary::Arbitrary))]\n");
+        item.push_str("#[non_exhaustive]\n");
+        item.push_str(&format!("pub struct {} {{\n{}}}\n", name, fields));
+        self.items.insert(name.clone(), item);
+        Ok(name)
+    }
+
+    /// The properties of an object schema, including those it has through `allOf`.
+    fn properties(
+        &self,
+        schema: &'a Value,
+        properties: &mut Vec<(&'a String, &'a Value)>,
+        required: &mut BTreeSet<&'a str>,
+    ) -> Result<(), String> {
+        if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+            let definition = reference
+                .strip_prefix("#/definitions/")
+                .and_then(|name| self.definitions.get(name))
+                .ok_or_else(|| format!("unsupported $ref `{}`", reference))?;
+            return self.properties(definition, properties, required);
+        }
+        for part in schema
+            .get("allOf")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+        {
+            self.properties(part, properties, required)?;
+        }
+        for (key, field) in schema
+            .get("properties")
+            .and_then(Value::as_object)
+            .into_iter()
+            .flatten()
+        {
+            match properties.iter_mut().find(|(existing, _)| *existing == key) {
+                // A later part of an `allOf` that only narrows a property, e.g. to one `enum`
+                // value, keeps its type.
+                Some(existing) if is_typed(field) => existing.1 = field,
+                Some(_) => {}
+                None => properties.push((key, field)),
+            }
+        }
+        let names = schema
+            .get("required")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten();
+        required.extend(names.filter_map(Value::as_str));
+        Ok(())
+    }
+}
+
+fn is_object(schema: &Value) -> bool {
+    schema.get("properties").is_some() || schema.get("allOf").is_some()
+}
+
+fn is_null(schema: &Value) -> bool {
+    schema.get("type").and_then(Value::as_str) == Some("null")
+}
+
+fn is_typed(schema: &Value) -> bool {
+    ["type", "$ref", "anyOf", "oneOf", "allOf", "properties"]
+        .iter()
+        .any(|key| schema.get(*key).is_some())
+}
+
+/// `Option<ty>`, or `serde_json::Value` where `arbitrary` couldn't generate that.
+fn option(ty: &str) -> String {
+    if ty.starts_with("Option<") {
+        ty.to_owned()
+    } else if ty != VALUE && ty.contains(VALUE) {
+        VALUE.to_owned()
+    } else {
+        format!("Option<{}>", ty)
+    }
+}
+
+/// `Vec<ty>`, or `serde_json::Value` where `arbitrary` couldn't generate that.
+fn vec(ty: &str) -> String {
+    if ty != VALUE && ty.contains(VALUE) {
+        VALUE.to_owned()
+    } else {
+        format!("Vec<{}>", ty)
+    }
+}
+
+/// `BTreeMap<String, ty>`, or `serde_json::Value` where `arbitrary` couldn't generate that.
+fn map(ty: &str) -> String {
+    if ty.contains(VALUE) {
+        VALUE.to_owned()
+    } else {
+        format!("BTreeMap<String, {}>", ty)
+    }
+}
+
+/// The `crate::fuzz` function generating a field of type `ty`.
+fn fuzz_with(ty: &str) -> Option<&'static str> {
+    match ty {
+        "serde_json::Value" => Some("value"),
+        "Vec<serde_json::Value>" => Some("values"),
+        "Option<serde_json::Value>" => Some("option_value"),
+        _ => None,
+    }
+}
+
+/// Append the description (or else title) of `schema`, and the values of its `enum`, as docs.
+fn docs(schema: &Value, indent: &str, out: &mut String) {
+    let text = schema
+        .get("description")
+        .or_else(|| schema.get("title"))
+        .and_then(Value::as_str)
+        .unwrap_or("");
+    let mut lines: Vec<String> = text.lines().map(str::trim_end).map(String::from).collect();
+    if let Some(values) = schema.get("enum").and_then(Value::as_array) {
+        let values: Vec<_> = values.iter().map(|v| format!("`{}`", v)).collect();
+        if !lines.is_empty() {
+            lines.push(String::new());
+        }
+        lines.push(format!("One of {}.", values.join(", ")));
+    }
+    for line in lines {
+        if line.is_empty() {
+            out.push_str(&format!("{}///\n", indent));
+        } else {
+            out.push_str(&format!("{}/// {}\n", indent, line));
+        }
+    }
+}
+
+/// `issues$opened` as `IssuesOpened`.
+fn type_name(name: &str) -> String {
+    let name: String = name
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .flat_map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase())
+                .into_iter()
+                .chain(chars)
+        })
+        .collect();
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("T{}", name)
+    } else {
+        name
+    }
+}
+
+/// The snake case identifier for a JSON key, e.g. `type_field` for `type`.
+fn field_name(key: &str) -> String {
+    let mut name = match key {
+        "+1" => "plus_one".to_owned(),
+        "-1" => "minus_one".to_owned(),
+        _ => {
+            let mut name = String::new();
+            for c in key.chars() {
+                if c.is_ascii_uppercase() {
+                    if !name.is_empty() && !name.ends_with('_') {
+                        name.push('_');
+                    }
+                    name.push(c.to_ascii_lowercase());
+                } else if c.is_ascii_alphanumeric() {
+                    name.push(c);
+                } else {
+                    name.push('_');
+                }
+            }
+            name
+        }
+    };
+    if KEYWORDS.contains(&name.as_str()) {
+        name.push_str("_field");
+    }
+    if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+        name.insert(0, '_');
+    }
+    name
+}
@@ -0,0 +1,86 @@
+//! Development tasks, run with `cargo xtask <task>`.
+use std::path::Path;
+use std::process::{self, Command};
+use std::{env, fs};
+
+mod codegen;
+
+const USAGE: &str = "\
+usage: cargo xtask <task>
+
+tasks:
+    codegen <schema.json> [out.rs]
+        Generate payload types from the JSON Schema of @octokit/webhooks-schemas
+        into `out.rs` (default: src/generated.rs).
+    wasm
+        Check that the crate builds for wasm32-unknown-unknown, with the `wasm` feature and
+        every other feature that doesn't need a native target.
+    no-std
+        Check that the crate builds without `std`, for thumbv7em-none-eabihf, with and without
+        the features that don't need `std`.";
+
+/// The features that build for wasm32-unknown-unknown. Not `simd-json`, whose hash maps seed
+/// themselves from `getrandom`, which has no source of randomness there.
+const WASM_FEATURES: &str =
+    "wasm,full,intern,slim,debug-full,ghes,fuzz,schemars,avro,cloudevents,slack,discord,signature,compression";
+
+/// The features that build without `std`.
+const NO_STD_FEATURES: &str = "full,slim,debug-full,ghes";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let result = match args.iter().map(String::as_str).collect::<Vec<_>>()[..] {
+        ["codegen", schema] => run_codegen(schema, "src/generated.rs"),
+        ["codegen", schema, out] => run_codegen(schema, out),
+        ["wasm"] => check_target("wasm32-unknown-unknown", &["wasm", WASM_FEATURES], true),
+        ["no-std"] => check_target("thumbv7em-none-eabihf", &["", NO_STD_FEATURES], false),
+        _ => {
+            eprintln!("{}", USAGE);
+            process::exit(2);
+        }
+    };
+    if let Err(e) = result {
+        eprintln!("error: {}", e);
+        process::exit(1);
+    }
+}
+
+fn run_codegen(schema: &str, out: &str) -> Result<(), String> {
+    let text = fs::read_to_string(schema).map_err(|e| format!("{}: {}", schema, e))?;
+    let schema = serde_json::from_str(&text).map_err(|e| format!("{}: {}", schema, e))?;
+    let source = codegen::generate(&schema)?;
+    fs::write(out, source).map_err(|e| format!("{}: {}", out, e))?;
+    // Formatting is a nicety; the output is valid without it.
+    let _ = Command::new("rustfmt")
+        .args(["--edition", "2018"])
+        .arg(Path::new(out))
+        .status();
+    println!("wrote {}", out);
+    Ok(())
+}
+
+/// `cargo check` the crate for `target` with each of `feature_sets`, on top of the default
+/// features if `default_features`.
+fn check_target(target: &str, feature_sets: &[&str], default_features: bool) -> Result<(), String> {
+    let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".to_owned());
+    for features in feature_sets {
+        let mut command = Command::new(&cargo);
+        command.args(["check", "--package", "github-events"]).args([
+            "--target",
+            target,
+            "--features",
+            features,
+        ]);
+        if !default_features {
+            command.arg("--no-default-features");
+        }
+        let status = command.status().map_err(|e| format!("cargo: {}", e))?;
+        if !status.success() {
+            return Err(format!(
+                "cargo check --target {} --features {:?} failed",
+                target, features
+            ));
+        }
+    }
+    Ok(())
+}
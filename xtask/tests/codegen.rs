@@ -0,0 +1,104 @@
+use std::fs;
+use std::process::Command;
+
+use serde_json::json;
+
+fn codegen(schema: serde_json::Value) -> String {
+    let dir = std::env::temp_dir().join(format!("xtask-codegen-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let (input, output) = (dir.join("schema.json"), dir.join("generated.rs"));
+    fs::write(&input, schema.to_string()).unwrap();
+    let status = Command::new(env!("CARGO_BIN_EXE_xtask"))
+        .arg("codegen")
+        .arg(&input)
+        .arg(&output)
+        .status()
+        .unwrap();
+    assert!(status.success());
+    let source = fs::read_to_string(&output).unwrap();
+    fs::remove_dir_all(&dir).unwrap();
+    source
+}
+
+#[test]
+fn generates_structs() {
+    let source = codegen(json!({
+        "definitions": {
+            "issues$opened": {
+                "title": "issues opened event",
+                "type": "object",
+                "required": ["action", "issue"],
+                "properties": {
+                    "action": { "type": "string", "enum": ["opened"] },
+                    "issue": {
+                        "allOf": [
+                            { "$ref": "#/definitions/issue" },
+                            { "type": "object", "properties": { "state": { "enum": ["open"] } } }
+                        ]
+                    },
+                    "changes": { "type": "object" },
+                    "assets": { "type": "array" }
+                }
+            },
+            "issue": {
+                "type": "object",
+                "required": ["number", "state", "labels"],
+                "properties": {
+                    "number": { "type": "integer" },
+                    "state": { "type": "string", "description": "State of the issue." },
+                    "type": { "type": ["string", "null"] },
+                    "labels": { "type": "array", "items": { "$ref": "#/definitions/label" } },
+                    "reactions": {
+                        "type": "object",
+                        "properties": { "+1": { "type": "integer" } },
+                        "required": ["+1"]
+                    },
+                    "parent": { "$ref": "#/definitions/issue" }
+                }
+            },
+            "label": {
+                "type": "object",
+                "required": ["name"],
+                "properties": { "name": { "type": "string" }, "isDefault": { "type": "boolean" } }
+            }
+        }
+    }));
+
+    for expected in &[
+        "use serde::{Deserialize, Serialize};\n",
+        "/// issues opened event\n",
+        "#[non_exhaustive]\npub struct IssuesOpened {",
+        "/// One of `\"opened\"`.\n    pub action: String,",
+        "pub issue: IssuesOpenedIssue,",
+        "crate::fuzz::option_value))]\n    pub changes: Option<serde_json::Value>,",
+        "#[serde(default)]\n    #[cfg_attr(feature = \"fuzz\", arbitrary(with = crate::fuzz::value))]\n    pub assets: serde_json::Value,",
+        "pub struct IssuesOpenedIssue {",
+        "/// State of the issue.\n    pub state: String,",
+        "pub struct Issue {",
+        "#[serde(rename = \"type\")]\n    pub type_field: Option<String>,",
+        "pub labels: Vec<Label>,",
+        "pub reactions: Option<IssueReactions>,",
+        "pub parent: Option<Box<Issue>>,",
+        "#[serde(rename = \"+1\")]\n    pub plus_one: i64,",
+        "#[serde(rename = \"isDefault\")]\n    pub is_default: Option<bool>,",
+    ] {
+        assert!(source.contains(expected), "{:?} in\n{}", expected, source);
+    }
+}
+
+#[test]
+fn rejects_schemas_without_definitions() {
+    let dir = std::env::temp_dir().join(format!("xtask-codegen-bad-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let input = dir.join("schema.json");
+    fs::write(&input, "{}").unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_xtask"))
+        .arg("codegen")
+        .arg(&input)
+        .arg(dir.join("generated.rs"))
+        .output()
+        .unwrap();
+    fs::remove_dir_all(&dir).unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("no `definitions`"));
+}